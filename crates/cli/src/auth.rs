@@ -4,9 +4,15 @@
 
 use {
     serde::{Deserialize, Serialize},
-    std::path::PathBuf,
+    std::{path::PathBuf, sync::RwLock},
 };
 
+/// What a masked credential is replaced with in logs and the API errors popup
+pub const REDACTED: &str = "***";
+
+/// Credential values from every config loaded or saved this session, masked in logs
+static SECRET_VALUES: RwLock<Vec<String>> = RwLock::new(Vec::new());
+
 /// Authentication credentials for Polymarket API
 #[allow(dead_code)]
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -59,8 +65,11 @@ impl AuthConfig {
         }
 
         match std::fs::read_to_string(&path) {
-            Ok(content) => match serde_json::from_str(&content) {
-                Ok(config) => Some(config),
+            Ok(content) => match serde_json::from_str::<Self>(&content) {
+                Ok(config) => {
+                    config.register_secrets();
+                    Some(config)
+                },
                 Err(e) => {
                     eprintln!("Failed to parse auth config: {}", e);
                     None
@@ -75,6 +84,7 @@ impl AuthConfig {
 
     /// Save auth config to file
     pub fn save(&self) -> Result<(), String> {
+        self.register_secrets();
         let dir = Self::config_dir();
         if !dir.exists() {
             std::fs::create_dir_all(&dir)
@@ -91,6 +101,17 @@ impl AuthConfig {
         Ok(())
     }
 
+    /// Remember the secret, passphrase and session cookies so `redact_credentials` masks them
+    pub fn register_secrets(&self) {
+        let values = [
+            Some(&self.secret),
+            Some(&self.passphrase),
+            self.session_cookie.as_ref(),
+            self.session_nonce.as_ref(),
+        ];
+        register_secret_values(values.into_iter().flatten().map(String::as_str));
+    }
+
     /// Delete auth config file (logout)
     pub fn delete() -> Result<(), String> {
         let path = Self::config_path();
@@ -153,3 +174,40 @@ impl AuthConfig {
             .unwrap_or_else(|| self.short_address())
     }
 }
+
+/// Add credential values to the set masked by `redact_credentials`
+fn register_secret_values<'a>(values: impl IntoIterator<Item = &'a str>) {
+    let mut registered = SECRET_VALUES.write().unwrap_or_else(|e| e.into_inner());
+    for value in values.into_iter().map(str::trim) {
+        if !value.is_empty() && !registered.iter().any(|v| v == value) {
+            registered.push(value.to_string());
+        }
+    }
+}
+
+/// Register the `secret`/`passphrase` environment variables read by `ClobClient::from_env`,
+/// once at startup, so `redact_credentials` masks them too
+pub fn register_env_secrets() {
+    let values: Vec<String> = ["secret", "passphrase"]
+        .into_iter()
+        .filter_map(|name| std::env::var(name).ok())
+        .collect();
+    register_secret_values(values.iter().map(String::as_str));
+}
+
+/// Replace every registered credential value with [`REDACTED`] wherever it appears in `text`
+pub fn redact_credentials(text: &str) -> String {
+    let registered = SECRET_VALUES.read().unwrap_or_else(|e| e.into_inner());
+    redact_values(text, &registered)
+}
+
+/// Replace each of `values` with [`REDACTED`] wherever it appears in `text`
+pub fn redact_values(text: &str, values: &[String]) -> String {
+    values
+        .iter()
+        .map(|value| value.trim())
+        .filter(|value| !value.is_empty())
+        .fold(text.to_string(), |text, value| {
+            text.replace(value, REDACTED)
+        })
+}
@@ -40,6 +40,42 @@ macro_rules! log_info {
     ($($arg:tt)*) => {};
 }
 
+/// Apply the API base URLs from `endpoints.json` in the config directory, if present.
+/// A malformed file stops startup rather than silently using the default endpoints.
+fn configure_endpoints() -> Result<()> {
+    let path = auth::AuthConfig::config_dir().join("endpoints.json");
+    let Ok(content) = std::fs::read_to_string(&path) else {
+        return Ok(());
+    };
+    let config = serde_json::from_str(&content)
+        .with_context(|| format!("Failed to parse {}", path.display()))?;
+    polymarket_api::endpoints::configure(config);
+    Ok(())
+}
+
+/// Log the effective API endpoints, so an active override is obvious
+fn log_endpoints() {
+    let endpoints = polymarket_api::Endpoints::from_env();
+    for (_name, _endpoint) in [
+        ("Gamma API", &endpoints.gamma_api),
+        ("Data API", &endpoints.data_api),
+        ("CLOB API", &endpoints.clob_api),
+        ("CLOB WebSocket", &endpoints.clob_ws),
+        ("RTDS WebSocket", &endpoints.rtds_ws),
+    ] {
+        log_info!(
+            "{}: {}{}",
+            _name,
+            _endpoint.url,
+            if _endpoint.overridden {
+                " (override)"
+            } else {
+                ""
+            }
+        );
+    }
+}
+
 #[derive(Parser)]
 #[command(name = "polymarket-tui")]
 #[command(about = "Polymarket TUI for browsing and monitoring prediction markets", long_about = None)]
@@ -116,15 +152,20 @@ enum Commands {
     },
     /// Browse trending events in TUI (requires --features tui)
     Trending {
-        /// Order by field (e.g., volume24hr, volume7d, volume30d)
-        #[arg(long, default_value = "volume24hr")]
-        order_by: String,
+        /// Order by field (e.g., volume24hr, volume7d, volume30d) [default: volume24hr, or the
+        /// POLYMARKET_TUI_TAB tab's order]
+        #[arg(long)]
+        order_by: Option<String>,
         /// Sort ascending instead of descending
         #[arg(long)]
         ascending: bool,
-        /// Limit number of events
-        #[arg(long, default_value = "50")]
-        limit: usize,
+        /// Limit number of events [default: POLYMARKET_TUI_LIMIT, or 50]
+        #[arg(long)]
+        limit: Option<usize>,
+        /// Maximum live trades kept per event (higher keeps more history, uses more memory).
+        /// Defaults to POLYMARKET_MAX_TRADES, or 500
+        #[arg(long)]
+        max_trades: Option<usize>,
     },
     /// Find high-probability markets for yield opportunities
     Yield {
@@ -449,6 +490,10 @@ async fn run_watch_event_tui(event_slug: String) -> Result<()> {
 #[tokio::main]
 async fn main() -> Result<()> {
     let cli = Cli::parse();
+    // Before any client is created, since clients resolve their base URLs when built
+    configure_endpoints()?;
+    // Env credentials never change while running, so register them for log redaction once
+    auth::register_env_secrets();
 
     // Check if we're running a TUI command (None = default TUI, or explicit Trending)
     let _is_tui_command = matches!(
@@ -467,11 +512,12 @@ async fn main() -> Result<()> {
             )
             .with_ansi(true)
             .init();
+        log_endpoints();
     }
 
     match cli.command {
         // Default to TUI when no command is provided
-        None => run_trending("volume24hr".to_string(), false, 50).await,
+        None => run_trending(None, false, None, None).await,
         Some(Commands::Monitor { rtds, event }) => run_monitor(rtds, event).await,
         Some(Commands::WatchEvent { event, tui }) => run_watch_event(event, tui).await,
         Some(Commands::Orderbook { market, asset }) => run_orderbook(market, asset).await,
@@ -488,7 +534,8 @@ async fn main() -> Result<()> {
             order_by,
             ascending,
             limit,
-        }) => run_trending(order_by, ascending, limit).await,
+            max_trades,
+        }) => run_trending(order_by, ascending, limit, max_trades).await,
         Some(Commands::Yield {
             min_prob,
             max_prob,
@@ -501,7 +548,12 @@ async fn main() -> Result<()> {
 }
 
 #[cfg(feature = "tui")]
-async fn run_trending(order_by: String, ascending: bool, limit: usize) -> Result<()> {
+async fn run_trending(
+    order_by: Option<String>,
+    ascending: bool,
+    limit: Option<usize>,
+    max_trades: Option<usize>,
+) -> Result<()> {
     use {
         crossterm::{
             event::{DisableMouseCapture, EnableMouseCapture},
@@ -536,22 +588,50 @@ async fn run_trending(order_by: String, ascending: bool, limit: usize) -> Result
             .with(log_layer)
             .init();
     }
+    log_endpoints();
+
+    // Command-line flags take precedence over the startup environment variables
+    let startup = trending_tui::state::StartupPrefs::from_env();
+    let limit = limit.or(startup.limit).unwrap_or(50);
+    let (order_by, ascending) = match (order_by, startup.tab.and_then(|(_, filter)| filter)) {
+        (Some(order_by), _) => (order_by, ascending),
+        (None, Some(filter)) => (
+            filter.order_by().to_string(),
+            ascending || filter.ascending(),
+        ),
+        (None, None) => ("volume24hr".to_string(), ascending),
+    };
 
     log_info!("🔥 Fetching trending events...");
 
     let gamma_client = GammaClient::new();
     // For trending events, we want descending order by default (highest volume first)
     // The API's ascending=false means descending (highest first), which is what we want for trending
-    let events = gamma_client
-        .get_trending_events(Some(&order_by), Some(ascending), Some(limit))
-        .await
-        .context("Failed to fetch trending events")?;
-
-    if events.is_empty() {
-        anyhow::bail!("No trending events found");
-    }
-
-    log_info!("Found {} trending events", events.len());
+    // A failed fetch still opens the TUI, which shows the error with a retry hint
+    // The same order the Closing Soon tab requests also skips events past their end date
+    let closing_soon = trending_tui::state::EventFilter::ClosingSoon;
+    let result = if order_by == closing_soon.order_by() && ascending {
+        gamma_client.get_closing_soon_events(Some(limit)).await
+    } else {
+        gamma_client
+            .get_trending_events(Some(&order_by), Some(ascending), Some(limit))
+            .await
+    };
+    let (events, load_error) = match result {
+        Ok(events) => {
+            // An empty list still opens the TUI, which explains it and lets the user refresh
+            if events.is_empty() {
+                log_info!("No trending events found");
+            } else {
+                log_info!("Found {} trending events", events.len());
+            }
+            (events, None)
+        },
+        Err(e) => {
+            log_info!("Failed to fetch trending events: {}", e);
+            (Vec::new(), Some(e))
+        },
+    };
 
     // Setup terminal
     enable_raw_mode()?;
@@ -567,12 +647,24 @@ async fn run_trending(order_by: String, ascending: bool, limit: usize) -> Result
         log_info!("CLOB API authentication available - trade counts will be fetched from API");
     }
 
-    let app_state = Arc::new(TokioMutex::new(trending_tui::TrendingAppState::new(
-        events,
-        order_by.clone(),
-        ascending,
-        has_clob_auth,
-    )));
+    let mut app =
+        trending_tui::TrendingAppState::new(events, order_by.clone(), ascending, has_clob_auth);
+    if let Some(max_trades) = max_trades {
+        app.trades.max_trades = max_trades;
+    }
+    if let Some(e) = load_error {
+        app.record_api_error("Events", &e);
+        app.load_error = Some(e.to_string());
+        // Retries ask for as many events as the failed request did
+        app.pagination.current_limit = limit;
+    }
+    if let Some((main_tab, _)) = startup.tab {
+        app.main_tab = main_tab;
+    }
+    if let Some(sort) = startup.sort {
+        app.set_event_sort(sort);
+    }
+    let app_state = Arc::new(TokioMutex::new(app));
 
     // Connect logs to app state (only when tracing is enabled)
     #[cfg(feature = "tracing")]
@@ -645,7 +737,12 @@ async fn run_trending(order_by: String, ascending: bool, limit: usize) -> Result
 }
 
 #[cfg(not(feature = "tui"))]
-async fn run_trending(_order_by: String, _ascending: bool, _limit: usize) -> Result<()> {
+async fn run_trending(
+    _order_by: Option<String>,
+    _ascending: bool,
+    _limit: Option<usize>,
+    _max_trades: Option<usize>,
+) -> Result<()> {
     anyhow::bail!("Trending command requires building with --features tui flag");
 }
 
@@ -0,0 +1,58 @@
+//! Copy text to the system clipboard using the platform's clipboard command
+
+use std::{
+    io::{self, Write},
+    process::{Command, Stdio},
+};
+
+/// Clipboard commands to try, in order, for the current platform
+fn clipboard_commands() -> &'static [(&'static str, &'static [&'static str])] {
+    #[cfg(target_os = "macos")]
+    {
+        &[("pbcopy", &[])]
+    }
+    #[cfg(target_os = "windows")]
+    {
+        &[("clip", &[])]
+    }
+    #[cfg(not(any(target_os = "macos", target_os = "windows")))]
+    {
+        &[
+            ("wl-copy", &[]),
+            ("xclip", &["-selection", "clipboard"]),
+            ("xsel", &["--clipboard", "--input"]),
+        ]
+    }
+}
+
+/// Copy `text` to the clipboard with the first available clipboard command
+pub fn copy_to_clipboard(text: &str) -> io::Result<()> {
+    let mut last_error = None;
+    for (program, args) in clipboard_commands() {
+        let child = Command::new(program)
+            .args(*args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn();
+        let mut child = match child {
+            Ok(child) => child,
+            Err(e) => {
+                last_error = Some(e);
+                continue;
+            },
+        };
+        if let Some(mut stdin) = child.stdin.take() {
+            stdin.write_all(text.as_bytes())?;
+        }
+        let status = child.wait()?;
+        if status.success() {
+            return Ok(());
+        }
+        last_error = Some(io::Error::other(format!(
+            "{} exited with {}",
+            program, status
+        )));
+    }
+    Err(last_error.unwrap_or_else(|| io::Error::other("no clipboard command available")))
+}
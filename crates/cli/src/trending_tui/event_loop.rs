@@ -3,43 +3,64 @@
 use {
     super::{
         fetch::{
-            fetch_event_trade_count, fetch_events_for_filter, fetch_market_prices_batch,
-            spawn_fetch_and_toggle_favorite, spawn_fetch_api_status, spawn_fetch_event_for_cache,
-            spawn_fetch_favorites, spawn_fetch_orderbook, spawn_fetch_portfolio,
-            spawn_fetch_user_profile, spawn_filter_fetch, spawn_toggle_favorite, spawn_yield_fetch,
-            spawn_yield_search, switch_filter_tab,
+            fetch_event_trade_count, fetch_events_for_filter, spawn_auto_watches,
+            spawn_cancel_order, spawn_copy_session_summary, spawn_event_search,
+            spawn_event_selection_fetches, spawn_fetch_and_toggle_favorite, spawn_fetch_api_status,
+            spawn_fetch_event_for_cache, spawn_fetch_favorites, spawn_fetch_fee_rate,
+            spawn_fetch_more_events, spawn_fetch_orderbook, spawn_fetch_portfolio,
+            spawn_fetch_user_profile, spawn_filter_fetch, spawn_load_positions,
+            spawn_refresh_event_prices, spawn_refresh_events, spawn_refresh_market_prices,
+            spawn_retry_initial_load, spawn_sync_favorites, spawn_toggle_favorite,
+            spawn_watch_all_visible, spawn_watch_event, spawn_yield_fetch, spawn_yield_search,
+            switch_filter_tab,
         },
-        layout::{calculate_panel_areas, get_panel_at_position},
+        layout::{calculate_panel_areas, calculate_panel_heights, get_panel_at_position},
         logging::{log_error, log_info, log_warn},
-        render::{self, ClickedTab, render, truncate},
+        render::{self, ClickedTab, Theme, render, truncate},
+        snapshot::save_screen_snapshot,
         state::{
-            self, EventFilter, EventTrades, FocusedPanel, MainTab, OutcomeInfo, PopupType,
-            SearchMode, TrendingAppState,
+            self, EventFilter, FocusedPanel, MainTab, OutcomeInfo, PaletteAction, PopupType,
+            RefreshSource, RefreshTimers, SearchMode, TrendingAppState, tab_digit,
         },
     },
     polymarket_api::clob::ClobClient,
     ratatui::{Terminal, backend::CrosstermBackend, layout::Rect},
-    std::{io, sync::Arc},
+    std::{collections::VecDeque, io, sync::Arc},
     tokio::sync::Mutex as TokioMutex,
 };
 
+/// How long a lone digit waits for a move before it switches tabs instead
+const PENDING_COUNT_TIMEOUT: std::time::Duration = std::time::Duration::from_millis(400);
+
 pub async fn run_trending_tui(
     mut terminal: Terminal<CrosstermBackend<io::Stdout>>,
     app_state: Arc<TokioMutex<TrendingAppState>>,
 ) -> anyhow::Result<Option<String>> {
     use {
-        crossterm::event::{self, Event, KeyCode, KeyEventKind, MouseButton, MouseEventKind},
-        polymarket_api::{GammaClient, RTDSClient},
+        crossterm::event::{
+            self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers, MouseButton, MouseEventKind,
+        },
+        polymarket_api::GammaClient,
     };
 
-    let mut search_debounce: Option<tokio::time::Instant> = None;
     let mut yield_search_debounce: Option<tokio::time::Instant> = None;
+    // When the pending vim-style count got its last digit, and keys to handle before reading
+    // the terminal again (the rest of a counted move, or a tab digit that timed out)
+    let mut pending_count_since: Option<tokio::time::Instant> = None;
+    let mut replay_keys: VecDeque<KeyCode> = VecDeque::new();
     let mut last_selected_event_slug: Option<String> = None;
     let mut last_click: Option<(tokio::time::Instant, u16, u16)> = None; // (time, column, row)
     let mut last_status_check: tokio::time::Instant = tokio::time::Instant::now();
     // Track tab and filter changes for orderbook reset
     let mut last_main_tab: Option<MainTab> = None;
     let mut last_event_filter: Option<state::EventFilter> = None;
+    // Track the selected event to save/restore its markets and trades view
+    let mut last_viewed_event_slug: Option<String> = None;
+    // Last price refresh for selected/watched events that are about to close
+    let mut last_closing_refresh: tokio::time::Instant = tokio::time::Instant::now();
+    // Last auto-refresh of each source with its own interval (see the settings popup)
+    let mut refresh_timers = RefreshTimers::new();
+    let dark_theme = Theme::load();
 
     // Load saved auth config on startup
     if let Some(auth_config) = crate::auth::AuthConfig::load() {
@@ -51,6 +72,7 @@ pub async fn run_trending_tui(
             app.auth_state.is_authenticated = true;
             app.auth_state.address = Some(auth_config.address);
             app.auth_state.username = auth_config.username;
+            app.auth_state.has_session_cookie = auth_config.session_cookie.is_some();
             app.has_clob_auth = true;
             log_info!("Loaded saved auth config for {}", short_addr);
         }
@@ -65,6 +87,22 @@ pub async fn run_trending_tui(
 
         // Load favorites in background at startup
         spawn_fetch_favorites(Arc::clone(&app_state));
+    } else if app_state.lock().await.can_load_favorites() {
+        // Logged out, but with local bookmarks to list
+        spawn_fetch_favorites(Arc::clone(&app_state));
+    }
+
+    // Load the data for a Yield or Positions startup tab (POLYMARKET_TUI_TAB)
+    {
+        let mut app = app_state.lock().await;
+        match app.main_tab {
+            MainTab::Yield => {
+                drop(app);
+                spawn_yield_fetch(Arc::clone(&app_state));
+            },
+            MainTab::Positions => spawn_load_positions(&app_state, &mut app, false),
+            _ => {},
+        }
     }
 
     // Fetch trade counts for the initially selected event (if authenticated)
@@ -99,8 +137,7 @@ pub async fn run_trending_tui(
         let app = app_state.lock().await;
         if let Some(event) = app.selected_event() {
             // Get the first non-closed market (same sorting as render_markets)
-            let mut sorted_markets: Vec<_> = event.markets.iter().collect();
-            sorted_markets.sort_by_key(|m| m.closed);
+            let sorted_markets = app.sorted_markets(event);
             let selected_idx = app
                 .orderbook_state
                 .selected_market_index
@@ -126,14 +163,13 @@ pub async fn run_trending_tui(
     // Fetch API status on startup
     spawn_fetch_api_status(Arc::clone(&app_state));
 
-    // Preload data for all filter tabs (Trending, Breaking, New)
+    // Preload every filter tab other than the one loaded at startup
     {
         let app = app_state.lock().await;
         let current_filter = app.event_filter;
         let limit = app.pagination.current_limit;
 
-        // Preload the filter that isn't currently loaded
-        let filters_to_preload: Vec<EventFilter> = [EventFilter::Trending, EventFilter::Breaking]
+        let filters_to_preload: Vec<EventFilter> = EventFilter::ALL
             .into_iter()
             .filter(|f| *f != current_filter)
             .collect();
@@ -146,6 +182,8 @@ pub async fn run_trending_tui(
                 match fetch_events_for_filter(&gamma_client, filter, limit).await {
                     Ok(events) => {
                         let mut app = app_state_clone.lock().await;
+                        // Preloaded tabs are part of the startup load, not newly-listed events
+                        app.trades.auto_watch.seed(&events);
                         // Only cache if not already cached (in case user switched tabs quickly)
                         app.events_cache.entry(filter).or_insert_with(|| {
                             log_info!(
@@ -158,6 +196,7 @@ pub async fn run_trending_tui(
                     },
                     Err(e) => {
                         log_error!("Failed to preload {} filter: {}", filter.label(), e);
+                        app_state_clone.lock().await.record_api_error("Events", &e);
                     },
                 }
             });
@@ -181,6 +220,9 @@ pub async fn run_trending_tui(
 
             if tab_changed || filter_changed {
                 // Tab or filter changed - reset orderbook state and fetch new data
+                app.restore_event_sort();
+                // The new tab's list may have shrunk while it was in the background
+                app.clamp_selection_to_filtered();
                 if last_main_tab.is_some() || last_event_filter.is_some() {
                     app.orderbook_state.reset();
 
@@ -194,8 +236,7 @@ pub async fn run_trending_tui(
                             if current_tab == MainTab::Favorites {
                                 // For favorites, get from favorites_state
                                 app.favorites_state.selected_event().and_then(|event| {
-                                    let mut sorted: Vec<_> = event.markets.iter().collect();
-                                    sorted.sort_by_key(|m| m.closed);
+                                    let sorted = app.sorted_markets(event);
                                     sorted.first().and_then(|market| {
                                         market.clob_token_ids.as_ref().and_then(|ids| {
                                             ids.first().cloned().map(|id| (id, !market.closed))
@@ -205,8 +246,7 @@ pub async fn run_trending_tui(
                             } else {
                                 // For Events/Breaking tabs
                                 app.selected_event().and_then(|event| {
-                                    let mut sorted: Vec<_> = event.markets.iter().collect();
-                                    sorted.sort_by_key(|m| m.closed);
+                                    let sorted = app.sorted_markets(event);
                                     sorted.first().and_then(|market| {
                                         market.clob_token_ids.as_ref().and_then(|ids| {
                                             ids.first().cloned().map(|id| (id, !market.closed))
@@ -226,7 +266,147 @@ pub async fn run_trending_tui(
             }
         }
 
-        // Periodically refresh orderbook data (every 5 seconds) when in Events/Favorites tab
+        // Save the view of the selected event while it stays selected, and restore the
+        // saved view when a different event gets selected
+        {
+            let mut app = app_state.lock().await;
+            let current_slug = app.current_tab_selected_event().map(|e| e.slug.clone());
+            if current_slug != last_viewed_event_slug {
+                // The markets filter was typed for the previous event's markets
+                app.orderbook_state.market_filter.clear();
+                app.orderbook_state.is_filtering_markets = false;
+                if let Some(ref slug) = current_slug
+                    && app.restore_event_view(slug)
+                    && let Some((token_id, is_active)) = app.selected_orderbook_token()
+                {
+                    drop(app);
+                    spawn_fetch_orderbook(Arc::clone(&app_state), token_id, is_active);
+                }
+                last_viewed_event_slug = current_slug;
+            } else if let Some(ref slug) = current_slug {
+                app.save_event_view(slug);
+            }
+        }
+
+        // Refresh faster while the selected or a watched event is near its end date,
+        // reverting to the normal cadence once it is past the resolution window
+        {
+            let mut app = app_state.lock().await;
+            let now = chrono::Utc::now();
+            let selected_interval = app
+                .current_tab_selected_event()
+                .and_then(|event| state::closing_refresh_interval(event, now));
+            let base_interval = app
+                .ui_prefs
+                .refresh_intervals
+                .interval(RefreshSource::Orderbook)
+                .unwrap_or(state::DEFAULT_ORDERBOOK_REFRESH_INTERVAL);
+            let orderbook_interval =
+                selected_interval.map_or(base_interval, |interval| interval.min(base_interval));
+            if app.orderbook_state.refresh_interval != orderbook_interval {
+                if let Some(_slug) = app.current_tab_selected_event().map(|e| &e.slug) {
+                    log_info!(
+                        "Orderbook refresh every {}s for {}",
+                        orderbook_interval.as_secs(),
+                        _slug
+                    );
+                }
+                app.orderbook_state.refresh_interval = orderbook_interval;
+            }
+
+            let mut closing_events = Vec::new();
+            if let Some(event) = app.current_tab_selected_event()
+                && let Some(interval) = selected_interval
+            {
+                closing_events.push((event.clone(), interval));
+            }
+            for slug in app.trades.ws_handles.keys() {
+                if let Some(event) = app.get_cached_event(slug)
+                    && !closing_events.iter().any(|(e, _)| &e.slug == slug)
+                    && let Some(interval) = state::closing_refresh_interval(event, now)
+                {
+                    closing_events.push((event.clone(), interval));
+                }
+            }
+
+            if let Some(interval) = closing_events.iter().map(|(_, i)| *i).min()
+                && last_closing_refresh.elapsed() >= interval
+                && !app.has_popup()
+                && !app.auto_refresh_paused
+            {
+                last_closing_refresh = tokio::time::Instant::now();
+                for (event, _) in &closing_events {
+                    spawn_refresh_event_prices(&app_state, &app, event);
+                }
+            }
+        }
+
+        // Auto-refresh prices, the events list, favorites and the logged-in portfolio on their
+        // configured intervals, unless paused with 'Z'
+        {
+            let mut app = app_state.lock().await;
+            let intervals = app.ui_prefs.refresh_intervals;
+            let idle = !app.has_popup() && !app.auto_refresh_paused;
+            let in_events_tab =
+                app.main_tab == MainTab::Trending || app.main_tab == MainTab::Favorites;
+
+            // `take_due` comes first in each condition so a timer restarts even when its
+            // refresh is skipped
+            if refresh_timers.take_due(RefreshSource::Prices, &intervals)
+                && idle
+                && in_events_tab
+                && let Some(event) = app.current_tab_selected_event()
+            {
+                // Only the markets on screen, not every market of the event
+                let term_size = terminal.size()?;
+                let markets_height = calculate_panel_heights(
+                    Rect::new(0, 0, term_size.width, term_size.height),
+                    app.top_height(),
+                    app.show_logs,
+                    app.main_tab,
+                    app.event_details_height(),
+                    app.orderbook_state.last_height,
+                )
+                .markets;
+                spawn_refresh_market_prices(
+                    &app_state,
+                    &app,
+                    app.visible_markets(event, markets_height),
+                );
+            }
+
+            if refresh_timers.take_due(RefreshSource::Events, &intervals)
+                && idle
+                && app.main_tab == MainTab::Trending
+                && !app.is_in_filter_mode()
+                && !app.pagination.is_fetching_more
+            {
+                spawn_refresh_events(
+                    Arc::clone(&app_state),
+                    app.event_filter,
+                    app.pagination.current_limit,
+                );
+            }
+
+            if refresh_timers.take_due(RefreshSource::Favorites, &intervals)
+                && idle
+                && app.can_load_favorites()
+                && !app.favorites_state.is_loading
+            {
+                spawn_fetch_favorites(Arc::clone(&app_state));
+            }
+
+            // Balance, positions and P&L; stops with the loop on exit and is skipped once
+            // logged out
+            if refresh_timers.take_due(RefreshSource::Portfolio, &intervals)
+                && app.auth_state.is_authenticated
+                && !app.auto_refresh_paused
+            {
+                spawn_load_positions(&app_state, &mut app, true);
+            }
+        }
+
+        // Periodically refresh orderbook data (on its configured interval) when in Events/Favorites tab
         // Skip refresh for closed/inactive markets
         {
             let app = app_state.lock().await;
@@ -236,8 +416,7 @@ pub async fn run_trending_tui(
             // Check if the selected market is active (not closed)
             let market_is_active = if app.main_tab == MainTab::Favorites {
                 app.favorites_state.selected_event().is_some_and(|event| {
-                    let mut sorted_markets: Vec<_> = event.markets.iter().collect();
-                    sorted_markets.sort_by_key(|m| m.closed);
+                    let sorted_markets = app.sorted_markets(event);
                     let idx = app
                         .orderbook_state
                         .selected_market_index
@@ -246,8 +425,7 @@ pub async fn run_trending_tui(
                 })
             } else {
                 app.selected_event().is_some_and(|event| {
-                    let mut sorted_markets: Vec<_> = event.markets.iter().collect();
-                    sorted_markets.sort_by_key(|m| m.closed);
+                    let sorted_markets = app.sorted_markets(event);
                     let idx = app
                         .orderbook_state
                         .selected_market_index
@@ -259,9 +437,11 @@ pub async fn run_trending_tui(
             if in_orderbook_tab
                 && market_is_active
                 && !app.has_popup()
+                && !app.auto_refresh_paused
                 && app.orderbook_state.needs_refresh()
                 && !app.orderbook_state.is_loading
                 && let Some(ref token_id) = app.orderbook_state.token_id
+                && app.orderbook_state.queued_token.as_ref() != Some(token_id)
             {
                 let token_id_clone = token_id.clone();
                 drop(app);
@@ -270,64 +450,20 @@ pub async fn run_trending_tui(
             }
         }
 
-        // Handle search debouncing and API calls
-        // Check debounce timer and trigger search if needed
-        if let Some(debounce_time) = search_debounce {
-            let elapsed = debounce_time.elapsed();
-            if elapsed >= tokio::time::Duration::from_millis(500) {
-                // Debounce period passed, perform search
-                let query = {
-                    let app = app_state.lock().await;
-                    app.search.query.clone()
-                };
-
-                // Clear debounce before processing to prevent race conditions
-                search_debounce = None;
-
-                if !query.is_empty() {
-                    // Search for any non-empty query
-                    log_info!("Searching for: '{}'", query);
-                    let app_state_clone = Arc::clone(&app_state);
-                    let query_clone = query.clone();
-                    // Create a new GammaClient for the async task
-                    let gamma_client_for_task = GammaClient::new();
-
-                    {
-                        let mut app = app_state.lock().await;
-                        app.set_searching(true);
-                    }
+        // Dispatch a debounced API search once typing has paused
+        {
+            let mut app = app_state.lock().await;
+            if let Some(query) = app.search.take_pending_search(false) {
+                drop(app);
+                spawn_event_search(Arc::clone(&app_state), query);
+            }
+        }
 
-                    // Spawn the search task
-                    // The tracing context should be inherited automatically since we're using set_default()
-                    tokio::spawn(async move {
-                        // Test log to verify tracing works in spawned task
-                        log_info!("[TASK] Starting search for: '{}'", query_clone);
-
-                        let result = gamma_client_for_task
-                            .search_events(&query_clone, Some(50))
-                            .await;
-
-                        match result {
-                            Ok(results) => {
-                                log_info!("Search found {} results", results.len());
-                                let mut app = app_state_clone.lock().await;
-                                app.set_search_results(results, query_clone);
-                            },
-                            Err(_e) => {
-                                log_error!("Search failed: {}", _e);
-                                let mut app = app_state_clone.lock().await;
-                                app.set_searching(false);
-                                app.search.results.clear();
-                            },
-                        }
-                    });
-                } else {
-                    // Query is empty, clear search results
-                    let mut app = app_state.lock().await;
-                    app.search.results.clear();
-                    app.search.last_searched_query.clear();
-                    app.set_searching(false);
-                }
+        // Start streams for newly-seen events matching an auto-watch keyword
+        {
+            let mut app = app_state.lock().await;
+            if !app.trades.pending_auto_watch.is_empty() {
+                spawn_auto_watches(&app_state, &mut app);
             }
         }
 
@@ -359,14 +495,48 @@ pub async fn run_trending_tui(
 
         {
             let mut app = app_state.lock().await;
-            terminal.draw(|f| {
-                render(f, &mut app);
+            let theme = Theme::for_preset(app.ui_prefs.theme_preset, &dark_theme);
+            let frame = terminal.draw(|f| {
+                render(f, &mut app, &theme);
             })?;
+
+            // Save the frame that was just drawn, exactly as it appears on screen
+            if app.snapshot_requested {
+                app.snapshot_requested = false;
+                match save_screen_snapshot(frame.buffer) {
+                    Ok(filename) => {
+                        log_info!("Screen snapshot saved to: {}", filename);
+                        app.show_toast(format!("Screen saved to {}", filename));
+                    },
+                    Err(e) => {
+                        log_error!("Failed to save screen snapshot: {}", e);
+                        app.show_toast(format!("Failed to save screen: {}", e));
+                    },
+                }
+            }
         }
 
-        if crossterm::event::poll(std::time::Duration::from_millis(100))? {
-            let event = event::read()?;
+        // A lone digit that no move followed within the timeout was a tab key after all
+        if let Some(since) = pending_count_since
+            && since.elapsed() >= PENDING_COUNT_TIMEOUT
+        {
+            pending_count_since = None;
+            let mut app = app_state.lock().await;
+            if let Some(tab) = app.navigation.take_pending_count().and_then(tab_digit) {
+                replay_keys.push_back(KeyCode::Char(tab));
+            }
+        }
 
+        let replayed = replay_keys.pop_front();
+        let is_replay = replayed.is_some();
+        let next_event = match replayed {
+            Some(code) => Some(Event::Key(KeyEvent::new(code, KeyModifiers::NONE))),
+            None if crossterm::event::poll(std::time::Duration::from_millis(100))? => {
+                Some(event::read()?)
+            },
+            None => None,
+        };
+        if let Some(event) = next_event {
             // Handle mouse events
             if let Event::Mouse(mouse) = &event {
                 // Handle popups first - they block all mouse events to background
@@ -400,9 +570,10 @@ pub async fn run_trending_tui(
                         mouse.column,
                         mouse.row,
                         size,
-                        app.is_in_filter_mode(),
+                        app.top_height(),
                         app.show_logs,
                         app.main_tab,
+                        app.event_details_height(),
                     ) {
                         app.navigation.focused_panel = panel;
                     }
@@ -436,6 +607,10 @@ pub async fn run_trending_tui(
 
                     // Check for trade popup clicks (BUY/SELL in title, outcome tabs inside)
                     if matches!(app.popup, Some(PopupType::Trade)) {
+                        // Clicks while the popup is open return a pending confirmation to editing
+                        if let Some(ref mut form) = app.trade_form {
+                            form.awaiting_confirm = false;
+                        }
                         // Calculate trade popup area (same as render_trade_popup)
                         let popup_area =
                             render::centered_rect_fixed_width(render::TRADE_POPUP_WIDTH, 60, size);
@@ -476,10 +651,10 @@ pub async fn run_trending_tui(
                         {
                             // Calculate tab positions
                             // Tabs start at x+2 (border + padding)
-                            // Each tab is " Name " with 2 spaces between tabs
+                            // Each tab is " Name 45.5¢ " with 2 spaces between tabs
                             let mut tab_x = popup_area.x + 2;
                             for (i, outcome) in form.outcomes.iter().enumerate() {
-                                let tab_width = outcome.name.len() as u16 + 2; // " Name "
+                                let tab_width = outcome.chip_label().chars().count() as u16;
                                 if mouse.column >= tab_x && mouse.column < tab_x + tab_width {
                                     form.select_outcome(i);
                                     break;
@@ -514,6 +689,7 @@ pub async fn run_trending_tui(
                             if mouse.column >= limit_start
                                 && mouse.column < limit_end
                                 && let Some(ref mut form) = app.trade_form
+                                && form.clob_tradeable
                                 && form.order_type != state::OrderType::Limit
                             {
                                 form.order_type = state::OrderType::Limit;
@@ -569,7 +745,7 @@ pub async fn run_trending_tui(
                                     // If switching to Favorites tab and no data loaded, fetch it
                                     if app.favorites_state.events.is_empty()
                                         && !app.favorites_state.is_loading
-                                        && app.auth_state.is_authenticated
+                                        && app.can_load_favorites()
                                     {
                                         drop(app);
                                         spawn_fetch_favorites(Arc::clone(&app_state));
@@ -588,13 +764,45 @@ pub async fn run_trending_tui(
                                     }
                                 }
                             },
+                            ClickedTab::Positions => {
+                                if app.main_tab != MainTab::Positions {
+                                    app.main_tab = MainTab::Positions;
+                                    spawn_load_positions(&app_state, &mut app, false);
+                                }
+                            },
+                            ClickedTab::ClosingSoon => {
+                                if app.main_tab != MainTab::Trending
+                                    || app.event_filter != EventFilter::ClosingSoon
+                                {
+                                    app.main_tab = MainTab::Trending;
+                                    if let Some((filter, limit)) =
+                                        switch_filter_tab(&mut app, EventFilter::ClosingSoon)
+                                    {
+                                        drop(app);
+                                        spawn_filter_fetch(Arc::clone(&app_state), filter, limit);
+                                    }
+                                }
+                            },
+                            ClickedTab::NewlyListed => {
+                                if app.main_tab != MainTab::Trending
+                                    || app.event_filter != EventFilter::NewlyListed
+                                {
+                                    app.main_tab = MainTab::Trending;
+                                    if let Some((filter, limit)) =
+                                        switch_filter_tab(&mut app, EventFilter::NewlyListed)
+                                    {
+                                        drop(app);
+                                        spawn_filter_fetch(Arc::clone(&app_state), filter, limit);
+                                    }
+                                }
+                            },
                         }
                         continue;
                     }
 
                     // Check for orderbook title tab clicks (Yes/No toggle)
-                    // Only for Trending/Breaking/Favorites tabs, not Yield
-                    if app.main_tab != MainTab::Yield {
+                    // Only for Trending/Breaking/Favorites tabs, not Yield/Positions
+                    if matches!(app.main_tab, MainTab::Trending | MainTab::Favorites) {
                         // Calculate orderbook area using same layout as render.rs
                         let header_height: u16 = if app.is_in_filter_mode() {
                             5
@@ -615,8 +823,8 @@ pub async fn run_trending_tui(
                         let right_x = size.width * 40 / 100;
                         let right_width = size.width - right_x;
 
-                        // Orderbook is after: event details (8) + markets (7) = 15 rows from main_area_y
-                        let orderbook_y = main_area_y + 8 + 7;
+                        // Orderbook is after: event details + markets (7) rows from main_area_y
+                        let orderbook_y = main_area_y + app.event_details_height() + 7;
                         let orderbook_height: u16 = 16; // Fixed height as per calculate_orderbook_height
 
                         let orderbook_area =
@@ -625,8 +833,7 @@ pub async fn run_trending_tui(
                         // Get outcome names for the selected market
                         let outcome_names: Option<(String, String)> =
                             app.selected_event().and_then(|event| {
-                                let mut sorted_markets: Vec<_> = event.markets.iter().collect();
-                                sorted_markets.sort_by_key(|m| m.closed);
+                                let sorted_markets = app.sorted_markets(event);
                                 let idx = app
                                     .orderbook_state
                                     .selected_market_index
@@ -661,8 +868,7 @@ pub async fn run_trending_tui(
 
                                 // Fetch orderbook for the new outcome
                                 if let Some(event) = app.selected_event() {
-                                    let mut sorted_markets: Vec<_> = event.markets.iter().collect();
-                                    sorted_markets.sort_by_key(|m| m.closed);
+                                    let sorted_markets = app.sorted_markets(event);
                                     let idx = app
                                         .orderbook_state
                                         .selected_market_index
@@ -694,18 +900,20 @@ pub async fn run_trending_tui(
 
                     let (_, events_list_area, ..) = calculate_panel_areas(
                         size,
-                        app.is_in_filter_mode(),
+                        app.top_height(),
                         app.show_logs,
                         app.main_tab,
+                        app.event_details_height(),
                     );
 
                     if let Some(panel) = get_panel_at_position(
                         mouse.column,
                         mouse.row,
                         size,
-                        app.is_in_filter_mode(),
+                        app.top_height(),
                         app.show_logs,
                         app.main_tab,
+                        app.event_details_height(),
                     ) {
                         // If clicking in events list, select the clicked item
                         if panel == FocusedPanel::EventsList {
@@ -726,16 +934,18 @@ pub async fn run_trending_tui(
                                     as usize;
                                 let clicked_index = app.yield_state.scroll + relative_y;
 
-                                // Determine the total items based on mode
-                                let total_items = if !app.yield_state.search_results.is_empty() {
-                                    app.yield_state.search_results.len()
-                                } else {
-                                    app.yield_state.filtered_opportunities().len()
-                                };
-
-                                if clicked_index < total_items {
+                                if clicked_index < app.yield_state.displayed_count() {
                                     app.yield_state.selected_index = clicked_index;
                                 }
+                            } else if app.main_tab == MainTab::Positions {
+                                // Positions tab: select position
+                                // Account for border (1) + header row (1) = 2
+                                let relative_y =
+                                    mouse.row.saturating_sub(events_list_area.y + 2) as usize;
+                                let clicked_index = app.positions_state.scroll + relative_y;
+                                if clicked_index < app.positions_state.positions.len() {
+                                    app.positions_state.selected_index = clicked_index;
+                                }
                             } else if app.main_tab == MainTab::Favorites {
                                 // Favorites tab: select favorite event
                                 // Account for border (1) = 1
@@ -752,8 +962,7 @@ pub async fn run_trending_tui(
                                     // Fetch orderbook for the first market of the selected favorite event
                                     let orderbook_info: Option<(String, bool)> =
                                         app.favorites_state.selected_event().and_then(|event| {
-                                            let mut sorted: Vec<_> = event.markets.iter().collect();
-                                            sorted.sort_by_key(|m| m.closed);
+                                            let sorted = app.sorted_markets(event);
                                             sorted.first().and_then(|market| {
                                                 market.clob_token_ids.as_ref().and_then(|ids| {
                                                     ids.first()
@@ -782,52 +991,7 @@ pub async fn run_trending_tui(
                                             app.stop_watching(&event_slug);
                                         } else {
                                             // Start watching
-                                            let event_slug_clone = event_slug.clone();
-
-                                            app.trades
-                                                .event_trades
-                                                .entry(event_slug_clone.clone())
-                                                .or_insert_with(EventTrades::new);
-
-                                            let app_state_ws = Arc::clone(&app_state);
-                                            let event_slug_for_closure = event_slug_clone.clone();
-
-                                            let rtds_client = RTDSClient::new()
-                                                .with_event_slug(event_slug_clone.clone());
-
-                                            log_info!(
-                                                "Starting RTDS WebSocket for event: {}",
-                                                event_slug_clone
-                                            );
-
-                                            let ws_handle = tokio::spawn(async move {
-                                                match rtds_client
-                                                    .connect_and_listen(move |msg| {
-                                                        let app_state = Arc::clone(&app_state_ws);
-                                                        let event_slug =
-                                                            event_slug_for_closure.clone();
-
-                                                        tokio::spawn(async move {
-                                                            let mut app = app_state.lock().await;
-                                                            if let Some(event_trades) = app
-                                                                .trades
-                                                                .event_trades
-                                                                .get_mut(&event_slug)
-                                                            {
-                                                                event_trades.add_trade(&msg);
-                                                            }
-                                                        });
-                                                    })
-                                                    .await
-                                                {
-                                                    Ok(()) => {},
-                                                    Err(_e) => {
-                                                        log_error!("RTDS WebSocket error: {}", _e);
-                                                    },
-                                                }
-                                            });
-
-                                            app.start_watching(event_slug_clone, ws_handle);
+                                            spawn_watch_event(&app_state, &mut app, event_slug);
                                         }
                                     }
                                 }
@@ -836,7 +1000,7 @@ pub async fn run_trending_tui(
                                 // Account for border (1) = 1
                                 let relative_y = mouse.row.saturating_sub(events_list_area.y + 1);
                                 let clicked_index = app.scroll.events_list + relative_y as usize;
-                                let filtered_len = app.filtered_events().len();
+                                let filtered_len = app.filtered_events_len();
 
                                 if clicked_index < filtered_len {
                                     app.navigation.selected_index = clicked_index;
@@ -847,8 +1011,7 @@ pub async fn run_trending_tui(
                                     // Fetch orderbook for first market (sorted, non-closed first)
                                     let orderbook_info: Option<(String, bool)> =
                                         app.selected_event().and_then(|event| {
-                                            let mut sorted: Vec<_> = event.markets.iter().collect();
-                                            sorted.sort_by_key(|m| m.closed);
+                                            let sorted = app.sorted_markets(event);
                                             sorted.first().and_then(|market| {
                                                 market.clob_token_ids.as_ref().and_then(|ids| {
                                                     ids.first()
@@ -875,57 +1038,54 @@ pub async fn run_trending_tui(
                                             app.stop_watching(&event_slug);
                                         } else {
                                             // Start watching
-                                            let event_slug_clone = event_slug.clone();
-
-                                            app.trades
-                                                .event_trades
-                                                .entry(event_slug_clone.clone())
-                                                .or_insert_with(EventTrades::new);
-
-                                            let app_state_ws = Arc::clone(&app_state);
-                                            let event_slug_for_closure = event_slug_clone.clone();
-
-                                            let rtds_client = RTDSClient::new()
-                                                .with_event_slug(event_slug_clone.clone());
-
-                                            log_info!(
-                                                "Starting RTDS WebSocket for event: {}",
-                                                event_slug_clone
-                                            );
-
-                                            let ws_handle = tokio::spawn(async move {
-                                                match rtds_client
-                                                    .connect_and_listen(move |msg| {
-                                                        let app_state = Arc::clone(&app_state_ws);
-                                                        let event_slug =
-                                                            event_slug_for_closure.clone();
-
-                                                        tokio::spawn(async move {
-                                                            let mut app = app_state.lock().await;
-                                                            if let Some(event_trades) = app
-                                                                .trades
-                                                                .event_trades
-                                                                .get_mut(&event_slug)
-                                                            {
-                                                                event_trades.add_trade(&msg);
-                                                            }
-                                                        });
-                                                    })
-                                                    .await
-                                                {
-                                                    Ok(()) => {},
-                                                    Err(_e) => {
-                                                        log_error!("RTDS WebSocket error: {}", _e);
-                                                    },
-                                                }
-                                            });
-
-                                            app.start_watching(event_slug_clone, ws_handle);
+                                            spawn_watch_event(&app_state, &mut app, event_slug);
                                         }
                                     }
                                 }
                             }
                         }
+                        // Handle click on a trade row to open the trade popup for its market
+                        if panel == FocusedPanel::Trades
+                            && matches!(app.main_tab, MainTab::Trending | MainTab::Favorites)
+                            && let Some(event) = app.current_tab_selected_event()
+                        {
+                            let (.., trades_area, _) = calculate_panel_areas(
+                                size,
+                                app.top_height(),
+                                app.show_logs,
+                                app.main_tab,
+                                app.event_details_height(),
+                            );
+                            let trades = app.get_trades(&event.slug);
+                            let popup = render::get_clicked_trade_index(
+                                mouse.row,
+                                trades_area,
+                                &app,
+                                trades.len(),
+                            )
+                            .and_then(|index| app.trades.display_order(trades).get(index).copied())
+                            .map(|trade| {
+                                (
+                                    trade.asset_id.clone(),
+                                    app.trade_popup_for_asset(event, &trade.asset_id),
+                                )
+                            });
+                            match popup {
+                                Some((_, Some((question, outcomes, selected_idx)))) => {
+                                    log_info!("Opening trade popup for: {}", question);
+                                    let fee_token = outcomes[selected_idx].token_id.clone();
+                                    app.open_trade_popup(question, outcomes, selected_idx);
+                                    spawn_fetch_fee_rate(Arc::clone(&app_state), fee_token);
+                                },
+                                Some((_asset_id, None)) => {
+                                    log_warn!(
+                                        "No open market for traded asset {}; not opening trade popup",
+                                        _asset_id
+                                    );
+                                },
+                                None => {},
+                            }
+                        }
                         // Handle click on Markets panel to select market or open trade popup
                         if panel == FocusedPanel::Markets
                             && matches!(app.main_tab, MainTab::Trending | MainTab::Favorites)
@@ -944,109 +1104,88 @@ pub async fn run_trending_tui(
                                 app.selected_event().cloned()
                             };
 
-                            let click_action: Option<MarketClickAction> = if let Some(ref event) =
-                                selected_event
-                            {
-                                // Calculate which market row was clicked
-                                let (_, _, _, markets_area, ..) = calculate_panel_areas(
-                                    size,
-                                    app.is_in_filter_mode(),
-                                    app.show_logs,
-                                    app.main_tab,
-                                );
-                                // Account for border (1 line at top)
-                                let relative_y =
-                                    mouse.row.saturating_sub(markets_area.y + 1) as usize;
-                                let clicked_idx = app.scroll.markets + relative_y;
-                                let click_x = mouse.column.saturating_sub(markets_area.x + 1);
-                                let panel_width = markets_area.width.saturating_sub(2); // borders
-
-                                // Sort markets same way as render_markets (non-closed first)
-                                let mut sorted_markets: Vec<_> = event.markets.iter().collect();
-                                sorted_markets.sort_by_key(|m| m.closed);
-
-                                if clicked_idx < sorted_markets.len() {
-                                    let market = sorted_markets[clicked_idx];
-
-                                    // For active markets, check if click is on Yes/No buttons
-                                    if !market.closed {
-                                        // Use fixed column widths (same as render.rs)
-                                        // Button column width = 17 chars each
-                                        const BUTTON_COL_WIDTH: u16 = 17;
-
-                                        // Buttons are at the right edge of the panel with fixed widths
-                                        // Layout: ... [Yes button 17] [No button 17]
-                                        let no_button_start =
-                                            panel_width.saturating_sub(BUTTON_COL_WIDTH);
-                                        let yes_button_start = no_button_start
-                                            .saturating_sub(1)
-                                            .saturating_sub(BUTTON_COL_WIDTH);
-
-                                        // Build outcome info for all outcomes
-                                        let build_outcomes = || -> Vec<OutcomeInfo> {
-                                            let mut outcomes = Vec::new();
-                                            if let Some(ref token_ids) = market.clob_token_ids {
-                                                for (i, token_id) in token_ids.iter().enumerate() {
-                                                    let name = market
-                                                        .outcomes
-                                                        .get(i)
-                                                        .cloned()
-                                                        .unwrap_or_else(|| {
-                                                            if i == 0 {
-                                                                "Yes".to_string()
-                                                            } else {
-                                                                "No".to_string()
-                                                            }
-                                                        });
-                                                    let price = app
-                                                        .market_prices
-                                                        .get(token_id)
-                                                        .copied()
-                                                        .or_else(|| {
-                                                            market
-                                                                .outcome_prices
-                                                                .get(i)
-                                                                .and_then(|p| p.parse::<f64>().ok())
-                                                        })
-                                                        .unwrap_or(0.5);
-                                                    outcomes.push(OutcomeInfo {
-                                                        name,
-                                                        token_id: token_id.clone(),
-                                                        price,
-                                                    });
+                            let click_action: Option<MarketClickAction> =
+                                if let Some(ref event) = selected_event {
+                                    // Calculate which market row was clicked
+                                    let (_, _, _, markets_area, ..) = calculate_panel_areas(
+                                        size,
+                                        app.top_height(),
+                                        app.show_logs,
+                                        app.main_tab,
+                                        app.event_details_height(),
+                                    );
+                                    // Account for border (1 line at top)
+                                    let relative_y =
+                                        mouse.row.saturating_sub(markets_area.y + 1) as usize;
+                                    let clicked_idx = app.scroll.markets + relative_y;
+                                    let click_x = mouse.column.saturating_sub(markets_area.x + 1);
+                                    let panel_width = markets_area.width.saturating_sub(2); // borders
+
+                                    // Sort markets same way as render_markets (non-closed first)
+                                    let sorted_markets = app.sorted_markets(event);
+
+                                    if clicked_idx < sorted_markets.len() {
+                                        let market = sorted_markets[clicked_idx];
+
+                                        // For active markets, check if click is on Yes/No buttons
+                                        if !market.closed {
+                                            // Use fixed column widths (same as render.rs)
+                                            // Button column width = 17 chars each
+                                            const BUTTON_COL_WIDTH: u16 = 17;
+
+                                            // Buttons are at the right edge of the panel with fixed widths
+                                            // Layout: ... [Yes button 17] [No button 17]
+                                            let no_button_start =
+                                                panel_width.saturating_sub(BUTTON_COL_WIDTH);
+                                            let yes_button_start = no_button_start
+                                                .saturating_sub(1)
+                                                .saturating_sub(BUTTON_COL_WIDTH);
+
+                                            if click_x >= no_button_start {
+                                                // Clicked on No button (index 1)
+                                                let outcomes = app.market_outcomes(market);
+                                                if outcomes.len() > 1 {
+                                                    Some(MarketClickAction::OpenTrade(
+                                                        market.question.clone(),
+                                                        outcomes,
+                                                        1, // No is at index 1
+                                                    ))
+                                                } else {
+                                                    None
+                                                }
+                                            } else if click_x >= yes_button_start
+                                                && click_x < no_button_start
+                                            {
+                                                // Clicked on Yes button (index 0)
+                                                let outcomes = app.market_outcomes(market);
+                                                if !outcomes.is_empty() {
+                                                    Some(MarketClickAction::OpenTrade(
+                                                        market.question.clone(),
+                                                        outcomes,
+                                                        0, // Yes is at index 0
+                                                    ))
+                                                } else {
+                                                    None
                                                 }
-                                            }
-                                            outcomes
-                                        };
-
-                                        if click_x >= no_button_start {
-                                            // Clicked on No button (index 1)
-                                            let outcomes = build_outcomes();
-                                            if outcomes.len() > 1 {
-                                                Some(MarketClickAction::OpenTrade(
-                                                    market.question.clone(),
-                                                    outcomes,
-                                                    1, // No is at index 1
-                                                ))
                                             } else {
-                                                None
-                                            }
-                                        } else if click_x >= yes_button_start
-                                            && click_x < no_button_start
-                                        {
-                                            // Clicked on Yes button (index 0)
-                                            let outcomes = build_outcomes();
-                                            if !outcomes.is_empty() {
-                                                Some(MarketClickAction::OpenTrade(
-                                                    market.question.clone(),
-                                                    outcomes,
-                                                    0, // Yes is at index 0
+                                                // Clicked elsewhere on the row - select market
+                                                let outcome_idx =
+                                                    match app.orderbook_state.selected_outcome {
+                                                        state::OrderbookOutcome::Yes => 0,
+                                                        state::OrderbookOutcome::No => 1,
+                                                    };
+                                                let token_id = market
+                                                    .clob_token_ids
+                                                    .as_ref()
+                                                    .and_then(|ids| ids.get(outcome_idx).cloned());
+                                                Some(MarketClickAction::SelectMarket(
+                                                    clicked_idx,
+                                                    token_id,
+                                                    true, // active market
                                                 ))
-                                            } else {
-                                                None
                                             }
                                         } else {
-                                            // Clicked elsewhere on the row - select market
+                                            // Closed market - just select it
                                             let outcome_idx =
                                                 match app.orderbook_state.selected_outcome {
                                                     state::OrderbookOutcome::Yes => 0,
@@ -1059,32 +1198,15 @@ pub async fn run_trending_tui(
                                             Some(MarketClickAction::SelectMarket(
                                                 clicked_idx,
                                                 token_id,
-                                                true, // active market
-                                            ))
+                                                false,
+                                            )) // closed market
                                         }
                                     } else {
-                                        // Closed market - just select it
-                                        let outcome_idx = match app.orderbook_state.selected_outcome
-                                        {
-                                            state::OrderbookOutcome::Yes => 0,
-                                            state::OrderbookOutcome::No => 1,
-                                        };
-                                        let token_id = market
-                                            .clob_token_ids
-                                            .as_ref()
-                                            .and_then(|ids| ids.get(outcome_idx).cloned());
-                                        Some(MarketClickAction::SelectMarket(
-                                            clicked_idx,
-                                            token_id,
-                                            false,
-                                        )) // closed market
+                                        None
                                     }
                                 } else {
                                     None
-                                }
-                            } else {
-                                None
-                            };
+                                };
 
                             // Handle the click action
                             match click_action {
@@ -1092,18 +1214,16 @@ pub async fn run_trending_tui(
                                     clicked_idx,
                                     token_id,
                                     is_active,
-                                )) => {
-                                    if app.orderbook_state.selected_market_index != clicked_idx {
-                                        app.orderbook_state.selected_market_index = clicked_idx;
-                                        if let Some(token_id) = token_id {
-                                            app.orderbook_state.orderbook = None;
-                                            drop(app);
-                                            spawn_fetch_orderbook(
-                                                Arc::clone(&app_state),
-                                                token_id,
-                                                is_active,
-                                            );
-                                        }
+                                )) if app.orderbook_state.selected_market_index != clicked_idx => {
+                                    app.orderbook_state.selected_market_index = clicked_idx;
+                                    if let Some(token_id) = token_id {
+                                        app.orderbook_state.orderbook = None;
+                                        drop(app);
+                                        spawn_fetch_orderbook(
+                                            Arc::clone(&app_state),
+                                            token_id,
+                                            is_active,
+                                        );
                                     }
                                 },
                                 Some(MarketClickAction::OpenTrade(
@@ -1112,204 +1232,128 @@ pub async fn run_trending_tui(
                                     selected_idx,
                                 )) => {
                                     log_info!("Opening trade popup for: {}", question);
+                                    let fee_token =
+                                        outcomes.get(selected_idx).map(|o| o.token_id.clone());
                                     app.open_trade_popup(question, outcomes, selected_idx);
+                                    if let Some(token_id) = fee_token {
+                                        spawn_fetch_fee_rate(Arc::clone(&app_state), token_id);
+                                    }
                                 },
-                                None => {},
+                                // Clicking the already selected market, or outside any market
+                                Some(MarketClickAction::SelectMarket(..)) | None => {},
                             }
                         }
                     }
                 }
-                // Handle scroll wheel
-                if let MouseEventKind::ScrollUp = mouse.kind {
+                // Handle scroll wheel over the panel under the cursor (popups return above)
+                if let MouseEventKind::ScrollUp | MouseEventKind::ScrollDown = mouse.kind {
+                    let scroll_up = matches!(mouse.kind, MouseEventKind::ScrollUp);
                     let mut app = app_state.lock().await;
+                    let step = app.scroll.wheel_step;
                     let term_size = terminal.size()?;
                     let size = Rect::new(0, 0, term_size.width, term_size.height);
+                    let heights = calculate_panel_heights(
+                        size,
+                        app.top_height(),
+                        app.show_logs,
+                        app.main_tab,
+                        app.event_details_height(),
+                        app.orderbook_state.last_height,
+                    );
+                    let (.., trades_area, _) = calculate_panel_areas(
+                        size,
+                        app.top_height(),
+                        app.show_logs,
+                        app.main_tab,
+                        app.event_details_height(),
+                    );
+                    // The orderbook sits at the top of the trades area and shows a fixed depth,
+                    // so the wheel over it doesn't scroll the trades below
+                    let over_orderbook =
+                        matches!(app.main_tab, MainTab::Trending | MainTab::Favorites)
+                            && mouse.row < trades_area.y + app.orderbook_state.last_height;
                     if let Some(panel) = get_panel_at_position(
                         mouse.column,
                         mouse.row,
                         size,
-                        app.is_in_filter_mode(),
+                        app.top_height(),
                         app.show_logs,
                         app.main_tab,
+                        app.event_details_height(),
                     ) {
                         match panel {
                             FocusedPanel::EventsList => {
                                 // In Yield tab, scroll yield list or search results
                                 if app.main_tab == MainTab::Yield {
-                                    app.yield_state.move_up();
-                                    // Fetch event if not in cache
-                                    if let Some(opp) = app.yield_state.selected_opportunity() {
-                                        let slug = opp.event_slug.clone();
-                                        if app.get_cached_event(&slug).is_none() {
-                                            drop(app);
-                                            spawn_fetch_event_for_cache(
-                                                Arc::clone(&app_state),
-                                                slug,
-                                            );
+                                    for _ in 0..step {
+                                        if scroll_up {
+                                            app.yield_state.move_up();
+                                        } else {
+                                            app.yield_state.move_down(heights.events_list);
                                         }
                                     }
+                                    // Fetch event if not in cache
+                                    if let Some(slug) = app.yield_state.selected_event_slug()
+                                        && app.get_cached_event(&slug).is_none()
+                                    {
+                                        drop(app);
+                                        spawn_fetch_event_for_cache(Arc::clone(&app_state), slug);
+                                    }
+                                } else if scroll_up {
+                                    for _ in 0..step {
+                                        app.move_up();
+                                    }
                                 } else {
-                                    app.move_up();
+                                    for _ in 0..step {
+                                        app.move_down(heights.events_list);
+                                    }
+                                    // Check if we need to fetch more events (infinite scroll)
+                                    if app.should_fetch_more() {
+                                        spawn_fetch_more_events(Arc::clone(&app_state), &mut app);
+                                    }
                                 }
                             },
                             FocusedPanel::EventDetails => {
-                                if app.scroll.event_details > 0 {
-                                    app.scroll.event_details -= 1;
-                                }
+                                app.scroll.event_details = if scroll_up {
+                                    app.scroll.event_details.saturating_sub(step)
+                                } else {
+                                    app.scroll.event_details + step
+                                };
                             },
                             FocusedPanel::Markets => {
-                                if app.scroll.markets > 0 {
-                                    app.scroll.markets -= 1;
-                                }
+                                let markets_len = app
+                                    .current_tab_selected_event()
+                                    .map_or(0, |event| app.sorted_markets(event).len());
+                                let max_scroll = markets_len.saturating_sub(heights.markets);
+                                app.scroll.markets = if scroll_up {
+                                    app.scroll.markets.saturating_sub(step)
+                                } else {
+                                    (app.scroll.markets + step).min(max_scroll)
+                                };
                             },
-                            FocusedPanel::Trades => {
-                                if app.scroll.trades > 0 {
-                                    app.scroll.trades -= 1;
-                                }
+                            FocusedPanel::Trades if !over_orderbook => {
+                                let trades_len = app
+                                    .selected_event()
+                                    .map_or(0, |event| app.get_trades(&event.slug).len());
+                                let max_scroll = trades_len.saturating_sub(heights.trades);
+                                app.scroll.trades = if scroll_up {
+                                    app.scroll.trades.saturating_sub(step)
+                                } else {
+                                    (app.scroll.trades + step).min(max_scroll)
+                                };
                             },
                             FocusedPanel::Logs => {
-                                if app.logs.scroll > 0 {
-                                    app.logs.scroll -= 1;
-                                }
-                            },
-                            _ => {},
-                        }
-                    }
-                }
-                if let MouseEventKind::ScrollDown = mouse.kind {
-                    let mut app = app_state.lock().await;
-                    let term_size = terminal.size()?;
-                    let size = Rect::new(0, 0, term_size.width, term_size.height);
-                    if let Some(panel) = get_panel_at_position(
-                        mouse.column,
-                        mouse.row,
-                        size,
-                        app.is_in_filter_mode(),
-                        app.show_logs,
-                        app.main_tab,
-                    ) {
-                        match panel {
-                            FocusedPanel::EventsList => {
-                                // In Yield tab, scroll yield list or search results
-                                if app.main_tab == MainTab::Yield {
-                                    // Use approximate visible height for yield list
-                                    let visible_height = 20;
-                                    app.yield_state.move_down(visible_height);
-                                    // Fetch event if not in cache
-                                    if let Some(opp) = app.yield_state.selected_opportunity() {
-                                        let slug = opp.event_slug.clone();
-                                        if app.get_cached_event(&slug).is_none() {
-                                            drop(app);
-                                            spawn_fetch_event_for_cache(
-                                                Arc::clone(&app_state),
-                                                slug,
-                                            );
-                                        }
-                                    }
-                                } else {
-                                    app.move_down();
-                                    // Check if we need to fetch more events (infinite scroll)
-                                    if app.should_fetch_more() {
-                                        let app_state_clone = Arc::clone(&app_state);
-                                        let gamma_client_clone = GammaClient::new();
-                                        let current_filter = app.event_filter;
-                                        let current_limit = app.pagination.current_limit;
-
-                                        // Set fetching flag to prevent duplicate requests
-                                        app.pagination.is_fetching_more = true;
-
-                                        // Fetch 50 more events
-                                        let new_limit = current_limit + 50;
-                                        log_info!("Fetching more events (limit: {})", new_limit);
-
-                                        tokio::spawn(async move {
-                                            match fetch_events_for_filter(
-                                                &gamma_client_clone,
-                                                current_filter,
-                                                new_limit,
-                                            )
-                                            .await
-                                            {
-                                                Ok(mut new_events) => {
-                                                    // Remove duplicates by comparing slugs
-                                                    let existing_slugs: std::collections::HashSet<
-                                                        _,
-                                                    > = {
-                                                        let app = app_state_clone.lock().await;
-                                                        app.events
-                                                            .iter()
-                                                            .map(|e| e.slug.clone())
-                                                            .collect()
-                                                    };
-
-                                                    new_events.retain(|e| {
-                                                        !existing_slugs.contains(&e.slug)
-                                                    });
-
-                                                    if !new_events.is_empty() {
-                                                        log_info!(
-                                                            "Fetched {} new events",
-                                                            new_events.len()
-                                                        );
-                                                        let mut app = app_state_clone.lock().await;
-                                                        app.events.append(&mut new_events);
-                                                        app.pagination.current_limit = new_limit;
-                                                    } else {
-                                                        log_info!(
-                                                            "No new events to add (already have all events)"
-                                                        );
-                                                    }
-
-                                                    let mut app = app_state_clone.lock().await;
-                                                    app.pagination.is_fetching_more = false;
-                                                },
-                                                Err(_e) => {
-                                                    log_error!(
-                                                        "Failed to fetch more events: {}",
-                                                        _e
-                                                    );
-                                                    let mut app = app_state_clone.lock().await;
-                                                    app.pagination.is_fetching_more = false;
-                                                },
-                                            }
-                                        });
-                                    }
-                                }
-                            },
-                            FocusedPanel::EventDetails => {
-                                app.scroll.event_details += 1;
-                            },
-                            FocusedPanel::Markets => {
-                                if let Some(event) = app.selected_event() {
-                                    let visible_height: usize = 5;
-                                    if app.scroll.markets
-                                        < event.markets.len().saturating_sub(visible_height)
-                                    {
-                                        app.scroll.markets += 1;
-                                    }
-                                }
-                            },
-                            FocusedPanel::Trades => {
-                                let trades_len = if let Some(event) = app.selected_event() {
-                                    app.get_trades(&event.slug).len()
-                                } else {
-                                    0
-                                };
-                                let visible_height: usize = 10;
-                                if app.scroll.trades < trades_len.saturating_sub(visible_height) {
-                                    app.scroll.trades += 1;
-                                }
-                            },
-                            FocusedPanel::Logs => {
-                                let visible_height: usize = 10;
-                                let max_scroll = app
-                                    .logs
-                                    .messages
-                                    .len()
-                                    .saturating_sub(visible_height.max(1));
-                                if app.logs.scroll < max_scroll {
-                                    app.logs.scroll += 1;
-                                }
+                                let visible_height: usize = 10;
+                                let max_scroll = app
+                                    .logs
+                                    .messages
+                                    .len()
+                                    .saturating_sub(visible_height.max(1));
+                                app.logs.scroll = if scroll_up {
+                                    app.logs.scroll.saturating_sub(step)
+                                } else {
+                                    (app.logs.scroll + step).min(max_scroll)
+                                };
                             },
                             _ => {},
                         }
@@ -1318,7 +1362,7 @@ pub async fn run_trending_tui(
             }
 
             // Handle key events
-            if let Event::Key(key) = event {
+            if let Event::Key(mut key) = event {
                 if key.kind != KeyEventKind::Press {
                     continue;
                 }
@@ -1377,20 +1421,47 @@ pub async fn run_trending_tui(
                                         Ok(()) => {
                                             // Update auth state
                                             let address_for_profile = config.address.clone();
+                                            let sync_favorites = config.session_cookie.is_some();
                                             app.auth_state.is_authenticated = true;
                                             app.auth_state.address = Some(config.address.clone());
                                             app.auth_state.username = config.username.clone();
+                                            app.auth_state.has_session_cookie = sync_favorites;
                                             app.has_clob_auth = true;
                                             app.login_form.clear();
                                             app.close_popup();
                                             log_info!("Logged in successfully");
+                                            // Favorites need the session cookie; without it the
+                                            // Favorites tab explains how to add one
+                                            let message = if sync_favorites {
+                                                format!(
+                                                    "Logged in as {}, favorites syncing…",
+                                                    app.auth_state.display_name()
+                                                )
+                                            } else {
+                                                format!(
+                                                    "Logged in as {}",
+                                                    app.auth_state.display_name()
+                                                )
+                                            };
+                                            app.show_toast(message);
+                                            if sync_favorites {
+                                                app.favorites_state.is_loading = true;
+                                            }
 
-                                            // Fetch user profile to get username
+                                            // Fetch user profile to get username, and
+                                            // the portfolio for the header and Positions tab
                                             drop(app); // Release lock before spawning
                                             spawn_fetch_user_profile(
+                                                Arc::clone(&app_state),
+                                                address_for_profile.clone(),
+                                            );
+                                            spawn_fetch_portfolio(
                                                 Arc::clone(&app_state),
                                                 address_for_profile,
                                             );
+                                            if sync_favorites {
+                                                spawn_fetch_favorites(Arc::clone(&app_state));
+                                            }
                                         },
                                         Err(e) => {
                                             app.login_form.error_message = Some(e);
@@ -1416,16 +1487,263 @@ pub async fn run_trending_tui(
                         KeyCode::Esc | KeyCode::Char('p') => {
                             app.close_popup();
                         },
-                        KeyCode::Char('l') | KeyCode::Char('L') => {
-                            // Logout
-                            let _ = crate::auth::AuthConfig::delete();
-                            app.auth_state.is_authenticated = false;
-                            app.auth_state.address = None;
-                            app.auth_state.username = None;
-                            app.auth_state.balance = None;
-                            app.has_clob_auth = false;
+                        KeyCode::Char('l') => {
+                            // Logout for this session; the saved login is used on next start
+                            app.logout(false);
+                            app.close_popup();
+                        },
+                        KeyCode::Char('L') => {
+                            // Logout and delete the saved login
+                            app.logout(true);
+                            app.close_popup();
+                        },
+                        _ => {},
+                    }
+                    continue;
+                }
+
+                // Handle API errors popup input
+                if matches!(app.popup, Some(PopupType::ApiErrors)) {
+                    match key.code {
+                        KeyCode::Esc | KeyCode::Char('E') => {
+                            app.close_popup();
+                        },
+                        KeyCode::Up | KeyCode::Char('k') => {
+                            app.api_errors.scroll_up();
+                        },
+                        KeyCode::Down | KeyCode::Char('j') => {
+                            app.api_errors.scroll_down();
+                        },
+                        KeyCode::Char('c') => {
+                            app.api_errors.clear();
+                        },
+                        _ => {},
+                    }
+                    continue;
+                }
+
+                // Handle tag filter popup input
+                if matches!(app.popup, Some(PopupType::TagFilter)) {
+                    match key.code {
+                        KeyCode::Esc | KeyCode::Char('#') => {
+                            app.close_popup();
+                        },
+                        KeyCode::Up | KeyCode::Char('k') => {
+                            app.tag_filter.move_up();
+                        },
+                        KeyCode::Down | KeyCode::Char('j') => {
+                            app.tag_filter.move_down();
+                        },
+                        KeyCode::Enter => {
+                            if let Some(_tag) = app.apply_tag_filter() {
+                                log_info!("Filtering events by tag: {}", _tag);
+                            }
+                            app.close_popup();
+                        },
+                        _ => {},
+                    }
+                    continue;
+                }
+
+                // Handle resolution rules popup input
+                if let Some(PopupType::Resolution(slug)) = app.popup.clone() {
+                    match key.code {
+                        KeyCode::Esc | KeyCode::Char('u') => {
+                            app.close_popup();
+                        },
+                        KeyCode::Up | KeyCode::Char('k') => {
+                            app.scroll.resolution = app.scroll.resolution.saturating_sub(1);
+                        },
+                        KeyCode::Down | KeyCode::Char('j') => {
+                            // Unwrapped line count; long lines wrap, so this only bounds the scroll
+                            let theme = Theme::for_preset(app.ui_prefs.theme_preset, &dark_theme);
+                            let last_line = render::resolution_lines(&app, &theme, &slug)
+                                .len()
+                                .saturating_sub(1);
+                            app.scroll.resolution = (app.scroll.resolution + 1).min(last_line);
+                        },
+                        _ => {},
+                    }
+                    continue;
+                }
+
+                // Handle open orders popup input (cancelling asks for y/n first)
+                if matches!(app.popup, Some(PopupType::OpenOrders)) {
+                    if let Some(order_id) = app.orderbook_state.confirm_cancel.clone() {
+                        match key.code {
+                            KeyCode::Char('y') | KeyCode::Char('Y') => {
+                                // Cancel the order the prompt was shown for, even if the list
+                                // was refreshed since
+                                app.orderbook_state.confirm_cancel = None;
+                                app.orderbook_state.open_orders_message =
+                                    Some("Cancelling...".to_string());
+                                spawn_cancel_order(Arc::clone(&app_state), order_id);
+                            },
+                            KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => {
+                                app.orderbook_state.confirm_cancel = None;
+                            },
+                            _ => {},
+                        }
+                        continue;
+                    }
+                    match key.code {
+                        KeyCode::Esc | KeyCode::Char('X') => {
+                            app.close_popup();
+                        },
+                        KeyCode::Up | KeyCode::Char('k') => {
+                            app.orderbook_state.select_previous_open_order();
+                        },
+                        KeyCode::Down | KeyCode::Char('j') => {
+                            app.orderbook_state.select_next_open_order();
+                        },
+                        KeyCode::Enter | KeyCode::Char('x') => {
+                            let order_id = app
+                                .orderbook_state
+                                .selected_open_order()
+                                .map(|order| order.id.clone());
+                            if order_id.is_some() {
+                                app.orderbook_state.confirm_cancel = order_id;
+                                app.orderbook_state.open_orders_message = None;
+                            }
+                        },
+                        _ => {},
+                    }
+                    continue;
+                }
+
+                // Handle settings popup input (each change is saved right away)
+                if matches!(app.popup, Some(PopupType::Settings)) {
+                    match key.code {
+                        KeyCode::Esc | KeyCode::Char(',') => {
+                            app.close_popup();
+                        },
+                        KeyCode::Up | KeyCode::Char('k') => {
+                            app.refresh.select_previous();
+                        },
+                        KeyCode::Down | KeyCode::Char('j') => {
+                            app.refresh.select_next();
+                        },
+                        KeyCode::Left
+                        | KeyCode::Right
+                        | KeyCode::Char('h')
+                        | KeyCode::Char('l')
+                        | KeyCode::Char('-')
+                        | KeyCode::Char('+') => {
+                            let forward = matches!(
+                                key.code,
+                                KeyCode::Right | KeyCode::Char('l') | KeyCode::Char('+')
+                            );
+                            let source = app.refresh.selected_source();
+                            app.ui_prefs.refresh_intervals.step(source, forward);
+                            if let Err(_e) = app.ui_prefs.save() {
+                                log_warn!("Failed to save UI preferences: {}", _e);
+                            }
+                        },
+                        _ => {},
+                    }
+                    continue;
+                }
+
+                // Handle command palette input. A picked command falls through to the key
+                // handlers below as its key press.
+                let mut from_palette = false;
+                if matches!(app.popup, Some(PopupType::CommandPalette)) {
+                    let picked = match key.code {
+                        KeyCode::Esc => {
+                            app.close_popup();
+                            None
+                        },
+                        KeyCode::Up => {
+                            app.command_palette.select_previous();
+                            None
+                        },
+                        KeyCode::Down | KeyCode::Tab => {
+                            app.command_palette.select_next();
+                            None
+                        },
+                        KeyCode::Backspace => {
+                            app.command_palette.delete_char();
+                            None
+                        },
+                        KeyCode::Char(c) => {
+                            app.command_palette.add_char(c);
+                            None
+                        },
+                        KeyCode::Enter => {
+                            let action = app.command_palette.selected_action();
+                            app.close_popup();
+                            action
+                        },
+                        _ => None,
+                    };
+                    match picked {
+                        Some(PaletteAction::Key(c)) => {
+                            log_info!("Command palette: running '{}'", c);
+                            key.code = KeyCode::Char(c);
+                            from_palette = true;
+                        },
+                        Some(PaletteAction::Login) => {
+                            if app.auth_state.is_authenticated {
+                                app.show_popup(PopupType::UserProfile);
+                            } else {
+                                app.show_popup(PopupType::Login);
+                            }
+                            continue;
+                        },
+                        None => continue,
+                    }
+                }
+
+                // Handle note popup input
+                if let Some(PopupType::EditNote(slug)) = app.popup.clone() {
+                    match key.code {
+                        KeyCode::Esc => {
+                            app.note_input.clear();
+                            app.close_popup();
+                        },
+                        KeyCode::Enter => {
+                            let message = if app.save_note(&slug) {
+                                "Note saved"
+                            } else {
+                                "Note removed"
+                            };
+                            log_info!("{} for {}", message, slug);
+                            app.show_toast(message);
+                            app.close_popup();
+                        },
+                        KeyCode::Backspace => {
+                            app.delete_note_char();
+                        },
+                        KeyCode::Char(c) => {
+                            app.add_note_char(c);
+                        },
+                        _ => {},
+                    }
+                    continue;
+                }
+
+                // Handle yield min volume popup input
+                if matches!(app.popup, Some(PopupType::YieldMinVolume)) {
+                    match key.code {
+                        KeyCode::Esc => {
                             app.close_popup();
-                            log_info!("Logged out");
+                        },
+                        KeyCode::Enter => {
+                            // Invalid input keeps the popup open for correction
+                            let applied = app.yield_state.apply_min_volume_input();
+                            if applied {
+                                log_info!(
+                                    "Yield min volume set to ${:.0}",
+                                    app.yield_state.min_volume
+                                );
+                                app.close_popup();
+                            }
+                        },
+                        KeyCode::Backspace => {
+                            app.yield_state.delete_min_volume_char();
+                        },
+                        KeyCode::Char(c) => {
+                            app.yield_state.add_min_volume_char(c);
                         },
                         _ => {},
                     }
@@ -1436,9 +1754,69 @@ pub async fn run_trending_tui(
                 if matches!(app.popup, Some(PopupType::Trade)) {
                     // Check auth state before borrowing trade_form mutably
                     let is_authenticated = app.auth_state.is_authenticated;
+                    let balance = app.auth_state.balance;
+                    let keep_open = app.keep_trade_popup_open;
+                    let market_closed = app.trade_market_closed();
                     let mut should_close = false;
+                    let mut submitted: Option<String> = None;
 
-                    if let Some(ref mut form) = app.trade_form {
+                    if let Some(ref mut form) = app.trade_form
+                        && form.awaiting_confirm
+                    {
+                        match key.code {
+                            KeyCode::Enter => {
+                                form.awaiting_confirm = false;
+                                // Re-check in case the market or balance changed while confirming
+                                if market_closed {
+                                    form.error_message =
+                                        Some("Market closed - order not submitted".to_string());
+                                } else if let Err(error) = form.validate(balance) {
+                                    form.error_message = Some(error);
+                                } else {
+                                    // TODO: Actually submit the trade via CLOB API
+                                    let submit_result: Result<String, String> =
+                                        Err("Trade submission not yet implemented".to_string());
+                                    match form.order_type {
+                                        state::OrderType::Limit => {
+                                            log_info!(
+                                                "Limit order: {} {} shares of {} at {:.1}¢ (total: ${:.2})",
+                                                form.side.label(),
+                                                form.shares,
+                                                form.outcome_name(),
+                                                form.limit_price * 100.0,
+                                                form.total_cost()
+                                            );
+                                        },
+                                        state::OrderType::Market => {
+                                            log_info!(
+                                                "Market order: {} ${} of {} at {:.1}¢",
+                                                form.side.label(),
+                                                form.amount,
+                                                form.outcome_name(),
+                                                form.best_ask() * 100.0
+                                            );
+                                        },
+                                    }
+                                    match submit_result {
+                                        // Failed orders stay in the form so they can be fixed
+                                        Err(error) => form.error_message = Some(error),
+                                        Ok(result) if keep_open => {
+                                            form.reset_for_next_order(result);
+                                        },
+                                        Ok(result) => {
+                                            should_close = true;
+                                            submitted = Some(result);
+                                        },
+                                    }
+                                }
+                            },
+                            KeyCode::Esc => {
+                                // Back to editing instead of closing the popup
+                                form.awaiting_confirm = false;
+                            },
+                            _ => {},
+                        }
+                    } else if let Some(ref mut form) = app.trade_form {
                         match key.code {
                             KeyCode::Esc => {
                                 should_close = true;
@@ -1449,96 +1827,66 @@ pub async fn run_trending_tui(
                             KeyCode::BackTab => {
                                 form.prev_field();
                             },
-                            KeyCode::Char(' ') => {
+                            KeyCode::Char(' ')
+                                if form.active_field == state::TradeField::OrderType =>
+                            {
                                 // Space toggles order type when on that field
-                                if form.active_field == state::TradeField::OrderType {
-                                    form.toggle_order_type();
-                                }
-                                // Note: Side is now toggled via title tabs, not space key
+                                form.toggle_order_type();
                             },
-                            KeyCode::Char('+') | KeyCode::Char('=') => {
-                                // + increases limit price
+                            // Elsewhere space does nothing (side is toggled via title tabs)
+                            KeyCode::Char(' ') => {},
+                            KeyCode::Char('+') | KeyCode::Char('=') | KeyCode::Up => {
+                                // + (or Up) increases the limit price, or steps the size up
                                 if form.active_field == state::TradeField::LimitPrice {
                                     form.increment_limit_price();
+                                } else {
+                                    form.apply_size_step('+', balance);
                                 }
                             },
-                            KeyCode::Char('-') | KeyCode::Char('_') => {
-                                // - decreases limit price
+                            KeyCode::Char('-') | KeyCode::Char('_') | KeyCode::Down => {
+                                // - (or Down) decreases the limit price, or steps the size down
                                 if form.active_field == state::TradeField::LimitPrice {
                                     form.decrement_limit_price();
+                                } else {
+                                    form.apply_size_step('-', balance);
                                 }
                             },
-                            KeyCode::Up => {
-                                // Up arrow also increases limit price
-                                if form.active_field == state::TradeField::LimitPrice {
-                                    form.increment_limit_price();
-                                }
+                            KeyCode::Char(state::LIMIT_PRICE_UNIT_KEY)
+                                if form.active_field == state::TradeField::LimitPrice =>
+                            {
+                                form.toggle_limit_price_unit();
                             },
-                            KeyCode::Down => {
-                                // Down arrow also decreases limit price
-                                if form.active_field == state::TradeField::LimitPrice {
-                                    form.decrement_limit_price();
-                                }
+                            KeyCode::Left if !form.is_numeric_field_active() => {
+                                form.cycle_outcome(false);
+                            },
+                            KeyCode::Right if !form.is_numeric_field_active() => {
+                                form.cycle_outcome(true);
                             },
                             KeyCode::Backspace => {
                                 form.delete_char();
                             },
                             KeyCode::Enter => {
                                 // Validate and submit trade
-                                if !is_authenticated {
+                                if market_closed {
+                                    form.error_message = Some("Market closed".to_string());
+                                } else if !is_authenticated {
                                     form.error_message =
                                         Some("Login required to trade".to_string());
+                                } else if let Err(error) = form.validate(balance) {
+                                    form.error_message = Some(error);
                                 } else {
-                                    // Validate based on order type
-                                    let is_valid = match form.order_type {
-                                        state::OrderType::Limit => {
-                                            !form.shares.is_empty() && form.shares_f64() > 0.0
-                                        },
-                                        state::OrderType::Market => {
-                                            !form.amount.is_empty() && form.amount_f64() > 0.0
-                                        },
-                                    };
-
-                                    if !is_valid {
-                                        form.error_message = Some(match form.order_type {
-                                            state::OrderType::Limit => {
-                                                "Please enter a valid number of shares".to_string()
-                                            },
-                                            state::OrderType::Market => {
-                                                "Please enter a valid amount".to_string()
-                                            },
-                                        });
-                                    } else {
-                                        // TODO: Actually submit the trade via CLOB API
-                                        match form.order_type {
-                                            state::OrderType::Limit => {
-                                                log_info!(
-                                                    "Limit order: {} {} shares of {} at {:.1}¢ (total: ${:.2})",
-                                                    form.side.label(),
-                                                    form.shares,
-                                                    form.outcome_name(),
-                                                    form.limit_price * 100.0,
-                                                    form.total_cost()
-                                                );
-                                            },
-                                            state::OrderType::Market => {
-                                                log_info!(
-                                                    "Market order: {} ${} of {} at {:.1}¢",
-                                                    form.side.label(),
-                                                    form.amount,
-                                                    form.outcome_name(),
-                                                    form.best_ask() * 100.0
-                                                );
-                                            },
-                                        }
-                                        form.error_message = Some(
-                                            "Trade submission not yet implemented".to_string(),
-                                        );
-                                    }
+                                    // Ask for a second Enter before submitting
+                                    form.error_message = None;
+                                    form.awaiting_confirm = true;
                                 }
                             },
                             KeyCode::Char(c) => {
-                                form.add_char(c);
+                                // Quick amount and size step keys take precedence over typing
+                                let applied = form.apply_quick_amount(c, balance)
+                                    || form.apply_size_step(c, balance);
+                                if !applied {
+                                    form.add_char(c);
+                                }
                             },
                             _ => {},
                         }
@@ -1550,9 +1898,87 @@ pub async fn run_trending_tui(
                     if should_close {
                         app.close_popup();
                     }
+                    if let Some(result) = submitted {
+                        app.show_toast(result);
+                    }
+                    continue;
+                }
+
+                // Markets filter input ('/' in the Markets panel): typing narrows the list,
+                // Enter keeps the filter, and Esc (also once typing is done) clears it
+                let markets_filter_esc = key.code == KeyCode::Esc
+                    && !app.orderbook_state.market_filter.is_empty()
+                    && app.navigation.focused_panel == FocusedPanel::Markets
+                    && matches!(app.main_tab, MainTab::Trending | MainTab::Favorites)
+                    && !app.is_in_filter_mode();
+                if !app.has_popup()
+                    && (app.orderbook_state.is_filtering_markets || markets_filter_esc)
+                {
+                    let selection_changed = match key.code {
+                        KeyCode::Esc => {
+                            app.orderbook_state.is_filtering_markets = false;
+                            app.edit_market_filter(String::clear)
+                        },
+                        KeyCode::Enter => {
+                            app.orderbook_state.is_filtering_markets = false;
+                            false
+                        },
+                        KeyCode::Backspace => app.edit_market_filter(|query| {
+                            query.pop();
+                        }),
+                        KeyCode::Char(c) => app.edit_market_filter(|query| query.push(c)),
+                        _ => false,
+                    };
+                    if selection_changed
+                        && let Some((token_id, is_active)) = app.selected_orderbook_token()
+                    {
+                        drop(app);
+                        spawn_fetch_orderbook(Arc::clone(&app_state), token_id, is_active);
+                    }
                     continue;
                 }
 
+                // Vim-style counts: digits build a count that repeats the next move ("5j"),
+                // and j/k move like ↓/↑ outside text input
+                let mut move_count: usize = 1;
+                let typing = app.is_in_filter_mode()
+                    || (app.main_tab == MainTab::Yield
+                        && (app.yield_state.is_searching || app.yield_state.is_filtering));
+                if !typing && !app.has_popup() {
+                    if !is_replay
+                        && !from_palette
+                        && let KeyCode::Char(c @ '0'..='9') = key.code
+                        && (c != '0' || app.navigation.pending_count.is_some())
+                    {
+                        app.navigation.push_count_digit(c);
+                        pending_count_since = Some(tokio::time::Instant::now());
+                        continue;
+                    }
+                    match key.code {
+                        KeyCode::Char('j') => key.code = KeyCode::Down,
+                        KeyCode::Char('k') => key.code = KeyCode::Up,
+                        _ => {},
+                    }
+                    if let Some(count) = app.navigation.take_pending_count() {
+                        pending_count_since = None;
+                        if matches!(
+                            key.code,
+                            KeyCode::Up | KeyCode::Down | KeyCode::PageUp | KeyCode::PageDown
+                        ) {
+                            // Applied as one move so only the final selection is fetched
+                            move_count = count;
+                        } else if key.code == KeyCode::Esc {
+                            // Esc only cancels the count
+                            continue;
+                        } else if let Some(tab) = tab_digit(count) {
+                            // A lone tab digit followed by another key: switch tabs, then
+                            // handle the key
+                            replay_keys.extend([KeyCode::Char(tab), key.code]);
+                            continue;
+                        }
+                    }
+                }
+
                 match key.code {
                     KeyCode::Char('q') => {
                         if app.main_tab == MainTab::Yield && app.yield_state.is_searching {
@@ -1570,7 +1996,8 @@ pub async fn run_trending_tui(
                         }
                     },
                     KeyCode::Esc => {
-                        // Close popup first, then yield search/filter mode, then search/filter mode, then quit
+                        // Close popup first, then yield search/filter mode, then search/filter mode, then the
+                        // tag filter, then quit
                         if app.has_popup() {
                             app.close_popup();
                         } else if app.main_tab == MainTab::Yield && app.yield_state.is_searching {
@@ -1581,6 +2008,8 @@ pub async fn run_trending_tui(
                             log_info!("Exited yield filter mode");
                         } else if app.is_in_filter_mode() {
                             app.exit_search_mode();
+                        } else if app.clear_tag_filter() {
+                            log_info!("Cleared tag filter");
                         } else {
                             app.should_quit = true;
                             break;
@@ -1619,9 +2048,6 @@ pub async fn run_trending_tui(
                             }
                         } else if app.is_in_filter_mode() {
                             app.add_search_char('1');
-                            if app.search.mode == SearchMode::ApiSearch {
-                                search_debounce = Some(tokio::time::Instant::now());
-                            }
                         }
                     },
                     KeyCode::Char('2') => {
@@ -1636,7 +2062,7 @@ pub async fn run_trending_tui(
                             // Fetch favorites if not already loaded
                             if app.favorites_state.events.is_empty()
                                 && !app.favorites_state.is_loading
-                                && app.auth_state.is_authenticated
+                                && app.can_load_favorites()
                             {
                                 drop(app);
                                 spawn_fetch_favorites(Arc::clone(&app_state));
@@ -1644,9 +2070,6 @@ pub async fn run_trending_tui(
                             log_info!("Switched to Favorites tab");
                         } else if app.is_in_filter_mode() {
                             app.add_search_char('2');
-                            if app.search.mode == SearchMode::ApiSearch {
-                                search_debounce = Some(tokio::time::Instant::now());
-                            }
                         }
                     },
                     KeyCode::Char('3') => {
@@ -1671,9 +2094,6 @@ pub async fn run_trending_tui(
                             }
                         } else if app.is_in_filter_mode() {
                             app.add_search_char('3');
-                            if app.search.mode == SearchMode::ApiSearch {
-                                search_debounce = Some(tokio::time::Instant::now());
-                            }
                         }
                     },
                     KeyCode::Char('4') => {
@@ -1695,28 +2115,53 @@ pub async fn run_trending_tui(
                             log_info!("Switched to Yield tab");
                         } else if app.is_in_filter_mode() {
                             app.add_search_char('4');
-                            if app.search.mode == SearchMode::ApiSearch {
-                                search_debounce = Some(tokio::time::Instant::now());
-                            }
                         }
                     },
                     KeyCode::Char('5') => {
-                        // '5' is now just a regular character (no tab assigned)
+                        // Switch to Positions tab (unless in search/filter mode)
                         if app.main_tab == MainTab::Yield && app.yield_state.is_searching {
                             app.yield_state.add_search_char('5');
                             yield_search_debounce = Some(tokio::time::Instant::now());
                         } else if app.main_tab == MainTab::Yield && app.yield_state.is_filtering {
                             app.yield_state.add_filter_char('5');
+                        } else if !app.is_in_filter_mode() && app.main_tab != MainTab::Positions {
+                            app.main_tab = MainTab::Positions;
+                            spawn_load_positions(&app_state, &mut app, false);
+                            log_info!("Switched to Positions tab");
                         } else if app.is_in_filter_mode() {
                             app.add_search_char('5');
-                            if app.search.mode == SearchMode::ApiSearch {
-                                search_debounce = Some(tokio::time::Instant::now());
-                            }
                         }
                     },
-                    KeyCode::Char('l') => {
-                        // Toggle logs panel visibility (disabled in filter/search mode)
-                        if app.main_tab == MainTab::Yield && app.yield_state.is_searching {
+                    KeyCode::Char(c @ ('6' | '7')) => {
+                        // Switch to the Closing Soon (6) or New (7) tab (unless in search/filter mode)
+                        let new_filter = if c == '6' {
+                            EventFilter::ClosingSoon
+                        } else {
+                            EventFilter::NewlyListed
+                        };
+                        if app.main_tab == MainTab::Yield && app.yield_state.is_searching {
+                            app.yield_state.add_search_char(c);
+                            yield_search_debounce = Some(tokio::time::Instant::now());
+                        } else if app.main_tab == MainTab::Yield && app.yield_state.is_filtering {
+                            app.yield_state.add_filter_char(c);
+                        } else if !app.is_in_filter_mode() {
+                            if app.main_tab != MainTab::Trending || app.event_filter != new_filter {
+                                app.main_tab = MainTab::Trending;
+                                if let Some((filter, limit)) =
+                                    switch_filter_tab(&mut app, new_filter)
+                                {
+                                    drop(app);
+                                    spawn_filter_fetch(Arc::clone(&app_state), filter, limit);
+                                }
+                                log_info!("Switched to {} tab", new_filter.label());
+                            }
+                        } else if app.is_in_filter_mode() {
+                            app.add_search_char(c);
+                        }
+                    },
+                    KeyCode::Char('l') => {
+                        // Toggle logs panel visibility (disabled in filter/search mode)
+                        if app.main_tab == MainTab::Yield && app.yield_state.is_searching {
                             app.yield_state.add_search_char('l');
                             yield_search_debounce = Some(tokio::time::Instant::now());
                         } else if app.main_tab == MainTab::Yield && app.yield_state.is_filtering {
@@ -1731,9 +2176,25 @@ pub async fn run_trending_tui(
                         } else {
                             // In filter mode, add 'l' to search query
                             app.add_search_char('l');
-                            if app.search.mode == SearchMode::ApiSearch {
-                                search_debounce = Some(tokio::time::Instant::now());
-                            }
+                        }
+                    },
+                    KeyCode::Char('a') => {
+                        // Toggle showing each event's creation age in the events list
+                        if app.main_tab == MainTab::Yield && app.yield_state.is_searching {
+                            app.yield_state.add_search_char('a');
+                            yield_search_debounce = Some(tokio::time::Instant::now());
+                        } else if app.main_tab == MainTab::Yield && app.yield_state.is_filtering {
+                            app.yield_state.add_filter_char('a');
+                        } else if app.is_in_filter_mode() {
+                            app.add_search_char('a');
+                        } else if !app.has_popup() {
+                            app.show_event_age = !app.show_event_age;
+                            let message = if app.show_event_age {
+                                "Showing event creation age"
+                            } else {
+                                "Hiding event creation age"
+                            };
+                            app.show_toast(message);
                         }
                     },
                     KeyCode::Char('p') => {
@@ -1745,9 +2206,6 @@ pub async fn run_trending_tui(
                             app.yield_state.add_filter_char('p');
                         } else if app.is_in_filter_mode() {
                             app.add_search_char('p');
-                            if app.search.mode == SearchMode::ApiSearch {
-                                search_debounce = Some(tokio::time::Instant::now());
-                            }
                         } else if app.popup == Some(state::PopupType::UserProfile) {
                             // Close profile popup if already open
                             app.close_popup();
@@ -1765,27 +2223,17 @@ pub async fn run_trending_tui(
                             app.yield_state.add_filter_char('b');
                         } else if app.is_in_filter_mode() {
                             app.add_search_char('b');
-                            if app.search.mode == SearchMode::ApiSearch {
-                                search_debounce = Some(tokio::time::Instant::now());
-                            }
-                        } else if !app.has_popup() && app.auth_state.is_authenticated {
-                            // Get the event to toggle based on current tab
-                            match app.main_tab {
-                                MainTab::Trending | MainTab::Favorites => {
-                                    if let Some(e) = app.selected_event() {
-                                        spawn_toggle_favorite(
-                                            Arc::clone(&app_state),
-                                            e.id.clone(),
-                                            e.slug.clone(),
-                                            Some(e.clone()),
-                                        );
-                                    }
-                                },
+                        } else if !app.has_popup() {
+                            // The event to toggle on the current tab, by slug, with its data
+                            // when already loaded
+                            let target = match app.main_tab {
+                                MainTab::Trending | MainTab::Favorites => app
+                                    .selected_event()
+                                    .map(|e| (e.slug.clone(), Some(e.clone()))),
                                 MainTab::Yield => {
-                                    // For yield tab, get event_slug from selected opportunity
-                                    // We need to fetch the event to get the ID
-                                    if let Some(opp) = app.yield_state.selected_opportunity() {
-                                        let event_slug = opp.event_slug.clone();
+                                    // For yield tab, get event_slug from the selected opportunity
+                                    // or search result. We need to fetch the event to get the ID
+                                    app.yield_state.selected_event_slug().map(|event_slug| {
                                         // Try to find it in the events cache or favorites
                                         let cached_event = app
                                             .events
@@ -1799,28 +2247,57 @@ pub async fn run_trending_tui(
                                                     .find(|e| e.slug == event_slug)
                                                     .cloned()
                                             });
-
-                                        if let Some(event) = cached_event {
-                                            spawn_toggle_favorite(
-                                                Arc::clone(&app_state),
-                                                event.id.clone(),
-                                                event.slug.clone(),
-                                                Some(event),
-                                            );
-                                        } else {
-                                            // Event not in cache, fetch it first then toggle
-                                            spawn_fetch_and_toggle_favorite(
-                                                Arc::clone(&app_state),
-                                                event_slug,
-                                            );
-                                        }
-                                    }
+                                        (event_slug, cached_event)
+                                    })
                                 },
+                                MainTab::Positions => None,
                             };
+                            // Account favorites need session cookies; without them (or for an
+                            // event already bookmarked locally) the bookmark is kept locally
+                            let use_account = app.auth_state.is_authenticated
+                                && app.auth_state.has_session_cookie;
+                            match target {
+                                Some((slug, event))
+                                    if !use_account
+                                        || app.favorites_state.is_local_favorite(&slug) =>
+                                {
+                                    let message = if app.favorites_state.toggle_local(&slug, event)
+                                    {
+                                        if !app
+                                            .favorites_state
+                                            .events
+                                            .iter()
+                                            .any(|e| e.slug == slug)
+                                        {
+                                            // Listed once its data is fetched
+                                            spawn_fetch_favorites(Arc::clone(&app_state));
+                                        }
+                                        "Bookmarked locally"
+                                    } else {
+                                        "Removed local bookmark"
+                                    };
+                                    log_info!("{}: {}", message, slug);
+                                    app.clamp_selection_to_filtered();
+                                    app.show_toast(message);
+                                },
+                                Some((slug, Some(event))) => {
+                                    spawn_toggle_favorite(
+                                        Arc::clone(&app_state),
+                                        event.id.clone(),
+                                        slug,
+                                        Some(event),
+                                    );
+                                },
+                                Some((slug, None)) => {
+                                    // Event not in cache, fetch it first then toggle
+                                    spawn_fetch_and_toggle_favorite(Arc::clone(&app_state), slug);
+                                },
+                                None => {},
+                            }
                         }
                     },
                     KeyCode::Char('/') => {
-                        // API search mode - works from any panel (except when popup is open)
+                        // API search mode - works from any panel but Markets (except when popup is open)
                         if app.main_tab == MainTab::Yield {
                             if app.yield_state.is_filtering {
                                 // If in filter mode, add '/' to filter
@@ -1833,10 +2310,13 @@ pub async fn run_trending_tui(
                         } else if app.is_in_filter_mode() {
                             // Already in search/filter mode, add '/' to query
                             app.add_search_char('/');
-                            if app.search.mode == SearchMode::ApiSearch {
-                                search_debounce = Some(tokio::time::Instant::now());
-                            }
-                        } else if !app.has_popup() {
+                        } else if !app.has_popup()
+                            && app.navigation.focused_panel == FocusedPanel::Markets
+                            && matches!(app.main_tab, MainTab::Trending | MainTab::Favorites)
+                        {
+                            // Filter the selected event's markets instead
+                            app.orderbook_state.is_filtering_markets = true;
+                        } else if !app.has_popup() && app.main_tab != MainTab::Positions {
                             // API search in Trending/Favorites tab from any panel
                             app.enter_search_mode();
                         }
@@ -1858,10 +2338,7 @@ pub async fn run_trending_tui(
                         } else if app.is_in_filter_mode() {
                             // Already in search/filter mode, add 'f' to query
                             app.add_search_char('f');
-                            if app.search.mode == SearchMode::ApiSearch {
-                                search_debounce = Some(tokio::time::Instant::now());
-                            }
-                        } else if !app.has_popup() {
+                        } else if !app.has_popup() && app.main_tab != MainTab::Positions {
                             // Local filter in Trending/Favorites tab from any panel
                             app.enter_local_filter_mode();
                         }
@@ -1875,16 +2352,14 @@ pub async fn run_trending_tui(
                             app.yield_state.add_filter_char('o');
                         } else if app.is_in_filter_mode() {
                             app.add_search_char('o');
-                            if app.search.mode == SearchMode::ApiSearch {
-                                search_debounce = Some(tokio::time::Instant::now());
-                            }
                         } else if !app.has_popup() {
                             // Open event URL in browser (works from any panel, any tab)
                             let event_slug: Option<String> = match app.main_tab {
-                                MainTab::Yield => app
-                                    .yield_state
-                                    .selected_opportunity()
-                                    .map(|o| o.event_slug.clone()),
+                                MainTab::Yield => app.yield_state.selected_event_slug(),
+                                MainTab::Positions => app
+                                    .positions_state
+                                    .selected_position()
+                                    .map(|p| p.event_slug.clone()),
                                 MainTab::Trending | MainTab::Favorites => {
                                     app.selected_event().map(|e| e.slug.clone())
                                 },
@@ -1903,6 +2378,20 @@ pub async fn run_trending_tui(
                             }
                         }
                     },
+                    KeyCode::Char('C') => {
+                        // Copy a summary of this session's trades to the clipboard
+                        if app.main_tab == MainTab::Yield && app.yield_state.is_searching {
+                            app.yield_state.add_search_char('C');
+                            yield_search_debounce = Some(tokio::time::Instant::now());
+                        } else if app.main_tab == MainTab::Yield && app.yield_state.is_filtering {
+                            app.yield_state.add_filter_char('C');
+                        } else if app.is_in_filter_mode() {
+                            app.add_search_char('C');
+                        } else if !app.has_popup() {
+                            let summary = app.trades.session_summary();
+                            spawn_copy_session_summary(Arc::clone(&app_state), summary);
+                        }
+                    },
                     KeyCode::Char('e') => {
                         // Open config file in editor (only in Favorites tab when session cookie is missing)
                         if app.main_tab == MainTab::Favorites
@@ -1940,9 +2429,6 @@ pub async fn run_trending_tui(
                             app.yield_state.add_filter_char('e');
                         } else if app.is_in_filter_mode() {
                             app.add_search_char('e');
-                            if app.search.mode == SearchMode::ApiSearch {
-                                search_debounce = Some(tokio::time::Instant::now());
-                            }
                         }
                     },
                     KeyCode::Char('s') => {
@@ -1960,33 +2446,175 @@ pub async fn run_trending_tui(
                             log_info!("Sort changed to: {}", app.yield_state.sort_by.label());
                         } else if app.is_in_filter_mode() {
                             app.add_search_char('s');
-                            if app.search.mode == SearchMode::ApiSearch {
-                                search_debounce = Some(tokio::time::Instant::now());
-                            }
+                        } else if app.main_tab == MainTab::Positions {
+                            app.positions_state.cycle_sort();
+                            log_info!(
+                                "Positions sort changed to: {}",
+                                app.positions_state.sort_by.label()
+                            );
                         } else if app.main_tab == MainTab::Trending
                             || app.main_tab == MainTab::Favorites
                         {
-                            // Cycle sort order for Events tab
-                            app.event_sort_by = app.event_sort_by.next();
-                            app.sort_events();
+                            // Cycle the current tab's sort order
+                            let _sort_by = app.cycle_event_sort();
                             app.navigation.selected_index = 0;
                             app.scroll.events_list = 0;
-                            log_info!("Events sort changed to: {}", app.event_sort_by.label());
+                            log_info!("Events sort changed to: {}", _sort_by.label());
                         }
                     },
-                    KeyCode::Char('S') => {
-                        // Save logs to file (Shift+S) when logs panel is visible
-                        if app.show_logs && !app.is_in_filter_mode() {
-                            match app.logs.save_to_file() {
+                    KeyCode::Char('x') => {
+                        // Pin/unpin the selected market so it sorts to the top
+                        if app.main_tab == MainTab::Yield && app.yield_state.is_searching {
+                            app.yield_state.add_search_char('x');
+                            yield_search_debounce = Some(tokio::time::Instant::now());
+                        } else if app.main_tab == MainTab::Yield && app.yield_state.is_filtering {
+                            app.yield_state.add_filter_char('x');
+                        } else if app.is_in_filter_mode() {
+                            app.add_search_char('x');
+                        } else if app.navigation.focused_panel == FocusedPanel::Markets
+                            && !app.has_popup()
+                            && let Some(pinned) = app.toggle_selected_market_pin()
+                        {
+                            log_info!(
+                                "Market {}",
+                                if pinned {
+                                    "pinned"
+                                } else {
+                                    "unpinned"
+                                }
+                            );
+                        }
+                    },
+                    KeyCode::Char('[') | KeyCode::Char(']') => {
+                        // Step the orderbook's market without focusing the Markets panel
+                        let c = if key.code == KeyCode::Char(']') {
+                            ']'
+                        } else {
+                            '['
+                        };
+                        if app.main_tab == MainTab::Yield && app.yield_state.is_searching {
+                            app.yield_state.add_search_char(c);
+                            yield_search_debounce = Some(tokio::time::Instant::now());
+                        } else if app.main_tab == MainTab::Yield && app.yield_state.is_filtering {
+                            app.yield_state.add_filter_char(c);
+                        } else if app.is_in_filter_mode() {
+                            app.add_search_char(c);
+                        } else if matches!(app.main_tab, MainTab::Trending | MainTab::Favorites)
+                            && !app.has_popup()
+                        {
+                            let term_size = terminal.size()?;
+                            let markets_height = calculate_panel_heights(
+                                Rect::new(0, 0, term_size.width, term_size.height),
+                                app.top_height(),
+                                app.show_logs,
+                                app.main_tab,
+                                app.event_details_height(),
+                                app.orderbook_state.last_height,
+                            )
+                            .markets;
+                            if let Some(_question) =
+                                app.step_selected_market(c == ']', markets_height)
+                            {
+                                log_info!("Orderbook market: {}", _question);
+                                if let Some((token_id, is_active)) = app.selected_orderbook_token()
+                                {
+                                    drop(app);
+                                    spawn_fetch_orderbook(
+                                        Arc::clone(&app_state),
+                                        token_id,
+                                        is_active,
+                                    );
+                                }
+                            }
+                        }
+                    },
+                    KeyCode::Char('X') => {
+                        // List your open orders on the displayed outcome to cancel them
+                        if app.main_tab == MainTab::Yield && app.yield_state.is_searching {
+                            app.yield_state.add_search_char('X');
+                            yield_search_debounce = Some(tokio::time::Instant::now());
+                        } else if app.main_tab == MainTab::Yield && app.yield_state.is_filtering {
+                            app.yield_state.add_filter_char('X');
+                        } else if app.is_in_filter_mode() {
+                            app.add_search_char('X');
+                        } else if matches!(app.main_tab, MainTab::Trending | MainTab::Favorites)
+                            && !app.has_popup()
+                        {
+                            if app.has_clob_auth {
+                                app.orderbook_state.confirm_cancel = None;
+                                app.orderbook_state.open_orders_message = None;
+                                app.show_popup(PopupType::OpenOrders);
+                            } else {
+                                app.show_toast("Open orders need CLOB API credentials");
+                            }
+                        }
+                    },
+                    KeyCode::Char('P') => {
+                        // Snapshot the screen to a text file (captured on the next draw)
+                        if app.main_tab == MainTab::Yield && app.yield_state.is_searching {
+                            app.yield_state.add_search_char('P');
+                            yield_search_debounce = Some(tokio::time::Instant::now());
+                        } else if app.main_tab == MainTab::Yield && app.yield_state.is_filtering {
+                            app.yield_state.add_filter_char('P');
+                        } else if app.is_in_filter_mode() {
+                            app.add_search_char('P');
+                        } else {
+                            app.snapshot_requested = true;
+                        }
+                    },
+                    KeyCode::Char('Z') => {
+                        // Pause or resume timed refreshes (prices, orderbook, lists, portfolio)
+                        if app.main_tab == MainTab::Yield && app.yield_state.is_searching {
+                            app.yield_state.add_search_char('Z');
+                            yield_search_debounce = Some(tokio::time::Instant::now());
+                        } else if app.main_tab == MainTab::Yield && app.yield_state.is_filtering {
+                            app.yield_state.add_filter_char('Z');
+                        } else if app.is_in_filter_mode() {
+                            app.add_search_char('Z');
+                        } else if !app.has_popup() {
+                            app.auto_refresh_paused = !app.auto_refresh_paused;
+                            let message = if app.auto_refresh_paused {
+                                "Auto-refresh paused"
+                            } else {
+                                "Auto-refresh resumed"
+                            };
+                            log_info!("{}", message);
+                            app.show_toast(message);
+                        }
+                    },
+                    KeyCode::Char('J') => {
+                        // Export the loaded events, with live prices, to a JSON file
+                        if app.main_tab == MainTab::Yield && app.yield_state.is_searching {
+                            app.yield_state.add_search_char('J');
+                            yield_search_debounce = Some(tokio::time::Instant::now());
+                        } else if app.main_tab == MainTab::Yield && app.yield_state.is_filtering {
+                            app.yield_state.add_filter_char('J');
+                        } else if app.is_in_filter_mode() {
+                            app.add_search_char('J');
+                        } else if !app.has_popup() {
+                            match app.save_events_to_file() {
                                 Ok(filename) => {
-                                    log_info!("Logs saved to: {}", filename);
+                                    log_info!("Events exported to: {}", filename);
+                                    app.show_toast(format!("Exported events to {}", filename));
                                 },
                                 Err(e) => {
-                                    log_error!("Failed to save logs: {}", e);
+                                    log_error!("Failed to export events: {}", e);
+                                    app.show_toast(format!("Failed to export events: {}", e));
                                 },
                             }
                         }
                     },
+                    KeyCode::Char('S') if app.show_logs && !app.is_in_filter_mode() => {
+                        // Save logs to file (Shift+S) when logs panel is visible
+                        match app.logs.save_to_file() {
+                            Ok(filename) => {
+                                log_info!("Logs saved to: {}", filename);
+                            },
+                            Err(e) => {
+                                log_error!("Failed to save logs: {}", e);
+                            },
+                        }
+                    },
                     KeyCode::Char('t') => {
                         // Toggle orderbook Yes/No outcome (or add to search/filter if in input mode)
                         if app.main_tab == MainTab::Yield && app.yield_state.is_searching {
@@ -1996,9 +2624,6 @@ pub async fn run_trending_tui(
                             app.yield_state.add_filter_char('t');
                         } else if app.is_in_filter_mode() {
                             app.add_search_char('t');
-                            if app.search.mode == SearchMode::ApiSearch {
-                                search_debounce = Some(tokio::time::Instant::now());
-                            }
                         } else if matches!(app.main_tab, MainTab::Trending | MainTab::Favorites)
                             && !app.has_popup()
                         {
@@ -2017,45 +2642,42 @@ pub async fn run_trending_tui(
                             };
                             // Trigger orderbook fetch for the new outcome (use sorted markets)
                             // Get event from appropriate source based on tab
-                            let orderbook_info: Option<(String, bool)> = if app.main_tab
-                                == MainTab::Favorites
-                            {
-                                app.favorites_state.selected_event().and_then(|event| {
-                                    let mut sorted_markets: Vec<_> = event.markets.iter().collect();
-                                    sorted_markets.sort_by_key(|m| m.closed);
-                                    let market_idx = app.orderbook_state.selected_market_index;
-                                    sorted_markets.get(market_idx).and_then(|market| {
-                                        log_info!(
-                                            "Toggle: market={}, token_ids={:?}",
-                                            market.question,
-                                            market.clob_token_ids
-                                        );
-                                        market.clob_token_ids.as_ref().and_then(|ids| {
-                                            ids.get(outcome_idx)
-                                                .cloned()
-                                                .map(|id| (id, !market.closed))
+                            let orderbook_info: Option<(String, bool)> =
+                                if app.main_tab == MainTab::Favorites {
+                                    app.favorites_state.selected_event().and_then(|event| {
+                                        let sorted_markets = app.sorted_markets(event);
+                                        let market_idx = app.orderbook_state.selected_market_index;
+                                        sorted_markets.get(market_idx).and_then(|market| {
+                                            log_info!(
+                                                "Toggle: market={}, token_ids={:?}",
+                                                market.question,
+                                                market.clob_token_ids
+                                            );
+                                            market.clob_token_ids.as_ref().and_then(|ids| {
+                                                ids.get(outcome_idx)
+                                                    .cloned()
+                                                    .map(|id| (id, !market.closed))
+                                            })
                                         })
                                     })
-                                })
-                            } else {
-                                app.selected_event().and_then(|event| {
-                                    let mut sorted_markets: Vec<_> = event.markets.iter().collect();
-                                    sorted_markets.sort_by_key(|m| m.closed);
-                                    let market_idx = app.orderbook_state.selected_market_index;
-                                    sorted_markets.get(market_idx).and_then(|market| {
-                                        log_info!(
-                                            "Toggle: market={}, token_ids={:?}",
-                                            market.question,
-                                            market.clob_token_ids
-                                        );
-                                        market.clob_token_ids.as_ref().and_then(|ids| {
-                                            ids.get(outcome_idx)
-                                                .cloned()
-                                                .map(|id| (id, !market.closed))
+                                } else {
+                                    app.selected_event().and_then(|event| {
+                                        let sorted_markets = app.sorted_markets(event);
+                                        let market_idx = app.orderbook_state.selected_market_index;
+                                        sorted_markets.get(market_idx).and_then(|market| {
+                                            log_info!(
+                                                "Toggle: market={}, token_ids={:?}",
+                                                market.question,
+                                                market.clob_token_ids
+                                            );
+                                            market.clob_token_ids.as_ref().and_then(|ids| {
+                                                ids.get(outcome_idx)
+                                                    .cloned()
+                                                    .map(|id| (id, !market.closed))
+                                            })
                                         })
                                     })
-                                })
-                            };
+                                };
                             if let Some((token_id, is_active)) = orderbook_info {
                                 log_info!(
                                     "Fetching orderbook for outcome_idx={}, token={}",
@@ -2068,6 +2690,623 @@ pub async fn run_trending_tui(
                             }
                         }
                     },
+                    KeyCode::Char('T') => {
+                        // Show both outcomes' orderbooks side by side
+                        if app.main_tab == MainTab::Yield && app.yield_state.is_searching {
+                            app.yield_state.add_search_char('T');
+                            yield_search_debounce = Some(tokio::time::Instant::now());
+                        } else if app.main_tab == MainTab::Yield && app.yield_state.is_filtering {
+                            app.yield_state.add_filter_char('T');
+                        } else if app.is_in_filter_mode() {
+                            app.add_search_char('T');
+                        } else if matches!(app.main_tab, MainTab::Trending | MainTab::Favorites)
+                            && !app.has_popup()
+                        {
+                            app.orderbook_state.toggle_both_sides();
+                            let message = if app.orderbook_state.both_sides {
+                                "Orderbook: both outcomes"
+                            } else {
+                                "Orderbook: selected outcome"
+                            };
+                            app.show_toast(message);
+                            // Refetch right away so the opposite book appears without waiting
+                            if let Some((token_id, is_active)) = app.selected_orderbook_token() {
+                                spawn_fetch_orderbook(Arc::clone(&app_state), token_id, is_active);
+                            }
+                        }
+                    },
+                    KeyCode::Char('B') => {
+                        // Group orderbook levels into wider price buckets
+                        if app.main_tab == MainTab::Yield && app.yield_state.is_searching {
+                            app.yield_state.add_search_char('B');
+                            yield_search_debounce = Some(tokio::time::Instant::now());
+                        } else if app.main_tab == MainTab::Yield && app.yield_state.is_filtering {
+                            app.yield_state.add_filter_char('B');
+                        } else if app.is_in_filter_mode() {
+                            app.add_search_char('B');
+                        } else if matches!(app.main_tab, MainTab::Trending | MainTab::Favorites)
+                            && !app.has_popup()
+                        {
+                            let message = match app.orderbook_state.cycle_group_increment() {
+                                Some(increment) => format!(
+                                    "Orderbook grouped by {}",
+                                    state::format_group_increment(increment)
+                                ),
+                                None => "Orderbook: every price level".to_string(),
+                            };
+                            app.show_toast(message);
+                        }
+                    },
+                    KeyCode::Char('v') => {
+                        // Toggle the yield tab between opportunities and search results
+                        if app.main_tab == MainTab::Yield && app.yield_state.is_searching {
+                            app.yield_state.add_search_char('v');
+                            yield_search_debounce = Some(tokio::time::Instant::now());
+                        } else if app.main_tab == MainTab::Yield && app.yield_state.is_filtering {
+                            app.yield_state.add_filter_char('v');
+                        } else if app.is_in_filter_mode() {
+                            app.add_search_char('v');
+                        } else if app.main_tab == MainTab::Yield && !app.has_popup() {
+                            if app.yield_state.toggle_view_mode() {
+                                log_info!("Yield view: {}", app.yield_state.view_mode.label());
+                                if let Some(slug) = app.yield_state.selected_event_slug()
+                                    && app.get_cached_event(&slug).is_none()
+                                {
+                                    spawn_fetch_event_for_cache(Arc::clone(&app_state), slug);
+                                }
+                            } else {
+                                log_info!("No yield search results to show (press / to search)");
+                            }
+                        }
+                    },
+                    KeyCode::Char('m') => {
+                        // Set the minimum 24h volume for yield opportunities
+                        if app.main_tab == MainTab::Yield && app.yield_state.is_searching {
+                            app.yield_state.add_search_char('m');
+                            yield_search_debounce = Some(tokio::time::Instant::now());
+                        } else if app.main_tab == MainTab::Yield && app.yield_state.is_filtering {
+                            app.yield_state.add_filter_char('m');
+                        } else if app.is_in_filter_mode() {
+                            app.add_search_char('m');
+                        } else if app.main_tab == MainTab::Yield && !app.has_popup() {
+                            app.yield_state.start_min_volume_edit();
+                            app.show_popup(PopupType::YieldMinVolume);
+                        }
+                    },
+                    KeyCode::Char('g') | KeyCode::Char('G') | KeyCode::Home | KeyCode::End => {
+                        // Jump to the top (g/Home) or bottom (G/End) of the focused list
+                        let typed_char = match key.code {
+                            KeyCode::Char(c) => Some(c),
+                            _ => None,
+                        };
+                        if let Some(c) = typed_char
+                            && app.main_tab == MainTab::Yield
+                            && app.yield_state.is_searching
+                        {
+                            app.yield_state.add_search_char(c);
+                            yield_search_debounce = Some(tokio::time::Instant::now());
+                        } else if let Some(c) = typed_char
+                            && app.main_tab == MainTab::Yield
+                            && app.yield_state.is_filtering
+                        {
+                            app.yield_state.add_filter_char(c);
+                        } else if let Some(c) = typed_char
+                            && app.is_in_filter_mode()
+                        {
+                            app.add_search_char(c);
+                        } else if !app.is_in_filter_mode() && !app.has_popup() {
+                            let to_bottom = matches!(key.code, KeyCode::Char('G') | KeyCode::End);
+                            let term_size = terminal.size()?;
+                            let heights = calculate_panel_heights(
+                                Rect::new(0, 0, term_size.width, term_size.height),
+                                app.top_height(),
+                                app.show_logs,
+                                app.main_tab,
+                                app.event_details_height(),
+                                app.orderbook_state.last_height,
+                            );
+
+                            match (app.main_tab, app.navigation.focused_panel) {
+                                (MainTab::Positions, _) => {
+                                    if to_bottom {
+                                        app.positions_state.select_last(heights.events_list);
+                                    } else {
+                                        app.positions_state.select_first();
+                                    }
+                                },
+                                (MainTab::Yield, _) => {
+                                    if to_bottom {
+                                        app.yield_state.select_last(heights.events_list);
+                                    } else {
+                                        app.yield_state.select_first();
+                                    }
+                                    if let Some(slug) = app.yield_state.selected_event_slug()
+                                        && app.get_cached_event(&slug).is_none()
+                                    {
+                                        spawn_fetch_event_for_cache(Arc::clone(&app_state), slug);
+                                    }
+                                },
+                                (_, FocusedPanel::EventsList) => {
+                                    let previous_slug =
+                                        app.current_tab_selected_event().map(|e| e.slug.clone());
+                                    if app.main_tab == MainTab::Favorites {
+                                        if to_bottom {
+                                            app.favorites_state.select_last(heights.events_list);
+                                        } else {
+                                            app.favorites_state.select_first();
+                                        }
+                                    } else if to_bottom {
+                                        app.select_last_event(heights.events_list);
+                                        if app.should_fetch_more() {
+                                            spawn_fetch_more_events(
+                                                Arc::clone(&app_state),
+                                                &mut app,
+                                            );
+                                        }
+                                    } else {
+                                        app.select_first_event();
+                                    }
+                                    let current_slug =
+                                        app.current_tab_selected_event().map(|e| e.slug.clone());
+                                    if current_slug != previous_slug {
+                                        if app.main_tab == MainTab::Trending {
+                                            last_selected_event_slug = current_slug;
+                                        }
+                                        spawn_event_selection_fetches(&app_state, &mut app);
+                                    }
+                                },
+                                (_, FocusedPanel::Markets) => {
+                                    if app.select_edge_market(to_bottom, heights.markets)
+                                        && let Some((token_id, is_active)) =
+                                            app.selected_orderbook_token()
+                                    {
+                                        drop(app);
+                                        spawn_fetch_orderbook(
+                                            Arc::clone(&app_state),
+                                            token_id,
+                                            is_active,
+                                        );
+                                    }
+                                },
+                                (_, FocusedPanel::Trades) => {
+                                    app.scroll_trades_to_edge(to_bottom, heights.trades);
+                                },
+                                _ => {},
+                            }
+                        }
+                    },
+                    KeyCode::PageUp | KeyCode::PageDown
+                        if !app.is_in_filter_mode() && !app.has_popup() =>
+                    {
+                        // Move the list selection by a full visible page
+                        let page_down = key.code == KeyCode::PageDown;
+                        let term_size = terminal.size()?;
+                        let visible_height = calculate_panel_heights(
+                            Rect::new(0, 0, term_size.width, term_size.height),
+                            app.top_height(),
+                            app.show_logs,
+                            app.main_tab,
+                            app.event_details_height(),
+                            app.orderbook_state.last_height,
+                        )
+                        .events_list
+                        .saturating_mul(move_count);
+
+                        if app.main_tab == MainTab::Positions {
+                            if page_down {
+                                app.positions_state.page_down(visible_height);
+                            } else {
+                                app.positions_state.page_up(visible_height);
+                            }
+                        } else if app.main_tab == MainTab::Yield {
+                            if page_down {
+                                app.yield_state.page_down(visible_height);
+                            } else {
+                                app.yield_state.page_up(visible_height);
+                            }
+                            if let Some(slug) = app.yield_state.selected_event_slug()
+                                && app.get_cached_event(&slug).is_none()
+                            {
+                                spawn_fetch_event_for_cache(Arc::clone(&app_state), slug);
+                            }
+                        } else if app.navigation.focused_panel == FocusedPanel::EventsList {
+                            let previous_slug =
+                                app.current_tab_selected_event().map(|e| e.slug.clone());
+                            match (app.main_tab, page_down) {
+                                (MainTab::Favorites, true) => {
+                                    app.favorites_state.page_down(visible_height)
+                                },
+                                (MainTab::Favorites, false) => {
+                                    app.favorites_state.page_up(visible_height)
+                                },
+                                (_, true) => app.page_down(visible_height),
+                                (_, false) => app.page_up(visible_height),
+                            }
+                            if app.main_tab == MainTab::Trending && app.should_fetch_more() {
+                                spawn_fetch_more_events(Arc::clone(&app_state), &mut app);
+                            }
+                            let current_slug =
+                                app.current_tab_selected_event().map(|e| e.slug.clone());
+                            if current_slug != previous_slug {
+                                if app.main_tab == MainTab::Trending {
+                                    last_selected_event_slug = current_slug;
+                                }
+                                spawn_event_selection_fetches(&app_state, &mut app);
+                            }
+                        }
+                    },
+                    KeyCode::Char('R') => {
+                        // Retry a failed startup load, or a failed orderbook fetch now instead of
+                        // waiting for the backoff
+                        if app.main_tab == MainTab::Yield && app.yield_state.is_searching {
+                            app.yield_state.add_search_char('R');
+                            yield_search_debounce = Some(tokio::time::Instant::now());
+                        } else if app.main_tab == MainTab::Yield && app.yield_state.is_filtering {
+                            app.yield_state.add_filter_char('R');
+                        } else if app.is_in_filter_mode() {
+                            app.add_search_char('R');
+                        } else if !app.has_popup() {
+                            if app.load_error.is_some() {
+                                // The startup events fetch failed; retrying it comes first
+                                spawn_retry_initial_load(Arc::clone(&app_state), &mut app);
+                            } else if matches!(app.main_tab, MainTab::Trending | MainTab::Favorites)
+                                && app.orderbook_state.error.is_some()
+                            {
+                                app.orderbook_state.retry_now();
+                                log_info!("Retrying orderbook fetch");
+                            }
+                        }
+                    },
+                    KeyCode::Char('F') => {
+                        // Force a favorites resync with the Polymarket account
+                        if app.main_tab == MainTab::Yield && app.yield_state.is_searching {
+                            app.yield_state.add_search_char('F');
+                            yield_search_debounce = Some(tokio::time::Instant::now());
+                        } else if app.main_tab == MainTab::Yield && app.yield_state.is_filtering {
+                            app.yield_state.add_filter_char('F');
+                        } else if app.is_in_filter_mode() {
+                            app.add_search_char('F');
+                        } else if app.main_tab == MainTab::Favorites && !app.has_popup() {
+                            if !app.auth_state.is_authenticated {
+                                app.show_toast("Login required to sync favorites");
+                            } else if !app.favorites_state.is_loading {
+                                log_info!("Syncing favorites...");
+                                app.favorites_state.is_loading = true;
+                                spawn_sync_favorites(Arc::clone(&app_state));
+                            }
+                        }
+                    },
+                    KeyCode::Char(c @ ('+' | '=' | '-' | '_')) => {
+                        // Step the events list's minimum volume filter up (+/=) or down (-/_)
+                        if app.main_tab == MainTab::Yield && app.yield_state.is_searching {
+                            app.yield_state.add_search_char(c);
+                            yield_search_debounce = Some(tokio::time::Instant::now());
+                        } else if app.main_tab == MainTab::Yield && app.yield_state.is_filtering {
+                            app.yield_state.add_filter_char(c);
+                        } else if app.is_in_filter_mode() {
+                            app.add_search_char(c);
+                        } else if (app.main_tab == MainTab::Trending
+                            || app.main_tab == MainTab::Favorites)
+                            && !app.has_popup()
+                        {
+                            app.step_min_volume_filter(matches!(c, '+' | '='));
+                            log_info!("Events min volume set to ${:.0}", app.min_volume_filter);
+                        }
+                    },
+                    KeyCode::Char('i') => {
+                        // Show the selected event's full title (the list truncates it)
+                        if app.main_tab == MainTab::Yield && app.yield_state.is_searching {
+                            app.yield_state.add_search_char('i');
+                            yield_search_debounce = Some(tokio::time::Instant::now());
+                        } else if app.main_tab == MainTab::Yield && app.yield_state.is_filtering {
+                            app.yield_state.add_filter_char('i');
+                        } else if app.is_in_filter_mode() {
+                            app.add_search_char('i');
+                        } else if matches!(app.popup, Some(PopupType::EventInfo(_))) {
+                            app.close_popup();
+                        } else if !app.has_popup()
+                            && let Some(slug) = app
+                                .current_tab_selected_event()
+                                .map(|event| event.slug.clone())
+                        {
+                            app.show_popup(PopupType::EventInfo(slug));
+                        }
+                    },
+                    KeyCode::Char('u') => {
+                        // Show the selected event's resolution rules (also from the event info popup).
+                        // Bound to 'u' because 'R' already retries failed fetches.
+                        if app.main_tab == MainTab::Yield && app.yield_state.is_searching {
+                            app.yield_state.add_search_char('u');
+                            yield_search_debounce = Some(tokio::time::Instant::now());
+                        } else if app.main_tab == MainTab::Yield && app.yield_state.is_filtering {
+                            app.yield_state.add_filter_char('u');
+                        } else if app.is_in_filter_mode() {
+                            app.add_search_char('u');
+                        } else if (!app.has_popup()
+                            || matches!(app.popup, Some(PopupType::EventInfo(_))))
+                            && let Some(slug) = app
+                                .current_tab_selected_event()
+                                .map(|event| event.slug.clone())
+                        {
+                            app.scroll.resolution = 0;
+                            app.show_popup(PopupType::Resolution(slug));
+                        }
+                    },
+                    KeyCode::Char('#') => {
+                        // Pick a tag to filter the events list by
+                        if app.main_tab == MainTab::Yield && app.yield_state.is_searching {
+                            app.yield_state.add_search_char('#');
+                            yield_search_debounce = Some(tokio::time::Instant::now());
+                        } else if app.main_tab == MainTab::Yield && app.yield_state.is_filtering {
+                            app.yield_state.add_filter_char('#');
+                        } else if app.is_in_filter_mode() {
+                            app.add_search_char('#');
+                        } else if (app.main_tab == MainTab::Trending
+                            || app.main_tab == MainTab::Favorites)
+                            && !app.has_popup()
+                        {
+                            app.open_tag_filter();
+                        }
+                    },
+                    KeyCode::Char('w') => {
+                        // Watch every visible event
+                        if app.main_tab == MainTab::Yield && app.yield_state.is_searching {
+                            app.yield_state.add_search_char('w');
+                            yield_search_debounce = Some(tokio::time::Instant::now());
+                        } else if app.main_tab == MainTab::Yield && app.yield_state.is_filtering {
+                            app.yield_state.add_filter_char('w');
+                        } else if app.is_in_filter_mode() {
+                            app.add_search_char('w');
+                        } else if (app.main_tab == MainTab::Trending
+                            || app.main_tab == MainTab::Favorites)
+                            && !app.has_popup()
+                        {
+                            spawn_watch_all_visible(&app_state, &mut app);
+                        }
+                    },
+                    KeyCode::Char('W') => {
+                        // Stop all live trade streams
+                        if app.main_tab == MainTab::Yield && app.yield_state.is_searching {
+                            app.yield_state.add_search_char('W');
+                            yield_search_debounce = Some(tokio::time::Instant::now());
+                        } else if app.main_tab == MainTab::Yield && app.yield_state.is_filtering {
+                            app.yield_state.add_filter_char('W');
+                        } else if app.is_in_filter_mode() {
+                            app.add_search_char('W');
+                        } else if !app.has_popup() {
+                            let stopped = app.stop_watching_all();
+                            log_info!("Stopped {} event stream(s)", stopped);
+                            app.show_toast(format!("Stopped watching {} events", stopped));
+                        }
+                    },
+                    KeyCode::Char('c') => {
+                        // Cycle the color theme preset
+                        if app.main_tab == MainTab::Yield && app.yield_state.is_searching {
+                            app.yield_state.add_search_char('c');
+                            yield_search_debounce = Some(tokio::time::Instant::now());
+                        } else if app.main_tab == MainTab::Yield && app.yield_state.is_filtering {
+                            app.yield_state.add_filter_char('c');
+                        } else if app.is_in_filter_mode() {
+                            app.add_search_char('c');
+                        } else if !app.has_popup() {
+                            let preset = app.ui_prefs.theme_preset.next();
+                            app.ui_prefs.theme_preset = preset;
+                            if let Err(_e) = app.ui_prefs.save() {
+                                log_warn!("Failed to save UI preferences: {}", _e);
+                            }
+                            app.show_toast(format!("Theme: {}", preset.label()));
+                        }
+                    },
+                    KeyCode::Char('A') => {
+                        // Cycle the large-trade alert threshold
+                        if app.main_tab == MainTab::Yield && app.yield_state.is_searching {
+                            app.yield_state.add_search_char('A');
+                            yield_search_debounce = Some(tokio::time::Instant::now());
+                        } else if app.main_tab == MainTab::Yield && app.yield_state.is_filtering {
+                            app.yield_state.add_filter_char('A');
+                        } else if app.is_in_filter_mode() {
+                            app.add_search_char('A');
+                        } else if !app.has_popup() {
+                            let message = match app.trades.cycle_trade_alert_threshold() {
+                                Some(threshold) => format!(
+                                    "Alerting on live trades over {}",
+                                    render::utils::format_volume(threshold)
+                                ),
+                                None => "Large-trade alerts off".to_string(),
+                            };
+                            app.show_toast(message);
+                        }
+                    },
+                    KeyCode::Char(',') => {
+                        // Settings popup: auto-refresh interval per data source
+                        if app.main_tab == MainTab::Yield && app.yield_state.is_searching {
+                            app.yield_state.add_search_char(',');
+                            yield_search_debounce = Some(tokio::time::Instant::now());
+                        } else if app.main_tab == MainTab::Yield && app.yield_state.is_filtering {
+                            app.yield_state.add_filter_char(',');
+                        } else if app.is_in_filter_mode() {
+                            app.add_search_char(',');
+                        } else if !app.has_popup() {
+                            app.show_popup(PopupType::Settings);
+                        }
+                    },
+                    KeyCode::Char('d') => {
+                        // Split view: follow the selected event's trades in a second panel
+                        if app.main_tab == MainTab::Yield && app.yield_state.is_searching {
+                            app.yield_state.add_search_char('d');
+                            yield_search_debounce = Some(tokio::time::Instant::now());
+                        } else if app.main_tab == MainTab::Yield && app.yield_state.is_filtering {
+                            app.yield_state.add_filter_char('d');
+                        } else if app.is_in_filter_mode() {
+                            app.add_search_char('d');
+                        } else if matches!(app.main_tab, MainTab::Trending | MainTab::Favorites)
+                            && !app.has_popup()
+                        {
+                            if let Some(_slug) = app.exit_split_watch() {
+                                log_info!("Closed split view for {}", _slug);
+                                app.show_toast("Split view closed");
+                            } else if let Some(event) = app.current_tab_selected_event() {
+                                let slug = event.slug.clone();
+                                let message = format!("Split view: {}", truncate(&event.title, 40));
+                                let started_stream = !app.is_watching(&slug);
+                                if started_stream {
+                                    spawn_watch_event(&app_state, &mut app, slug.clone());
+                                }
+                                log_info!("Split view following {}", slug);
+                                app.split_watch_started_stream = started_stream;
+                                app.secondary_watch_slug = Some(slug);
+                                app.show_toast(message);
+                            }
+                        }
+                    },
+                    KeyCode::Char(':') => {
+                        // Search every action by name and run the picked one
+                        if app.main_tab == MainTab::Yield && app.yield_state.is_searching {
+                            app.yield_state.add_search_char(':');
+                            yield_search_debounce = Some(tokio::time::Instant::now());
+                        } else if app.main_tab == MainTab::Yield && app.yield_state.is_filtering {
+                            app.yield_state.add_filter_char(':');
+                        } else if app.is_in_filter_mode() {
+                            app.add_search_char(':');
+                        } else if !app.has_popup() {
+                            app.command_palette.reset();
+                            app.show_popup(PopupType::CommandPalette);
+                        }
+                    },
+                    KeyCode::Char('n') => {
+                        // Add or edit a personal note on the selected event
+                        if app.main_tab == MainTab::Yield && app.yield_state.is_searching {
+                            app.yield_state.add_search_char('n');
+                            yield_search_debounce = Some(tokio::time::Instant::now());
+                        } else if app.main_tab == MainTab::Yield && app.yield_state.is_filtering {
+                            app.yield_state.add_filter_char('n');
+                        } else if app.is_in_filter_mode() {
+                            app.add_search_char('n');
+                        } else if !app.has_popup()
+                            && let Some(slug) =
+                                app.current_tab_selected_event().map(|e| e.slug.clone())
+                        {
+                            app.open_note_editor(slug);
+                        }
+                    },
+                    KeyCode::Char('y') => {
+                        // Show only events whose markets the live trade feed can stream
+                        if app.main_tab == MainTab::Yield && app.yield_state.is_searching {
+                            app.yield_state.add_search_char('y');
+                            yield_search_debounce = Some(tokio::time::Instant::now());
+                        } else if app.main_tab == MainTab::Yield && app.yield_state.is_filtering {
+                            app.yield_state.add_filter_char('y');
+                        } else if app.is_in_filter_mode() {
+                            app.add_search_char('y');
+                        } else if matches!(app.main_tab, MainTab::Trending | MainTab::Favorites)
+                            && !app.has_popup()
+                        {
+                            let message = if app.toggle_streamable_only() {
+                                "Showing only events with live trades"
+                            } else {
+                                "Showing all events"
+                            };
+                            log_info!("{}", message);
+                            app.show_toast(message);
+                        }
+                    },
+                    KeyCode::Char('z') => {
+                        // Compact events lists: icons, title and market count only
+                        if app.main_tab == MainTab::Yield && app.yield_state.is_searching {
+                            app.yield_state.add_search_char('z');
+                            yield_search_debounce = Some(tokio::time::Instant::now());
+                        } else if app.main_tab == MainTab::Yield && app.yield_state.is_filtering {
+                            app.yield_state.add_filter_char('z');
+                        } else if app.is_in_filter_mode() {
+                            app.add_search_char('z');
+                        } else if !app.has_popup() {
+                            app.ui_prefs.compact_mode = !app.ui_prefs.compact_mode;
+                            if let Err(_e) = app.ui_prefs.save() {
+                                log_warn!("Failed to save UI preferences: {}", _e);
+                            }
+                            let message = if app.ui_prefs.compact_mode {
+                                "Compact events list"
+                            } else {
+                                "Full events list"
+                            };
+                            app.show_toast(message);
+                        }
+                    },
+                    KeyCode::Char('V') => {
+                        // Show liquidity next to the sort metric in the events list
+                        if app.main_tab == MainTab::Yield && app.yield_state.is_searching {
+                            app.yield_state.add_search_char('V');
+                            yield_search_debounce = Some(tokio::time::Instant::now());
+                        } else if app.main_tab == MainTab::Yield && app.yield_state.is_filtering {
+                            app.yield_state.add_filter_char('V');
+                        } else if app.is_in_filter_mode() {
+                            app.add_search_char('V');
+                        } else if !app.has_popup() {
+                            app.ui_prefs.show_liquidity = !app.ui_prefs.show_liquidity;
+                            if let Err(_e) = app.ui_prefs.save() {
+                                log_warn!("Failed to save UI preferences: {}", _e);
+                            }
+                            let message = if app.ui_prefs.show_liquidity {
+                                "Showing liquidity in the events list"
+                            } else {
+                                "Hiding liquidity in the events list"
+                            };
+                            app.show_toast(message);
+                        }
+                    },
+                    KeyCode::Char('D') => {
+                        // Collapse the event details panel to a single line
+                        if app.main_tab == MainTab::Yield && app.yield_state.is_searching {
+                            app.yield_state.add_search_char('D');
+                            yield_search_debounce = Some(tokio::time::Instant::now());
+                        } else if app.main_tab == MainTab::Yield && app.yield_state.is_filtering {
+                            app.yield_state.add_filter_char('D');
+                        } else if app.is_in_filter_mode() {
+                            app.add_search_char('D');
+                        } else if !app.has_popup() {
+                            app.compact_event_details = !app.compact_event_details;
+                            app.scroll.event_details = 0;
+                            let message = if app.compact_event_details {
+                                "Compact event details"
+                            } else {
+                                "Full event details"
+                            };
+                            app.show_toast(message);
+                        }
+                    },
+                    KeyCode::Char('O') => {
+                        // Flip the trades feed between newest-first and oldest-first
+                        if app.main_tab == MainTab::Yield && app.yield_state.is_searching {
+                            app.yield_state.add_search_char('O');
+                            yield_search_debounce = Some(tokio::time::Instant::now());
+                        } else if app.main_tab == MainTab::Yield && app.yield_state.is_filtering {
+                            app.yield_state.add_filter_char('O');
+                        } else if app.is_in_filter_mode() {
+                            app.add_search_char('O');
+                        } else if !app.has_popup() {
+                            let oldest_first = !app.trades.oldest_first;
+                            app.trades.oldest_first = oldest_first;
+                            app.show_toast(if oldest_first {
+                                "Trades: oldest first"
+                            } else {
+                                "Trades: newest first"
+                            });
+                        }
+                    },
+                    KeyCode::Char('E') => {
+                        // Show captured API failures
+                        if app.main_tab == MainTab::Yield && app.yield_state.is_searching {
+                            app.yield_state.add_search_char('E');
+                            yield_search_debounce = Some(tokio::time::Instant::now());
+                        } else if app.main_tab == MainTab::Yield && app.yield_state.is_filtering {
+                            app.yield_state.add_filter_char('E');
+                        } else if app.is_in_filter_mode() {
+                            app.add_search_char('E');
+                        } else if !app.has_popup() {
+                            app.api_errors.scroll = 0;
+                            app.show_popup(PopupType::ApiErrors);
+                        }
+                    },
                     KeyCode::Char('r') => {
                         if app.main_tab == MainTab::Yield && app.yield_state.is_searching {
                             // In yield search mode, add 'r' to search query
@@ -2079,9 +3318,6 @@ pub async fn run_trending_tui(
                         } else if app.is_in_filter_mode() {
                             // In filter mode, add 'r' to search query
                             app.add_search_char('r');
-                            if app.search.mode == SearchMode::ApiSearch {
-                                search_debounce = Some(tokio::time::Instant::now());
-                            }
                         } else if app.main_tab == MainTab::Yield {
                             // Refresh yield opportunities
                             if !app.yield_state.is_loading {
@@ -2092,38 +3328,27 @@ pub async fn run_trending_tui(
                                     spawn_fetch_favorites(Arc::clone(&app_state));
                                 }
                             }
+                        } else if app.main_tab == MainTab::Positions {
+                            // Refresh positions (and the portfolio totals in the header)
+                            if app.auth_state.is_authenticated {
+                                log_info!("Refreshing positions...");
+                                spawn_load_positions(&app_state, &mut app, true);
+                            }
                         } else if app.main_tab == MainTab::Favorites {
                             // Refresh favorites list
-                            if !app.favorites_state.is_loading && app.auth_state.is_authenticated {
+                            if !app.favorites_state.is_loading && app.can_load_favorites() {
                                 log_info!("Refreshing favorites...");
                                 spawn_fetch_favorites(Arc::clone(&app_state));
                             }
                         } else if app.navigation.focused_panel == FocusedPanel::EventsList {
-                            // Refresh events list and update cache
-                            let current_filter = app.event_filter;
-                            let limit = app.pagination.current_limit;
-                            let app_state_clone = Arc::clone(&app_state);
-                            let gamma_client = GammaClient::new();
-                            let is_authenticated = app.auth_state.is_authenticated;
-
-                            log_info!("Refreshing events list...");
-
-                            tokio::spawn(async move {
-                                match fetch_events_for_filter(&gamma_client, current_filter, limit)
-                                    .await
-                                {
-                                    Ok(new_events) => {
-                                        let mut app = app_state_clone.lock().await;
-                                        // Update cache for current filter
-                                        app.events_cache.insert(current_filter, new_events.clone());
-                                        app.events = new_events;
-                                        log_info!("Events refreshed ({} events)", app.events.len());
-                                    },
-                                    Err(_e) => {
-                                        log_info!("Failed to refresh events: {}", _e);
-                                    },
-                                }
-                            });
+                            // Refresh events list and update cache
+                            log_info!("Refreshing events list...");
+                            spawn_refresh_events(
+                                Arc::clone(&app_state),
+                                app.event_filter,
+                                app.pagination.current_limit,
+                            );
+                            let is_authenticated = app.auth_state.is_authenticated;
 
                             // Also refresh favorites in background to sync bookmark icons
                             if is_authenticated {
@@ -2132,184 +3357,223 @@ pub async fn run_trending_tui(
                         } else if app.navigation.focused_panel == FocusedPanel::Markets
                             && let Some(event) = app.selected_event()
                         {
-                            // Refresh market prices
-                            // Only fetch prices for active (non-closed) markets
-                            let active_markets: Vec<_> = event
-                                .markets
-                                .iter()
-                                .filter(|m| !m.closed)
-                                .filter_map(|m| m.clob_token_ids.clone())
-                                .collect();
-
-                            let _active_count = active_markets.len();
-                            let _closed_count = event.markets.iter().filter(|m| m.closed).count();
+                            // Refresh the active markets' prices
+                            let _active_count = event.markets.iter().filter(|m| !m.closed).count();
                             log_info!(
                                 "Refreshing market prices for event: {} ({} active, {} resolved)",
                                 event.slug,
                                 _active_count,
-                                _closed_count
+                                event.markets.len() - _active_count
                             );
-
-                            if active_markets.is_empty() {
-                                log_info!("No active markets to refresh");
-                            } else {
-                                let app_state_clone = Arc::clone(&app_state);
-                                let clob_client = ClobClient::new();
-
-                                tokio::spawn(async move {
-                                    let prices =
-                                        fetch_market_prices_batch(&clob_client, active_markets)
-                                            .await;
-                                    let mut app = app_state_clone.lock().await;
-                                    app.market_prices.extend(prices);
-                                    log_info!("Market prices refreshed via batch API");
-                                });
-                            }
+                            spawn_refresh_event_prices(&app_state, &app, event);
                         }
                     },
-                    KeyCode::Tab => {
-                        if !app.is_in_filter_mode() {
-                            // Cycle through panels, skipping Logs if hidden
-                            app.navigation.focused_panel = match app.navigation.focused_panel {
-                                FocusedPanel::Header => FocusedPanel::EventsList,
-                                FocusedPanel::EventsList => FocusedPanel::EventDetails,
-                                FocusedPanel::EventDetails => FocusedPanel::Markets,
-                                FocusedPanel::Markets => FocusedPanel::Trades,
-                                FocusedPanel::Trades => {
-                                    if app.show_logs {
-                                        FocusedPanel::Logs
-                                    } else {
-                                        FocusedPanel::Header
-                                    }
-                                },
-                                FocusedPanel::Logs => FocusedPanel::Header,
-                            };
-                        }
+                    KeyCode::Tab if !app.is_in_filter_mode() => {
+                        // Cycle through panels, skipping Logs if hidden
+                        app.navigation.focused_panel =
+                            app.navigation.focused_panel.next(app.show_logs);
                     },
-                    KeyCode::Left => {
+                    KeyCode::BackTab if !app.is_in_filter_mode() => {
+                        // Same rotation as Tab, backwards
+                        app.navigation.focused_panel =
+                            app.navigation.focused_panel.prev(app.show_logs);
+                    },
+                    KeyCode::Left
                         if !app.is_in_filter_mode()
-                            && app.navigation.focused_panel == FocusedPanel::Header
-                        {
-                            // Cycle through all tabs: Yield -> Breaking -> Favorites -> Events -> Yield
-                            match app.main_tab {
-                                MainTab::Trending => {
-                                    match app.event_filter {
-                                        EventFilter::Trending => {
-                                            // Wrap to Yield tab
-                                            app.main_tab = MainTab::Yield;
-                                            if app.yield_state.opportunities.is_empty()
-                                                && !app.yield_state.is_loading
-                                            {
-                                                drop(app);
-                                                spawn_yield_fetch(Arc::clone(&app_state));
-                                            }
-                                        },
-                                        EventFilter::Breaking => {
-                                            // Go to Favorites tab
-                                            app.main_tab = MainTab::Favorites;
-                                            if app.favorites_state.events.is_empty()
-                                                && !app.favorites_state.is_loading
-                                                && app.auth_state.is_authenticated
-                                            {
-                                                drop(app);
-                                                spawn_fetch_favorites(Arc::clone(&app_state));
-                                            }
-                                        },
-                                    }
-                                },
-                                MainTab::Favorites => {
-                                    // Go to Events tab
-                                    app.main_tab = MainTab::Trending;
-                                    if let Some((filter, limit)) =
-                                        switch_filter_tab(&mut app, EventFilter::Trending)
-                                    {
-                                        drop(app);
-                                        spawn_filter_fetch(Arc::clone(&app_state), filter, limit);
-                                    }
-                                },
-                                MainTab::Yield => {
-                                    // Go to Breaking tab
-                                    app.main_tab = MainTab::Trending;
-                                    if let Some((filter, limit)) =
-                                        switch_filter_tab(&mut app, EventFilter::Breaking)
-                                    {
-                                        drop(app);
-                                        spawn_filter_fetch(Arc::clone(&app_state), filter, limit);
-                                    }
-                                },
-                            }
+                            && app.navigation.focused_panel == FocusedPanel::Header =>
+                    {
+                        // Cycle through all tabs: New -> Closing -> Positions -> Yield -> Breaking ->
+                        // Favorites -> Events -> New
+                        match app.main_tab {
+                            MainTab::Trending => {
+                                match app.event_filter {
+                                    EventFilter::Trending => {
+                                        // Wrap to New tab
+                                        if let Some((filter, limit)) =
+                                            switch_filter_tab(&mut app, EventFilter::NewlyListed)
+                                        {
+                                            drop(app);
+                                            spawn_filter_fetch(
+                                                Arc::clone(&app_state),
+                                                filter,
+                                                limit,
+                                            );
+                                        }
+                                    },
+                                    EventFilter::NewlyListed => {
+                                        // Go to Closing Soon tab
+                                        if let Some((filter, limit)) =
+                                            switch_filter_tab(&mut app, EventFilter::ClosingSoon)
+                                        {
+                                            drop(app);
+                                            spawn_filter_fetch(
+                                                Arc::clone(&app_state),
+                                                filter,
+                                                limit,
+                                            );
+                                        }
+                                    },
+                                    EventFilter::ClosingSoon => {
+                                        // Go to Positions tab
+                                        app.main_tab = MainTab::Positions;
+                                        spawn_load_positions(&app_state, &mut app, false);
+                                    },
+                                    EventFilter::Breaking => {
+                                        // Go to Favorites tab
+                                        app.main_tab = MainTab::Favorites;
+                                        if app.favorites_state.events.is_empty()
+                                            && !app.favorites_state.is_loading
+                                            && app.can_load_favorites()
+                                        {
+                                            drop(app);
+                                            spawn_fetch_favorites(Arc::clone(&app_state));
+                                        }
+                                    },
+                                }
+                            },
+                            MainTab::Favorites => {
+                                // Go to Events tab
+                                app.main_tab = MainTab::Trending;
+                                if let Some((filter, limit)) =
+                                    switch_filter_tab(&mut app, EventFilter::Trending)
+                                {
+                                    drop(app);
+                                    spawn_filter_fetch(Arc::clone(&app_state), filter, limit);
+                                }
+                            },
+                            MainTab::Yield => {
+                                // Go to Breaking tab
+                                app.main_tab = MainTab::Trending;
+                                if let Some((filter, limit)) =
+                                    switch_filter_tab(&mut app, EventFilter::Breaking)
+                                {
+                                    drop(app);
+                                    spawn_filter_fetch(Arc::clone(&app_state), filter, limit);
+                                }
+                            },
+                            MainTab::Positions => {
+                                // Go to Yield tab
+                                app.main_tab = MainTab::Yield;
+                                if app.yield_state.opportunities.is_empty()
+                                    && !app.yield_state.is_loading
+                                {
+                                    drop(app);
+                                    spawn_yield_fetch(Arc::clone(&app_state));
+                                }
+                            },
                         }
                     },
-                    KeyCode::Right => {
+                    KeyCode::Right
                         if !app.is_in_filter_mode()
-                            && app.navigation.focused_panel == FocusedPanel::Header
-                        {
-                            // Cycle through all tabs: Events -> Favorites -> Breaking -> Yield -> Events
-                            match app.main_tab {
-                                MainTab::Trending => {
-                                    match app.event_filter {
-                                        EventFilter::Trending => {
-                                            // Go to Favorites tab
-                                            app.main_tab = MainTab::Favorites;
-                                            if app.favorites_state.events.is_empty()
-                                                && !app.favorites_state.is_loading
-                                                && app.auth_state.is_authenticated
-                                            {
-                                                drop(app);
-                                                spawn_fetch_favorites(Arc::clone(&app_state));
-                                            }
-                                        },
-                                        EventFilter::Breaking => {
-                                            // Go to Yield tab
-                                            app.main_tab = MainTab::Yield;
-                                            if app.yield_state.opportunities.is_empty()
-                                                && !app.yield_state.is_loading
-                                            {
-                                                drop(app);
-                                                spawn_yield_fetch(Arc::clone(&app_state));
-                                            }
-                                        },
-                                    }
-                                },
-                                MainTab::Favorites => {
-                                    // Go to Breaking tab
-                                    app.main_tab = MainTab::Trending;
-                                    if let Some((filter, limit)) =
-                                        switch_filter_tab(&mut app, EventFilter::Breaking)
-                                    {
-                                        drop(app);
-                                        spawn_filter_fetch(Arc::clone(&app_state), filter, limit);
-                                    }
-                                },
-                                MainTab::Yield => {
-                                    // Wrap to Events tab
-                                    app.main_tab = MainTab::Trending;
-                                    if let Some((filter, limit)) =
-                                        switch_filter_tab(&mut app, EventFilter::Trending)
-                                    {
-                                        drop(app);
-                                        spawn_filter_fetch(Arc::clone(&app_state), filter, limit);
-                                    }
-                                },
-                            }
+                            && app.navigation.focused_panel == FocusedPanel::Header =>
+                    {
+                        // Cycle through all tabs: Events -> Favorites -> Breaking -> Yield -> Positions ->
+                        // Closing -> New -> Events
+                        match app.main_tab {
+                            MainTab::Trending => {
+                                match app.event_filter {
+                                    EventFilter::Trending => {
+                                        // Go to Favorites tab
+                                        app.main_tab = MainTab::Favorites;
+                                        if app.favorites_state.events.is_empty()
+                                            && !app.favorites_state.is_loading
+                                            && app.can_load_favorites()
+                                        {
+                                            drop(app);
+                                            spawn_fetch_favorites(Arc::clone(&app_state));
+                                        }
+                                    },
+                                    EventFilter::Breaking => {
+                                        // Go to Yield tab
+                                        app.main_tab = MainTab::Yield;
+                                        if app.yield_state.opportunities.is_empty()
+                                            && !app.yield_state.is_loading
+                                        {
+                                            drop(app);
+                                            spawn_yield_fetch(Arc::clone(&app_state));
+                                        }
+                                    },
+                                    EventFilter::ClosingSoon => {
+                                        // Go to New tab
+                                        if let Some((filter, limit)) =
+                                            switch_filter_tab(&mut app, EventFilter::NewlyListed)
+                                        {
+                                            drop(app);
+                                            spawn_filter_fetch(
+                                                Arc::clone(&app_state),
+                                                filter,
+                                                limit,
+                                            );
+                                        }
+                                    },
+                                    EventFilter::NewlyListed => {
+                                        // Wrap to Events tab
+                                        if let Some((filter, limit)) =
+                                            switch_filter_tab(&mut app, EventFilter::Trending)
+                                        {
+                                            drop(app);
+                                            spawn_filter_fetch(
+                                                Arc::clone(&app_state),
+                                                filter,
+                                                limit,
+                                            );
+                                        }
+                                    },
+                                }
+                            },
+                            MainTab::Favorites => {
+                                // Go to Breaking tab
+                                app.main_tab = MainTab::Trending;
+                                if let Some((filter, limit)) =
+                                    switch_filter_tab(&mut app, EventFilter::Breaking)
+                                {
+                                    drop(app);
+                                    spawn_filter_fetch(Arc::clone(&app_state), filter, limit);
+                                }
+                            },
+                            MainTab::Yield => {
+                                // Go to Positions tab
+                                app.main_tab = MainTab::Positions;
+                                spawn_load_positions(&app_state, &mut app, false);
+                            },
+                            MainTab::Positions => {
+                                // Go to Closing Soon tab
+                                app.main_tab = MainTab::Trending;
+                                if let Some((filter, limit)) =
+                                    switch_filter_tab(&mut app, EventFilter::ClosingSoon)
+                                {
+                                    drop(app);
+                                    spawn_filter_fetch(Arc::clone(&app_state), filter, limit);
+                                }
+                            },
                         }
                     },
                     KeyCode::Up => {
                         if !app.is_in_filter_mode() {
                             // Handle favorites tab navigation
                             if app.main_tab == MainTab::Favorites {
-                                app.favorites_state.move_up();
+                                for _ in 0..move_count {
+                                    app.favorites_state.move_up();
+                                }
+                                continue;
+                            }
+                            // Handle positions tab navigation
+                            if app.main_tab == MainTab::Positions {
+                                for _ in 0..move_count {
+                                    app.positions_state.move_up();
+                                }
                                 continue;
                             }
                             // Handle yield tab navigation
                             if app.main_tab == MainTab::Yield {
-                                app.yield_state.move_up();
+                                for _ in 0..move_count {
+                                    app.yield_state.move_up();
+                                }
                                 // Fetch event if not in cache
-                                if let Some(opp) = app.yield_state.selected_opportunity() {
-                                    let slug = opp.event_slug.clone();
-                                    if app.get_cached_event(&slug).is_none() {
-                                        spawn_fetch_event_for_cache(Arc::clone(&app_state), slug);
-                                    }
+                                if let Some(slug) = app.yield_state.selected_event_slug()
+                                    && app.get_cached_event(&slug).is_none()
+                                {
+                                    spawn_fetch_event_for_cache(Arc::clone(&app_state), slug);
                                 }
                                 continue;
                             }
@@ -2318,107 +3582,30 @@ pub async fn run_trending_tui(
                                     // Header doesn't scroll, but we can allow it for consistency
                                 },
                                 FocusedPanel::EventsList => {
-                                    app.move_up();
-                                    // Fetch market prices and trade counts when event selection changes
-                                    if let Some(event) = app.selected_event() {
-                                        let current_slug = event.slug.clone();
-                                        if last_selected_event_slug.as_ref() != Some(&current_slug)
-                                        {
-                                            last_selected_event_slug = Some(current_slug.clone());
-                                            // Only fetch prices for active (non-closed) markets
-                                            let active_markets: Vec<_> = event
-                                                .markets
-                                                .iter()
-                                                .filter(|m| !m.closed)
-                                                .filter_map(|m| m.clob_token_ids.clone())
-                                                .collect();
-
-                                            if !active_markets.is_empty() {
-                                                let app_state_clone = Arc::clone(&app_state);
-                                                let clob_client = ClobClient::from_env();
-
-                                                tokio::spawn(async move {
-                                                    let prices = fetch_market_prices_batch(
-                                                        &clob_client,
-                                                        active_markets,
-                                                    )
-                                                    .await;
-                                                    let mut app = app_state_clone.lock().await;
-                                                    app.market_prices.extend(prices);
-                                                });
-                                            }
-
-                                            // Fetch trade counts if authenticated and not already fetched
-                                            let has_auth = app.has_clob_auth;
-                                            let already_fetched =
-                                                app.event_trade_counts.contains_key(&current_slug);
-                                            if has_auth && !already_fetched {
-                                                // Market ID is the condition_id used by CLOB API
-                                                let condition_ids: Vec<String> = event
-                                                    .markets
-                                                    .iter()
-                                                    .filter_map(|m| m.id.clone())
-                                                    .collect();
-                                                if !condition_ids.is_empty() {
-                                                    let app_state_clone = Arc::clone(&app_state);
-                                                    let slug_clone = current_slug.clone();
-                                                    let clob_client = ClobClient::from_env();
-
-                                                    tokio::spawn(async move {
-                                                        if let Some(count) =
-                                                            fetch_event_trade_count(
-                                                                &clob_client,
-                                                                condition_ids,
-                                                            )
-                                                            .await
-                                                        {
-                                                            let mut app =
-                                                                app_state_clone.lock().await;
-                                                            app.event_trade_counts
-                                                                .insert(slug_clone, count);
-                                                            log_info!(
-                                                                "Fetched trade count: {} trades",
-                                                                count
-                                                            );
-                                                        }
-                                                    });
-                                                }
-                                            }
-
-                                            // Fetch orderbook for the first market's first outcome (Yes)
-                                            // Use sorted markets (non-closed first)
-                                            let orderbook_info: Option<(String, bool)> = {
-                                                let mut sorted: Vec<_> =
-                                                    event.markets.iter().collect();
-                                                sorted.sort_by_key(|m| m.closed);
-                                                sorted.first().and_then(|market| {
-                                                    market.clob_token_ids.as_ref().and_then(|ids| {
-                                                        ids.first()
-                                                            .cloned()
-                                                            .map(|id| (id, !market.closed))
-                                                    })
-                                                })
-                                            };
-                                            app.orderbook_state.reset();
-                                            if let Some((token_id, is_active)) = orderbook_info {
-                                                spawn_fetch_orderbook(
-                                                    Arc::clone(&app_state),
-                                                    token_id,
-                                                    is_active,
-                                                );
-                                            }
-                                        }
+                                    for _ in 0..move_count {
+                                        app.move_up();
+                                    }
+                                    // Fetch market prices, the trade count and the orderbook
+                                    // when the event selection changes
+                                    let current_slug = app.selected_event().map(|e| e.slug.clone());
+                                    if current_slug.is_some()
+                                        && current_slug != last_selected_event_slug
+                                    {
+                                        last_selected_event_slug = current_slug;
+                                        spawn_event_selection_fetches(&app_state, &mut app);
                                     }
                                 },
                                 FocusedPanel::EventDetails => {
-                                    if app.scroll.event_details > 0 {
-                                        app.scroll.event_details -= 1;
-                                    }
+                                    app.scroll.event_details =
+                                        app.scroll.event_details.saturating_sub(move_count);
                                 },
                                 FocusedPanel::Markets => {
                                     // Move selected market up and fetch orderbook
                                     if app.orderbook_state.selected_market_index > 0 {
-                                        app.orderbook_state.selected_market_index -= 1;
+                                        app.orderbook_state.selected_market_index = app
+                                            .orderbook_state
+                                            .selected_market_index
+                                            .saturating_sub(move_count);
                                         // Adjust scroll if needed to keep selection visible
                                         if app.orderbook_state.selected_market_index
                                             < app.scroll.markets
@@ -2428,9 +3615,7 @@ pub async fn run_trending_tui(
                                         }
                                         // Fetch orderbook for new selection (use sorted markets)
                                         if let Some(event) = app.selected_event() {
-                                            let mut sorted_markets: Vec<_> =
-                                                event.markets.iter().collect();
-                                            sorted_markets.sort_by_key(|m| m.closed);
+                                            let sorted_markets = app.sorted_markets(event);
                                             let market_idx =
                                                 app.orderbook_state.selected_market_index;
                                             let outcome_idx =
@@ -2457,37 +3642,53 @@ pub async fn run_trending_tui(
                                     }
                                 },
                                 FocusedPanel::Trades => {
-                                    if app.scroll.trades > 0 {
-                                        app.scroll.trades -= 1;
-                                    }
+                                    app.scroll.trades =
+                                        app.scroll.trades.saturating_sub(move_count);
                                 },
                                 FocusedPanel::Logs => {
-                                    if app.logs.scroll > 0 {
-                                        app.logs.scroll -= 1;
-                                    }
+                                    app.logs.scroll = app.logs.scroll.saturating_sub(move_count);
                                 },
                             }
+                        } else if app.search.mode == SearchMode::ApiSearch {
+                            // Cycle through previous API searches
+                            app.recall_search_history(true);
                         }
                     },
                     KeyCode::Down => {
                         if !app.is_in_filter_mode() {
+                            let term_size = terminal.size()?;
+                            let heights = calculate_panel_heights(
+                                Rect::new(0, 0, term_size.width, term_size.height),
+                                app.top_height(),
+                                app.show_logs,
+                                app.main_tab,
+                                app.event_details_height(),
+                                app.orderbook_state.last_height,
+                            );
                             // Handle favorites tab navigation
                             if app.main_tab == MainTab::Favorites {
-                                let visible_height = 20; // Approximate visible rows
-                                app.favorites_state.move_down(visible_height);
+                                for _ in 0..move_count {
+                                    app.favorites_state.move_down(heights.events_list);
+                                }
+                                continue;
+                            }
+                            // Handle positions tab navigation
+                            if app.main_tab == MainTab::Positions {
+                                for _ in 0..move_count {
+                                    app.positions_state.move_down(heights.events_list);
+                                }
                                 continue;
                             }
                             // Handle yield tab navigation
                             if app.main_tab == MainTab::Yield {
-                                // Calculate visible height (approximate)
-                                let visible_height = 20; // Approximate visible rows
-                                app.yield_state.move_down(visible_height);
+                                for _ in 0..move_count {
+                                    app.yield_state.move_down(heights.events_list);
+                                }
                                 // Fetch event if not in cache
-                                if let Some(opp) = app.yield_state.selected_opportunity() {
-                                    let slug = opp.event_slug.clone();
-                                    if app.get_cached_event(&slug).is_none() {
-                                        spawn_fetch_event_for_cache(Arc::clone(&app_state), slug);
-                                    }
+                                if let Some(slug) = app.yield_state.selected_event_slug()
+                                    && app.get_cached_event(&slug).is_none()
+                                {
+                                    spawn_fetch_event_for_cache(Arc::clone(&app_state), slug);
                                 }
                                 continue;
                             }
@@ -2496,162 +3697,21 @@ pub async fn run_trending_tui(
                                     // Header doesn't scroll, but we can allow it for consistency
                                 },
                                 FocusedPanel::EventsList => {
-                                    app.move_down();
-                                    // Fetch market prices and trade counts when event selection changes
-                                    if let Some(event) = app.selected_event() {
-                                        let current_slug = event.slug.clone();
-                                        if last_selected_event_slug.as_ref() != Some(&current_slug)
-                                        {
-                                            last_selected_event_slug = Some(current_slug.clone());
-                                            // Only fetch prices for active (non-closed) markets
-                                            let active_markets: Vec<_> = event
-                                                .markets
-                                                .iter()
-                                                .filter(|m| !m.closed)
-                                                .filter_map(|m| m.clob_token_ids.clone())
-                                                .collect();
-
-                                            if !active_markets.is_empty() {
-                                                let app_state_clone = Arc::clone(&app_state);
-                                                let clob_client = ClobClient::from_env();
-
-                                                tokio::spawn(async move {
-                                                    let prices = fetch_market_prices_batch(
-                                                        &clob_client,
-                                                        active_markets,
-                                                    )
-                                                    .await;
-                                                    let mut app = app_state_clone.lock().await;
-                                                    app.market_prices.extend(prices);
-                                                });
-                                            }
-
-                                            // Fetch trade counts if authenticated and not already fetched
-                                            let has_auth = app.has_clob_auth;
-                                            let already_fetched =
-                                                app.event_trade_counts.contains_key(&current_slug);
-                                            if has_auth && !already_fetched {
-                                                // Market ID is the condition_id used by CLOB API
-                                                let condition_ids: Vec<String> = event
-                                                    .markets
-                                                    .iter()
-                                                    .filter_map(|m| m.id.clone())
-                                                    .collect();
-                                                if !condition_ids.is_empty() {
-                                                    let app_state_clone = Arc::clone(&app_state);
-                                                    let slug_clone = current_slug.clone();
-                                                    let clob_client = ClobClient::from_env();
-
-                                                    tokio::spawn(async move {
-                                                        if let Some(count) =
-                                                            fetch_event_trade_count(
-                                                                &clob_client,
-                                                                condition_ids,
-                                                            )
-                                                            .await
-                                                        {
-                                                            let mut app =
-                                                                app_state_clone.lock().await;
-                                                            app.event_trade_counts
-                                                                .insert(slug_clone, count);
-                                                            log_info!(
-                                                                "Fetched trade count: {} trades",
-                                                                count
-                                                            );
-                                                        }
-                                                    });
-                                                }
-                                            }
-
-                                            // Fetch orderbook for the first market's first outcome (Yes)
-                                            // Use sorted markets (non-closed first)
-                                            let orderbook_info: Option<(String, bool)> = {
-                                                let mut sorted: Vec<_> =
-                                                    event.markets.iter().collect();
-                                                sorted.sort_by_key(|m| m.closed);
-                                                sorted.first().and_then(|market| {
-                                                    market.clob_token_ids.as_ref().and_then(|ids| {
-                                                        ids.first()
-                                                            .cloned()
-                                                            .map(|id| (id, !market.closed))
-                                                    })
-                                                })
-                                            };
-                                            app.orderbook_state.reset();
-                                            if let Some((token_id, is_active)) = orderbook_info {
-                                                spawn_fetch_orderbook(
-                                                    Arc::clone(&app_state),
-                                                    token_id,
-                                                    is_active,
-                                                );
-                                            }
-                                        }
+                                    for _ in 0..move_count {
+                                        app.move_down(heights.events_list);
+                                    }
+                                    // Fetch market prices, the trade count and the orderbook
+                                    // when the event selection changes
+                                    let current_slug = app.selected_event().map(|e| e.slug.clone());
+                                    if current_slug.is_some()
+                                        && current_slug != last_selected_event_slug
+                                    {
+                                        last_selected_event_slug = current_slug;
+                                        spawn_event_selection_fetches(&app_state, &mut app);
                                     }
                                     // Check if we need to fetch more events (infinite scroll)
                                     if app.should_fetch_more() {
-                                        let app_state_clone = Arc::clone(&app_state);
-                                        let gamma_client_clone = GammaClient::new();
-                                        let current_filter = app.event_filter;
-                                        let current_limit = app.pagination.current_limit;
-
-                                        // Set fetching flag to prevent duplicate requests
-                                        app.pagination.is_fetching_more = true;
-
-                                        // Fetch 50 more events
-                                        let new_limit = current_limit + 50;
-                                        log_info!("Fetching more events (limit: {})", new_limit);
-
-                                        tokio::spawn(async move {
-                                            match fetch_events_for_filter(
-                                                &gamma_client_clone,
-                                                current_filter,
-                                                new_limit,
-                                            )
-                                            .await
-                                            {
-                                                Ok(mut new_events) => {
-                                                    // Remove duplicates by comparing slugs
-                                                    let existing_slugs: std::collections::HashSet<
-                                                        _,
-                                                    > = {
-                                                        let app = app_state_clone.lock().await;
-                                                        app.events
-                                                            .iter()
-                                                            .map(|e| e.slug.clone())
-                                                            .collect()
-                                                    };
-
-                                                    new_events.retain(|e| {
-                                                        !existing_slugs.contains(&e.slug)
-                                                    });
-
-                                                    if !new_events.is_empty() {
-                                                        log_info!(
-                                                            "Fetched {} new events",
-                                                            new_events.len()
-                                                        );
-                                                        let mut app = app_state_clone.lock().await;
-                                                        app.events.append(&mut new_events);
-                                                        app.pagination.current_limit = new_limit;
-                                                    } else {
-                                                        log_info!(
-                                                            "No new events to add (already have all events)"
-                                                        );
-                                                    }
-
-                                                    let mut app = app_state_clone.lock().await;
-                                                    app.pagination.is_fetching_more = false;
-                                                },
-                                                Err(_e) => {
-                                                    log_error!(
-                                                        "Failed to fetch more events: {}",
-                                                        _e
-                                                    );
-                                                    let mut app = app_state_clone.lock().await;
-                                                    app.pagination.is_fetching_more = false;
-                                                },
-                                            }
-                                        });
+                                        spawn_fetch_more_events(Arc::clone(&app_state), &mut app);
                                     }
                                 },
                                 FocusedPanel::EventDetails => {
@@ -2659,6 +3719,10 @@ pub async fn run_trending_tui(
                                     if let Some(event) = app.selected_event() {
                                         // Base lines: Title, Slug, Event ID, Status, Estimated End, Total Volume
                                         let mut total_lines = 6;
+                                        // Activity heatmap line once live trades are buffered
+                                        if !app.get_trades(&event.slug).is_empty() {
+                                            total_lines += 1;
+                                        }
 
                                         // Calculate wrapped tags lines
                                         if !event.tags.is_empty() {
@@ -2686,7 +3750,9 @@ pub async fn run_trending_tui(
                                         let max_scroll =
                                             total_lines.saturating_sub(visible_height.max(1));
                                         if app.scroll.event_details < max_scroll {
-                                            app.scroll.event_details += 1;
+                                            app.scroll.event_details = (app.scroll.event_details
+                                                + move_count)
+                                                .min(max_scroll);
                                         }
                                     }
                                 },
@@ -2694,13 +3760,11 @@ pub async fn run_trending_tui(
                                     // Move selected market down and fetch orderbook
                                     // Extract data we need before modifying app state (use sorted markets)
                                     let market_info = app.selected_event().and_then(|event| {
-                                        let mut sorted_markets: Vec<_> =
-                                            event.markets.iter().collect();
-                                        sorted_markets.sort_by_key(|m| m.closed);
+                                        let sorted_markets = app.sorted_markets(event);
                                         let max_index = sorted_markets.len().saturating_sub(1);
                                         let current_idx = app.orderbook_state.selected_market_index;
                                         if current_idx < max_index {
-                                            let new_idx = current_idx + 1;
+                                            let new_idx = (current_idx + move_count).min(max_index);
                                             let outcome_idx =
                                                 match app.orderbook_state.selected_outcome {
                                                     state::OrderbookOutcome::Yes => 0,
@@ -2747,9 +3811,10 @@ pub async fn run_trending_tui(
                                         0
                                     };
                                     let visible_height: usize = 10; // Approximate
-                                    if app.scroll.trades < trades_len.saturating_sub(visible_height)
-                                    {
-                                        app.scroll.trades += 1;
+                                    let max_scroll = trades_len.saturating_sub(visible_height);
+                                    if app.scroll.trades < max_scroll {
+                                        app.scroll.trades =
+                                            (app.scroll.trades + move_count).min(max_scroll);
                                     }
                                 },
                                 FocusedPanel::Logs => {
@@ -2762,10 +3827,14 @@ pub async fn run_trending_tui(
                                         .len()
                                         .saturating_sub(visible_height.max(1));
                                     if app.logs.scroll < max_scroll {
-                                        app.logs.scroll += 1;
+                                        app.logs.scroll =
+                                            (app.logs.scroll + move_count).min(max_scroll);
                                     }
                                 },
                             }
+                        } else if app.search.mode == SearchMode::ApiSearch {
+                            // Cycle through previous API searches
+                            app.recall_search_history(false);
                         }
                     },
                     KeyCode::Backspace => {
@@ -2778,10 +3847,6 @@ pub async fn run_trending_tui(
                             app.yield_state.delete_filter_char();
                         } else if app.is_in_filter_mode() {
                             app.delete_search_char();
-                            // Trigger API search after backspace only if in API search mode (with debounce)
-                            if app.search.mode == SearchMode::ApiSearch {
-                                search_debounce = Some(tokio::time::Instant::now());
-                            }
                         }
                     },
                     KeyCode::Char(c) => {
@@ -2794,10 +3859,6 @@ pub async fn run_trending_tui(
                             app.yield_state.add_filter_char(c);
                         } else if app.is_in_filter_mode() {
                             app.add_search_char(c);
-                            // Trigger API search after character input only if in API search mode (with debounce)
-                            if app.search.mode == SearchMode::ApiSearch {
-                                search_debounce = Some(tokio::time::Instant::now());
-                            }
                             // Local filter mode filters immediately (no API call needed)
                         }
                     },
@@ -2818,6 +3879,10 @@ pub async fn run_trending_tui(
                         // Only handle Enter when EventsList panel is focused
                         if app.navigation.focused_panel == FocusedPanel::EventsList {
                             if app.is_in_filter_mode() {
+                                // Enter skips the debounce window for a pending API search
+                                if let Some(query) = app.search.take_pending_search(true) {
+                                    spawn_event_search(Arc::clone(&app_state), query);
+                                }
                                 // Exit search/filter mode and keep selection
                                 app.search.mode = SearchMode::None;
                             } else {
@@ -2828,74 +3893,7 @@ pub async fn run_trending_tui(
                                         app.stop_watching(&event_slug);
                                     } else {
                                         // Start watching
-                                        let event_slug_clone = event_slug.clone();
-
-                                        // Ensure the event_trades entry exists before starting websocket
-                                        app.trades
-                                            .event_trades
-                                            .entry(event_slug_clone.clone())
-                                            .or_insert_with(EventTrades::new);
-
-                                        let app_state_ws = Arc::clone(&app_state);
-                                        let event_slug_for_closure = event_slug_clone.clone();
-
-                                        let rtds_client = RTDSClient::new()
-                                            .with_event_slug(event_slug_clone.clone());
-                                        let _event_slug_for_log = event_slug_clone.clone();
-
-                                        log_info!(
-                                            "Starting RTDS WebSocket for event: {}",
-                                            event_slug_clone
-                                        );
-
-                                        let ws_handle = tokio::spawn(async move {
-                                            match rtds_client
-                                            .connect_and_listen(move |msg| {
-                                                let app_state = Arc::clone(&app_state_ws);
-                                                let event_slug = event_slug_for_closure.clone();
-
-                                                log_info!(
-                                                    "Received RTDS trade for event: {}",
-                                                    event_slug
-                                                );
-
-                                                tokio::spawn(async move {
-                                                    let mut app = app_state.lock().await;
-                                                    if let Some(event_trades) =
-                                                        app.trades.event_trades.get_mut(&event_slug)
-                                                    {
-                                                        event_trades.add_trade(&msg);
-                                                        log_info!(
-                                                            "Trade added to event_trades for: {}",
-                                                            event_slug
-                                                        );
-                                                    } else {
-                                                        log_warn!(
-                                                            "No event_trades entry found for: {}",
-                                                            event_slug
-                                                        );
-                                                    }
-                                                });
-                                            })
-                                            .await
-                                        {
-                                            Ok(()) => {
-                                                log_info!(
-                                                    "RTDS WebSocket connection closed normally for event: {}",
-                                                    _event_slug_for_log
-                                                );
-                                            },
-                                            Err(_e) => {
-                                                log_error!(
-                                                    "RTDS WebSocket error for event {}: {}",
-                                                    _event_slug_for_log,
-                                                    _e
-                                                );
-                                            },
-                                        }
-                                        });
-
-                                        app.start_watching(event_slug_clone, ws_handle);
+                                        spawn_watch_event(&app_state, &mut app, event_slug);
                                     }
                                 }
                             }
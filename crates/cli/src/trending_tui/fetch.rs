@@ -2,18 +2,28 @@
 
 use {
     super::{
+        clipboard::copy_to_clipboard,
         logging::{log_debug, log_error, log_info, log_warn},
+        render::utils::format_volume,
         state::{
-            self, EventFilter, OrderbookLevel, SearchMode, TrendingAppState, YieldOpportunity,
-            YieldSearchResult,
+            self, EventFilter, FetchLimiter, OrderbookLevel, SearchMode, TrendingAppState,
+            YieldOpportunity, YieldSearchResult,
         },
     },
     chrono::{DateTime, Utc},
     polymarket_api::{
-        GammaClient,
-        clob::{BatchTokenRequest, ClobClient, Side},
+        GammaClient, PolymarketError,
+        clob::{BatchTokenRequest, ClobClient, Orderbook, Side},
+        gamma::{Event, Market},
+    },
+    std::{
+        collections::HashMap,
+        io::Write,
+        sync::{
+            Arc,
+            atomic::{AtomicBool, Ordering},
+        },
     },
-    std::{collections::HashMap, sync::Arc},
     tokio::sync::Mutex as TokioMutex,
 };
 
@@ -29,15 +39,16 @@ pub fn switch_filter_tab(
 
     app.event_filter = new_filter;
     // Clear all search state when switching tabs
-    app.search.results.clear();
+    app.search.clear_results();
     app.search.last_searched_query.clear();
     app.search.query.clear();
     app.search.mode = SearchMode::None;
     app.search.is_searching = false;
+    app.search.last_keystroke = None;
     app.navigation.selected_index = 0;
     app.scroll.events_list = 0;
     app.pagination.order_by = new_filter.order_by().to_string();
-    app.pagination.ascending = false;
+    app.pagination.ascending = new_filter.ascending();
 
     // Check cache first
     if let Some(cached_events) = app.events_cache.get(&new_filter) {
@@ -47,10 +58,12 @@ pub fn switch_filter_tab(
             new_filter.label()
         );
         app.events = cached_events.clone();
+        app.mark_events_changed();
         None
     } else {
         // Need to fetch from API - clear events to show loading state
         app.events.clear();
+        app.mark_events_changed();
         app.pagination.is_fetching_more = true;
         log_info!(
             "Switching to {} filter, fetching events...",
@@ -81,43 +94,175 @@ pub fn spawn_filter_fetch(
                 app.cache_events(&new_events);
                 app.events_cache.insert(filter, new_events.clone());
                 app.events = new_events;
+                app.mark_events_changed();
+                app.restore_event_sort();
                 app.pagination.is_fetching_more = false;
+                app.load_error = None;
                 app.navigation.selected_index = 0;
                 app.scroll.events_list = 0;
             },
             Err(e) => {
                 log_error!("Failed to fetch events: {}", e);
                 let mut app = app_state.lock().await;
+                app.record_api_error("Events", &e);
                 app.pagination.is_fetching_more = false;
             },
         }
     });
 }
 
+/// Retry the failed events fetch for the current filter tab with its limit. Success replaces
+/// the (empty) events list, clears the load error banner and loads the first event's data.
+pub fn spawn_retry_initial_load(
+    app_state: Arc<TokioMutex<TrendingAppState>>,
+    app: &mut TrendingAppState,
+) {
+    if app.pagination.is_fetching_more {
+        return;
+    }
+    // Set before spawning so a second 'R' doesn't start another retry
+    app.pagination.is_fetching_more = true;
+    let gamma_client = GammaClient::new();
+    let filter = app.event_filter;
+    let limit = app.pagination.current_limit;
+    // The events tab keeps the startup order (which `--order-by` may have changed)
+    let startup_order = (filter == EventFilter::Trending)
+        .then(|| (app.pagination.order_by.clone(), app.pagination.ascending));
+
+    tokio::spawn(async move {
+        log_info!("Retrying initial events fetch...");
+        let result = match startup_order {
+            Some((order_by, ascending)) => {
+                gamma_client
+                    .get_trending_events(Some(&order_by), Some(ascending), Some(limit))
+                    .await
+            },
+            None => fetch_events_for_filter(&gamma_client, filter, limit).await,
+        };
+        let mut app = app_state.lock().await;
+        app.pagination.is_fetching_more = false;
+        match result {
+            Ok(new_events) => {
+                log_info!("Loaded {} events", new_events.len());
+                app.cache_events(&new_events);
+                app.events_cache.insert(filter, new_events.clone());
+                app.events = new_events;
+                app.mark_events_changed();
+                app.restore_event_sort();
+                app.clamp_selection_to_filtered();
+                app.load_error = None;
+                app.show_toast("Events loaded");
+                spawn_event_selection_fetches(&app_state, &mut app);
+            },
+            Err(e) => {
+                log_error!("Retry of the initial events fetch failed: {}", e);
+                app.record_api_error("Events", &e);
+                app.load_error = Some(e.to_string());
+            },
+        }
+    });
+}
+
+/// Spawn async task to re-fetch the events of a filter tab, keeping the selection in place
+pub fn spawn_refresh_events(
+    app_state: Arc<TokioMutex<TrendingAppState>>,
+    filter: EventFilter,
+    limit: usize,
+) {
+    let gamma_client = GammaClient::new();
+
+    tokio::spawn(async move {
+        match fetch_events_for_filter(&gamma_client, filter, limit).await {
+            Ok(new_events) => {
+                let mut app = app_state.lock().await;
+                // A tab switch while the request was in flight makes the result stale for the list
+                app.events_cache.insert(filter, new_events.clone());
+                if app.event_filter == filter {
+                    app.events = new_events;
+                    app.mark_events_changed();
+                    app.restore_event_sort();
+                    app.clamp_selection_to_filtered();
+                    app.load_error = None;
+                }
+                log_info!("Events refreshed ({} events)", app.events.len());
+            },
+            Err(e) => {
+                log_info!("Failed to refresh events: {}", e);
+                app_state.lock().await.record_api_error("Events", &e);
+            },
+        }
+    });
+}
+
 /// Fetch events for a given filter using the appropriate API call
 pub async fn fetch_events_for_filter(
     gamma_client: &GammaClient,
     filter: EventFilter,
     limit: usize,
-) -> crate::Result<Vec<polymarket_api::gamma::Event>> {
+) -> polymarket_api::Result<Vec<polymarket_api::gamma::Event>> {
     match filter {
         EventFilter::Breaking => {
             // Breaking = markets that moved the most in the last 24 hours
-            gamma_client
-                .get_breaking_events(Some(limit))
-                .await
-                .map_err(|e| anyhow::anyhow!("{}", e))
+            gamma_client.get_breaking_events(Some(limit)).await
         },
-        _ => {
-            // Trending and New use the events endpoint with different ordering
+        EventFilter::ClosingSoon => gamma_client.get_closing_soon_events(Some(limit)).await,
+        EventFilter::Trending | EventFilter::NewlyListed => {
+            // Both use the events endpoint, with different ordering
             gamma_client
-                .get_trending_events(Some(filter.order_by()), Some(false), Some(limit))
+                .get_trending_events(
+                    Some(filter.order_by()),
+                    Some(filter.ascending()),
+                    Some(limit),
+                )
                 .await
-                .map_err(|e| anyhow::anyhow!("{}", e))
         },
     }
 }
 
+/// Load the next page of events for the current filter (infinite scroll)
+pub fn spawn_fetch_more_events(
+    app_state: Arc<TokioMutex<TrendingAppState>>,
+    app: &mut TrendingAppState,
+) {
+    let gamma_client = GammaClient::new();
+    let current_filter = app.event_filter;
+    // Fetch 50 more events
+    let new_limit = app.pagination.current_limit + 50;
+
+    // Set fetching flag to prevent duplicate requests
+    app.pagination.is_fetching_more = true;
+    log_info!("Fetching more events (limit: {})", new_limit);
+
+    tokio::spawn(async move {
+        match fetch_events_for_filter(&gamma_client, current_filter, new_limit).await {
+            Ok(mut new_events) => {
+                let mut app = app_state.lock().await;
+                // Remove duplicates by comparing slugs
+                let existing_slugs: std::collections::HashSet<_> =
+                    app.events.iter().map(|e| e.slug.clone()).collect();
+                new_events.retain(|e| !existing_slugs.contains(&e.slug));
+
+                if !new_events.is_empty() {
+                    log_info!("Fetched {} new events", new_events.len());
+                    app.events.append(&mut new_events);
+                    app.mark_events_changed();
+                    app.pagination.current_limit = new_limit;
+                } else {
+                    log_info!("No new events to add (already have all events)");
+                }
+                app.pagination.is_fetching_more = false;
+                app.load_error = None;
+            },
+            Err(e) => {
+                log_error!("Failed to fetch more events: {}", e);
+                let mut app = app_state.lock().await;
+                app.record_api_error("More events", &e);
+                app.pagination.is_fetching_more = false;
+            },
+        }
+    });
+}
+
 /// Spawn async task to fetch API status and update app state
 pub fn spawn_fetch_api_status(app_state: Arc<TokioMutex<TrendingAppState>>) {
     use polymarket_api::DataClient;
@@ -138,6 +283,7 @@ pub fn spawn_fetch_api_status(app_state: Arc<TokioMutex<TrendingAppState>>) {
             Err(e) => {
                 log_error!("Gamma API status check failed: {}", e);
                 let mut app = app_state_gamma.lock().await;
+                app.record_api_error("Gamma API status", &e);
                 app.gamma_api_status = Some(false);
             },
         }
@@ -155,6 +301,7 @@ pub fn spawn_fetch_api_status(app_state: Arc<TokioMutex<TrendingAppState>>) {
             Err(e) => {
                 log_error!("Data API status check failed: {}", e);
                 let mut app = app_state.lock().await;
+                app.record_api_error("Data API status", &e);
                 app.data_api_status = Some(false);
             },
         }
@@ -209,10 +356,13 @@ pub fn spawn_fetch_user_profile(app_state: Arc<TokioMutex<TrendingAppState>>, ad
 }
 
 /// Spawn async task to fetch user's portfolio data (balance, positions)
+/// The positions also fill the Positions tab. Results are dropped if `address` logged out
+/// meanwhile, and a failed call keeps the last known values.
 pub fn spawn_fetch_portfolio(app_state: Arc<TokioMutex<TrendingAppState>>, address: String) {
     use polymarket_api::{DataClient, clob::AssetType};
 
     tokio::spawn(async move {
+        app_state.lock().await.positions_state.is_loading = true;
         let clob_client = ClobClient::from_env();
         let data_client = DataClient::new();
 
@@ -232,10 +382,12 @@ pub fn spawn_fetch_portfolio(app_state: Arc<TokioMutex<TrendingAppState>>, addre
                     log_info!("Fetched balance: ${:.2} USDC", balance);
 
                     let mut app = app_state.lock().await;
-                    app.auth_state.balance = Some(balance);
+                    if app.auth_state.address.as_deref() == Some(address.as_str()) {
+                        app.auth_state.balance = Some(balance);
+                    }
                 },
                 Err(e) => {
-                    log_debug!("Failed to fetch balance: {}", e);
+                    log_warn!("Failed to fetch balance: {}", e);
                 },
             }
         }
@@ -262,18 +414,51 @@ pub fn spawn_fetch_portfolio(app_state: Arc<TokioMutex<TrendingAppState>>, addre
                 );
 
                 let mut app = app_state.lock().await;
+                if app.auth_state.address.as_deref() != Some(address.as_str()) {
+                    app.positions_state.is_loading = false;
+                    return;
+                }
                 app.auth_state.portfolio_value = Some(total_value);
                 app.auth_state.positions_count = Some(positions_count);
                 app.auth_state.unrealized_pnl = Some(unrealized_pnl);
                 app.auth_state.realized_pnl = Some(realized_pnl);
+                app.auth_state.record_pnl_sample();
+                app.positions_state
+                    .set_positions(positions.iter().map(state::PositionRow::from).collect());
             },
             Err(e) => {
-                log_debug!("Failed to fetch positions: {}", e);
+                log_warn!("Failed to fetch positions: {}", e);
+                let mut app = app_state.lock().await;
+                app.record_api_error("Positions", &e);
+                app.positions_state.is_loading = false;
+                app.positions_state.error_message =
+                    Some(format!("Failed to load positions: {}", e));
             },
         }
     });
 }
 
+/// Load the Positions tab for the logged-in user; unless `force`, only when nothing is
+/// loaded or loading yet. Marks the tab loading right away so a second call before the
+/// task starts doesn't fetch again.
+pub fn spawn_load_positions(
+    app_state: &Arc<TokioMutex<TrendingAppState>>,
+    app: &mut TrendingAppState,
+    force: bool,
+) {
+    let Some(address) = app.auth_state.address.clone() else {
+        return;
+    };
+    let positions_state = &mut app.positions_state;
+    if positions_state.is_loading
+        || (!force && (positions_state.loaded || positions_state.error_message.is_some()))
+    {
+        return;
+    }
+    positions_state.is_loading = true;
+    spawn_fetch_portfolio(Arc::clone(app_state), address);
+}
+
 /// Spawn async task to toggle favorite status for an event
 pub fn spawn_toggle_favorite(
     app_state: Arc<TokioMutex<TrendingAppState>>,
@@ -311,56 +496,77 @@ pub fn spawn_toggle_favorite(
         };
         let gamma_client = GammaClient::with_auth(gamma_auth);
 
-        // Check current favorite status and toggle
-        let is_currently_favorite = {
-            let app = app_state.lock().await;
-            app.favorites_state.is_favorite(&event_slug)
+        // Flip the bookmark icon right away; a failed request flips it back
+        let (is_currently_favorite, favorite_id) = {
+            let mut app = app_state.lock().await;
+            let favorites = &mut app.favorites_state;
+            let is_favorite = favorites.favorite_event_slugs.contains(&event_slug);
+            let favorite_id = favorites
+                .favorite_ids
+                .iter()
+                .find(|f| f.event_id == event_id)
+                .map(|f| f.id);
+            if is_favorite {
+                favorites.favorite_event_slugs.remove(&event_slug);
+            } else {
+                favorites.favorite_event_slugs.insert(event_slug.clone());
+            }
+            (is_favorite, favorite_id)
         };
 
         if is_currently_favorite {
-            // Find the favorite ID to remove
-            let favorite_id = {
-                let app = app_state.lock().await;
-                app.favorites_state
-                    .favorite_ids
-                    .iter()
-                    .find(|f| f.event_id == event_id)
-                    .map(|f| f.id)
+            let result = match favorite_id {
+                Some(fav_id) => gamma_client.remove_favorite_event(fav_id).await,
+                None => Err(PolymarketError::InvalidData(format!(
+                    "No favorite entry for event {}",
+                    event_id
+                ))),
             };
-
-            if let Some(fav_id) = favorite_id {
-                match gamma_client.remove_favorite_event(fav_id).await {
-                    Ok(()) => {
-                        log_info!("Removed favorite: {}", event_slug);
-                        let mut app = app_state.lock().await;
-                        app.favorites_state.favorite_event_slugs.remove(&event_slug);
-                        app.favorites_state
-                            .favorite_ids
-                            .retain(|f| f.event_id != event_id);
-                        app.favorites_state.events.retain(|e| e.slug != event_slug);
-                    },
-                    Err(e) => {
-                        log_error!("Failed to remove favorite: {}", e);
-                    },
-                }
+            let mut app = app_state.lock().await;
+            match result {
+                Ok(()) => {
+                    log_info!("Removed favorite: {}", event_slug);
+                    app.favorites_state
+                        .favorite_ids
+                        .retain(|f| f.event_id != event_id);
+                    app.favorites_state.events.retain(|e| e.slug != event_slug);
+                    app.favorites_state.mark_events_changed();
+                    app.clamp_selection_to_filtered();
+                    app.show_toast("Removed from favorites");
+                },
+                Err(e) => {
+                    log_error!("Failed to remove favorite: {}", e);
+                    app.favorites_state.favorite_event_slugs.insert(event_slug);
+                    app.record_api_error("Remove favorite", &e);
+                    app.show_toast("Failed to remove favorite");
+                },
             }
         } else {
             // Add to favorites
-            match gamma_client.add_favorite_event(&event_id).await {
+            let result = gamma_client.add_favorite_event(&event_id).await;
+            let mut app = app_state.lock().await;
+            match result {
                 Ok(favorite_entry) => {
                     log_info!("Added favorite: {}", event_slug);
-                    let mut app = app_state.lock().await;
-                    app.favorites_state
-                        .favorite_event_slugs
-                        .insert(event_slug.clone());
                     app.favorites_state.favorite_ids.push(favorite_entry);
                     // Add the event to favorites list if we have the full event data
-                    if let Some(evt) = event {
+                    if let Some(evt) = event
+                        && !app
+                            .favorites_state
+                            .events
+                            .iter()
+                            .any(|e| e.slug == evt.slug)
+                    {
                         app.favorites_state.events.push(evt);
+                        app.favorites_state.mark_events_changed();
                     }
+                    app.show_toast("Added to favorites");
                 },
                 Err(e) => {
                     log_error!("Failed to add favorite: {}", e);
+                    app.favorites_state.favorite_event_slugs.remove(&event_slug);
+                    app.record_api_error("Add favorite", &e);
+                    app.show_toast("Failed to add favorite");
                 },
             }
         }
@@ -389,6 +595,7 @@ pub fn spawn_fetch_and_toggle_favorite(
             },
             Err(e) => {
                 log_error!("Failed to fetch event {}: {}", event_slug, e);
+                app_state.lock().await.record_api_error("Event", &e);
             },
         }
     });
@@ -420,11 +627,183 @@ pub fn spawn_fetch_event_for_cache(
             },
             Err(e) => {
                 log_error!("Failed to fetch event {}: {}", event_slug, e);
+                app_state.lock().await.record_api_error("Event", &e);
             },
         }
     });
 }
 
+/// Start streaming live trades for an event over the RTDS WebSocket
+pub fn spawn_watch_event(
+    app_state: &Arc<TokioMutex<TrendingAppState>>,
+    app: &mut TrendingAppState,
+    event_slug: String,
+) {
+    use polymarket_api::RTDSClient;
+
+    // Create the event_trades entry and show "Connecting" before starting the websocket
+    app.start_watching(&event_slug);
+
+    let app_state_ws = Arc::clone(app_state);
+    let event_slug_for_task = event_slug.clone();
+
+    log_info!("Starting RTDS WebSocket for event: {}", event_slug);
+
+    // Reconnects with backoff until stopped (aborting the task also cancels a pending retry)
+    let ws_handle = tokio::spawn(async move {
+        let event_slug = event_slug_for_task;
+        let mut attempt = 0;
+        loop {
+            let connected = AtomicBool::new(false);
+            let rtds_client = RTDSClient::new().with_event_slug(event_slug.clone());
+            let app_state_connected = Arc::clone(&app_state_ws);
+            let app_state_updates = Arc::clone(&app_state_ws);
+            let slug_connected = event_slug.clone();
+            let slug_updates = event_slug.clone();
+
+            let result = rtds_client
+                .connect_and_listen_with_status(
+                    || {
+                        connected.store(true, Ordering::Relaxed);
+                        tokio::spawn(async move {
+                            log_info!("RTDS WebSocket connected for event: {}", slug_connected);
+                            let mut app = app_state_connected.lock().await;
+                            app.set_connection_state(
+                                &slug_connected,
+                                state::ConnectionState::Connected,
+                            );
+                        });
+                    },
+                    move |msg| {
+                        let app_state = Arc::clone(&app_state_updates);
+                        let event_slug = slug_updates.clone();
+
+                        tokio::spawn(async move {
+                            let mut app = app_state.lock().await;
+                            app.trades
+                                .record_trade_time(&msg.payload.asset, msg.payload.timestamp);
+                            let max_trades = app.trades.max_trades;
+                            if let Some(event_trades) = app.trades.event_trades.get_mut(&event_slug)
+                            {
+                                event_trades.add_trade(&msg, max_trades);
+                            } else {
+                                log_warn!("No event_trades entry found for: {}", event_slug);
+                                return;
+                            }
+                            let total_value = msg.payload.price * msg.payload.size;
+                            if app.trades.is_alert_trade(total_value) {
+                                let alert = format!(
+                                    "Large trade {}: {} {:.2} {} @ {:.1}¢ - {}",
+                                    format_volume(total_value),
+                                    msg.payload.side,
+                                    msg.payload.size,
+                                    msg.payload.outcome,
+                                    msg.payload.price * 100.0,
+                                    msg.payload.title
+                                );
+                                log_warn!("🚨 {}", alert);
+                                if app.trades.trade_alert_bell {
+                                    let mut stdout = std::io::stdout();
+                                    let _ = stdout.write_all(b"\x07").and_then(|_| stdout.flush());
+                                }
+                                app.show_toast(alert);
+                            }
+                        });
+                    },
+                )
+                .await;
+
+            match result {
+                Ok(()) => {
+                    log_warn!("RTDS WebSocket closed for event: {}", event_slug);
+                },
+                Err(_e) => {
+                    log_error!("RTDS WebSocket error for event {}: {}", event_slug, _e);
+                },
+            }
+
+            // A connection that got established resets the backoff
+            if connected.load(Ordering::Relaxed) {
+                attempt = 0;
+            }
+            attempt += 1;
+            if attempt > state::MAX_RECONNECT_ATTEMPTS {
+                log_error!(
+                    "Giving up on RTDS WebSocket for {} after {} reconnect attempts",
+                    event_slug,
+                    state::MAX_RECONNECT_ATTEMPTS
+                );
+                let mut app = app_state_ws.lock().await;
+                app.set_connection_state(&event_slug, state::ConnectionState::Error);
+                break;
+            }
+
+            let delay = state::reconnect_delay(attempt);
+            log_info!(
+                "Reconnecting RTDS WebSocket for {} in {}s (attempt {}/{})",
+                event_slug,
+                delay.as_secs(),
+                attempt,
+                state::MAX_RECONNECT_ATTEMPTS
+            );
+            {
+                let mut app = app_state_ws.lock().await;
+                app.set_connection_state(
+                    &event_slug,
+                    state::ConnectionState::Reconnecting(attempt),
+                );
+            }
+            tokio::time::sleep(delay).await;
+        }
+    });
+
+    app.set_watch_handle(event_slug, ws_handle);
+}
+
+/// Start watching queued events that matched an auto-watch keyword, up to the connection cap
+pub fn spawn_auto_watches(
+    app_state: &Arc<TokioMutex<TrendingAppState>>,
+    app: &mut TrendingAppState,
+) {
+    let (ready, _skipped) = app.take_auto_watch_events();
+    for _slug in &_skipped {
+        log_info!("Auto-watch connection cap reached, not watching: {}", _slug);
+    }
+    for (slug, title) in ready {
+        log_info!("Auto-watching event: {}", slug);
+        app.show_toast(format!("Auto-watching: {}", title));
+        spawn_watch_event(app_state, app, slug);
+    }
+}
+
+/// Start streams for every open, unwatched event in the filtered list (up to `MAX_BULK_WATCH`)
+pub fn spawn_watch_all_visible(
+    app_state: &Arc<TokioMutex<TrendingAppState>>,
+    app: &mut TrendingAppState,
+) {
+    let (ready, capped) = app.bulk_watch_events();
+    let started = ready.len();
+    for slug in ready {
+        spawn_watch_event(app_state, app, slug);
+    }
+    log_info!("Bulk watch started {} event stream(s)", started);
+    if capped {
+        log_warn!(
+            "Bulk watch cap of {} reached, some visible events were not watched",
+            state::MAX_BULK_WATCH
+        );
+        app.show_toast(format!(
+            "Watching {} more events (cap of {} reached)",
+            started,
+            state::MAX_BULK_WATCH
+        ));
+    } else if started == 0 {
+        app.show_toast("All visible events are already watched");
+    } else {
+        app.show_toast(format!("Watching {} more events", started));
+    }
+}
+
 /// Spawn async task to fetch orderbook data for a specific token ID
 /// Only fetches if market_is_active is true (closed markets don't need orderbook)
 pub fn spawn_fetch_orderbook(
@@ -442,9 +821,38 @@ pub fn spawn_fetch_orderbook(
     tokio::spawn(async move {
         log_info!("Fetching orderbook for token: {}", token_id);
 
-        // Set loading state
+        let limiter = {
+            let mut app = app_state.lock().await;
+            // AMM-only markets have no orderbook to fetch
+            if app.is_off_clob_token(&token_id) {
+                log_debug!("Skipping orderbook for non-CLOB token: {}", token_id);
+                return;
+            }
+            // Keeps the periodic refresh from queueing more fetches for this token meanwhile
+            app.orderbook_state.queued_token = Some(token_id.clone());
+            app.fetch_limiter.clone()
+        };
+
+        // Wait for a request slot; the user may have moved on while this was queued
+        let _permit = limiter.acquire().await;
         {
             let mut app = app_state.lock().await;
+            if app.orderbook_state.queued_token.as_ref() == Some(&token_id) {
+                app.orderbook_state.queued_token = None;
+            }
+            let stale = app.current_tab_selected_event().is_some_and(|event| {
+                !event.markets.iter().any(|m| {
+                    m.clob_token_ids
+                        .as_ref()
+                        .is_some_and(|ids| ids.contains(&token_id))
+                })
+            });
+            if stale {
+                limiter.record_dropped();
+                log_debug!("Dropped stale orderbook request for token: {}", token_id);
+                return;
+            }
+            // Set loading state only once the request is going out
             app.orderbook_state.is_loading = true;
         }
 
@@ -464,98 +872,343 @@ pub fn spawn_fetch_orderbook(
                     log_info!("Raw first ask: {} @ {}", ask.size, ask.price);
                 }
 
-                // Convert CLOB API Orderbook to our OrderbookData
-                // First, parse and sort the levels:
-                // - Bids: sorted descending by price (highest/best bid first)
-                // - Asks: sorted ascending by price (lowest/best ask first)
-                let mut bids: Vec<OrderbookLevel> = orderbook
-                    .bids
-                    .iter()
-                    .map(|level| {
-                        let price = level.price.parse::<f64>().unwrap_or(0.0);
-                        let size = level.size.parse::<f64>().unwrap_or(0.0);
-                        OrderbookLevel {
-                            price,
-                            size,
-                            total: 0.0, // Will calculate cumulative after sorting
-                        }
-                    })
-                    .collect();
-                // Sort bids descending by price (best bid = highest price first)
-                bids.sort_by(|a, b| {
-                    b.price
-                        .partial_cmp(&a.price)
-                        .unwrap_or(std::cmp::Ordering::Equal)
-                });
-                // Calculate cumulative totals after sorting
-                let mut cumulative_total = 0.0;
-                for bid in &mut bids {
-                    cumulative_total += bid.price * bid.size;
-                    bid.total = cumulative_total;
-                }
+                let orderbook_data = to_orderbook_data(&orderbook);
 
-                let mut asks: Vec<OrderbookLevel> = orderbook
-                    .asks
-                    .iter()
-                    .map(|level| {
-                        let price = level.price.parse::<f64>().unwrap_or(0.0);
-                        let size = level.size.parse::<f64>().unwrap_or(0.0);
-                        OrderbookLevel {
-                            price,
-                            size,
-                            total: 0.0, // Will calculate cumulative after sorting
-                        }
-                    })
-                    .collect();
-                // Sort asks ascending by price (best ask = lowest price first)
-                asks.sort_by(|a, b| {
-                    a.price
-                        .partial_cmp(&b.price)
-                        .unwrap_or(std::cmp::Ordering::Equal)
-                });
-                // Calculate cumulative totals after sorting
-                let mut cumulative_total = 0.0;
-                for ask in &mut asks {
-                    cumulative_total += ask.price * ask.size;
-                    ask.total = cumulative_total;
-                }
-
-                // Calculate spread
-                let spread = if let (Some(best_bid), Some(best_ask)) = (bids.first(), asks.first())
-                {
-                    Some(best_ask.price - best_bid.price)
-                } else {
-                    None
+                // In both-sides mode, also fetch the other outcome of the same market
+                let sibling_token = {
+                    let app = app_state.lock().await;
+                    if app.orderbook_state.both_sides {
+                        app.sibling_token(&token_id)
+                    } else {
+                        None
+                    }
+                };
+                let other_orderbook = match sibling_token {
+                    Some(sibling) => match clob_client.get_orderbook_by_asset(&sibling).await {
+                        Ok(other) => Some(to_orderbook_data(&other)),
+                        Err(e) => {
+                            log_warn!("Failed to fetch opposite orderbook for {}: {}", sibling, e);
+                            app_state.lock().await.record_api_error("Orderbook", &e);
+                            None
+                        },
+                    },
+                    None => None,
                 };
 
-                let orderbook_data = state::OrderbookData {
-                    bids,
-                    asks,
-                    spread,
-                    last_price: None,
+                // Your resting orders on this token, to mark on the book (needs CLOB auth)
+                let auth_client = ClobClient::from_env();
+                let open_orders = if auth_client.has_auth() {
+                    match auth_client.get_open_orders_for_asset(&token_id).await {
+                        Ok(orders) => orders,
+                        Err(e) => {
+                            log_warn!("Failed to fetch open orders: {}", e);
+                            app_state.lock().await.record_api_error("Open orders", &e);
+                            Vec::new()
+                        },
+                    }
+                } else {
+                    Vec::new()
                 };
 
-                // Calculate height based on data (up to 6 per side)
-                let asks_count = orderbook_data.asks.len().min(6);
-                let bids_count = orderbook_data.bids.len().min(6);
+                // Calculate height based on data (up to 6 per side, tallest book when showing both)
+                let books = std::iter::once(&orderbook_data).chain(other_orderbook.as_ref());
+                let asks_count = books
+                    .clone()
+                    .map(|b| b.asks.len())
+                    .max()
+                    .unwrap_or(0)
+                    .min(6);
+                let bids_count = books.map(|b| b.bids.len()).max().unwrap_or(0).min(6);
                 let new_height = (2 + 1 + asks_count + 1 + bids_count) as u16; // borders + header + asks + spread + bids
 
                 let mut app = app_state.lock().await;
                 app.orderbook_state.orderbook = Some(orderbook_data);
+                app.orderbook_state.other_orderbook = other_orderbook;
+                app.orderbook_state.set_open_orders(open_orders);
                 app.orderbook_state.is_loading = false;
                 app.orderbook_state.last_fetch = Some(std::time::Instant::now());
                 app.orderbook_state.token_id = Some(token_id);
                 app.orderbook_state.last_height = new_height.max(5); // min height of 5
+                app.orderbook_state.clear_error();
             },
             Err(e) => {
                 log_error!("Failed to fetch orderbook for {}: {}", token_id, e);
                 let mut app = app_state.lock().await;
-                app.orderbook_state.is_loading = false;
+                app.record_api_error("Orderbook", &e);
+                // Only track the failure if the token is still the one being shown
+                let is_current = app
+                    .selected_orderbook_token()
+                    .is_none_or(|(selected, _)| selected == token_id);
+                if is_current {
+                    app.orderbook_state.record_error(token_id, e.to_string());
+                    if let Some(_secs) = app.orderbook_state.retry_in_secs() {
+                        log_warn!("Retrying orderbook fetch in {}s", _secs);
+                    }
+                } else {
+                    app.orderbook_state.is_loading = false;
+                }
             },
         }
     });
 }
 
+/// Cancel one of your resting orders, dropping it from the open orders popup once it's gone.
+/// An order the API won't cancel (usually because it filled or was cancelled meanwhile) is
+/// dropped too, with the API's reason shown in the popup.
+pub fn spawn_cancel_order(app_state: Arc<TokioMutex<TrendingAppState>>, order_id: String) {
+    let clob_client = ClobClient::from_env();
+
+    tokio::spawn(async move {
+        log_info!("Cancelling order {}", order_id);
+        let result = clob_client.cancel_order(&order_id).await;
+        let mut app = app_state.lock().await;
+        let message = match result {
+            Ok(response) if response.canceled.contains(&order_id) => {
+                log_info!("Order {} cancelled", order_id);
+                app.orderbook_state.remove_open_order(&order_id);
+                "Order cancelled".to_string()
+            },
+            Ok(response) => {
+                let reason = response
+                    .not_canceled
+                    .get(&order_id)
+                    .and_then(|reason| reason.as_str())
+                    .unwrap_or("already filled or cancelled")
+                    .to_string();
+                log_warn!("Order {} not cancelled: {}", order_id, reason);
+                app.orderbook_state.remove_open_order(&order_id);
+                format!("Order no longer open: {}", reason)
+            },
+            Err(PolymarketError::Api { status: 404, .. }) => {
+                log_warn!("Order {} not found, already filled or cancelled", order_id);
+                app.orderbook_state.remove_open_order(&order_id);
+                "Order no longer open: already filled or cancelled".to_string()
+            },
+            Err(e) => {
+                log_error!("Failed to cancel order {}: {}", order_id, e);
+                app.record_api_error("Cancel order", &e);
+                format!("Cancel failed: {}", e)
+            },
+        };
+        app.orderbook_state.open_orders_message = Some(message);
+    });
+}
+
+/// Fetch the fee rate for the trade popup's market; it stays unknown ("N/A") on failure
+pub fn spawn_fetch_fee_rate(app_state: Arc<TokioMutex<TrendingAppState>>, token_id: String) {
+    let clob_client = ClobClient::new();
+
+    tokio::spawn(async move {
+        match clob_client.get_fee_rate_bps(&token_id).await {
+            Ok(fee_rate_bps) => {
+                log_info!("Fee rate for {}: {} bps", token_id, fee_rate_bps);
+                let mut app = app_state.lock().await;
+                // The popup may have been closed or reopened for another market meanwhile
+                if let Some(form) = app.trade_form.as_mut()
+                    && form.outcomes.iter().any(|o| o.token_id == token_id)
+                {
+                    form.fee_rate = Some(fee_rate_bps as f64 / 10_000.0);
+                }
+            },
+            Err(e) => {
+                log_warn!("Failed to fetch fee rate for {}: {}", token_id, e);
+                app_state.lock().await.record_api_error("Fee rate", &e);
+            },
+        }
+    });
+}
+
+/// Copy the session trades summary to the clipboard on a blocking thread, since a clipboard
+/// command can hang (e.g. xclip without a display), and report the outcome in a toast
+pub fn spawn_copy_session_summary(app_state: Arc<TokioMutex<TrendingAppState>>, summary: String) {
+    tokio::spawn(async move {
+        let result = tokio::task::spawn_blocking(move || copy_to_clipboard(&summary))
+            .await
+            .unwrap_or_else(|e| Err(std::io::Error::other(e)));
+        let mut app = app_state.lock().await;
+        match result {
+            Ok(()) => {
+                log_info!("Copied session summary to clipboard");
+                app.show_toast("Session summary copied to clipboard");
+            },
+            Err(e) => {
+                log_error!("Failed to copy session summary: {}", e);
+                app.show_toast(format!("Clipboard unavailable: {}", e));
+            },
+        }
+    });
+}
+
+/// Convert a CLOB API orderbook into sorted levels with cumulative totals and the spread
+fn to_orderbook_data(orderbook: &Orderbook) -> state::OrderbookData {
+    // Convert CLOB API Orderbook to our OrderbookData
+    // First, parse and sort the levels:
+    // - Bids: sorted descending by price (highest/best bid first)
+    // - Asks: sorted ascending by price (lowest/best ask first)
+    let mut bids: Vec<OrderbookLevel> = orderbook
+        .bids
+        .iter()
+        .map(|level| {
+            let price = level.price.parse::<f64>().unwrap_or(0.0);
+            let size = level.size.parse::<f64>().unwrap_or(0.0);
+            OrderbookLevel {
+                price,
+                size,
+                total: 0.0, // Will calculate cumulative after sorting
+                cumulative_size: 0.0,
+            }
+        })
+        .collect();
+    // Sort bids descending by price (best bid = highest price first)
+    bids.sort_by(|a, b| {
+        b.price
+            .partial_cmp(&a.price)
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+    // Calculate cumulative totals after sorting
+    let mut cumulative_total = 0.0;
+    let mut cumulative_size = 0.0;
+    for bid in &mut bids {
+        cumulative_total += bid.price * bid.size;
+        cumulative_size += bid.size;
+        bid.total = cumulative_total;
+        bid.cumulative_size = cumulative_size;
+    }
+
+    let mut asks: Vec<OrderbookLevel> = orderbook
+        .asks
+        .iter()
+        .map(|level| {
+            let price = level.price.parse::<f64>().unwrap_or(0.0);
+            let size = level.size.parse::<f64>().unwrap_or(0.0);
+            OrderbookLevel {
+                price,
+                size,
+                total: 0.0, // Will calculate cumulative after sorting
+                cumulative_size: 0.0,
+            }
+        })
+        .collect();
+    // Sort asks ascending by price (best ask = lowest price first)
+    asks.sort_by(|a, b| {
+        a.price
+            .partial_cmp(&b.price)
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+    // Calculate cumulative totals after sorting
+    let mut cumulative_total = 0.0;
+    let mut cumulative_size = 0.0;
+    for ask in &mut asks {
+        cumulative_total += ask.price * ask.size;
+        cumulative_size += ask.size;
+        ask.total = cumulative_total;
+        ask.cumulative_size = cumulative_size;
+    }
+
+    // Calculate spread
+    let spread = if let (Some(best_bid), Some(best_ask)) = (bids.first(), asks.first()) {
+        Some(best_ask.price - best_bid.price)
+    } else {
+        None
+    };
+
+    state::OrderbookData {
+        bids,
+        asks,
+        spread,
+        last_price: None,
+    }
+}
+
+/// Fetch current prices for an event's active markets
+pub fn spawn_refresh_event_prices(
+    app_state: &Arc<TokioMutex<TrendingAppState>>,
+    app: &TrendingAppState,
+    event: &Event,
+) {
+    spawn_refresh_market_prices(app_state, app, event.markets.iter());
+}
+
+/// Events on each side of the selection whose prices are fetched along with its own
+const PRICE_PREFETCH_NEIGHBORS: usize = 1;
+
+/// Fetch current prices for the active ones among `markets`
+pub fn spawn_refresh_market_prices<'a>(
+    app_state: &Arc<TokioMutex<TrendingAppState>>,
+    app: &TrendingAppState,
+    markets: impl IntoIterator<Item = &'a Market>,
+) {
+    let active_markets: Vec<_> = markets
+        .into_iter()
+        .filter(|m| !m.closed)
+        .filter_map(|m| m.clob_token_ids.clone())
+        .collect();
+    if active_markets.is_empty() {
+        return;
+    }
+    log_info!(
+        "Polling prices for {} assets in {} markets",
+        active_markets.iter().map(Vec::len).sum::<usize>(),
+        active_markets.len()
+    );
+    let app_state_clone = Arc::clone(app_state);
+    let clob_client = ClobClient::from_env();
+    let fetch_limiter = app.fetch_limiter.clone();
+    tokio::spawn(async move {
+        let prices = fetch_market_prices_batch(&clob_client, &fetch_limiter, active_markets).await;
+        let mut app = app_state_clone.lock().await;
+        app.update_market_prices(prices);
+    });
+}
+
+/// Start the fetches that follow selecting a different event: prices for its active
+/// markets (and those of its neighbors in the list not priced yet), its trade count (when
+/// authenticated), and the orderbook of its first market
+pub fn spawn_event_selection_fetches(
+    app_state: &Arc<TokioMutex<TrendingAppState>>,
+    app: &mut TrendingAppState,
+) {
+    let Some(event) = app.current_tab_selected_event().cloned() else {
+        return;
+    };
+
+    spawn_refresh_event_prices(app_state, app, &event);
+    let unpriced_neighbors: Vec<&Market> = app
+        .neighbor_events(PRICE_PREFETCH_NEIGHBORS)
+        .into_iter()
+        .flat_map(|neighbor| &neighbor.markets)
+        .filter(|m| {
+            m.clob_token_ids
+                .as_ref()
+                .and_then(|ids| ids.first())
+                .is_some_and(|id| !app.market_prices.contains_key(id))
+        })
+        .collect();
+    spawn_refresh_market_prices(app_state, app, unpriced_neighbors);
+
+    if app.has_clob_auth && !app.event_trade_counts.contains_key(&event.slug) {
+        let condition_ids: Vec<String> =
+            event.markets.iter().filter_map(|m| m.id.clone()).collect();
+        if !condition_ids.is_empty() {
+            let app_state_clone = Arc::clone(app_state);
+            let slug = event.slug.clone();
+            let clob_client = ClobClient::from_env();
+            tokio::spawn(async move {
+                if let Some(count) = fetch_event_trade_count(&clob_client, condition_ids).await {
+                    let mut app = app_state_clone.lock().await;
+                    app.event_trade_counts.insert(slug, count);
+                    log_info!("Fetched trade count: {} trades", count);
+                }
+            });
+        }
+    }
+
+    app.orderbook_state.reset();
+    if let Some((token_id, is_active)) = app.selected_orderbook_token() {
+        spawn_fetch_orderbook(Arc::clone(app_state), token_id, is_active);
+    }
+}
+
 /// Fetch trade count for an event's markets using authenticated CLOB API
 /// Returns total number of trades across all markets in the event
 pub async fn fetch_event_trade_count(
@@ -586,8 +1239,10 @@ pub async fn fetch_event_trade_count(
 
 /// Fetch market prices using the batch API
 /// Returns a HashMap mapping asset_id to the best ask price
+/// Waits for a slot from `limiter` so concurrent refreshes queue instead of flooding the API
 pub async fn fetch_market_prices_batch(
     clob_client: &ClobClient,
+    limiter: &FetchLimiter,
     active_markets: Vec<Vec<String>>,
 ) -> HashMap<String, f64> {
     // Collect all asset IDs from active markets
@@ -607,7 +1262,7 @@ pub async fn fetch_market_prices_batch(
         .collect();
 
     let request_count = requests.len();
-    log_debug!("Fetching {} market prices via batch API", request_count);
+    let _permit = limiter.acquire().await;
 
     // Try batch orderbooks first (more reliable for getting best ask)
     match clob_client.get_orderbooks(requests).await {
@@ -754,14 +1409,10 @@ pub async fn fetch_yield_opportunities(
 pub fn spawn_yield_fetch(app_state: Arc<TokioMutex<TrendingAppState>>) {
     let app_state_clone = Arc::clone(&app_state);
     tokio::spawn(async move {
-        let (min_prob, min_volume, max_prob) = {
+        let (min_prob, max_prob) = {
             let mut app = app_state.lock().await;
             app.yield_state.is_loading = true;
-            (
-                app.yield_state.min_prob,
-                app.yield_state.min_volume,
-                app.yield_state.max_prob,
-            )
+            (app.yield_state.min_prob, app.yield_state.max_prob)
         };
 
         log_info!(
@@ -770,7 +1421,8 @@ pub fn spawn_yield_fetch(app_state: Arc<TokioMutex<TrendingAppState>>) {
             max_prob * 100.0
         );
 
-        let opportunities = fetch_yield_opportunities(min_prob, max_prob, 500, min_volume).await;
+        // The min volume is applied when listing, so changing it doesn't need a refetch
+        let opportunities = fetch_yield_opportunities(min_prob, max_prob, 500, 0.0).await;
         let slug_to_fetch = {
             let mut app = app_state.lock().await;
             app.yield_state.opportunities = opportunities;
@@ -800,104 +1452,245 @@ pub fn spawn_yield_fetch(app_state: Arc<TokioMutex<TrendingAppState>>) {
 
 /// Spawn async task to fetch favorite events
 pub fn spawn_fetch_favorites(app_state: Arc<TokioMutex<TrendingAppState>>) {
-    use polymarket_api::{GammaAuth, GammaClient};
+    spawn_favorites_fetch(app_state, false);
+}
 
+/// Force a favorites resync and report what changed since the last fetch in a toast
+pub fn spawn_sync_favorites(app_state: Arc<TokioMutex<TrendingAppState>>) {
+    spawn_favorites_fetch(app_state, true);
+}
+
+fn spawn_favorites_fetch(app_state: Arc<TokioMutex<TrendingAppState>>, report_changes: bool) {
     tokio::spawn(async move {
         // Set loading state
-        {
+        let local_slugs = {
             let mut app = app_state.lock().await;
             app.favorites_state.is_loading = true;
             app.favorites_state.error_message = None;
-        }
-
-        // Load auth config
-        let auth_config = match crate::auth::AuthConfig::load() {
-            Some(config) => config,
-            None => {
-                let mut app = app_state.lock().await;
-                app.favorites_state.is_loading = false;
-                app.favorites_state.error_message = Some("No auth credentials found".to_string());
-                return;
-            },
+            app.favorites_state.local_slugs.clone()
         };
 
-        // Check if session cookie is available
-        if auth_config.session_cookie.is_none() {
+        let account = fetch_account_favorites(&app_state).await;
+        if let Err(ref message) = account
+            && local_slugs.is_empty()
+        {
             let mut app = app_state.lock().await;
             app.favorites_state.is_loading = false;
-            app.favorites_state.error_message = Some(
-                "Session cookie required. Add 'session_cookie' to your auth.json config \
-                 with the value of 'polymarketsession' cookie from browser dev tools."
-                    .to_string(),
-            );
+            app.favorites_state.error_message = Some(message.clone());
             return;
         }
 
-        // Create authenticated gamma client with session cookies
-        let gamma_auth = GammaAuth {
-            api_key: auth_config.api_key,
-            api_secret: auth_config.secret,
-            passphrase: auth_config.passphrase,
-            address: auth_config.address,
-            session_cookie: auth_config.session_cookie,
-            session_nonce: auth_config.session_nonce,
-            session_auth_type: auth_config.session_auth_type,
-        };
-        let gamma_client = GammaClient::with_auth(gamma_auth);
-
-        log_info!("Fetching favorite events...");
-
-        // Fetch favorite event IDs
-        let favorites = match gamma_client.get_favorite_events().await {
-            Ok(favs) => favs,
-            Err(e) => {
-                log_error!("Failed to fetch favorites: {}", e);
-                let mut app = app_state.lock().await;
-                app.favorites_state.is_loading = false;
-                app.favorites_state.error_message = Some(format!("Failed to fetch: {}", e));
-                return;
+        // Without account favorites (no session cookie, or the fetch failed), the ones
+        // already listed stay and the local bookmarks still load
+        let (favorites, mut events, error_message) = match account {
+            Ok((favorites, events)) => (favorites, events, None),
+            Err(message) => {
+                let app = app_state.lock().await;
+                let favorites_state = &app.favorites_state;
+                let events = favorites_state
+                    .events
+                    .iter()
+                    .filter(|e| favorites_state.favorite_event_slugs.contains(&e.slug))
+                    .cloned()
+                    .collect();
+                (favorites_state.favorite_ids.clone(), events, Some(message))
             },
         };
 
-        log_info!("Found {} favorites", favorites.len());
-
-        // Fetch full event data for each favorite (the embedded events have empty markets)
-        let mut events = Vec::with_capacity(favorites.len());
-        for fav in &favorites {
-            match gamma_client.get_event_by_id(&fav.event_id).await {
-                Ok(Some(event)) => {
-                    log_info!(
-                        "Fetched event: {} with {} markets",
-                        event.title,
-                        event.markets.len()
-                    );
-                    events.push(event);
-                },
-                Ok(None) => {
-                    log_warn!("Event {} not found", fav.event_id);
-                },
-                Err(e) => {
-                    log_error!("Failed to fetch event {}: {}", fav.event_id, e);
-                },
-            }
-        }
-
-        log_info!("Loaded {} favorite events with full data", events.len());
-
         // Build slug lookup set for quick favorite checking
         let favorite_slugs: std::collections::HashSet<String> =
             events.iter().map(|e| e.slug.clone()).collect();
 
+        // Local bookmarks that aren't also account favorites go after them
+        let local_only: Vec<String> = local_slugs
+            .into_iter()
+            .filter(|slug| !favorite_slugs.contains(slug))
+            .collect();
+        events.extend(fetch_local_favorite_events(&app_state, local_only).await);
+
         // Update state
         let mut app = app_state.lock().await;
+        if report_changes && error_message.is_none() {
+            let message = match app.favorites_state.diff_slugs(&favorite_slugs) {
+                (0, 0) => "Favorites already up to date".to_string(),
+                (added, removed) => {
+                    format!("Favorites synced: {} added, {} removed", added, removed)
+                },
+            };
+            app.show_toast(message);
+        }
+        // Keep the selected event highlighted when it is still a favorite
+        let selected_slug = app.favorites_state.selected_event().map(|e| e.slug.clone());
+        // Favorites were picked by the user rather than newly listed, so never auto-watch them
+        app.trades.auto_watch.seed(&events);
         // Cache events in global event cache
         app.cache_events(&events);
         app.favorites_state.events = events;
+        app.favorites_state.mark_events_changed();
+        app.restore_favorites_sort();
+        let kept_index = selected_slug.and_then(|slug| {
+            app.favorites_state
+                .events
+                .iter()
+                .position(|e| e.slug == slug)
+        });
         app.favorites_state.favorite_ids = favorites;
         app.favorites_state.favorite_event_slugs = favorite_slugs;
+        app.favorites_state.error_message = error_message;
         app.favorites_state.is_loading = false;
-        app.favorites_state.selected_index = 0;
-        app.favorites_state.scroll = 0;
+        match kept_index {
+            Some(index) => {
+                app.favorites_state.selected_index = index;
+                app.favorites_state.scroll = app.favorites_state.scroll.min(index);
+            },
+            None => {
+                app.favorites_state.selected_index = 0;
+                app.favorites_state.scroll = 0;
+            },
+        }
+    });
+}
+
+/// Fetch the account's favorites with full event data. Errors are messages for the
+/// Favorites tab (missing credentials or a failed request).
+async fn fetch_account_favorites(
+    app_state: &Arc<TokioMutex<TrendingAppState>>,
+) -> Result<(Vec<polymarket_api::FavoriteEvent>, Vec<Event>), String> {
+    use polymarket_api::{GammaAuth, GammaClient};
+
+    // Load auth config
+    let Some(auth_config) = crate::auth::AuthConfig::load() else {
+        return Err("No auth credentials found".to_string());
+    };
+
+    // Check if session cookie is available
+    if auth_config.session_cookie.is_none() {
+        return Err(
+            "Session cookie required. Add 'session_cookie' to your auth.json config \
+             with the value of 'polymarketsession' cookie from browser dev tools."
+                .to_string(),
+        );
+    }
+
+    // Create authenticated gamma client with session cookies
+    let gamma_auth = GammaAuth {
+        api_key: auth_config.api_key,
+        api_secret: auth_config.secret,
+        passphrase: auth_config.passphrase,
+        address: auth_config.address,
+        session_cookie: auth_config.session_cookie,
+        session_nonce: auth_config.session_nonce,
+        session_auth_type: auth_config.session_auth_type,
+    };
+    let gamma_client = GammaClient::with_auth(gamma_auth);
+
+    log_info!("Fetching favorite events...");
+
+    // Fetch favorite event IDs
+    let favorites = match gamma_client.get_favorite_events().await {
+        Ok(favs) => favs,
+        Err(e) => {
+            log_error!("Failed to fetch favorites: {}", e);
+            app_state.lock().await.record_api_error("Favorites", &e);
+            return Err(format!("Failed to fetch: {}", e));
+        },
+    };
+
+    log_info!("Found {} favorites", favorites.len());
+
+    // Fetch full event data for each favorite (the embedded events have empty markets)
+    let mut events = Vec::with_capacity(favorites.len());
+    for fav in &favorites {
+        match gamma_client.get_event_by_id(&fav.event_id).await {
+            Ok(Some(event)) => {
+                log_info!(
+                    "Fetched event: {} with {} markets",
+                    event.title,
+                    event.markets.len()
+                );
+                events.push(event);
+            },
+            Ok(None) => {
+                log_warn!("Event {} not found", fav.event_id);
+            },
+            Err(e) => {
+                log_error!("Failed to fetch event {}: {}", fav.event_id, e);
+            },
+        }
+    }
+
+    log_info!("Loaded {} favorite events with full data", events.len());
+    Ok((favorites, events))
+}
+
+/// Fetch the events of local bookmarks by slug, falling back to cached data when a fetch fails
+async fn fetch_local_favorite_events(
+    app_state: &Arc<TokioMutex<TrendingAppState>>,
+    slugs: Vec<String>,
+) -> Vec<Event> {
+    let gamma_client = GammaClient::new();
+    let mut events = Vec::with_capacity(slugs.len());
+    for slug in slugs {
+        match gamma_client.get_event_by_slug(&slug).await {
+            Ok(Some(event)) => events.push(event),
+            Ok(None) => {
+                log_warn!("Bookmarked event {} not found", slug);
+            },
+            Err(e) => {
+                log_error!("Failed to fetch bookmarked event {}: {}", slug, e);
+                let app = app_state.lock().await;
+                if let Some(event) = app.get_cached_event(&slug) {
+                    events.push(event.clone());
+                }
+            },
+        }
+    }
+    if !events.is_empty() {
+        log_info!("Loaded {} local bookmarks", events.len());
+    }
+    events
+}
+
+/// Spawn async task to search events via the API (clears results for an empty query)
+pub fn spawn_event_search(app_state: Arc<TokioMutex<TrendingAppState>>, query: String) {
+    tokio::spawn(async move {
+        if query.is_empty() {
+            let mut app = app_state.lock().await;
+            app.search.clear_results();
+            app.search.last_searched_query.clear();
+            app.set_searching(false);
+            app.clamp_selection_to_filtered();
+            return;
+        }
+
+        log_info!("Searching for: '{}'", query);
+        {
+            let mut app = app_state.lock().await;
+            app.set_searching(true);
+        }
+
+        let gamma_client = GammaClient::new();
+        match gamma_client.search_events(&query, Some(50)).await {
+            Ok(results) => {
+                log_info!("Search found {} results", results.len());
+                let history = app_state.lock().await.set_search_results(results, query);
+                if let Some(history) = history {
+                    // History is a convenience; a read-only config dir shouldn't break searching
+                    let _ = tokio::task::spawn_blocking(move || {
+                        state::SearchState::save_history(&history)
+                    })
+                    .await;
+                }
+            },
+            Err(e) => {
+                log_error!("Search failed: {}", e);
+                let mut app = app_state.lock().await;
+                app.record_api_error("Search", &e);
+                app.set_searching(false);
+                app.search.clear_results();
+                app.clamp_selection_to_filtered();
+            },
+        }
     });
 }
 
@@ -922,6 +1715,7 @@ pub fn spawn_yield_search(app_state: Arc<TokioMutex<TrendingAppState>>, query: S
             Err(e) => {
                 log_error!("Yield search failed: {}", e);
                 let mut app = app_state.lock().await;
+                app.record_api_error("Yield search", &e);
                 app.yield_state.is_search_loading = false;
                 return;
             },
@@ -6,7 +6,7 @@
 //! ## Panel-specific keys:
 //! - **Header**: ←/→ to switch filters
 //! - **EventsList**: / for API search, f for local filter, r to refresh, Enter to watch/unwatch
-//! - **Markets**: r to refresh prices
+//! - **Markets**: r to refresh prices, x to pin/unpin the selected market
 //! - **All panels**: ↑/↓ to scroll, Tab to switch panels, l to toggle logs, q to quit
 
 use super::state::FocusedPanel;
@@ -18,12 +18,24 @@ impl FocusedPanel {
             FocusedPanel::Header => "←/→: Filter",
             FocusedPanel::EventsList => "/: Search | f: Filter | r: Refresh | Enter: Watch",
             FocusedPanel::EventDetails => "o: Open URL | ↑/↓: Scroll",
-            FocusedPanel::Markets => "r: Refresh | ↑/↓: Scroll",
+            FocusedPanel::Markets => "/: Filter | r: Refresh | x: Pin | ↑/↓: Scroll",
             FocusedPanel::Trades => "↑/↓: Scroll",
             FocusedPanel::Logs => "↑/↓: Scroll",
         }
     }
 
+    /// Returns the panel's most used keys, compact enough for its bottom border
+    pub fn border_hint(&self) -> &'static str {
+        match self {
+            FocusedPanel::Header => "←/→: filter",
+            FocusedPanel::EventsList => "Enter: watch  b: bookmark  n: note  s: sort",
+            FocusedPanel::EventDetails => "o: open  i: info  u: rules",
+            FocusedPanel::Markets => "/: filter  [/]: select  x: pin",
+            FocusedPanel::Trades => "O: order  C: copy",
+            FocusedPanel::Logs => "S: save",
+        }
+    }
+
     /// Returns the panel name for display
     pub fn name(&self) -> &'static str {
         match self {
@@ -1,35 +1,35 @@
 //! Layout calculations for panel areas
 
 use {
-    super::state::{FocusedPanel, MainTab},
+    super::{
+        render::TRADES_SUMMARY_HEIGHT,
+        state::{FocusedPanel, MainTab},
+    },
     ratatui::layout::{Constraint, Direction, Layout, Rect},
 };
 
-/// Helper to calculate panel areas for mouse click detection
+/// Helper to calculate panel areas for mouse click detection. `top_height` is
+/// `TrendingAppState::top_height()`; the header area includes the load error banner.
 /// Returns (header_area, events_list_area, event_details_area, markets_area, trades_area, logs_area)
 pub fn calculate_panel_areas(
     size: Rect,
-    is_in_filter_mode: bool,
+    top_height: u16,
     show_logs: bool,
     main_tab: MainTab,
+    event_details_height: u16,
 ) -> (Rect, Rect, Rect, Rect, Rect, Rect) {
-    let header_height = if is_in_filter_mode {
-        5
-    } else {
-        2
-    };
     // No overlap - all panels have full borders
     // Conditionally include logs area
     let constraints: Vec<Constraint> = if show_logs {
         vec![
-            Constraint::Length(header_height),
+            Constraint::Length(top_height),
             Constraint::Min(0),
             Constraint::Length(8),
             Constraint::Length(3),
         ]
     } else {
         vec![
-            Constraint::Length(header_height),
+            Constraint::Length(top_height),
             Constraint::Min(0),
             Constraint::Length(3),
         ]
@@ -46,6 +46,18 @@ pub fn calculate_panel_areas(
         Rect::default() // Empty rect when logs hidden
     };
 
+    // Positions tab is a single full-width table
+    if main_tab == MainTab::Positions {
+        return (
+            header_area,
+            chunks[1], // Positions table (maps to EventsList)
+            Rect::default(),
+            Rect::default(),
+            Rect::default(),
+            logs_area,
+        );
+    }
+
     // For Yield tab, layout is: 55% list on left, details on right (Event + Market Details)
     if main_tab == MainTab::Yield {
         let yield_chunks = Layout::default()
@@ -83,7 +95,7 @@ pub fn calculate_panel_areas(
     let right_chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
-            Constraint::Length(8),
+            Constraint::Length(event_details_height),
             Constraint::Length(7),
             Constraint::Min(0),
         ])
@@ -103,17 +115,56 @@ pub fn calculate_panel_areas(
     )
 }
 
+/// Visible row counts of the scrollable panels (panel height minus borders and header rows)
+#[derive(Debug, Clone, Copy)]
+pub struct PanelHeights {
+    pub events_list: usize, // Events/Favorites list, or the Yield/Positions table on those tabs
+    pub markets: usize,
+    pub trades: usize,
+}
+
+/// Calculate how many rows of each scrollable panel are visible, for keyboard navigation.
+/// `orderbook_height` is the current height of the orderbook panel above the trades table.
+pub fn calculate_panel_heights(
+    size: Rect,
+    top_height: u16,
+    show_logs: bool,
+    main_tab: MainTab,
+    event_details_height: u16,
+    orderbook_height: u16,
+) -> PanelHeights {
+    let (_, events_list, _, markets, trades, _) =
+        calculate_panel_areas(size, top_height, show_logs, main_tab, event_details_height);
+    // The Yield and Positions lists are tables with a header row
+    let list_chrome = if matches!(main_tab, MainTab::Yield | MainTab::Positions) {
+        3
+    } else {
+        2
+    };
+    PanelHeights {
+        events_list: (events_list.height as usize).saturating_sub(list_chrome),
+        markets: (markets.height as usize).saturating_sub(2),
+        // The trades area here still includes the orderbook panel and the buy/sell summary
+        // line; -3 for borders and header
+        trades: (trades
+            .height
+            .saturating_sub(orderbook_height + TRADES_SUMMARY_HEIGHT) as usize)
+            .saturating_sub(3),
+    }
+}
+
 /// Determine which panel was clicked based on coordinates
 pub fn get_panel_at_position(
     x: u16,
     y: u16,
     size: Rect,
-    is_in_filter_mode: bool,
+    top_height: u16,
     show_logs: bool,
     main_tab: MainTab,
+    event_details_height: u16,
 ) -> Option<FocusedPanel> {
     let (header, events_list, event_details, markets, trades, logs) =
-        calculate_panel_areas(size, is_in_filter_mode, show_logs, main_tab);
+        calculate_panel_areas(size, top_height, show_logs, main_tab, event_details_height);
 
     if y >= header.y && y < header.y + header.height && x >= header.x && x < header.x + header.width
     {
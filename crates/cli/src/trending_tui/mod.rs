@@ -1,5 +1,6 @@
 //! TUI for browsing trending events with live trade monitoring
 
+mod clipboard;
 mod event_loop;
 mod fetch;
 mod keys;
@@ -7,6 +8,7 @@ mod layout;
 #[macro_use]
 mod logging;
 mod render;
+mod snapshot;
 pub mod state;
 
 pub use {event_loop::run_trending_tui, state::TrendingAppState};
@@ -1,6 +1,10 @@
 //! Click detection for the trending TUI
 
-use {crate::trending_tui::state::TrendingAppState, ratatui::layout::Rect};
+use {
+    super::{header::tab_titles, trades::TRADES_SUMMARY_HEIGHT},
+    crate::trending_tui::state::{MainTab, TrendingAppState},
+    ratatui::layout::Rect,
+};
 
 /// Unified tab enum for click detection (combines MainTab and EventFilter)
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -9,6 +13,9 @@ pub enum ClickedTab {
     Favorites,
     Breaking,
     Yield,
+    Positions,
+    ClosingSoon,
+    NewlyListed,
 }
 
 /// Check if the login button was clicked (top right)
@@ -32,7 +39,8 @@ pub fn is_login_button_clicked(x: u16, y: u16, size: Rect, app: &TrendingAppStat
 }
 
 /// Tabs are rendered on the first line (y = 0)
-/// Returns which tab was clicked: Trending [1], Breaking [2], New [3], Yield [4]
+/// Returns which tab was clicked: Events [1], Favorites [2], Breaking [3], Yield [4], Positions [5],
+/// Closing [6], New [7]
 pub fn get_clicked_tab(x: u16, y: u16, size: Rect, app: &TrendingAppState) -> Option<ClickedTab> {
     // Tabs are on the first line (y = 0)
     if y != 0 {
@@ -52,20 +60,58 @@ pub fn get_clicked_tab(x: u16, y: u16, size: Rect, app: &TrendingAppState) -> Op
         return None;
     }
 
-    // Actual rendered output (Tabs widget adds leading space and " " divider):
-    // " Events [1] Favorites [2] Breaking [3] Yield [4]"
-    // 0         1         2         3         4         5
-    // 012345678901234567890123456789012345678901234567890
-    //  Events [1] Favorites [2] Breaking [3] Yield [4]
-    // Positions: 1-10 = Events, 12-25 = Favorites, 27-38 = Breaking, 40-49 = Yield
-    if x <= 10 {
-        return Some(ClickedTab::Trending);
-    } else if (12..26).contains(&x) {
-        return Some(ClickedTab::Favorites);
-    } else if (27..39).contains(&x) {
-        return Some(ClickedTab::Breaking);
-    } else if (40..50).contains(&x) {
-        return Some(ClickedTab::Yield);
+    // Walk the titles as the Tabs widget lays them out: a space of padding on each side of
+    // every title and a one-column divider between tabs
+    const TABS: [ClickedTab; 7] = [
+        ClickedTab::Trending,
+        ClickedTab::Favorites,
+        ClickedTab::Breaking,
+        ClickedTab::Yield,
+        ClickedTab::Positions,
+        ClickedTab::ClosingSoon,
+        ClickedTab::NewlyListed,
+    ];
+    let mut start: u16 = 0;
+    for (title, tab) in tab_titles(app).iter().zip(TABS) {
+        let end = start + title.width() as u16 + 2;
+        if (start..end).contains(&x) {
+            return Some(tab);
+        }
+        start = end + 1;
     }
     None
 }
+
+/// Map a click in the trades area (orderbook + trades, as laid out by `calculate_panel_areas`)
+/// to an index into the displayed trades, i.e. `app.trades.display_order(trades)`
+/// Returns None for clicks on the orderbook, summary line, borders, header or empty rows
+pub fn get_clicked_trade_index(
+    y: u16,
+    trades_area: Rect,
+    app: &TrendingAppState,
+    trades_len: usize,
+) -> Option<usize> {
+    // The Events tab draws a buy/sell summary line above the table; Favorites doesn't
+    let summary_height = if app.main_tab == MainTab::Trending && trades_len > 0 {
+        TRADES_SUMMARY_HEIGHT
+    } else {
+        0
+    };
+    let table_top = trades_area.y + app.orderbook_state.last_height + summary_height;
+    let table_height = trades_area
+        .height
+        .saturating_sub(app.orderbook_state.last_height + summary_height);
+
+    // Same visible height and scroll clamping as render_trades_table
+    let visible_height = (table_height as usize).saturating_sub(3);
+    let first_row = table_top + 2; // Border + header
+    if y < first_row || (y - first_row) as usize >= visible_height {
+        return None;
+    }
+    let scroll = app
+        .scroll
+        .trades
+        .min(trades_len.saturating_sub(visible_height.max(1)));
+    let index = scroll + (y - first_row) as usize;
+    (index < trades_len).then_some(index)
+}
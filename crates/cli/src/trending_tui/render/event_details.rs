@@ -1,9 +1,14 @@
 //! Event details panel rendering functions
 
 use {
-    super::utils::truncate,
-    crate::trending_tui::state::{FocusedPanel, TrendingAppState},
-    chrono::{DateTime, Utc},
+    super::{
+        theme::Theme,
+        utils::{format_volume, panel_key_hint, price_sparkline_spans, sparkline_spans, truncate},
+    },
+    crate::trending_tui::state::{
+        FocusedPanel, MAX_PRICE_HISTORY, TrendingAppState, closing_refresh_interval, event_end_date,
+    },
+    chrono::Utc,
     polymarket_api::gamma::Event,
     ratatui::{
         Frame,
@@ -20,6 +25,7 @@ use {
 pub fn render_event_details(
     f: &mut Frame,
     app: &TrendingAppState,
+    theme: &Theme,
     event: &Event,
     is_watching: bool,
     ws_trade_count: usize,
@@ -44,15 +50,7 @@ pub fn render_event_details(
         .sum();
 
     // Format end date if available
-    let end_date_str = event
-        .end_date
-        .as_ref()
-        .and_then(|date_str| {
-            // Try RFC3339 parsing (handles timezone offsets and UTC)
-            DateTime::parse_from_rfc3339(date_str)
-                .ok()
-                .map(|dt| dt.with_timezone(&Utc))
-        })
+    let end_date_str = event_end_date(event)
         .map(|dt| {
             // Format as relative time or absolute date
             let now = Utc::now();
@@ -61,8 +59,10 @@ pub fn render_event_details(
                 format!("{} days", duration.num_days())
             } else if duration.num_hours() > 0 {
                 format!("{} hours", duration.num_hours())
-            } else if duration.num_minutes() > 0 {
-                format!("{} min", duration.num_minutes())
+            } else if duration.num_seconds() > 0 {
+                // Final hour: live countdown
+                let secs = duration.num_seconds();
+                format!("Closes in {:02}:{:02}", secs / 60, secs % 60)
             } else if duration.num_seconds() < 0 {
                 format!("Expired ({})", dt.format("%Y-%m-%d %H:%M UTC"))
             } else {
@@ -71,6 +71,29 @@ pub fn render_event_details(
         })
         .unwrap_or_else(|| "N/A".to_string());
 
+    // Format volume in short form
+    let volume_str = if total_volume >= 1_000_000.0 {
+        format!("${:.1}M", total_volume / 1_000_000.0)
+    } else if total_volume >= 1_000.0 {
+        format!("${:.1}K", total_volume / 1_000.0)
+    } else {
+        format!("${:.0}", total_volume)
+    };
+
+    if app.compact_event_details {
+        render_compact_event_details(
+            f,
+            app,
+            theme,
+            event,
+            is_watching,
+            &volume_str,
+            &end_date_str,
+            area,
+        );
+        return;
+    }
+
     // Build compact lines without blank lines (title is in panel header)
     let mut lines = vec![Line::from(vec![
         Span::styled("Slug: ", Style::default().fg(Color::Yellow).bold()),
@@ -81,6 +104,17 @@ pub fn render_event_details(
         Span::styled("URL: ", Style::default().fg(Color::Yellow).bold()),
         Span::styled(event_url, Style::default().fg(Color::Cyan)),
     ]));
+    if let Some(note) = app.notes.get(&event.slug) {
+        // "Note: " (6) + borders (2)
+        let note_width = (area.width as usize).saturating_sub(8);
+        lines.push(Line::from(vec![
+            Span::styled("Note: ", Style::default().fg(Color::Yellow).bold()),
+            Span::styled(
+                truncate(note, note_width),
+                Style::default().fg(Color::White),
+            ),
+        ]));
+    }
     lines.push(Line::from(vec![
         Span::styled("Status: ", Style::default().fg(Color::Yellow).bold()),
         Span::styled(
@@ -95,7 +129,7 @@ pub fn render_event_details(
                 Color::Red
             }),
         ),
-        Span::styled(" | ", Style::default().fg(Color::Gray)),
+        Span::styled(" | ", Style::default().fg(theme.dim)),
         Span::styled(
             if event.closed {
                 "Closed"
@@ -108,7 +142,7 @@ pub fn render_event_details(
                 Color::Green
             }),
         ),
-        Span::styled(" | ", Style::default().fg(Color::Gray)),
+        Span::styled(" | ", Style::default().fg(theme.dim)),
         Span::styled(
             if is_watching {
                 "🔴 Watching"
@@ -118,24 +152,23 @@ pub fn render_event_details(
             Style::default().fg(if is_watching {
                 Color::Red
             } else {
-                Color::Gray
+                theme.dim
             }),
         ),
     ]));
     lines.push(Line::from(vec![
         Span::styled("Estimated End: ", Style::default().fg(Color::Yellow).bold()),
         Span::styled(end_date_str, Style::default().fg(Color::Magenta)),
+        match closing_refresh_interval(event, Utc::now()) {
+            Some(interval) => Span::styled(
+                format!(" (refreshing every {}s)", interval.as_secs()),
+                Style::default().fg(theme.muted),
+            ),
+            None => Span::raw(""),
+        },
     ]));
-    // Format volume in short form
-    let volume_str = if total_volume >= 1_000_000.0 {
-        format!("${:.1}M", total_volume / 1_000_000.0)
-    } else if total_volume >= 1_000.0 {
-        format!("${:.1}K", total_volume / 1_000.0)
-    } else {
-        format!("${:.0}", total_volume)
-    };
     // Build trades display with label
-    let trades_spans = vec![
+    let mut trades_spans = vec![
         Span::styled("Total Volume: ", Style::default().fg(Color::Yellow).bold()),
         Span::styled(
             volume_str,
@@ -143,7 +176,21 @@ pub fn render_event_details(
                 .fg(Color::Green)
                 .add_modifier(Modifier::BOLD),
         ),
-        Span::styled(" | ", Style::default().fg(Color::Gray)),
+    ];
+    // Open interest (capital currently committed), when the API reports it
+    if let Some(open_interest) = event.total_open_interest() {
+        trades_spans.push(Span::styled(" | ", Style::default().fg(theme.dim)));
+        trades_spans.push(Span::styled(
+            "OI: ",
+            Style::default().fg(Color::Yellow).bold(),
+        ));
+        trades_spans.push(Span::styled(
+            format_volume(open_interest),
+            Style::default().fg(Color::LightBlue),
+        ));
+    }
+    trades_spans.extend([
+        Span::styled(" | ", Style::default().fg(theme.dim)),
         Span::styled(
             format!("{}: ", trade_label),
             Style::default().fg(Color::Yellow).bold(),
@@ -157,12 +204,138 @@ pub fn render_event_details(
             } else if is_watching {
                 Color::Cyan
             } else {
-                Color::Gray
+                theme.dim
             }),
         ),
-    ];
+    ]);
+    // Distinct traders among the buffered live trades (breadth of participation)
+    if let Some(event_trades) = app.trades.event_trades.get(&event.slug)
+        && !event_trades.trades.is_empty()
+    {
+        trades_spans.push(Span::styled(" | ", Style::default().fg(theme.dim)));
+        trades_spans.push(Span::styled(
+            "Traders: ",
+            Style::default().fg(Color::Yellow).bold(),
+        ));
+        trades_spans.push(Span::styled(
+            event_trades.unique_traders().to_string(),
+            Style::default().fg(Color::Cyan),
+        ));
+    }
     lines.push(Line::from(trades_spans));
 
+    // Activity heatmap: traded value per minute from the buffered live trades
+    if let Some(event_trades) = app.trades.event_trades.get(&event.slug)
+        && !event_trades.trades.is_empty()
+    {
+        const MAX_MINUTES: usize = 60;
+        let label = "Activity: ";
+        // Leave room for the label, the " peak $999K/min" suffix, and borders
+        let minutes = (area.width as usize)
+            .saturating_sub(2 + label.len() + 16)
+            .clamp(1, MAX_MINUTES);
+        let bins = event_trades.volume_per_minute(minutes, Utc::now().timestamp());
+        let peak = bins.iter().copied().fold(0.0_f64, f64::max);
+        let mut activity_spans = vec![Span::styled(
+            label,
+            Style::default().fg(Color::Yellow).bold(),
+        )];
+        activity_spans.extend(sparkline_spans(&bins, theme));
+        if peak > 0.0 {
+            activity_spans.push(Span::styled(
+                format!(" peak {}/min", format_volume(peak)),
+                Style::default().fg(theme.dim),
+            ));
+        } else {
+            activity_spans.push(Span::styled(
+                format!(" quiet {}m", minutes),
+                Style::default().fg(theme.muted),
+            ));
+        }
+        lines.push(Line::from(activity_spans));
+    }
+
+    // Price history of the outcome selected in the orderbook panel
+    if let Some((token_id, _)) = app.selected_orderbook_token() {
+        let label = "Price: ";
+        let history = app
+            .price_history
+            .get(&token_id)
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+        let mut price_spans = vec![Span::styled(
+            label,
+            Style::default().fg(Color::Yellow).bold(),
+        )];
+        if let [first, .., last] = history {
+            // Leave room for the label, the " 99.9¢ (+99.9¢)" suffix, and borders
+            let samples = (area.width as usize)
+                .saturating_sub(2 + label.len() + 16)
+                .clamp(1, MAX_PRICE_HISTORY);
+            let recent = &history[history.len().saturating_sub(samples)..];
+            price_spans.extend(price_sparkline_spans(recent));
+            let change = (last - first) * 100.0;
+            price_spans.push(Span::styled(
+                format!(" {:.1}¢ ({:+.1}¢)", last * 100.0, change),
+                Style::default().fg(theme.dim),
+            ));
+        } else {
+            price_spans.push(Span::styled(
+                format!(
+                    "collecting… ({}/{} samples)",
+                    history.len(),
+                    MAX_PRICE_HISTORY
+                ),
+                Style::default().fg(theme.muted),
+            ));
+        }
+        lines.push(Line::from(price_spans));
+
+        // Volume-weighted average of the watched live trades in this outcome, against its price
+        if is_watching {
+            let vwap = app
+                .trades
+                .event_trades
+                .get(&event.slug)
+                .and_then(|event_trades| event_trades.vwap_for(&token_id));
+            let mut vwap_spans = vec![Span::styled(
+                "VWAP: ",
+                Style::default().fg(Color::Yellow).bold(),
+            )];
+            match vwap {
+                Some(vwap) => {
+                    vwap_spans.push(Span::styled(
+                        format!("{:.1}¢", vwap * 100.0),
+                        Style::default().fg(Color::Cyan),
+                    ));
+                    let current = app
+                        .market_prices
+                        .get(&token_id)
+                        .copied()
+                        .or_else(|| history.last().copied());
+                    if let Some(current) = current {
+                        let diff = (current - vwap) * 100.0;
+                        let (label, color) = if diff.abs() < 0.05 {
+                            ("level".to_string(), theme.dim)
+                        } else if diff > 0.0 {
+                            (format!("{:.1}¢ above", diff), Color::Green)
+                        } else {
+                            (format!("{:.1}¢ below", -diff), Color::Red)
+                        };
+                        vwap_spans.push(Span::styled(
+                            format!(" (now {:.1}¢, ", current * 100.0),
+                            Style::default().fg(theme.dim),
+                        ));
+                        vwap_spans.push(Span::styled(label, Style::default().fg(color)));
+                        vwap_spans.push(Span::styled(")", Style::default().fg(theme.dim)));
+                    }
+                },
+                None => vwap_spans.push(Span::styled("N/A", Style::default().fg(theme.muted))),
+            }
+            lines.push(Line::from(vwap_spans));
+        }
+    }
+
     // Add tags - may wrap to multiple lines
     if !event.tags.is_empty() {
         let tag_labels: Vec<String> = event
@@ -253,6 +426,13 @@ pub fn render_event_details(
                 .borders(Borders::ALL)
                 .border_type(BorderType::Rounded)
                 .title(title)
+                .title_bottom(panel_key_hint(
+                    FocusedPanel::EventDetails,
+                    is_focused,
+                    area.width,
+                    0,
+                    theme,
+                ))
                 .border_style(block_style),
         )
         .wrap(Wrap { trim: true });
@@ -270,3 +450,65 @@ pub fn render_event_details(
         );
     }
 }
+
+/// One dense line in place of the details panel: "title · volume · end · status"
+fn render_compact_event_details(
+    f: &mut Frame,
+    app: &TrendingAppState,
+    theme: &Theme,
+    event: &Event,
+    is_watching: bool,
+    volume_str: &str,
+    end_date_str: &str,
+    area: Rect,
+) {
+    let separator = || Span::styled(" · ", Style::default().fg(theme.muted));
+    let (status, status_color) = if event.closed {
+        ("Closed", Color::Red)
+    } else if is_watching {
+        ("Watching", Color::Red)
+    } else {
+        ("Open", Color::Green)
+    };
+    // Title gets whatever the other fields leave over (borders included)
+    let fixed_width =
+        volume_str.chars().count() + end_date_str.chars().count() + status.len() + 3 * 3 + 2;
+    let title_width = (area.width as usize).saturating_sub(fixed_width).max(10);
+    let line = Line::from(vec![
+        Span::styled(
+            truncate(&event.title, title_width),
+            Style::default().fg(Color::White).bold(),
+        ),
+        separator(),
+        Span::styled(volume_str.to_string(), Style::default().fg(Color::Green)),
+        separator(),
+        Span::styled(
+            end_date_str.to_string(),
+            Style::default().fg(Color::Magenta),
+        ),
+        separator(),
+        Span::styled(status, Style::default().fg(status_color)),
+    ]);
+
+    let is_focused = app.navigation.focused_panel == FocusedPanel::EventDetails;
+    let block_style = if is_focused {
+        Style::default().fg(Color::Yellow)
+    } else {
+        Style::default()
+    };
+    let paragraph = Paragraph::new(line).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .title("Event")
+            .title_bottom(panel_key_hint(
+                FocusedPanel::EventDetails,
+                is_focused,
+                area.width,
+                0,
+                theme,
+            ))
+            .border_style(block_style),
+    );
+    f.render_widget(paragraph, area);
+}
@@ -1,8 +1,19 @@
 //! Events list rendering for the trending TUI
 
 use {
-    super::utils::{event_has_yield, format_volume, truncate_to_width},
-    crate::trending_tui::state::{EventFilter, EventSortBy, FocusedPanel, TrendingAppState},
+    super::{
+        theme::Theme,
+        utils::{
+            competitive_color, event_has_yield, format_age, format_volume, panel_key_hint,
+            resolution_window, spinner_frame, truncate_to_width,
+        },
+    },
+    crate::trending_tui::state::{
+        EventFilter, EventRowKey, EventRowsKey, EventSortBy, FocusedPanel, TrendingAppState,
+        parse_utc_timestamp,
+    },
+    chrono::{DateTime, Utc},
+    polymarket_api::gamma::Event,
     ratatui::{
         Frame,
         layout::Rect,
@@ -10,18 +21,82 @@ use {
         text::{Line, Span},
         widgets::{
             Block, BorderType, Borders, List, ListItem, ListState, Paragraph, Scrollbar,
-            ScrollbarOrientation, ScrollbarState,
+            ScrollbarOrientation, ScrollbarState, Wrap,
         },
     },
     unicode_width::UnicodeWidthStr,
 };
 
-pub fn render_events_list(f: &mut Frame, app: &TrendingAppState, area: Rect) {
+/// Explanation shown in place of an empty events list
+fn empty_list_lines(app: &TrendingAppState, theme: &Theme) -> Vec<Line<'static>> {
+    if app.events.is_empty() {
+        let health_dot = |status: Option<bool>| {
+            let color = match status {
+                Some(true) => theme.positive,
+                Some(false) => theme.negative,
+                None => theme.muted,
+            };
+            Span::styled("•", Style::default().fg(color))
+        };
+        return vec![
+            Line::from(Span::styled(
+                "No events returned by the API",
+                Style::default().fg(theme.text).add_modifier(Modifier::BOLD),
+            )),
+            Line::from(""),
+            Line::from("Polymarket may be having an outage, or nothing matches this tab."),
+            Line::from("Press r to refresh."),
+            Line::from(""),
+            Line::from(vec![
+                Span::raw("Gamma API "),
+                health_dot(app.gamma_health()),
+                Span::raw("   Data API "),
+                health_dot(app.data_api_status),
+            ]),
+        ];
+    }
+
+    let mut lines = vec![
+        Line::from(Span::styled(
+            format!(
+                "No events match the current filters ({} loaded)",
+                app.events.len()
+            ),
+            Style::default().fg(theme.text).add_modifier(Modifier::BOLD),
+        )),
+        Line::from(""),
+    ];
+    if !app.search.query.is_empty() {
+        lines.push(Line::from(format!("Search: \"{}\"", app.search.query)));
+    }
+    if let Some(tag) = &app.tag_filter.active {
+        lines.push(Line::from(format!("Tag: {}", tag)));
+    }
+    if app.min_volume_filter > 0.0 {
+        lines.push(Line::from(format!(
+            "Min volume: {}",
+            format_volume(app.min_volume_filter)
+        )));
+    }
+    if app.streamable_only {
+        lines.push(Line::from("Live trades only"));
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::from(
+        "Esc clears the search or tag filter; - lowers the minimum volume.",
+    ));
+    if app.streamable_only {
+        lines.push(Line::from("y shows events without live trades again."));
+    }
+    lines
+}
+
+pub fn render_events_list(f: &mut Frame, app: &TrendingAppState, theme: &Theme, area: Rect) {
     // Show loading state when events are empty and we're fetching
     if app.events.is_empty() && app.pagination.is_fetching_more {
         let is_focused = app.navigation.focused_panel == FocusedPanel::EventsList;
         let block_style = if is_focused {
-            Style::default().fg(Color::Yellow)
+            Style::default().fg(theme.accent)
         } else {
             Style::default()
         };
@@ -33,22 +108,17 @@ pub fn render_events_list(f: &mut Frame, app: &TrendingAppState, area: Rect) {
                 Block::default()
                     .borders(Borders::ALL)
                     .border_type(BorderType::Rounded)
-                    .title(" Events (Loading...) ")
+                    .title(format!(" Events {} Loading... ", spinner_frame()))
                     .border_style(block_style),
             );
         f.render_widget(loading, area);
         return;
     }
 
-    let filtered_events = app.filtered_events();
+    let now = chrono::Utc::now();
     let scroll = app.current_events_scroll();
     let selected_index = app.current_selected_index();
-    let visible_events: Vec<_> = filtered_events
-        .iter()
-        .enumerate()
-        .skip(scroll)
-        .take(area.height as usize - 2)
-        .collect();
+    let visible_events = app.filtered_events_window(scroll, area.height as usize - 2);
 
     // First pass: calculate max width of market count for alignment
     let max_markets_width = visible_events
@@ -57,201 +127,52 @@ pub fn render_events_list(f: &mut Frame, app: &TrendingAppState, area: Rect) {
         .max()
         .unwrap_or(1);
 
+    let rows_key = EventRowsKey {
+        filter_generation: app.filtered_events_generation(),
+        width: area.width,
+        markets_width: max_markets_width,
+        theme_preset: app.ui_prefs.theme_preset,
+        event_filter: app.event_filter,
+        sort_by: app.event_sort_by(),
+        compact: app.ui_prefs.compact_mode,
+        show_liquidity: app.ui_prefs.show_liquidity,
+        show_age: app.show_event_age,
+        minute: now.timestamp() / 60,
+    };
+
     let items: Vec<ListItem> = visible_events
         .into_iter()
         .map(|(idx, event)| {
-            let is_selected = idx == selected_index;
-
-            // Check if event is closed/inactive (not accepting trades)
-            let is_closed = event.closed || !event.active;
-
-            let style = if is_selected {
-                Style::default()
-                    .fg(Color::Yellow)
-                    .add_modifier(Modifier::BOLD | Modifier::REVERSED)
-            } else if is_closed {
-                Style::default().fg(Color::DarkGray)
-            } else {
-                Style::default().fg(Color::White)
-            };
-
-            let markets_count = event.markets.len();
-            let markets_str = format!("{:>width$}", markets_count, width = max_markets_width);
-
-            // Show metric based on current sort option (or price change for Breaking tab)
-            let (metric_str, metric_color) = if app.event_filter == EventFilter::Breaking {
-                // Show price change percentage for Breaking tab
-                if let Some(price_change) = event.max_price_change_24hr {
-                    let change_str = format!("{:+.0}%", price_change * 100.0);
-                    let color = if price_change >= 0.0 {
-                        Color::Green
-                    } else {
-                        Color::Red
-                    };
-                    (change_str, color)
+            let compact = app.ui_prefs.compact_mode;
+            let row_key = EventRowKey {
+                selected: idx == selected_index,
+                // Received trade count for this event (from websocket)
+                trade_count: if compact {
+                    0
                 } else {
-                    (String::new(), Color::Green)
-                }
-            } else {
-                // Show metric based on current sort option
-                match app.event_sort_by {
-                    EventSortBy::Volume24hr => {
-                        // Calculate 24h volume from all markets
-                        let total_volume: f64 = event
-                            .markets
-                            .iter()
-                            .map(|m| m.volume_24hr.unwrap_or(0.0))
-                            .sum();
-                        (format_volume(total_volume), Color::Green)
-                    },
-                    EventSortBy::VolumeTotal => {
-                        // Use event's total volume or sum from markets
-                        let total_volume = event.volume.unwrap_or_else(|| {
-                            event
-                                .markets
-                                .iter()
-                                .map(|m| m.volume_total.unwrap_or(0.0))
-                                .sum()
-                        });
-                        (format_volume(total_volume), Color::Green)
-                    },
-                    EventSortBy::Liquidity | EventSortBy::Newest | EventSortBy::EndingSoon => {
-                        // Show liquidity for these sort options
-                        let liquidity = event.liquidity.unwrap_or(0.0);
-                        (format_volume(liquidity), Color::Cyan)
-                    },
-                    EventSortBy::Competitive => {
-                        // Show competitive score as percentage
-                        if let Some(competitive) = event.competitive {
-                            (format!("{:.0}%", competitive * 100.0), Color::Magenta)
-                        } else {
-                            (String::new(), Color::Magenta)
-                        }
-                    },
-                }
-            };
-            let volume_str = metric_str;
-            let volume_color = metric_color;
-
-            // Format: "title ...spaces... [trades] volume markets" (right-aligned)
-            // Account for List widget borders (2 chars) and some padding
-            let usable_width = area.width.saturating_sub(2) as usize; // -2 for borders
-
-            // Get received trade count for this event (from websocket)
-            let trade_count = app.get_trades(&event.slug).len();
-            let trade_count_str = if trade_count > 0 {
-                format!("{} ", trade_count)
-            } else {
-                String::new()
+                    app.get_trades(&event.slug).len()
+                },
+                watching: app.is_watching(&event.slug),
+                favorite: app.favorites_state.is_favorite(&event.slug),
+                // Personal note (kept after the event closes)
+                note: app.notes.contains_key(&event.slug),
             };
-
-            // Build the right-aligned text: "[trades] volume markets"
-            let right_text = if volume_str.is_empty() {
-                format!("{}{}", trade_count_str, markets_str)
-            } else {
-                format!("{}{} {}", trade_count_str, volume_str, markets_str)
-            };
-            let right_text_width = right_text.width();
-
-            // Reserve space for right text + 1 space padding + icons if needed
-            let closed_icon = if is_closed {
-                "✕ "
-            } else {
-                ""
-            };
-            let closed_icon_width = closed_icon.width();
-
-            // Check for yield opportunity (high probability market)
-            let has_yield = !is_closed && event_has_yield(event);
-            let yield_icon = if has_yield {
-                "$ "
-            } else {
-                ""
-            };
-            let yield_icon_width = yield_icon.width();
-
-            // Check if event is favorited
-            let is_favorite = app.favorites_state.is_favorite(&event.slug);
-            let favorite_icon = if is_favorite {
-                "⚑ "
-            } else {
-                ""
-            };
-            let favorite_icon_width = favorite_icon.width();
-
-            let reserved_width =
-                right_text_width + 1 + closed_icon_width + yield_icon_width + favorite_icon_width;
-            let available_width = usable_width.saturating_sub(reserved_width);
-
-            // Truncate title to fit available space (using display width)
-            let title = truncate_to_width(&event.title, available_width);
-
-            let title_width = title.width();
-            let remaining_width = usable_width
-                .saturating_sub(closed_icon_width)
-                .saturating_sub(yield_icon_width)
-                .saturating_sub(favorite_icon_width)
-                .saturating_sub(title_width)
-                .saturating_sub(right_text_width);
-
-            let mut line_spans = Vec::new();
-            if is_favorite {
-                line_spans.push(Span::styled(
-                    favorite_icon,
-                    Style::default().fg(Color::Magenta),
-                ));
-            }
-            if is_closed {
-                line_spans.push(Span::styled(closed_icon, Style::default().fg(Color::Red)));
-            }
-            if has_yield {
-                line_spans.push(Span::styled(yield_icon, Style::default().fg(Color::Green)));
-            }
-            line_spans.push(Span::styled(title, style));
-
-            // Add spaces to right-align the markets/trades count
-            if remaining_width > 0 {
-                line_spans.push(Span::styled(" ".repeat(remaining_width), Style::default()));
-            }
-
-            // Add the right-aligned text with appropriate styling
-            // Trade count in yellow, volume/price-change in green/red, markets in cyan
-            if trade_count > 0 {
-                line_spans.push(Span::styled(
-                    format!("{} ", trade_count),
-                    Style::default().fg(Color::Yellow),
-                ));
-            }
-            if !volume_str.is_empty() {
-                line_spans.push(Span::styled(
-                    volume_str.clone(),
-                    Style::default().fg(volume_color),
-                ));
-                line_spans.push(Span::styled(" ", Style::default()));
-            }
-            line_spans.push(Span::styled(markets_str, Style::default().fg(Color::Cyan)));
-
-            // Alternating row colors (zebra striping) for better readability
-            let bg_color = if idx % 2 == 0 {
-                Color::Reset // Default background
-            } else {
-                Color::Rgb(30, 30, 40) // Slightly darker for odd rows
-            };
-
-            ListItem::new(Line::from(line_spans)).style(Style::default().bg(bg_color))
+            app.event_row(rows_key, idx, row_key, || {
+                build_event_row(theme, event, idx, rows_key, row_key, now)
+            })
         })
         .collect();
 
     let is_focused = app.navigation.focused_panel == FocusedPanel::EventsList;
     let block_style = if is_focused {
-        Style::default().fg(Color::Yellow)
+        Style::default().fg(theme.accent)
     } else {
         Style::default()
     };
 
     // Build title with sort option and search query if applicable (count moved to bottom)
-    let event_count = app.filtered_events().len();
-    let sort_label = app.event_sort_by.label();
+    let event_count = app.filtered_events_len();
+    let sort_label = app.event_sort_label();
     let title = if !app.search.last_searched_query.is_empty() && !app.search.results.is_empty() {
         // Show search query in title when displaying API search results
         format!(
@@ -279,23 +200,58 @@ pub fn render_events_list(f: &mut Frame, app: &TrendingAppState, area: Rect) {
     // Add status or position indicator at bottom (lazygit style: "1 of 50─" with trailing dash)
     if app.pagination.is_fetching_more {
         block = block.title_bottom(Line::from(vec![
-            Span::raw(" Loading more... "),
+            Span::raw(format!(" {} Loading more... ", spinner_frame())),
             Span::raw(" ".repeat(10)), // spacer
             Span::raw(format!("{}─", position_indicator)),
         ]));
     } else if app.search.is_searching {
         block = block.title_bottom(Line::from(vec![
-            Span::raw(" Searching... "),
+            Span::raw(format!(" {} Searching... ", spinner_frame())),
             Span::raw(" ".repeat(10)), // spacer
             Span::raw(format!("{}─", position_indicator)),
         ]));
     } else {
-        block = block.title_bottom(Line::from(format!("{}─", position_indicator)).right_aligned());
+        let position = format!("{}─", position_indicator);
+        block = block
+            .title_bottom(panel_key_hint(
+                FocusedPanel::EventsList,
+                is_focused,
+                area.width,
+                position.width(),
+                theme,
+            ))
+            .title_bottom(Line::from(position).right_aligned());
+    }
+
+    // An empty API search gets an explanation instead of a blank list
+    if event_count == 0 && app.api_search_found_nothing() {
+        let message = format!(
+            "No results for '{}' via API. Press Esc, then f to filter the current list instead",
+            app.search.last_searched_query
+        );
+        let empty = Paragraph::new(message)
+            .block(block)
+            .alignment(ratatui::layout::Alignment::Center)
+            .wrap(Wrap { trim: true })
+            .style(Style::default().fg(theme.dim));
+        f.render_widget(empty, area);
+        return;
+    }
+
+    // Nothing to list: say whether the API sent nothing or the filters hid everything
+    if event_count == 0 && !app.search.is_searching {
+        let empty = Paragraph::new(empty_list_lines(app, theme))
+            .block(block)
+            .alignment(ratatui::layout::Alignment::Center)
+            .wrap(Wrap { trim: true })
+            .style(Style::default().fg(theme.dim));
+        f.render_widget(empty, area);
+        return;
     }
 
     let list = List::new(items).block(block).highlight_style(
         Style::default()
-            .fg(Color::Yellow)
+            .fg(theme.accent)
             .add_modifier(Modifier::BOLD | Modifier::REVERSED),
     );
 
@@ -304,7 +260,7 @@ pub fn render_events_list(f: &mut Frame, app: &TrendingAppState, area: Rect) {
     f.render_stateful_widget(list, area, &mut state);
 
     // Render scrollbar for events list if needed
-    let total_events = filtered_events.len();
+    let total_events = event_count;
     let visible_height = (area.height as usize).saturating_sub(2);
     if total_events > visible_height {
         // ScrollbarState automatically calculates thumb size as:
@@ -321,3 +277,322 @@ pub fn render_events_list(f: &mut Frame, app: &TrendingAppState, area: Rect) {
         );
     }
 }
+
+/// One events list row: status icons, the truncated title and the right-aligned metrics
+fn build_event_row(
+    theme: &Theme,
+    event: &Event,
+    idx: usize,
+    rows_key: EventRowsKey,
+    row_key: EventRowKey,
+    now: DateTime<Utc>,
+) -> ListItem<'static> {
+    let is_selected = row_key.selected;
+
+    // Check if event is closed/inactive (not accepting trades)
+    let is_closed = event.closed || !event.active;
+
+    let style = if is_selected {
+        Style::default()
+            .fg(theme.accent)
+            .add_modifier(Modifier::BOLD | Modifier::REVERSED)
+    } else if is_closed {
+        Style::default().fg(theme.muted)
+    } else {
+        Style::default().fg(theme.text)
+    };
+
+    let markets_count = event.markets.len();
+    let markets_str = format!("{:>width$}", markets_count, width = rows_key.markets_width);
+
+    // Show metric based on current sort option (or price change for Breaking tab)
+    let (metric_str, metric_color) = if rows_key.event_filter == EventFilter::Breaking {
+        // Show price change percentage for Breaking tab
+        if let Some(price_change) = event.max_price_change_24hr {
+            let change_str = format!("{:+.0}%", price_change * 100.0);
+            let color = if price_change >= 0.0 {
+                theme.positive
+            } else {
+                theme.negative
+            };
+            (change_str, color)
+        } else {
+            (String::new(), theme.positive)
+        }
+    } else {
+        // Show metric based on current sort option
+        match rows_key.sort_by {
+            EventSortBy::Volume24hr => {
+                // Calculate 24h volume from all markets
+                let total_volume: f64 = event
+                    .markets
+                    .iter()
+                    .map(|m| m.volume_24hr.unwrap_or(0.0))
+                    .sum();
+                (format_volume(total_volume), theme.positive)
+            },
+            EventSortBy::VolumeTotal => {
+                // Use event's total volume or sum from markets
+                let total_volume = event.volume.unwrap_or_else(|| {
+                    event
+                        .markets
+                        .iter()
+                        .map(|m| m.volume_total.unwrap_or(0.0))
+                        .sum()
+                });
+                (format_volume(total_volume), theme.positive)
+            },
+            EventSortBy::Liquidity | EventSortBy::Newest | EventSortBy::EndingSoon => {
+                // Show liquidity for these sort options
+                let liquidity = event.liquidity.unwrap_or(0.0);
+                (format_volume(liquidity), theme.info)
+            },
+            EventSortBy::OpenInterest => {
+                let open_interest = event
+                    .total_open_interest()
+                    .map(format_volume)
+                    .unwrap_or_default();
+                (open_interest, theme.secondary)
+            },
+            EventSortBy::Competitive => {
+                // Show competitive score as percentage
+                if let Some(competitive) = event.competitive {
+                    (
+                        format!("{:.0}%", competitive * 100.0),
+                        competitive_color(competitive, theme),
+                    )
+                } else {
+                    (String::new(), theme.emphasis)
+                }
+            },
+        }
+    };
+    // Compact mode drops every column except the market count, leaving the width to titles
+    let compact = rows_key.compact;
+    let volume_str = if compact {
+        String::new()
+    } else {
+        metric_str
+    };
+    let volume_color = metric_color;
+
+    // Liquidity column, when toggled on and not already the metric shown
+    let shows_liquidity = rows_key.event_filter != EventFilter::Breaking
+        && matches!(
+            rows_key.sort_by,
+            EventSortBy::Liquidity | EventSortBy::Newest | EventSortBy::EndingSoon
+        );
+    let liquidity_str = if rows_key.show_liquidity && !shows_liquidity && !compact {
+        format!("{} ", format_volume(event.liquidity.unwrap_or(0.0)))
+    } else {
+        String::new()
+    };
+
+    // Competitive badge on every row, unless it's already the metric shown
+    let shows_competitive = rows_key.event_filter != EventFilter::Breaking
+        && rows_key.sort_by == EventSortBy::Competitive;
+    let competitive = event.competitive.filter(|_| !shows_competitive && !compact);
+    let competitive_str = competitive
+        .map(|score| format!("{:.0}% ", score * 100.0))
+        .unwrap_or_default();
+
+    // Format: "title ...spaces... [trades] volume markets" (right-aligned)
+    // Account for List widget borders (2 chars) and some padding
+    let usable_width = rows_key.width.saturating_sub(2) as usize; // -2 for borders
+
+    let trade_count = row_key.trade_count;
+    let trade_count_str = if trade_count > 0 {
+        format!("{} ", trade_count)
+    } else {
+        String::new()
+    };
+
+    // Creation age, when toggled on (most useful when sorting by Newest)
+    let age_str = if rows_key.show_age && !compact {
+        event
+            .created_at
+            .as_deref()
+            .and_then(parse_utc_timestamp)
+            .map(|created| format!("{} ", format_age(created, now)))
+            .unwrap_or_default()
+    } else {
+        String::new()
+    };
+
+    // Build the right-aligned text: "[age] [trades] volume [competitive] [liquidity] markets"
+    let right_text = if volume_str.is_empty() {
+        format!(
+            "{}{}{}{}{}",
+            age_str, trade_count_str, competitive_str, liquidity_str, markets_str
+        )
+    } else {
+        format!(
+            "{}{}{} {}{}{}",
+            age_str, trade_count_str, volume_str, competitive_str, liquidity_str, markets_str
+        )
+    };
+    let right_text_width = right_text.width();
+
+    // Watch status column, always present so titles stay aligned
+    let is_watching = row_key.watching;
+    let watch_icon = if is_watching {
+        "● "
+    } else {
+        "○ "
+    };
+    let watch_icon_width = watch_icon.width();
+
+    // Reserve space for right text + 1 space padding + icons if needed
+    let closed_icon = if is_closed {
+        "✕ "
+    } else {
+        ""
+    };
+    let closed_icon_width = closed_icon.width();
+
+    // Check for yield opportunity (high probability market)
+    let has_yield = !is_closed && event_has_yield(event);
+    let yield_icon = if has_yield {
+        "$ "
+    } else {
+        ""
+    };
+    let yield_icon_width = yield_icon.width();
+
+    let is_favorite = row_key.favorite;
+    let favorite_icon = if is_favorite {
+        "⚑ "
+    } else {
+        ""
+    };
+    let favorite_icon_width = favorite_icon.width();
+
+    let has_note = row_key.note;
+    let note_icon = if has_note {
+        "📝 "
+    } else {
+        ""
+    };
+    let note_icon_width = note_icon.width();
+
+    // Time-to-resolution marker (red < 24h, yellow < 7d, gray otherwise)
+    let resolution = if is_closed {
+        None
+    } else {
+        resolution_window(event.end_date.as_deref(), now)
+    };
+    let resolution_icon = if resolution.is_some() {
+        "● "
+    } else {
+        ""
+    };
+    let resolution_icon_width = resolution_icon.width();
+
+    let reserved_width = right_text_width
+        + 1
+        + watch_icon_width
+        + closed_icon_width
+        + yield_icon_width
+        + favorite_icon_width
+        + note_icon_width
+        + resolution_icon_width;
+    let available_width = usable_width.saturating_sub(reserved_width);
+
+    // Truncate title to fit available space (using display width)
+    let title = truncate_to_width(&event.title, available_width);
+
+    let title_width = title.width();
+    let remaining_width = usable_width
+        .saturating_sub(watch_icon_width)
+        .saturating_sub(closed_icon_width)
+        .saturating_sub(yield_icon_width)
+        .saturating_sub(favorite_icon_width)
+        .saturating_sub(note_icon_width)
+        .saturating_sub(resolution_icon_width)
+        .saturating_sub(title_width)
+        .saturating_sub(right_text_width);
+
+    let mut line_spans = vec![Span::styled(
+        watch_icon,
+        Style::default().fg(if is_watching {
+            theme.positive
+        } else {
+            theme.muted
+        }),
+    )];
+    if is_favorite {
+        line_spans.push(Span::styled(
+            favorite_icon,
+            Style::default().fg(theme.emphasis),
+        ));
+    }
+    if is_closed {
+        line_spans.push(Span::styled(
+            closed_icon,
+            Style::default().fg(theme.negative),
+        ));
+    }
+    if let Some(window) = resolution {
+        line_spans.push(Span::styled(
+            resolution_icon,
+            Style::default().fg(window.color(theme)),
+        ));
+    }
+    if has_yield {
+        line_spans.push(Span::styled(
+            yield_icon,
+            Style::default().fg(theme.positive),
+        ));
+    }
+    if has_note {
+        line_spans.push(Span::styled(note_icon, Style::default().fg(theme.text)));
+    }
+    line_spans.push(Span::styled(title, style));
+
+    // Add spaces to right-align the markets/trades count
+    if remaining_width > 0 {
+        line_spans.push(Span::styled(" ".repeat(remaining_width), Style::default()));
+    }
+
+    // Add the right-aligned text with appropriate styling
+    // Age in gray, trade count in yellow, volume/price-change in green/red, competitive by
+    // closeness to 50/50, liquidity in blue, markets in cyan
+    if !age_str.is_empty() {
+        line_spans.push(Span::styled(age_str, Style::default().fg(theme.muted)));
+    }
+    if trade_count > 0 {
+        line_spans.push(Span::styled(
+            format!("{} ", trade_count),
+            Style::default().fg(theme.accent),
+        ));
+    }
+    if !volume_str.is_empty() {
+        line_spans.push(Span::styled(
+            volume_str.clone(),
+            Style::default().fg(volume_color),
+        ));
+        line_spans.push(Span::styled(" ", Style::default()));
+    }
+    if let Some(score) = competitive {
+        line_spans.push(Span::styled(
+            competitive_str,
+            Style::default().fg(competitive_color(score, theme)),
+        ));
+    }
+    if !liquidity_str.is_empty() {
+        line_spans.push(Span::styled(
+            liquidity_str,
+            Style::default().fg(theme.secondary),
+        ));
+    }
+    line_spans.push(Span::styled(markets_str, Style::default().fg(theme.info)));
+
+    // Alternating row colors (zebra striping) for better readability
+    let bg_color = if idx.is_multiple_of(2) {
+        Color::Reset // Default background
+    } else {
+        theme.stripe_bg // Slightly darker for odd rows
+    };
+
+    ListItem::new(Line::from(line_spans)).style(Style::default().bg(bg_color))
+}
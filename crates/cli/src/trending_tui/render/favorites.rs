@@ -1,12 +1,15 @@
 //! Favorites tab rendering functions
 
 use {
-    super::utils::{event_has_yield, format_volume, truncate_to_width},
+    super::utils::{
+        competitive_color, event_has_yield, format_volume, panel_key_hint, spinner_frame,
+        truncate_to_width,
+    },
     crate::trending_tui::state::{FocusedPanel, TrendingAppState},
     ratatui::{
         Frame,
         layout::{Alignment, Constraint, Direction, Layout, Rect},
-        style::{Color, Modifier, Style},
+        style::{Modifier, Style},
         text::{Line, Span},
         widgets::{
             Block, BorderType, Borders, List, ListItem, Paragraph, Scrollbar, ScrollbarOrientation,
@@ -21,16 +24,20 @@ use super::{
     event_details::render_event_details,
     markets::render_markets,
     orderbook::{calculate_orderbook_height, render_orderbook},
-    trades::render_trades_panel,
+    theme::Theme,
+    trades::{connection_state, render_secondary_trades, render_trades_panel, split_trades_areas},
 };
 
 /// Render the favorites tab
-pub fn render_favorites_tab(f: &mut Frame, app: &TrendingAppState, area: Rect) {
+pub fn render_favorites_tab(f: &mut Frame, app: &TrendingAppState, theme: &Theme, area: Rect) {
     let favorites_state = &app.favorites_state;
 
-    // Check authentication first
-    if !app.auth_state.is_authenticated {
-        let message = Paragraph::new("Please login to view your favorites.\n\nPress Tab to go to Login button, then Enter to open login dialog.")
+    // Check authentication first (local bookmarks are listed without a login)
+    if !app.auth_state.is_authenticated
+        && favorites_state.events.is_empty()
+        && !favorites_state.is_loading
+    {
+        let message = Paragraph::new("Please login to view your favorites.\n\nPress Tab to go to Login button, then Enter to open login dialog.\n\nOr press 'b' on any event to bookmark it locally.")
             .block(
                 Block::default()
                     .title(" Favorites ")
@@ -38,13 +45,14 @@ pub fn render_favorites_tab(f: &mut Frame, app: &TrendingAppState, area: Rect) {
                     .border_type(BorderType::Rounded),
             )
             .alignment(Alignment::Center)
-            .style(Style::default().fg(Color::Yellow));
+            .style(Style::default().fg(theme.accent));
         f.render_widget(message, area);
         return;
     }
 
-    // Show loading state - use same layout but with loading indicator
-    if favorites_state.is_loading {
+    // Show loading state - use same layout but with loading indicator. A refresh of an
+    // already-loaded list keeps the list up and spins in its title instead.
+    if favorites_state.is_loading && favorites_state.events.is_empty() {
         let main_chunks = Layout::default()
             .direction(Direction::Horizontal)
             .constraints([
@@ -57,12 +65,12 @@ pub fn render_favorites_tab(f: &mut Frame, app: &TrendingAppState, area: Rect) {
         let loading_list = Paragraph::new("Loading favorites...")
             .block(
                 Block::default()
-                    .title(" Events (Loading...) ")
+                    .title(format!(" Events {} Loading... ", spinner_frame()))
                     .borders(Borders::ALL)
                     .border_type(BorderType::Rounded),
             )
             .alignment(Alignment::Center)
-            .style(Style::default().fg(Color::Yellow));
+            .style(Style::default().fg(theme.accent));
         f.render_widget(loading_list, main_chunks[0]);
 
         // Empty right panel
@@ -76,8 +84,10 @@ pub fn render_favorites_tab(f: &mut Frame, app: &TrendingAppState, area: Rect) {
         return;
     }
 
-    // Show error/info state
-    if let Some(ref error) = favorites_state.error_message {
+    // Show error/info state, unless local bookmarks can be listed instead
+    if let Some(ref error) = favorites_state.error_message
+        && favorites_state.events.is_empty()
+    {
         // Check if this is a "missing session cookie" info message vs actual error
         let is_session_cookie_missing =
             error.contains("session_cookie") || error.contains("Session cookie");
@@ -92,7 +102,7 @@ pub fn render_favorites_tab(f: &mut Frame, app: &TrendingAppState, area: Rect) {
                 Line::from(Span::styled(
                     "Session Cookie Required",
                     Style::default()
-                        .fg(Color::Yellow)
+                        .fg(theme.accent)
                         .add_modifier(Modifier::BOLD),
                 )),
                 Line::from(""),
@@ -109,26 +119,28 @@ pub fn render_favorites_tab(f: &mut Frame, app: &TrendingAppState, area: Rect) {
                 Line::from(""),
                 Line::from(Span::styled(
                     format!("   {}", config_path_str),
-                    Style::default().fg(Color::Cyan),
+                    Style::default().fg(theme.info),
                 )),
                 Line::from(""),
                 Line::from(Span::styled(
                     "   \"session_cookie\": \"<polymarketsession>\",",
-                    Style::default().fg(Color::Cyan),
+                    Style::default().fg(theme.info),
                 )),
                 Line::from(Span::styled(
                     "   \"session_nonce\": \"<polymarketnonce>\",",
-                    Style::default().fg(Color::Cyan),
+                    Style::default().fg(theme.info),
                 )),
                 Line::from(Span::styled(
                     "   \"session_auth_type\": \"magic\"",
-                    Style::default().fg(Color::Cyan),
+                    Style::default().fg(theme.info),
                 )),
                 Line::from(""),
                 Line::from(Span::styled(
                     "Press 'e' to open config in system editor",
-                    Style::default().fg(Color::Green),
+                    Style::default().fg(theme.positive),
                 )),
+                Line::from(""),
+                Line::from("Without cookies, press 'b' on any event to bookmark it locally."),
             ];
 
             let info_msg = Paragraph::new(lines)
@@ -137,7 +149,7 @@ pub fn render_favorites_tab(f: &mut Frame, app: &TrendingAppState, area: Rect) {
                         .title(" Favorites - Setup Required ")
                         .borders(Borders::ALL)
                         .border_type(BorderType::Rounded)
-                        .border_style(Style::default().fg(Color::Yellow)),
+                        .border_style(Style::default().fg(theme.accent)),
                 )
                 .alignment(Alignment::Left)
                 .wrap(Wrap { trim: true });
@@ -153,7 +165,7 @@ pub fn render_favorites_tab(f: &mut Frame, app: &TrendingAppState, area: Rect) {
                 )
                 .alignment(Alignment::Center)
                 .wrap(Wrap { trim: true })
-                .style(Style::default().fg(Color::Red));
+                .style(Style::default().fg(theme.negative));
             f.render_widget(error_msg, area);
         }
         return;
@@ -171,7 +183,7 @@ pub fn render_favorites_tab(f: &mut Frame, app: &TrendingAppState, area: Rect) {
                 .border_type(BorderType::Rounded),
         )
         .alignment(Alignment::Center)
-        .style(Style::default().fg(Color::DarkGray));
+        .style(Style::default().fg(theme.muted));
         f.render_widget(empty, area);
         return;
     }
@@ -185,7 +197,7 @@ pub fn render_favorites_tab(f: &mut Frame, app: &TrendingAppState, area: Rect) {
         ])
         .split(area);
 
-    render_favorites_list(f, app, main_chunks[0]);
+    render_favorites_list(f, app, theme, main_chunks[0]);
 
     // Right side: event details, markets, orderbook, trades (if event selected)
     if let Some(event) = favorites_state.selected_event() {
@@ -200,24 +212,47 @@ pub fn render_favorites_tab(f: &mut Frame, app: &TrendingAppState, area: Rect) {
         let right_chunks = Layout::default()
             .direction(Direction::Vertical)
             .constraints([
-                Constraint::Length(8),                // Event details
-                Constraint::Length(7),                // Markets panel
-                Constraint::Length(orderbook_height), // Order Book panel (dynamic)
-                Constraint::Min(0),                   // Trades table
+                Constraint::Length(app.event_details_height()), // Event details
+                Constraint::Length(7),                          // Markets panel
+                Constraint::Length(orderbook_height),           // Order Book panel (dynamic)
+                Constraint::Min(0),                             // Trades table
             ])
             .split(main_chunks[1]);
 
         // Render event details
-        render_event_details(f, app, event, is_watching, trades.len(), right_chunks[0]);
+        render_event_details(
+            f,
+            app,
+            theme,
+            event,
+            is_watching,
+            trades.len(),
+            right_chunks[0],
+        );
 
         // Render markets panel
-        render_markets(f, app, event, right_chunks[1]);
+        render_markets(f, app, theme, event, right_chunks[1]);
 
         // Render order book panel
-        render_orderbook(f, app, event, right_chunks[2]);
-
-        // Render trades
-        render_trades_panel(f, app, trades, is_watching, right_chunks[3]);
+        render_orderbook(f, app, theme, event, right_chunks[2]);
+
+        // Render trades, above the split view's second panel when one is open
+        let trades_area = match split_trades_areas(app, event_slug, right_chunks[3]) {
+            Some((primary, secondary, secondary_slug)) => {
+                render_secondary_trades(f, app, theme, secondary_slug, secondary);
+                primary
+            },
+            None => right_chunks[3],
+        };
+        render_trades_panel(
+            f,
+            app,
+            theme,
+            trades,
+            is_watching,
+            connection_state(app, event, is_watching),
+            trades_area,
+        );
     } else {
         // No event selected - show empty panel
         let empty = Paragraph::new("Select a favorite event to view details")
@@ -228,13 +263,13 @@ pub fn render_favorites_tab(f: &mut Frame, app: &TrendingAppState, area: Rect) {
                     .title("Event Details"),
             )
             .alignment(Alignment::Center)
-            .style(Style::default().fg(Color::DarkGray));
+            .style(Style::default().fg(theme.muted));
         f.render_widget(empty, main_chunks[1]);
     }
 }
 
 /// Render the favorites events list (separate from main events list)
-fn render_favorites_list(f: &mut Frame, app: &TrendingAppState, area: Rect) {
+fn render_favorites_list(f: &mut Frame, app: &TrendingAppState, theme: &Theme, area: Rect) {
     let favorites_state = &app.favorites_state;
     let events = &favorites_state.events;
 
@@ -269,8 +304,18 @@ fn render_favorites_list(f: &mut Frame, app: &TrendingAppState, area: Rect) {
                 .map(|m| m.volume_24hr.or(m.volume_total).unwrap_or(0.0))
                 .sum();
 
-            // Format volume
-            let volume_str = format_volume(total_volume);
+            // Format volume (hidden in compact mode to give titles the room)
+            let volume_str = if app.ui_prefs.compact_mode {
+                String::new()
+            } else {
+                format_volume(total_volume)
+            };
+
+            // Competitive badge, colored by closeness to 50/50 (hidden in compact mode too)
+            let competitive = event.competitive.filter(|_| !app.ui_prefs.compact_mode);
+            let competitive_str = competitive
+                .map(|score| format!("{:.0}% ", score * 100.0))
+                .unwrap_or_default();
 
             // Format market count with padding
             let markets_str = format!("{:>width$}", event.markets.len(), width = max_markets_width);
@@ -288,17 +333,25 @@ fn render_favorites_list(f: &mut Frame, app: &TrendingAppState, area: Rect) {
                 ""
             };
             let yield_icon_width = yield_icon.width();
+            let has_note = app.notes.contains_key(&event.slug);
+            let note_icon = if has_note {
+                "📝 "
+            } else {
+                ""
+            };
+            let note_icon_width = note_icon.width();
 
-            // Build right-aligned text: "volume markets"
+            // Build right-aligned text: "volume [competitive] markets"
             let right_text = if volume_str.is_empty() {
-                markets_str.clone()
+                format!("{}{}", competitive_str, markets_str)
             } else {
-                format!("{} {}", volume_str, markets_str)
+                format!("{} {}{}", volume_str, competitive_str, markets_str)
             };
             let right_text_width = right_text.width();
 
             // Calculate available width for title
-            let reserved_width = favorite_icon_width + yield_icon_width + right_text_width + 1;
+            let reserved_width =
+                favorite_icon_width + yield_icon_width + note_icon_width + right_text_width + 1;
             let available_width = usable_width.saturating_sub(reserved_width);
 
             // Truncate title to fit
@@ -309,18 +362,17 @@ fn render_favorites_list(f: &mut Frame, app: &TrendingAppState, area: Rect) {
             let remaining_width = usable_width
                 .saturating_sub(favorite_icon_width)
                 .saturating_sub(yield_icon_width)
+                .saturating_sub(note_icon_width)
                 .saturating_sub(title_width)
                 .saturating_sub(right_text_width);
 
             // Title style
             let title_style = if is_closed {
-                Style::default().fg(Color::DarkGray)
+                Style::default().fg(theme.muted)
             } else if is_selected {
-                Style::default()
-                    .fg(Color::White)
-                    .add_modifier(Modifier::BOLD)
+                Style::default().fg(theme.text).add_modifier(Modifier::BOLD)
             } else {
-                Style::default().fg(Color::White)
+                Style::default().fg(theme.text)
             };
 
             // Build spans with proper alignment
@@ -329,12 +381,19 @@ fn render_favorites_list(f: &mut Frame, app: &TrendingAppState, area: Rect) {
             // Favorite icon (always shown for favorites)
             spans.push(Span::styled(
                 favorite_icon,
-                Style::default().fg(Color::Magenta),
+                Style::default().fg(theme.emphasis),
             ));
 
             // Yield icon if applicable
             if has_yield {
-                spans.push(Span::styled(yield_icon, Style::default().fg(Color::Green)));
+                spans.push(Span::styled(
+                    yield_icon,
+                    Style::default().fg(theme.positive),
+                ));
+            }
+
+            if has_note {
+                spans.push(Span::styled(note_icon, Style::default().fg(theme.text)));
             }
 
             // Title
@@ -350,21 +409,28 @@ fn render_favorites_list(f: &mut Frame, app: &TrendingAppState, area: Rect) {
                 spans.push(Span::styled(
                     volume_str.clone(),
                     Style::default().fg(if is_closed {
-                        Color::DarkGray
+                        theme.muted
                     } else {
-                        Color::Green
+                        theme.positive
                     }),
                 ));
                 spans.push(Span::styled(" ", Style::default()));
             }
 
+            if let Some(score) = competitive {
+                spans.push(Span::styled(
+                    competitive_str,
+                    Style::default().fg(competitive_color(score, theme)),
+                ));
+            }
+
             // Market count (right-aligned)
             spans.push(Span::styled(
                 markets_str,
                 Style::default().fg(if is_closed {
-                    Color::DarkGray
+                    theme.muted
                 } else {
-                    Color::Cyan
+                    theme.info
                 }),
             ));
 
@@ -374,7 +440,7 @@ fn render_favorites_list(f: &mut Frame, app: &TrendingAppState, area: Rect) {
             if is_selected {
                 item = item.style(
                     Style::default()
-                        .bg(Color::Rgb(60, 60, 80))
+                        .bg(theme.highlight_bg)
                         .add_modifier(Modifier::BOLD),
                 );
             }
@@ -385,7 +451,7 @@ fn render_favorites_list(f: &mut Frame, app: &TrendingAppState, area: Rect) {
 
     let is_focused = app.navigation.focused_panel == FocusedPanel::EventsList;
     let block_style = if is_focused {
-        Style::default().fg(Color::Yellow)
+        Style::default().fg(theme.accent)
     } else {
         Style::default()
     };
@@ -403,13 +469,24 @@ fn render_favorites_list(f: &mut Frame, app: &TrendingAppState, area: Rect) {
             Block::default()
                 .borders(Borders::ALL)
                 .border_type(BorderType::Rounded)
-                .title("Favorites")
+                .title(if favorites_state.is_loading {
+                    format!("Favorites {}", spinner_frame())
+                } else {
+                    "Favorites".to_string()
+                })
+                .title_bottom(panel_key_hint(
+                    FocusedPanel::EventsList,
+                    is_focused,
+                    area.width,
+                    position_indicator.width() + 1,
+                    theme,
+                ))
                 .title_bottom(Line::from(format!("{}─", position_indicator)).right_aligned())
                 .border_style(block_style),
         )
         .highlight_style(
             Style::default()
-                .bg(Color::Rgb(60, 60, 80))
+                .bg(theme.highlight_bg)
                 .add_modifier(Modifier::BOLD),
         );
     f.render_widget(list, area);
@@ -1,8 +1,13 @@
 //! Header rendering functions (tabs, search input, portfolio info)
 
 use {
-    super::utils::render_search_input,
-    crate::trending_tui::state::{EventFilter, MainTab, SearchMode, TrendingAppState},
+    super::{
+        theme::Theme,
+        utils::{format_volume, render_search_input, spinner_frame},
+    },
+    crate::trending_tui::state::{
+        EventFilter, MainTab, SearchMode, TrendingAppState, YieldViewMode,
+    },
     ratatui::{
         Frame,
         layout::{Alignment, Constraint, Direction, Layout, Rect},
@@ -12,15 +17,36 @@ use {
     },
 };
 
-pub fn render_header(f: &mut Frame, app: &TrendingAppState, area: Rect) {
-    // Calculate unified tab index: 0=Events, 1=Favorites, 2=Breaking, 3=Yield
+/// Unified tab titles; the Yield tab shows which list is active
+pub(super) fn tab_titles(app: &TrendingAppState) -> Vec<Line<'static>> {
+    let yield_title = match app.yield_state.view_mode {
+        YieldViewMode::Opportunities => "Yield [4]".to_string(),
+        view_mode => format!("Yield: {} [4]", view_mode.label()),
+    };
+    vec![
+        Line::from("Events [1]"),
+        Line::from("Favorites [2]"),
+        Line::from("Breaking [3]"),
+        Line::from(yield_title),
+        Line::from("Positions [5]"),
+        Line::from("Closing [6]"),
+        Line::from("New [7]"),
+    ]
+}
+
+pub fn render_header(f: &mut Frame, app: &TrendingAppState, theme: &Theme, area: Rect) {
+    // Calculate unified tab index: 0=Events, 1=Favorites, 2=Breaking, 3=Yield, 4=Positions,
+    // 5=Closing Soon, 6=New
     let tab_index = match app.main_tab {
         MainTab::Trending => match app.event_filter {
             EventFilter::Trending => 0,
             EventFilter::Breaking => 2,
+            EventFilter::ClosingSoon => 5,
+            EventFilter::NewlyListed => 6,
         },
         MainTab::Favorites => 1,
         MainTab::Yield => 3,
+        MainTab::Positions => 4,
     };
 
     if app.is_in_filter_mode() {
@@ -35,15 +61,9 @@ pub fn render_header(f: &mut Frame, app: &TrendingAppState, area: Rect) {
             .split(area);
 
         // Render unified tabs
-        let tab_titles: Vec<Line> = vec![
-            Line::from("Events [1]"),
-            Line::from("Favorites [2]"),
-            Line::from("Breaking [3]"),
-            Line::from("Yield [4]"),
-        ];
-        let tabs = Tabs::new(tab_titles)
+        let tabs = Tabs::new(tab_titles(app))
             .select(tab_index)
-            .style(Style::default().fg(Color::DarkGray))
+            .style(Style::default().fg(theme.muted))
             .highlight_style(
                 Style::default()
                     .fg(Color::White)
@@ -55,7 +75,7 @@ pub fn render_header(f: &mut Frame, app: &TrendingAppState, area: Rect) {
         // Horizontal separator line (gitui-style) - full width line of ─ characters
         let line_width = header_chunks[1].width as usize;
         let separator_line = "─".repeat(line_width);
-        let separator = Paragraph::new(separator_line).style(Style::default().fg(Color::DarkGray));
+        let separator = Paragraph::new(separator_line).style(Style::default().fg(theme.muted));
         f.render_widget(separator, header_chunks[1]);
 
         // Search input field with proper styling
@@ -71,6 +91,7 @@ pub fn render_header(f: &mut Frame, app: &TrendingAppState, area: Rect) {
         };
         render_search_input(
             f,
+            theme,
             header_chunks[2],
             &app.search.query,
             title,
@@ -91,6 +112,50 @@ pub fn render_header(f: &mut Frame, app: &TrendingAppState, area: Rect) {
         // Build right side: portfolio info + profile button
         let mut right_spans: Vec<Span> = Vec::new();
 
+        // Network activity: animates while any request is in flight, so a slow fetch
+        // doesn't look like a hang
+        if app.is_network_busy() {
+            right_spans.push(Span::styled(
+                format!("{} ", spinner_frame()),
+                Style::default().fg(Color::Cyan),
+            ));
+        }
+
+        // Events list minimum volume (+/- to adjust)
+        if app.min_volume_filter > 0.0 {
+            right_spans.push(Span::styled(
+                format!("Min Vol: {} ", format_volume(app.min_volume_filter)),
+                Style::default().fg(Color::Yellow),
+            ));
+        }
+
+        // Only events with live trade support ('y' to toggle)
+        if app.streamable_only {
+            right_spans.push(Span::styled(
+                "Live only ",
+                Style::default().fg(Color::Green),
+            ));
+        }
+
+        // Active tag filter (Esc clears it)
+        if let Some(tag) = &app.tag_filter.active {
+            right_spans.push(Span::styled(
+                format!("#{} ", tag),
+                Style::default()
+                    .fg(Color::Magenta)
+                    .add_modifier(Modifier::BOLD),
+            ));
+        }
+
+        // Badge for recent API failures (press E for details)
+        let recent_errors = app.api_errors.recent_count();
+        if recent_errors > 0 {
+            right_spans.push(Span::styled(
+                format!("⚠ {} ", recent_errors),
+                Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+            ));
+        }
+
         // Add portfolio info if authenticated and available
         if app.auth_state.is_authenticated {
             // Total value (cash + portfolio)
@@ -109,7 +174,7 @@ pub fn render_header(f: &mut Frame, app: &TrendingAppState, area: Rect) {
                 let total_pnl = app.auth_state.unrealized_pnl.unwrap_or(0.0)
                     + app.auth_state.realized_pnl.unwrap_or(0.0);
                 let (pnl_str, pnl_color) = if total_pnl.abs() < 0.005 {
-                    ("$0".to_string(), Color::DarkGray)
+                    ("$0".to_string(), theme.muted)
                 } else if total_pnl > 0.0 {
                     (format!("+${:.0}", total_pnl), Color::Green)
                 } else {
@@ -131,13 +196,13 @@ pub fn render_header(f: &mut Frame, app: &TrendingAppState, area: Rect) {
 
         // API status indicator dot (using smaller bullet •)
         // Green = both APIs healthy, Yellow = one API down, Red = both down, Gray = unknown
-        let status_dot = match (app.gamma_api_status, app.data_api_status) {
+        let status_dot = match (app.gamma_health(), app.data_api_status) {
             (Some(true), Some(true)) => Span::styled(" •", Style::default().fg(Color::Green)),
             (Some(false), Some(false)) => Span::styled(" •", Style::default().fg(Color::Red)),
             (Some(true), Some(false)) | (Some(false), Some(true)) => {
                 Span::styled(" •", Style::default().fg(Color::Yellow))
             },
-            _ => Span::styled(" •", Style::default().fg(Color::DarkGray)),
+            _ => Span::styled(" •", Style::default().fg(theme.muted)),
         };
         right_spans.push(status_dot);
 
@@ -154,15 +219,9 @@ pub fn render_header(f: &mut Frame, app: &TrendingAppState, area: Rect) {
             .split(header_chunks[0]);
 
         // Render unified tabs in gitui-style (underline for selected, keyboard shortcuts)
-        let tab_titles: Vec<Line> = vec![
-            Line::from("Events [1]"),
-            Line::from("Favorites [2]"),
-            Line::from("Breaking [3]"),
-            Line::from("Yield [4]"),
-        ];
-        let tabs = Tabs::new(tab_titles)
+        let tabs = Tabs::new(tab_titles(app))
             .select(tab_index)
-            .style(Style::default().fg(Color::DarkGray))
+            .style(Style::default().fg(theme.muted))
             .highlight_style(
                 Style::default()
                     .fg(Color::White)
@@ -178,7 +237,7 @@ pub fn render_header(f: &mut Frame, app: &TrendingAppState, area: Rect) {
         // Horizontal separator line (gitui-style) - full width line of ─ characters
         let line_width = header_chunks[1].width as usize;
         let separator_line = "─".repeat(line_width);
-        let separator = Paragraph::new(separator_line).style(Style::default().fg(Color::DarkGray));
+        let separator = Paragraph::new(separator_line).style(Style::default().fg(theme.muted));
         f.render_widget(separator, header_chunks[1]);
     }
 }
@@ -1,6 +1,7 @@
 //! Logs panel rendering
 
 use {
+    super::{theme::Theme, utils::panel_key_hint},
     crate::trending_tui::state::{FocusedPanel, TrendingAppState},
     ratatui::{
         Frame,
@@ -13,7 +14,7 @@ use {
     },
 };
 
-pub fn render_logs(f: &mut Frame, app: &mut TrendingAppState, area: Rect) {
+pub fn render_logs(f: &mut Frame, app: &mut TrendingAppState, theme: &Theme, area: Rect) {
     // Calculate the actual visible height (accounting for borders)
     let visible_height = (area.height as usize).saturating_sub(2);
 
@@ -97,6 +98,13 @@ pub fn render_logs(f: &mut Frame, app: &mut TrendingAppState, area: Rect) {
                 } else {
                     "Logs"
                 })
+                .title_bottom(panel_key_hint(
+                    FocusedPanel::Logs,
+                    is_focused,
+                    area.width,
+                    0,
+                    theme,
+                ))
                 .border_style(block_style),
         )
         .style(Style::default().fg(Color::White));
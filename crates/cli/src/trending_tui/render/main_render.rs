@@ -10,7 +10,12 @@ use {
         markets::render_markets,
         orderbook::{calculate_orderbook_height, render_orderbook},
         popups::render_popup,
-        trades::render_trades_table,
+        positions::render_positions_tab,
+        theme::Theme,
+        trades::{
+            connection_state, render_secondary_trades, render_trades_table, split_trades_areas,
+        },
+        utils::spinner_frame,
         yield_tab::render_yield_tab,
     },
     crate::trending_tui::state::{MainTab, SearchMode, TrendingAppState},
@@ -18,40 +23,46 @@ use {
         Frame,
         layout::{Alignment, Constraint, Direction, Layout, Rect},
         style::{Color, Style},
+        text::{Line, Span},
         widgets::{Block, BorderType, Borders, Paragraph},
     },
 };
 
-pub fn render(f: &mut Frame, app: &mut TrendingAppState) {
-    // Header height: 2 lines for normal mode (tabs + separator), 5 for search mode
-    let header_height = if app.is_in_filter_mode() {
-        5
-    } else {
-        2
-    };
+pub fn render(f: &mut Frame, app: &mut TrendingAppState, theme: &Theme) {
+    // Header height: 2 lines for normal mode (tabs + separator), 5 for search mode.
+    // The load error banner, when shown, sits between the header and the main content.
+    let header_height = app.header_height();
+    let top_height = app.top_height();
     // No overlap - all panels have full borders with rounded corners
     // Conditionally include logs area based on show_logs
     let constraints: Vec<Constraint> = if app.show_logs {
         vec![
-            Constraint::Length(header_height), // Header (with search if active)
-            Constraint::Min(0),                // Main content
-            Constraint::Length(8),             // Logs area
-            Constraint::Length(3),             // Footer
+            Constraint::Length(top_height), // Header (with search if active) and banner
+            Constraint::Min(0),             // Main content
+            Constraint::Length(8),          // Logs area
+            Constraint::Length(3),          // Footer
         ]
     } else {
         vec![
-            Constraint::Length(header_height), // Header (with search if active)
-            Constraint::Min(0),                // Main content
-            Constraint::Length(3),             // Footer
+            Constraint::Length(top_height), // Header (with search if active) and banner
+            Constraint::Min(0),             // Main content
+            Constraint::Length(3),          // Footer
         ]
     };
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints(constraints)
         .split(f.area());
+    let top_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(header_height), Constraint::Min(0)])
+        .split(chunks[0]);
 
     // Render header with main tabs
-    render_header(f, app, chunks[0]);
+    render_header(f, app, theme, top_chunks[0]);
+    if let Some(ref error) = app.load_error {
+        render_load_error_banner(f, app, error, top_chunks[1]);
+    }
 
     // Main content depends on active main tab
     match app.main_tab {
@@ -65,21 +76,24 @@ pub fn render(f: &mut Frame, app: &mut TrendingAppState) {
                 ])
                 .split(chunks[1]);
 
-            render_events_list(f, app, main_chunks[0]);
-            render_trades(f, app, main_chunks[1]);
+            render_events_list(f, app, theme, main_chunks[0]);
+            render_trades(f, app, theme, main_chunks[1]);
         },
         MainTab::Favorites => {
-            render_favorites_tab(f, app, chunks[1]);
+            render_favorites_tab(f, app, theme, chunks[1]);
         },
         MainTab::Yield => {
-            render_yield_tab(f, app, chunks[1]);
+            render_yield_tab(f, app, theme, chunks[1]);
+        },
+        MainTab::Positions => {
+            render_positions_tab(f, app, theme, chunks[1]);
         },
     }
 
     // Logs area (only if shown)
     // Footer index depends on whether logs are shown
     let footer_idx = if app.show_logs {
-        render_logs(f, app, chunks[2]);
+        render_logs(f, app, theme, chunks[2]);
         3
     } else {
         2
@@ -88,7 +102,9 @@ pub fn render(f: &mut Frame, app: &mut TrendingAppState) {
     // Footer - show focused panel info with context-sensitive help
     let panel_name = app.navigation.focused_panel.name();
     let panel_help = if app.main_tab == MainTab::Yield {
-        "/: Search | f: Filter | s: Sort | r: Refresh | o: Open"
+        "/: Search | v: Results/Opportunities | f: Filter | m: Min Vol | s: Sort | r: Refresh | o: Open"
+    } else if app.main_tab == MainTab::Positions {
+        "↑↓: Select | s: Sort | r: Refresh | o: Open event"
     } else {
         app.navigation.focused_panel.help_text()
     };
@@ -97,15 +113,24 @@ pub fn render(f: &mut Frame, app: &mut TrendingAppState) {
     } else if app.main_tab == MainTab::Yield && app.yield_state.is_filtering {
         "Type to filter | Esc: Cancel".to_string()
     } else if app.search.mode == SearchMode::ApiSearch {
-        "Type to search | Esc: Cancel".to_string()
+        "Type to search | ↑↓: History | Esc: Cancel".to_string()
     } else if app.search.mode == SearchMode::LocalFilter {
         "Type to filter | Esc: Cancel".to_string()
+    } else if app.orderbook_state.is_filtering_markets {
+        "Type to filter markets | Enter: Keep | Esc: Clear".to_string()
+    } else if let Some(count) = app.navigation.pending_count {
+        format!("{} | j/k: move {} rows | Esc: Cancel", count, count)
     } else {
         format!(
             "{} | b: Bookmark | p: Profile | l: Logs | q: Quit | [{}]",
             panel_help, panel_name
         )
     };
+    // A toast temporarily replaces the footer help
+    let (footer_text, footer_color) = match app.active_toast() {
+        Some(message) => (message.to_string(), Color::Cyan),
+        None => (footer_text, Color::Gray),
+    };
     let footer = Paragraph::new(footer_text)
         .block(
             Block::default()
@@ -113,24 +138,48 @@ pub fn render(f: &mut Frame, app: &mut TrendingAppState) {
                 .border_type(BorderType::Rounded),
         )
         .alignment(Alignment::Center)
-        .style(Style::default().fg(Color::Gray));
+        .style(Style::default().fg(footer_color));
     f.render_widget(footer, chunks[footer_idx]);
 
     // Render popup if active (on top of everything)
     if let Some(ref popup) = app.popup {
-        render_popup(f, app, popup);
+        render_popup(f, app, theme, popup);
     }
 }
 
-fn render_trades(f: &mut Frame, app: &TrendingAppState, area: Rect) {
+/// Banner shown while the startup events fetch has failed, with how to retry
+fn render_load_error_banner(f: &mut Frame, app: &TrendingAppState, error: &str, area: Rect) {
+    let status = if app.pagination.is_fetching_more {
+        format!("{} Retrying...", spinner_frame())
+    } else {
+        "Press R to retry".to_string()
+    };
+    let banner = Paragraph::new(Line::from(vec![
+        Span::styled(
+            "Failed to load events: ",
+            Style::default().fg(Color::Red).bold(),
+        ),
+        Span::styled(error.to_string(), Style::default().fg(Color::White)),
+        Span::styled(format!("  {}", status), Style::default().fg(Color::Yellow)),
+    ]))
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(Color::Red)),
+    );
+    f.render_widget(banner, area);
+}
+
+fn render_trades(f: &mut Frame, app: &TrendingAppState, theme: &Theme, area: Rect) {
     if let Some(event) = app.selected_event() {
         let event_slug = &event.slug;
         let trades = app.get_trades(event_slug);
         let is_watching = app.is_watching(event_slug);
 
-        // Use a fixed minimum height for event details panel
+        // Fixed height for the event details panel (one line in compact mode)
         // Content will scroll if it exceeds this height
-        let min_event_details_height = 8; // Minimum height (6 base lines + 2 for borders)
+        let event_details_height = app.event_details_height();
 
         // Calculate dynamic orderbook height based on data
         let orderbook_height = calculate_orderbook_height(app, Some(event));
@@ -139,7 +188,7 @@ fn render_trades(f: &mut Frame, app: &TrendingAppState, area: Rect) {
         let chunks = Layout::default()
             .direction(Direction::Vertical)
             .constraints([
-                Constraint::Length(min_event_details_height as u16), // Event details (minimum height, scrollable)
+                Constraint::Length(event_details_height), // Event details (scrollable)
                 Constraint::Length(7), // Markets panel (5 lines + 2 for borders)
                 Constraint::Length(orderbook_height), // Order Book panel (dynamic)
                 Constraint::Min(0),    // Trades table
@@ -147,16 +196,32 @@ fn render_trades(f: &mut Frame, app: &TrendingAppState, area: Rect) {
             .split(area);
 
         // Render event details
-        render_event_details(f, app, event, is_watching, trades.len(), chunks[0]);
+        render_event_details(f, app, theme, event, is_watching, trades.len(), chunks[0]);
 
         // Render markets panel
-        render_markets(f, app, event, chunks[1]);
+        render_markets(f, app, theme, event, chunks[1]);
 
         // Render order book panel
-        render_orderbook(f, app, event, chunks[2]);
-
-        // Render trades table
-        render_trades_table(f, app, trades, Some(event), is_watching, chunks[3]);
+        render_orderbook(f, app, theme, event, chunks[2]);
+
+        // Render trades table, above the split view's second panel when one is open
+        let trades_area = match split_trades_areas(app, event_slug, chunks[3]) {
+            Some((primary, secondary, secondary_slug)) => {
+                render_secondary_trades(f, app, theme, secondary_slug, secondary);
+                primary
+            },
+            None => chunks[3],
+        };
+        render_trades_table(
+            f,
+            app,
+            theme,
+            trades,
+            Some(event),
+            is_watching,
+            connection_state(app, event, is_watching),
+            trades_area,
+        );
     } else {
         let paragraph = Paragraph::new("No event selected")
             .block(
@@ -1,14 +1,18 @@
 //! Markets panel rendering functions
 
 use {
-    super::utils::{
-        YIELD_MIN_PROB, format_price_cents, market_has_yield, truncate, truncate_to_width,
+    super::{
+        theme::Theme,
+        utils::{
+            YIELD_MIN_PROB, format_price_cents, market_has_yield, panel_key_hint, truncate,
+            truncate_to_width,
+        },
     },
-    crate::trending_tui::state::{FocusedPanel, TrendingAppState},
+    crate::trending_tui::state::{EventSortBy, FocusedPanel, TrendingAppState},
     polymarket_api::gamma::Event,
     ratatui::{
         Frame,
-        layout::{Alignment, Rect},
+        layout::{Alignment, Position, Rect},
         style::{Color, Style},
         text::{Line, Span},
         widgets::{
@@ -19,7 +23,13 @@ use {
     unicode_width::UnicodeWidthStr,
 };
 
-pub fn render_markets(f: &mut Frame, app: &TrendingAppState, event: &Event, area: Rect) {
+pub fn render_markets(
+    f: &mut Frame,
+    app: &TrendingAppState,
+    theme: &Theme,
+    event: &Event,
+    area: Rect,
+) {
     if event.markets.is_empty() {
         let paragraph = Paragraph::new("No markets available")
             .block(
@@ -29,7 +39,7 @@ pub fn render_markets(f: &mut Frame, app: &TrendingAppState, event: &Event, area
                     .title("Markets"),
             )
             .alignment(Alignment::Center)
-            .style(Style::default().fg(Color::Gray));
+            .style(Style::default().fg(theme.dim));
         f.render_widget(paragraph, area);
         return;
     }
@@ -37,17 +47,15 @@ pub fn render_markets(f: &mut Frame, app: &TrendingAppState, event: &Event, area
     // Calculate visible height (accounting for borders: top and bottom)
     // The List widget with borders takes 2 lines (top border + title, bottom border)
     let visible_height = (area.height as usize).saturating_sub(2);
-    let total_markets = event.markets.len();
+    // Sort markets: non-closed (active) first, then closed (resolved), narrowed by the filter
+    let sorted_markets = app.sorted_markets(event);
+    let total_markets = sorted_markets.len();
 
     // Calculate maximum scroll position (can't scroll past the end)
     let max_scroll = total_markets.saturating_sub(visible_height.max(1));
     // Clamp scroll position to valid range
     let scroll = app.scroll.markets.min(max_scroll);
 
-    // Sort markets: non-closed (active) first, then closed (resolved)
-    let mut sorted_markets: Vec<_> = event.markets.iter().collect();
-    sorted_markets.sort_by_key(|m| m.closed);
-
     // Fixed column widths for alignment - compact layout
     // Yield: "+XX.X%" = 6 chars max
     // Volume: "$XXX.XM" = 7 chars max
@@ -69,8 +77,20 @@ pub fn render_markets(f: &mut Frame, app: &TrendingAppState, event: &Event, area
         .skip(scroll)
         .take(visible_height)
         .map(|(idx, market)| {
-            // Use 24hr volume (more reliable) or fall back to total volume
-            let volume = market.volume_24hr.or(market.volume_total);
+            // Use 24hr volume (more reliable) or fall back to total volume; when sorting
+            // by open interest, show the market's open interest instead
+            let show_open_interest =
+                app.event_sort_by() == EventSortBy::OpenInterest && market.open_interest.is_some();
+            let volume = if show_open_interest {
+                market.open_interest
+            } else {
+                market.volume_24hr.or(market.volume_total)
+            };
+            let volume_color = if show_open_interest {
+                theme.secondary
+            } else {
+                theme.positive
+            };
             let volume_str = volume
                 .map(|v| {
                     if v >= 1_000_000.0 {
@@ -106,9 +126,16 @@ pub fn render_markets(f: &mut Frame, app: &TrendingAppState, event: &Event, area
             // Check if this market is selected for orderbook display
             let is_orderbook_selected = idx == app.orderbook_state.selected_market_index;
 
-            // Status indicator: ● for active, ◐ for in-review, ○ for resolved, $ for yield
-            let status_icon = if market.closed {
+            // Status indicator: 📌 for pinned, ● for active, ◐ for in-review, ○ for resolved,
+            // $ for yield, ◇ for markets that don't trade on the CLOB
+            let is_pinned = app.is_market_pinned(market);
+            let off_clob = !market.closed && !market.is_clob_tradeable();
+            let status_icon = if is_pinned {
+                "📌"
+            } else if market.closed {
                 "○ "
+            } else if off_clob {
+                "◇ "
             } else if has_yield {
                 "$ " // Yield opportunity indicator
             } else if market.is_in_review() {
@@ -245,6 +272,24 @@ pub fn render_markets(f: &mut Frame, app: &TrendingAppState, event: &Event, area
                 (String::new(), 0, String::new(), 0)
             };
 
+            // Price movement of the Yes outcome since the previous price update
+            let price_arrow = market
+                .clob_token_ids
+                .as_ref()
+                .and_then(|ids| ids.first())
+                .filter(|_| !market.closed)
+                .and_then(|asset_id| {
+                    let current = app.market_prices.get(asset_id)?;
+                    let previous = app.prev_prices.get(asset_id)?;
+                    if current > previous {
+                        Some(("▲", theme.positive))
+                    } else if current < previous {
+                        Some(("▼", theme.negative))
+                    } else {
+                        None
+                    }
+                });
+
             // Format yield return string if applicable
             let yield_str = yield_return.map(|ret| format!("+{:.1}%", ret));
 
@@ -281,17 +326,19 @@ pub fn render_markets(f: &mut Frame, app: &TrendingAppState, event: &Event, area
 
             // Start with status icon - use original colors
             let icon_color = if market.closed {
-                Color::DarkGray
+                theme.muted
+            } else if off_clob {
+                theme.secondary
             } else if has_yield {
-                Color::Green // Yield opportunity in green
+                theme.positive // Yield opportunity in green
             } else if market.is_in_review() {
-                Color::Cyan
+                theme.info
             } else {
-                Color::Green
+                theme.positive
             };
             let mut line_spans = vec![
                 Span::styled(status_icon, Style::default().fg(icon_color)),
-                Span::styled(question, Style::default().fg(Color::White)),
+                Span::styled(question, Style::default().fg(theme.text)),
             ];
 
             // Add spaces to push right content to the right
@@ -306,7 +353,7 @@ pub fn render_markets(f: &mut Frame, app: &TrendingAppState, event: &Event, area
                 let yield_padded = format!("{:>width$}", yield_display, width = YIELD_COL_WIDTH);
                 line_spans.push(Span::styled(
                     yield_padded,
-                    Style::default().fg(Color::Yellow),
+                    Style::default().fg(theme.accent),
                 ));
                 line_spans.push(Span::styled(" ", Style::default()));
 
@@ -314,27 +361,36 @@ pub fn render_markets(f: &mut Frame, app: &TrendingAppState, event: &Event, area
                 let volume_padded = format!("{:>width$}", volume_str, width = VOLUME_COL_WIDTH);
                 line_spans.push(Span::styled(
                     volume_padded,
-                    Style::default().fg(Color::Green),
+                    Style::default().fg(volume_color),
                 ));
-                line_spans.push(Span::styled(" ", Style::default()));
+                // Price change arrow takes the separator column so alignment is unchanged
+                match price_arrow {
+                    Some((arrow, color)) => {
+                        line_spans.push(Span::styled(arrow, Style::default().fg(color)))
+                    },
+                    None => line_spans.push(Span::styled(" ", Style::default())),
+                }
 
                 // Buttons with padding before each to align their right edges
                 // Padding before Yes button to align Yes button's right edge
                 if yes_padding > 0 {
                     line_spans.push(Span::raw(" ".repeat(yes_padding)));
                 }
-                line_spans.push(Span::styled(yes_button, Style::default().fg(Color::Green)));
+                line_spans.push(Span::styled(
+                    yes_button,
+                    Style::default().fg(theme.positive),
+                ));
                 // Padding before No button to align No button's right edge
                 if no_padding > 0 {
                     line_spans.push(Span::raw(" ".repeat(no_padding)));
                 }
-                line_spans.push(Span::styled(no_button, Style::default().fg(Color::Red)));
+                line_spans.push(Span::styled(no_button, Style::default().fg(theme.negative)));
             } else {
                 // For closed markets: show outcomes and volume
                 if !outcomes_str.is_empty() {
                     line_spans.push(Span::styled(
                         outcomes_str.clone(),
-                        Style::default().fg(Color::Cyan),
+                        Style::default().fg(theme.info),
                     ));
                     if !volume_str.is_empty() {
                         line_spans.push(Span::styled(" ", Style::default()));
@@ -343,18 +399,18 @@ pub fn render_markets(f: &mut Frame, app: &TrendingAppState, event: &Event, area
                 if !volume_str.is_empty() {
                     line_spans.push(Span::styled(
                         volume_str.clone(),
-                        Style::default().fg(Color::Green),
+                        Style::default().fg(volume_color),
                     ));
                 }
             }
 
             // Background color: highlight selected market, otherwise zebra striping
             let bg_color = if is_orderbook_selected {
-                Color::Rgb(60, 60, 80) // Highlight selected market (same as events list)
+                theme.highlight_bg // Highlight selected market (same as events list)
             } else if idx % 2 == 0 {
                 Color::Reset
             } else {
-                Color::Rgb(30, 30, 40)
+                theme.stripe_bg
             };
 
             ListItem::new(Line::from(line_spans)).style(Style::default().bg(bg_color))
@@ -363,17 +419,22 @@ pub fn render_markets(f: &mut Frame, app: &TrendingAppState, event: &Event, area
 
     let is_focused = app.navigation.focused_panel == FocusedPanel::Markets;
     let block_style = if is_focused {
-        Style::default().fg(Color::Yellow)
+        Style::default().fg(theme.accent)
     } else {
         Style::default()
     };
 
-    // Build title (without count, moved to bottom)
-    let title = if is_focused {
-        "Markets (Focused)"
+    // Build title (without count, moved to bottom), with the markets filter while one is set
+    let mut title = if is_focused {
+        "Markets (Focused)".to_string()
     } else {
-        "Markets"
+        "Markets".to_string()
     };
+    let filter = &app.orderbook_state.market_filter;
+    if app.orderbook_state.is_filtering_markets || !filter.is_empty() {
+        title.push_str(&format!(" /{}", filter));
+    }
+    let title_width = title.width() as u16;
 
     // Build position indicator for bottom right (lazygit style)
     let selected_idx = app.orderbook_state.selected_market_index;
@@ -383,16 +444,48 @@ pub fn render_markets(f: &mut Frame, app: &TrendingAppState, event: &Event, area
         "0 of 0".to_string()
     };
 
+    let position = format!("{}─", position_indicator);
+
+    // How fresh the prices are, and whether timed refreshes are paused ('Z')
+    let freshness = app.prices_updated_at.map(|at| {
+        let secs = at.elapsed().as_secs();
+        if secs < 60 {
+            format!("prices {}s ago", secs)
+        } else {
+            format!("prices {}m ago", secs / 60)
+        }
+    });
+    let freshness = match (app.auto_refresh_paused, freshness) {
+        (true, Some(freshness)) => format!(" ⏸ {} ", freshness),
+        (true, None) => " ⏸ paused ".to_string(),
+        (false, Some(freshness)) => format!(" {} ", freshness),
+        (false, None) => String::new(),
+    };
+
     let block = Block::default()
         .borders(Borders::ALL)
         .border_type(BorderType::Rounded)
         .title(title)
-        .title_bottom(Line::from(format!("{}─", position_indicator)).right_aligned())
+        .title(Line::styled(freshness, Style::default().fg(theme.dim)).right_aligned())
+        .title_bottom(panel_key_hint(
+            FocusedPanel::Markets,
+            is_focused,
+            area.width,
+            position.width(),
+            theme,
+        ))
+        .title_bottom(Line::from(position).right_aligned())
         .border_style(block_style);
 
     let list = List::new(items).block(block);
 
     f.render_widget(list, area);
+    if app.orderbook_state.is_filtering_markets {
+        f.set_cursor_position(Position::new(
+            (area.x + 1 + title_width).min(area.right().saturating_sub(2)),
+            area.y,
+        ));
+    }
 
     // Render scrollbar if needed
     // The scrollbar thumb size is: (visible_height / total_markets) * track_height
@@ -10,14 +10,18 @@ mod main_render;
 mod markets;
 mod orderbook;
 mod popups;
+mod positions;
+mod theme;
 mod trades;
 pub mod utils;
 mod yield_tab;
 
 pub use {
-    clicks::{ClickedTab, get_clicked_tab, is_login_button_clicked},
+    clicks::{ClickedTab, get_clicked_tab, get_clicked_trade_index, is_login_button_clicked},
     main_render::render,
     orderbook::check_orderbook_title_click,
-    popups::TRADE_POPUP_WIDTH,
+    popups::{TRADE_POPUP_WIDTH, resolution_lines},
+    theme::Theme,
+    trades::TRADES_SUMMARY_HEIGHT,
     utils::{centered_rect_fixed_width, truncate},
 };
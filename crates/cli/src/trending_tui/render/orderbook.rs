@@ -1,8 +1,15 @@
 //! Orderbook panel rendering functions
 
 use {
-    super::utils::{format_with_thousands, truncate},
-    crate::trending_tui::state::{FocusedPanel, OrderbookOutcome, TrendingAppState},
+    super::{
+        theme::Theme,
+        utils::{format_size_short, format_with_thousands, spinner_frame, truncate},
+    },
+    crate::trending_tui::state::{
+        FocusedPanel, OrderbookData, OrderbookLevel, OrderbookOutcome, TrendingAppState,
+        format_group_increment,
+    },
+    chrono::Utc,
     polymarket_api::gamma::Event,
     ratatui::{
         Frame,
@@ -25,8 +32,7 @@ pub fn calculate_orderbook_height(
 
     // Check if the selected market is closed
     let market_is_closed = event.is_some_and(|e| {
-        let mut sorted_markets: Vec<_> = e.markets.iter().collect();
-        sorted_markets.sort_by_key(|m| m.closed);
+        let sorted_markets = app.sorted_markets(e);
         let idx = app
             .orderbook_state
             .selected_market_index
@@ -40,7 +46,13 @@ pub fn calculate_orderbook_height(
     } else if app.orderbook_state.is_loading {
         // Keep the same height during loading to prevent layout jumps
         app.orderbook_state.last_height.max(MESSAGE_HEIGHT)
-    } else if let Some(orderbook) = &app.orderbook_state.orderbook {
+    } else if let Some((first, second)) = app.orderbook_state.both_books() {
+        // Side-by-side books share the panel, so size it for the deeper one
+        let asks_count = first.asks.len().max(second.asks.len()).min(MAX_PER_SIDE);
+        let bids_count = first.bids.len().max(second.bids.len()).min(MAX_PER_SIDE);
+        let height = 2 + 1 + asks_count + 1 + bids_count;
+        (height as u16).max(MESSAGE_HEIGHT)
+    } else if let Some(orderbook) = app.orderbook_state.displayed_orderbook() {
         let asks_count = orderbook.asks.len().min(MAX_PER_SIDE);
         let bids_count = orderbook.bids.len().min(MAX_PER_SIDE);
         // Height = borders(2) + header(1) + asks + spread(1) + bids
@@ -89,13 +101,18 @@ pub fn check_orderbook_title_click(
 }
 
 /// Render the order book panel for the selected market
-pub fn render_orderbook(f: &mut Frame, app: &TrendingAppState, event: &Event, area: Rect) {
+pub fn render_orderbook(
+    f: &mut Frame,
+    app: &TrendingAppState,
+    theme: &Theme,
+    event: &Event,
+    area: Rect,
+) {
     let orderbook_state = &app.orderbook_state;
     let selected_outcome = orderbook_state.selected_outcome;
 
     // Get the selected market from sorted list (non-closed first, same as render_markets)
-    let mut sorted_markets: Vec<_> = event.markets.iter().collect();
-    sorted_markets.sort_by_key(|m| m.closed);
+    let sorted_markets = app.sorted_markets(event);
     let selected_market_idx = orderbook_state
         .selected_market_index
         .min(sorted_markets.len().saturating_sub(1));
@@ -162,12 +179,9 @@ pub fn render_orderbook(f: &mut Frame, app: &TrendingAppState, event: &Event, ar
                     .add_modifier(Modifier::BOLD),
             )
         } else {
-            Span::styled(
-                truncated_name_0.clone(),
-                Style::default().fg(Color::DarkGray),
-            )
+            Span::styled(truncated_name_0.clone(), Style::default().fg(theme.muted))
         },
-        Span::styled(" - ", Style::default().fg(Color::DarkGray)),
+        Span::styled(" - ", Style::default().fg(theme.muted)),
         if selected_outcome == OrderbookOutcome::No {
             Span::styled(
                 truncated_name_1.clone(),
@@ -176,10 +190,7 @@ pub fn render_orderbook(f: &mut Frame, app: &TrendingAppState, event: &Event, ar
                     .add_modifier(Modifier::BOLD),
             )
         } else {
-            Span::styled(
-                truncated_name_1.clone(),
-                Style::default().fg(Color::DarkGray),
-            )
+            Span::styled(truncated_name_1.clone(), Style::default().fg(theme.muted))
         },
     ];
 
@@ -193,6 +204,30 @@ pub fn render_orderbook(f: &mut Frame, app: &TrendingAppState, event: &Event, ar
         ));
     }
 
+    // Levels are grouped into price buckets ('B')
+    if let Some(increment) = orderbook_state.group_increment {
+        title_spans.push(Span::styled(
+            format!(" [{}]", format_group_increment(increment)),
+            Style::default().fg(Color::Cyan),
+        ));
+    }
+
+    // A fetch is in flight
+    if orderbook_state.is_loading {
+        title_spans.push(Span::styled(
+            format!(" {}", spinner_frame()),
+            Style::default().fg(Color::Cyan),
+        ));
+    }
+
+    // Data from an earlier fetch is still shown while refreshes are failing
+    if orderbook_state.error.is_some() && orderbook_state.orderbook.is_some() {
+        title_spans.push(Span::styled(
+            " (stale, retrying...)",
+            Style::default().fg(Color::Red),
+        ));
+    }
+
     let title_line = Line::from(title_spans);
 
     let is_focused = app.navigation.focused_panel == FocusedPanel::Markets; // TODO: Add FocusedPanel::Orderbook
@@ -202,6 +237,38 @@ pub fn render_orderbook(f: &mut Frame, app: &TrendingAppState, event: &Event, ar
         Style::default()
     };
 
+    if let Some((first, second)) = orderbook_state.both_books() {
+        // Keep the arbitrage/stale indicators on the left book's title
+        let mut first_title = vec![Span::styled(
+            truncated_name_0.clone(),
+            Style::default()
+                .fg(Color::White)
+                .add_modifier(Modifier::BOLD),
+        )];
+        first_title.extend(title_line.spans.iter().skip(3).cloned());
+        let second_title = Line::from(Span::styled(
+            truncated_name_1.clone(),
+            Style::default()
+                .fg(Color::White)
+                .add_modifier(Modifier::BOLD),
+        ));
+
+        let halves = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .split(area);
+        render_compact_book(
+            f,
+            theme,
+            &first,
+            Line::from(first_title),
+            block_style,
+            halves[0],
+        );
+        render_compact_book(f, theme, &second, second_title, block_style, halves[1]);
+        return;
+    }
+
     // Check if we have orderbook data with actual orders
     let has_orders = orderbook_state
         .orderbook
@@ -210,7 +277,7 @@ pub fn render_orderbook(f: &mut Frame, app: &TrendingAppState, event: &Event, ar
         .unwrap_or(false);
 
     if has_orders {
-        let orderbook = orderbook_state.orderbook.as_ref().unwrap();
+        let orderbook = orderbook_state.displayed_orderbook().unwrap();
 
         // Find max cumulative total for scaling the depth bars
         // Scale each side (bids/asks) independently for better visualization
@@ -308,19 +375,45 @@ pub fn render_orderbook(f: &mut Frame, app: &TrendingAppState, event: &Event, ar
         let depth_para = Paragraph::new(depth_lines).block(depth_block);
         f.render_widget(depth_para, chunks[0]);
 
-        // Render price levels (right side)
-        let levels_block = Block::default()
+        let panel_width = (chunks[1].width as usize).saturating_sub(2); // Account for border
+
+        // Render price levels (right side), with the buy/sell pressure gauge in the bottom border
+        let mut levels_block = Block::default()
             .borders(Borders::RIGHT | Borders::TOP | Borders::BOTTOM)
             .border_type(BorderType::Rounded)
             .border_style(block_style);
+        if let Some(bid_share) = orderbook.bid_pressure() {
+            levels_block = levels_block
+                .title_bottom(pressure_gauge_line(bid_share, panel_width).right_aligned());
+        }
+        // Staleness of the live feed for this market (only known while watching)
+        let is_watching = app
+            .trades
+            .event_trades
+            .get(&event.slug)
+            .is_some_and(|trades| trades.is_watching);
+        if is_watching && let Some(m) = market {
+            let last_trade = m
+                .clob_token_ids
+                .as_ref()
+                .and_then(|ids| app.trades.last_trade_time(ids));
+            levels_block = levels_block.title_bottom(
+                last_trade_line(last_trade, Utc::now().timestamp(), theme).left_aligned(),
+            );
+        }
 
-        let panel_width = (chunks[1].width as usize).saturating_sub(2); // Account for border
-
-        // Fixed column widths for alignment
+        // Fixed column widths for alignment; the cumulative size column is dropped when the
+        // panel is too narrow for it
         let price_width = 8;
-        let shares_width = 12;
+        let shares_width = 11;
         let total_width = 14;
-        let columns_width = price_width + shares_width + total_width;
+        let base_columns_width = price_width + shares_width + total_width;
+        let cumulative_width = if panel_width >= base_columns_width + 9 {
+            9
+        } else {
+            0
+        };
+        let columns_width = base_columns_width + cumulative_width;
 
         // Calculate left padding to right-align all columns within the panel
         let left_padding = panel_width.saturating_sub(columns_width);
@@ -329,19 +422,25 @@ pub fn render_orderbook(f: &mut Frame, app: &TrendingAppState, event: &Event, ar
 
         // Header - right aligned
         let header = format!(
-            "{:padding$}{:>price$}{:>shares$}{:>total$}",
+            "{:padding$}{:>price$}{:>shares$}{:>cumulative$}{:>total$}",
             "",
             "PRICE",
             "SHARES",
+            if cumulative_width > 0 {
+                "CUM"
+            } else {
+                ""
+            },
             "TOTAL",
             padding = left_padding,
             price = price_width,
             shares = shares_width,
+            cumulative = cumulative_width,
             total = total_width
         );
         level_lines.push(Line::from(vec![Span::styled(
             header,
-            Style::default().fg(Color::DarkGray).bold(),
+            Style::default().fg(theme.muted).bold(),
         )]));
 
         // Helper to format price in cents or dollars (1 decimal place for cents)
@@ -354,33 +453,64 @@ pub fn render_orderbook(f: &mut Frame, app: &TrendingAppState, event: &Event, ar
             }
         };
 
-        // Helper to format a level line with proper alignment
-        let format_level =
-            |level: &crate::trending_tui::state::OrderbookLevel, price_color: Color| -> Line {
-                let price_str = format_price(level.price);
-                let shares_str = format_with_thousands(level.size, 0);
-                let total_str = format!("${}", format_with_thousands(level.total, 2));
-
-                let padding_span = Span::raw(" ".repeat(left_padding));
-                let price_span = Span::styled(
-                    format!("{:>width$}", price_str, width = price_width),
-                    Style::default().fg(price_color),
-                );
-                let shares_span = Span::styled(
-                    format!("{:>width$}", shares_str, width = shares_width),
-                    Style::default().fg(Color::White),
-                );
-                let total_span = Span::styled(
-                    format!("{:>width$}", total_str, width = total_width),
-                    Style::default().fg(Color::White),
-                );
-
-                Line::from(vec![padding_span, price_span, shares_span, total_span])
+        // Helper to format a level line with proper alignment; levels holding your own orders
+        // get a marker with your unfilled size in the left padding
+        let format_level = |level: &crate::trending_tui::state::OrderbookLevel,
+                            price_color: Color,
+                            is_bid: bool|
+         -> Line {
+            let price_str = format_price(level.price);
+            let shares_str = format_with_thousands(level.size, 0);
+            let total_str = format!("${}", format_with_thousands(level.total, 2));
+
+            let my_size = orderbook_state.open_order_size_at(level.price, is_bid);
+            let marker = format!("◆ {} ", format_with_thousands(my_size, 0));
+            let padding_span = if my_size > 0.0 && marker.chars().count() <= left_padding {
+                Span::styled(
+                    format!("{:>width$}", marker, width = left_padding),
+                    Style::default().fg(Color::Cyan).bold(),
+                )
+            } else {
+                Span::raw(" ".repeat(left_padding))
             };
+            let price_span = Span::styled(
+                format!("{:>width$}", price_str, width = price_width),
+                Style::default().fg(price_color),
+            );
+            let shares_span = Span::styled(
+                format!("{:>width$}", shares_str, width = shares_width),
+                Style::default().fg(Color::White),
+            );
+            // Shares resting between the best price and this level, inclusive
+            let cumulative_span = if cumulative_width > 0 {
+                Span::styled(
+                    format!(
+                        "{:>width$}",
+                        format_size_short(level.cumulative_size),
+                        width = cumulative_width
+                    ),
+                    Style::default().fg(theme.dim),
+                )
+            } else {
+                Span::raw("")
+            };
+            let total_span = Span::styled(
+                format!("{:>width$}", total_str, width = total_width),
+                Style::default().fg(Color::White),
+            );
+
+            Line::from(vec![
+                padding_span,
+                price_span,
+                shares_span,
+                cumulative_span,
+                total_span,
+            ])
+        };
 
         // Asks (sell orders) - show in descending price order (same count as depth chart)
         for level in orderbook.asks.iter().take(asks_count).rev() {
-            level_lines.push(format_level(level, Color::LightRed));
+            level_lines.push(format_level(level, Color::LightRed, false));
         }
 
         // Spread separator - right aligned
@@ -402,7 +532,7 @@ pub fn render_orderbook(f: &mut Frame, app: &TrendingAppState, event: &Event, ar
 
         // Bids (buy orders) - same count as depth chart
         for level in orderbook.bids.iter().take(bids_count) {
-            level_lines.push(format_level(level, Color::LightGreen));
+            level_lines.push(format_level(level, Color::LightGreen, true));
         }
 
         let levels_para = Paragraph::new(level_lines).block(levels_block);
@@ -411,16 +541,27 @@ pub fn render_orderbook(f: &mut Frame, app: &TrendingAppState, event: &Event, ar
         // No orderbook data or empty orderbook - show appropriate message
         let market_is_closed = market.map(|m| m.closed).unwrap_or(false);
         let message = if market_is_closed {
-            "Market is closed"
+            "Market is closed".to_string()
+        } else if market.is_some_and(|m| !m.is_clob_tradeable()) {
+            "Not traded on the CLOB (no orderbook or limit orders)".to_string()
+        } else if orderbook_state.error.is_some() {
+            // A failed fetch is distinct from an empty book
+            match orderbook_state.retry_in_secs() {
+                Some(secs) if secs > 0 && !orderbook_state.is_loading => format!(
+                    "Orderbook unavailable (retrying in {}s, R to retry now)",
+                    secs
+                ),
+                _ => "Orderbook unavailable (retrying...)".to_string(),
+            }
         } else if orderbook_state.is_loading {
-            "Loading orderbook..."
+            "Loading orderbook...".to_string()
         } else if orderbook_state.orderbook.is_some() {
             // We have an orderbook but it's empty (no orders)
-            "No orders in orderbook"
+            "No orders in orderbook".to_string()
         } else if market.is_some() {
-            "Loading orderbook..."
+            "Loading orderbook...".to_string()
         } else {
-            "No markets available"
+            "No markets available".to_string()
         };
 
         let block = Block::default()
@@ -432,7 +573,135 @@ pub fn render_orderbook(f: &mut Frame, app: &TrendingAppState, event: &Event, ar
         let paragraph = Paragraph::new(message)
             .block(block)
             .alignment(Alignment::Center)
-            .style(Style::default().fg(Color::DarkGray));
+            .style(Style::default().fg(theme.muted));
         f.render_widget(paragraph, area);
     }
 }
+
+/// Render one outcome's book as a price/shares ladder, used when both outcomes are shown
+fn render_compact_book(
+    f: &mut Frame,
+    theme: &Theme,
+    orderbook: &OrderbookData,
+    title: Line,
+    block_style: Style,
+    area: Rect,
+) {
+    const MAX_PER_SIDE: usize = 6;
+    let price_width = 8;
+    let shares_width = 12;
+    let panel_width = (area.width as usize).saturating_sub(2);
+    let left_padding = panel_width.saturating_sub(price_width + shares_width);
+
+    let level_line = |level: &OrderbookLevel, price_color: Color| -> Line {
+        Line::from(vec![
+            Span::raw(" ".repeat(left_padding)),
+            Span::styled(
+                format!(
+                    "{:>width$}",
+                    format!("{:.1}¢", level.price * 100.0),
+                    width = price_width
+                ),
+                Style::default().fg(price_color),
+            ),
+            Span::styled(
+                format!(
+                    "{:>width$}",
+                    format_with_thousands(level.size, 0),
+                    width = shares_width
+                ),
+                Style::default().fg(Color::White),
+            ),
+        ])
+    };
+
+    let mut lines = vec![Line::from(Span::styled(
+        format!(
+            "{:padding$}{:>price$}{:>shares$}",
+            "",
+            "PRICE",
+            "SHARES",
+            padding = left_padding,
+            price = price_width,
+            shares = shares_width
+        ),
+        Style::default().fg(theme.muted).bold(),
+    ))];
+    for level in orderbook.asks.iter().take(MAX_PER_SIDE).rev() {
+        lines.push(level_line(level, Color::LightRed));
+    }
+    let spread = orderbook
+        .spread
+        .map(|spread| format!("Spread: {:.1}¢", spread * 100.0))
+        .unwrap_or_else(|| "No spread".to_string());
+    lines.push(Line::from(Span::styled(spread, Style::default().fg(Color::Yellow))).centered());
+    for level in orderbook.bids.iter().take(MAX_PER_SIDE) {
+        lines.push(level_line(level, Color::LightGreen));
+    }
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .title(title)
+        .border_style(block_style);
+    f.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+/// Seconds without a trade after which a market counts as dead
+const DEAD_MARKET_SECS: i64 = 10 * 60;
+
+/// "last trade 42s ago", green within a minute and red once the market has gone quiet
+fn last_trade_line(last_trade: Option<i64>, now: i64, theme: &Theme) -> Line<'static> {
+    let Some(timestamp) = last_trade else {
+        return Line::from(Span::styled(
+            " no trades yet ",
+            Style::default().fg(theme.muted),
+        ));
+    };
+    let secs = (now - timestamp).max(0);
+    let age = if secs < 60 {
+        format!("{}s", secs)
+    } else if secs < 3600 {
+        format!("{}m", secs / 60)
+    } else {
+        format!("{}h", secs / 3600)
+    };
+    let color = if secs < 60 {
+        Color::Green
+    } else if secs < DEAD_MARKET_SECS {
+        theme.dim
+    } else {
+        Color::Red
+    };
+    Line::from(Span::styled(
+        format!(" last trade {} ago ", age),
+        Style::default().fg(color),
+    ))
+}
+
+/// Two-color bar comparing bid depth (green, left) against ask depth (red, right)
+fn pressure_gauge_line(bid_share: f64, panel_width: usize) -> Line<'static> {
+    const MAX_BAR_WIDTH: usize = 20;
+    let bid_pct = (bid_share * 100.0).round() as u32;
+    let ask_pct = 100 - bid_pct.min(100);
+    // Leave room for the percentage labels around the bar
+    let bar_width = panel_width.saturating_sub(16).min(MAX_BAR_WIDTH);
+    let bid_width = (bid_share * bar_width as f64).round() as usize;
+    let ask_width = bar_width.saturating_sub(bid_width);
+
+    Line::from(vec![
+        Span::styled(
+            format!(" B {}% ", bid_pct),
+            Style::default().fg(Color::LightGreen),
+        ),
+        Span::styled(
+            "█".repeat(bid_width),
+            Style::default().fg(Color::LightGreen),
+        ),
+        Span::styled("█".repeat(ask_width), Style::default().fg(Color::LightRed)),
+        Span::styled(
+            format!(" {}% A ", ask_pct),
+            Style::default().fg(Color::LightRed),
+        ),
+    ])
+}
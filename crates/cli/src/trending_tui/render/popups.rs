@@ -1,9 +1,16 @@
 //! Popup/modal rendering functions
 
 use {
-    super::utils::{centered_rect, centered_rect_fixed_width, format_pnl, truncate},
+    super::{
+        theme::Theme,
+        utils::{
+            COMPETITIVE_DESCRIPTION, centered_rect, centered_rect_fixed_width, format_pnl,
+            format_usd_exact, format_volume, price_sparkline_spans, truncate,
+        },
+    },
     crate::trending_tui::state::{
-        LoginField, MainTab, OrderType, PopupType, TradeField, TradeSide, TrendingAppState,
+        LoginField, MAX_AMOUNT_KEY, MAX_NOTE_CHARS, MainTab, OrderType, PaletteAction, PopupType,
+        QUICK_AMOUNTS, RefreshSource, SIZE_STEP_KEYS, TradeField, TradeSide, TrendingAppState,
     },
     ratatui::{
         Frame,
@@ -12,11 +19,12 @@ use {
         text::{Line, Span},
         widgets::{Block, BorderType, Borders, Clear, Paragraph, Wrap},
     },
+    unicode_width::UnicodeWidthStr,
 };
 
 /// Build context-aware help content based on current tab
 #[allow(clippy::vec_init_then_push)]
-fn build_help_content(app: &TrendingAppState) -> Vec<Line<'static>> {
+fn build_help_content(app: &TrendingAppState, theme: &Theme) -> Vec<Line<'static>> {
     let mut lines = Vec::new();
 
     // Common icons section
@@ -24,6 +32,11 @@ fn build_help_content(app: &TrendingAppState) -> Vec<Line<'static>> {
         "Icons:",
         Style::default().fg(Color::Yellow).bold(),
     )]));
+    lines.push(Line::from(vec![
+        Span::styled("  ●", Style::default().fg(Color::Green)),
+        Span::styled("○ ", Style::default().fg(theme.muted)),
+        Span::raw("Watching / not watching live trades"),
+    ]));
     lines.push(Line::from(vec![
         Span::styled("  ⚑ ", Style::default().fg(Color::Magenta)),
         Span::raw("Favorited event (synced from Polymarket)"),
@@ -36,6 +49,15 @@ fn build_help_content(app: &TrendingAppState) -> Vec<Line<'static>> {
         Span::styled("  ✕ ", Style::default().fg(Color::Red)),
         Span::raw("Closed/inactive event"),
     ]));
+    lines.push(Line::from(vec![
+        Span::styled("  📝 ", Style::default().fg(Color::Yellow)),
+        Span::raw("Event has a personal note (n to edit)"),
+    ]));
+    lines.push(Line::from(vec![
+        Span::styled("  ▲", Style::default().fg(Color::Green)),
+        Span::styled("▼ ", Style::default().fg(Color::Red)),
+        Span::raw("Market price moved since the last price refresh"),
+    ]));
     lines.push(Line::from(""));
 
     // Tab-specific content
@@ -46,7 +68,7 @@ fn build_help_content(app: &TrendingAppState) -> Vec<Line<'static>> {
                 Style::default().fg(Color::Yellow).bold(),
             )]));
             lines.push(Line::from(
-                "  Each line shows: [icons] Title ... [metric] [markets]",
+                "  Each line shows: [icons] Title ... [metric] [competitive] [markets]",
             ));
             lines.push(Line::from(""));
             lines.push(Line::from(vec![Span::styled(
@@ -65,6 +87,10 @@ fn build_help_content(app: &TrendingAppState) -> Vec<Line<'static>> {
                 Span::styled("  Liquidity:   ", Style::default().fg(Color::Cyan)),
                 Span::raw("Available liquidity for trading"),
             ]));
+            lines.push(Line::from(vec![
+                Span::styled("  Open Int:    ", Style::default().fg(Color::LightBlue)),
+                Span::raw("Open interest (capital committed; markets panel too)"),
+            ]));
             lines.push(Line::from(vec![
                 Span::styled("  Newest:      ", Style::default().fg(Color::Cyan)),
                 Span::raw("Shows liquidity, sorted by creation date"),
@@ -75,9 +101,13 @@ fn build_help_content(app: &TrendingAppState) -> Vec<Line<'static>> {
             ]));
             lines.push(Line::from(vec![
                 Span::styled("  Competitive: ", Style::default().fg(Color::Magenta)),
-                Span::raw("Score 0-100% (closer to 50/50 = more competitive)"),
+                Span::raw(COMPETITIVE_DESCRIPTION),
             ]));
             lines.push(Line::from(""));
+            lines.push(Line::from(vec![
+                Span::styled("  Competitive % ", Style::default().fg(Color::Magenta)),
+                Span::raw("(beside the metric) shown in every sort, brighter near 50/50"),
+            ]));
             lines.push(Line::from(vec![
                 Span::styled("  Markets count ", Style::default().fg(Color::Cyan)),
                 Span::raw("(rightmost) = number of markets in event"),
@@ -105,6 +135,9 @@ fn build_help_content(app: &TrendingAppState) -> Vec<Line<'static>> {
                 "  Favorites are synced from your Polymarket account.",
             ));
             lines.push(Line::from("  Login with 'L' to sync your favorites."));
+            lines.push(Line::from(
+                "  Press 'F' to resync now (e.g. after favoriting on the website).",
+            ));
         },
         MainTab::Yield => {
             lines.push(Line::from(vec![Span::styled(
@@ -140,6 +173,39 @@ fn build_help_content(app: &TrendingAppState) -> Vec<Line<'static>> {
                 Style::default().fg(Color::Cyan),
             )]));
             lines.push(Line::from("    Return, Volume, End Date"));
+            lines.push(Line::from(""));
+            lines.push(Line::from(
+                "  Press 'm' to hide opportunities below a minimum 24h volume.",
+            ));
+            lines.push(Line::from(""));
+            lines.push(Line::from(vec![
+                Span::styled("  Press 'v' ", Style::default().fg(Color::Cyan)),
+                Span::raw("to switch between opportunities and search results"),
+            ]));
+        },
+        MainTab::Positions => {
+            lines.push(Line::from(vec![Span::styled(
+                "Positions Tab - Columns:",
+                Style::default().fg(Color::Yellow).bold(),
+            )]));
+            lines.push(Line::from(vec![
+                Span::styled("  Avg ", Style::default().fg(Color::Cyan)),
+                Span::raw("= Average price paid per share"),
+            ]));
+            lines.push(Line::from(vec![
+                Span::styled("  Current ", Style::default().fg(Color::Cyan)),
+                Span::raw("= Current price of the outcome"),
+            ]));
+            lines.push(Line::from(vec![
+                Span::styled("  P&L ", Style::default().fg(Color::Green)),
+                Span::raw("= Unrealized profit/loss at the current price"),
+            ]));
+            lines.push(Line::from(""));
+            lines.push(Line::from(vec![Span::styled(
+                "  Sort options (press 's'):",
+                Style::default().fg(Color::Cyan),
+            )]));
+            lines.push(Line::from("    Value, P&L, Shares, Market"));
         },
     }
 
@@ -151,9 +217,16 @@ fn build_help_content(app: &TrendingAppState) -> Vec<Line<'static>> {
         Style::default().fg(Color::Yellow).bold(),
     )]));
     lines.push(Line::from("  ↑/k, ↓/j  Move up/down in lists"));
-    lines.push(Line::from("  Tab       Switch between panels"));
+    lines.push(Line::from("  g/G       Jump to top/bottom (also Home/End)"));
+    lines.push(Line::from("  PgUp/PgDn Move selection by a page"));
+    lines.push(Line::from(
+        "  5j        Count prefix: repeat the next move (j/k/↑/↓/PgUp/PgDn)",
+    ));
     lines.push(Line::from(
-        "  1-4       Switch tabs (Events/Favorites/Breaking/Yield)",
+        "  Tab/S-Tab Switch between panels (forward/back)",
+    ));
+    lines.push(Line::from(
+        "  1-7       Switch tabs (Events/Favorites/Breaking/Yield/Positions/Closing/New)",
     ));
     lines.push(Line::from("  s         Cycle sort options"));
     lines.push(Line::from("  /         API search (searches Polymarket)"));
@@ -161,38 +234,182 @@ fn build_help_content(app: &TrendingAppState) -> Vec<Line<'static>> {
         "  f         Local filter (filters current list)",
     ));
     lines.push(Line::from("  o         Open event in browser"));
+    lines.push(Line::from("  x         Pin/unpin market (Markets panel)"));
+    lines.push(Line::from(
+        "  /         Filter markets by name (Markets panel)",
+    ));
+    lines.push(Line::from(
+        "  [/]       Previous/next market for the orderbook",
+    ));
+    lines.push(Line::from(
+        "  X         Show/cancel your open orders on the orderbook",
+    ));
+    lines.push(Line::from("  a         Show/hide event creation age"));
+    lines.push(Line::from(
+        "  V         Show/hide liquidity in the events list",
+    ));
+    lines.push(Line::from(
+        "  z         Compact events list (title and market count)",
+    ));
+    lines.push(Line::from("  y         Show only events with live trades"));
+    lines.push(Line::from(
+        "  b         Bookmark/unbookmark the selected event",
+    ));
+    lines.push(Line::from(
+        "  n         Add or edit a note on the selected event",
+    ));
+    lines.push(Line::from(
+        "  A         Cycle the large-trade alert threshold",
+    ));
+    lines.push(Line::from("  ,         Settings: auto-refresh intervals"));
+    lines.push(Line::from("  Z         Pause/resume auto-refresh"));
+    lines.push(Line::from(
+        "  :         Command palette (search all actions)",
+    ));
+    lines.push(Line::from(
+        "  R         Retry a failed events load or orderbook fetch",
+    ));
+    lines.push(Line::from("  E         Show captured API errors"));
+    lines.push(Line::from(
+        "  i         Show the selected event's full title",
+    ));
+    lines.push(Line::from(
+        "  u         Show the selected event's resolution rules",
+    ));
+    lines.push(Line::from("  #         Filter events by tag (Esc clears)"));
+    lines.push(Line::from(
+        "  +/-       Raise/lower events min volume by $10K",
+    ));
+    lines.push(Line::from(
+        "  T         Show both outcomes' orderbooks side by side",
+    ));
+    lines.push(Line::from(
+        "  B         Group orderbook levels (off/0.5¢/1¢/5¢)",
+    ));
     lines.push(Line::from(
         "  Enter     Toggle watching event for live trades",
     ));
+    lines.push(Line::from(
+        "  O         Show trades oldest-first / newest-first",
+    ));
+    lines.push(Line::from("  D         Collapse event details to one line"));
+    lines.push(Line::from(
+        "  c         Cycle color theme (Dark/Light/High contrast)",
+    ));
+    lines.push(Line::from(
+        "  w/W       Watch all visible events (max 10) / stop all",
+    ));
+    lines.push(Line::from(
+        "  d         Split view: follow this event's trades below (d closes)",
+    ));
+    lines.push(Line::from("  P         Save a text snapshot of the screen"));
+    lines.push(Line::from(
+        "  J         Export loaded events with live prices as JSON",
+    ));
+    lines.push(Line::from(
+        "  C         Copy session trades summary to clipboard",
+    ));
     lines.push(Line::from("  L         Login to Polymarket"));
     lines.push(Line::from("  l         Toggle logs panel"));
     lines.push(Line::from("  Esc       Cancel/close"));
     lines.push(Line::from("  q         Quit"));
     lines.push(Line::from(""));
+
+    // Active API endpoints (overridden ones are highlighted)
+    lines.push(Line::from(vec![Span::styled(
+        "API Endpoints:",
+        Style::default().fg(Color::Yellow).bold(),
+    )]));
+    let endpoints = &app.endpoints;
+    for (label, endpoint) in [
+        ("Gamma", &endpoints.gamma_api),
+        ("Data", &endpoints.data_api),
+        ("CLOB", &endpoints.clob_api),
+        ("CLOB WS", &endpoints.clob_ws),
+        ("RTDS WS", &endpoints.rtds_ws),
+    ] {
+        let (url_style, suffix) = if endpoint.overridden {
+            (Style::default().fg(Color::Magenta), " (override)")
+        } else {
+            (Style::default().fg(theme.muted), "")
+        };
+        lines.push(Line::from(vec![
+            Span::raw(format!("  {:<10}", label)),
+            Span::styled(endpoint.url.clone(), url_style),
+            Span::styled(suffix, Style::default().fg(Color::Magenta)),
+        ]));
+    }
+    let limiter = &app.fetch_limiter;
+    lines.push(Line::from(vec![
+        Span::raw(format!("  {:<10}", "Fetches")),
+        Span::styled(
+            format!(
+                "{}/{} in flight, {} queued, {} dropped",
+                limiter.in_flight(),
+                limiter.max_concurrent(),
+                limiter.queued(),
+                limiter.dropped()
+            ),
+            Style::default().fg(theme.muted),
+        ),
+    ]));
+    lines.push(Line::from(""));
     lines.push(Line::from(vec![Span::styled(
         "Press Esc to close",
-        Style::default().fg(Color::DarkGray),
+        Style::default().fg(theme.muted),
     )]));
 
     lines
 }
 
 /// Render a popup/modal dialog
-pub fn render_popup(f: &mut Frame, app: &TrendingAppState, popup: &PopupType) {
+pub fn render_popup(f: &mut Frame, app: &TrendingAppState, theme: &Theme, popup: &PopupType) {
     // Note: We don't dim the full screen - content behind remains visible.
     // Only the popup area itself is cleared and has a solid background.
 
     match popup {
         PopupType::Login => {
-            render_login_popup(f, app);
+            render_login_popup(f, app, theme);
             return;
         },
         PopupType::UserProfile => {
-            render_user_profile_popup(f, app);
+            render_user_profile_popup(f, app, theme);
             return;
         },
         PopupType::Trade => {
-            render_trade_popup(f, app);
+            render_trade_popup(f, app, theme);
+            return;
+        },
+        PopupType::YieldMinVolume => {
+            render_yield_min_volume_popup(f, app, theme);
+            return;
+        },
+        PopupType::EditNote(slug) => {
+            render_edit_note_popup(f, app, theme, slug);
+            return;
+        },
+        PopupType::CommandPalette => {
+            render_command_palette_popup(f, app, theme);
+            return;
+        },
+        PopupType::ApiErrors => {
+            render_api_errors_popup(f, app, theme);
+            return;
+        },
+        PopupType::TagFilter => {
+            render_tag_filter_popup(f, app, theme);
+            return;
+        },
+        PopupType::OpenOrders => {
+            render_open_orders_popup(f, app, theme);
+            return;
+        },
+        PopupType::Settings => {
+            render_settings_popup(f, app, theme);
+            return;
+        },
+        PopupType::Resolution(slug) => {
+            render_resolution_popup(f, app, theme, slug);
             return;
         },
         _ => {},
@@ -209,7 +426,7 @@ pub fn render_popup(f: &mut Frame, app: &TrendingAppState, popup: &PopupType) {
 
     let (title, content) = match popup {
         PopupType::Help => {
-            let content = build_help_content(app);
+            let content = build_help_content(app, theme);
             ("Help", content)
         },
         PopupType::ConfirmQuit => ("Confirm Quit", vec![
@@ -226,15 +443,33 @@ pub fn render_popup(f: &mut Frame, app: &TrendingAppState, popup: &PopupType) {
             ]),
         ]),
         PopupType::EventInfo(slug) => ("Event Info", vec![
+            Line::from(vec![Span::styled(
+                app.current_tab_selected_event()
+                    .filter(|event| &event.slug == slug)
+                    .map(|event| event.title.clone())
+                    .unwrap_or_default(),
+                Style::default().fg(Color::White).bold(),
+            )]),
+            Line::from(""),
             Line::from(format!("Slug: {}", slug)),
             Line::from(""),
             Line::from(vec![Span::styled(
-                "Press Esc to close",
-                Style::default().fg(Color::DarkGray),
+                "Press u for resolution rules, Esc to close",
+                Style::default().fg(theme.muted),
             )]),
         ]),
         // These are handled above with early return
-        PopupType::Login | PopupType::UserProfile | PopupType::Trade => unreachable!(),
+        PopupType::Login
+        | PopupType::UserProfile
+        | PopupType::Trade
+        | PopupType::YieldMinVolume
+        | PopupType::ApiErrors
+        | PopupType::TagFilter
+        | PopupType::OpenOrders
+        | PopupType::Settings
+        | PopupType::EditNote(_)
+        | PopupType::CommandPalette
+        | PopupType::Resolution(_) => unreachable!(),
     };
 
     let block = Block::default()
@@ -252,9 +487,580 @@ pub fn render_popup(f: &mut Frame, app: &TrendingAppState, popup: &PopupType) {
     f.render_widget(paragraph, area);
 }
 
+/// Render the captured API failures, newest first
+fn render_api_errors_popup(f: &mut Frame, app: &TrendingAppState, theme: &Theme) {
+    let area = centered_rect(80, 70, f.area());
+    f.render_widget(Clear, area);
+
+    let errors = &app.api_errors;
+    let mut content: Vec<Line> = Vec::new();
+    if errors.is_empty() {
+        content.push(Line::from(""));
+        content.push(Line::from(vec![Span::styled(
+            "No API errors captured this session",
+            Style::default().fg(theme.muted),
+        )]));
+    }
+    for entry in errors.entries() {
+        let status_color = match entry.status {
+            Some(status) if status >= 500 => Color::Red,
+            Some(_) => Color::Yellow,
+            None => Color::Magenta,
+        };
+        content.push(Line::from(vec![
+            Span::styled(
+                entry.timestamp.format("%H:%M:%S ").to_string(),
+                Style::default().fg(theme.muted),
+            ),
+            Span::styled(
+                entry.context.clone(),
+                Style::default().fg(Color::White).bold(),
+            ),
+            Span::raw("  "),
+            Span::styled(entry.message.clone(), Style::default().fg(status_color)),
+        ]));
+        if let Some(url) = &entry.url {
+            content.push(Line::from(vec![
+                Span::styled("  URL:  ", Style::default().fg(theme.muted)),
+                Span::styled(url.clone(), Style::default().fg(Color::Cyan)),
+            ]));
+        }
+        if let Some(body) = entry.body.as_ref().filter(|body| !body.is_empty()) {
+            content.push(Line::from(vec![
+                Span::styled("  Body: ", Style::default().fg(theme.muted)),
+                Span::styled(body.clone(), Style::default().fg(theme.dim)),
+            ]));
+        }
+        content.push(Line::from(""));
+    }
+
+    let block = Block::default()
+        .title(format!("API Errors ({})", errors.len()))
+        .title_bottom(Line::from(vec![
+            Span::styled(" ↑↓", Style::default().fg(Color::Cyan).bold()),
+            Span::styled(" scroll  ", Style::default().fg(theme.muted)),
+            Span::styled("c", Style::default().fg(Color::Yellow).bold()),
+            Span::styled(" clear  ", Style::default().fg(theme.muted)),
+            Span::styled("Esc", Style::default().fg(Color::Red).bold()),
+            Span::styled(" close ", Style::default().fg(theme.muted)),
+        ]))
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(Color::Cyan))
+        .style(Style::default().bg(Color::Black));
+    let paragraph = Paragraph::new(content)
+        .block(block)
+        .wrap(Wrap { trim: false })
+        .scroll((errors.scroll.min(u16::MAX as usize) as u16, 0));
+    f.render_widget(paragraph, area);
+}
+
+/// Render the tag picker: tags across the current events list with their event counts
+fn render_tag_filter_popup(f: &mut Frame, app: &TrendingAppState, theme: &Theme) {
+    let area = centered_rect_fixed_width(44, 60, f.area());
+    f.render_widget(Clear, area);
+
+    let tag_filter = &app.tag_filter;
+    let content: Vec<Line> = if tag_filter.options.is_empty() {
+        vec![
+            Line::from(""),
+            Line::from(vec![Span::styled(
+                "No tags on the current events",
+                Style::default().fg(theme.muted),
+            )]),
+        ]
+    } else {
+        tag_filter
+            .options
+            .iter()
+            .enumerate()
+            .map(|(idx, (label, count))| {
+                let is_selected = idx == tag_filter.selected;
+                let is_active = tag_filter.active.as_ref() == Some(label);
+                let style = if is_selected {
+                    Style::default().fg(Color::Black).bg(Color::Cyan)
+                } else if is_active {
+                    Style::default().fg(Color::Magenta).bold()
+                } else {
+                    Style::default().fg(Color::White)
+                };
+                Line::from(vec![
+                    Span::styled(format!(" {} ", label), style),
+                    Span::styled(format!(" {}", count), Style::default().fg(theme.muted)),
+                ])
+            })
+            .collect()
+    };
+
+    // Keep the highlighted tag on screen
+    let visible_height = (area.height as usize).saturating_sub(2).max(1);
+    let scroll = (tag_filter.selected + 1).saturating_sub(visible_height);
+
+    let block = Block::default()
+        .title(format!("Filter by Tag ({})", tag_filter.options.len()))
+        .title_bottom(Line::from(vec![
+            Span::styled(" ↑↓", Style::default().fg(Color::Cyan).bold()),
+            Span::styled(" select  ", Style::default().fg(theme.muted)),
+            Span::styled("Enter", Style::default().fg(Color::Green).bold()),
+            Span::styled(" apply  ", Style::default().fg(theme.muted)),
+            Span::styled("Esc", Style::default().fg(Color::Red).bold()),
+            Span::styled(" close ", Style::default().fg(theme.muted)),
+        ]))
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(Color::Cyan))
+        .style(Style::default().bg(Color::Black));
+    let paragraph = Paragraph::new(content)
+        .block(block)
+        .scroll((scroll.min(u16::MAX as usize) as u16, 0));
+    f.render_widget(paragraph, area);
+}
+
+/// Lines of the resolution rules popup for `slug`: the event's rules, the selected market's
+/// own rules when they differ, and the resolution source
+pub fn resolution_lines(app: &TrendingAppState, theme: &Theme, slug: &str) -> Vec<Line<'static>> {
+    let Some(event) = app
+        .current_tab_selected_event()
+        .filter(|event| event.slug == slug)
+    else {
+        return Vec::new();
+    };
+    let market = app
+        .sorted_markets(event)
+        .get(app.orderbook_state.selected_market_index)
+        .copied();
+    let non_empty = |text: &Option<String>| {
+        text.as_deref()
+            .map(str::trim)
+            .filter(|text| !text.is_empty())
+            .map(str::to_string)
+    };
+    let event_rules = non_empty(&event.description);
+    let market_rules = market
+        .and_then(|m| non_empty(&m.description))
+        .filter(|rules| Some(rules) != event_rules.as_ref());
+    let source = non_empty(&event.resolution_source)
+        .or_else(|| market.and_then(|m| non_empty(&m.resolution_source)));
+
+    let mut lines = vec![
+        Line::from(vec![Span::styled(
+            event.title.clone(),
+            Style::default().fg(Color::White).bold(),
+        )]),
+        Line::from(""),
+    ];
+    let text_lines = |text: &str| -> Vec<Line<'static>> {
+        text.lines()
+            .map(|line| Line::from(line.to_string()))
+            .collect()
+    };
+    if event_rules.is_none() && market_rules.is_none() {
+        lines.push(Line::from(vec![Span::styled(
+            "No resolution rules provided for this event",
+            Style::default().fg(theme.muted),
+        )]));
+    }
+    if let Some(rules) = event_rules {
+        lines.extend(text_lines(&rules));
+    }
+    if let (Some(rules), Some(market)) = (market_rules, market) {
+        lines.push(Line::from(""));
+        lines.push(Line::from(vec![Span::styled(
+            market.question.clone(),
+            Style::default().fg(Color::Cyan).bold(),
+        )]));
+        lines.extend(text_lines(&rules));
+    }
+    if let Some(source) = source {
+        lines.push(Line::from(""));
+        lines.push(Line::from(vec![
+            Span::styled("Source: ", Style::default().fg(Color::Yellow).bold()),
+            Span::raw(source),
+        ]));
+    }
+    lines
+}
+
+/// Render the resolution rules of the selected event, scrolled with ↑/↓
+fn render_resolution_popup(f: &mut Frame, app: &TrendingAppState, theme: &Theme, slug: &str) {
+    let area = centered_rect(70, 70, f.area());
+    f.render_widget(Clear, area);
+
+    let block = Block::default()
+        .title("Resolution")
+        .title_bottom(Line::from(vec![
+            Span::styled(" ↑↓", Style::default().fg(Color::Cyan).bold()),
+            Span::styled(" scroll  ", Style::default().fg(theme.muted)),
+            Span::styled("Esc", Style::default().fg(Color::Cyan).bold()),
+            Span::styled(" close ", Style::default().fg(theme.muted)),
+        ]))
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(Color::Yellow))
+        .style(Style::default().bg(Color::Black));
+    let paragraph = Paragraph::new(resolution_lines(app, theme, slug))
+        .block(block)
+        .wrap(Wrap { trim: false })
+        .scroll((app.scroll.resolution.min(u16::MAX as usize) as u16, 0));
+    f.render_widget(paragraph, area);
+}
+
+/// Hint line listing the shares/amount step keys, e.g. "-/+ $10"
+fn size_step_hint(prefix: &str, theme: &Theme) -> Line<'static> {
+    let mut spans = vec![Span::styled(
+        "Step:       ",
+        Style::default().fg(theme.muted),
+    )];
+    for (down, up, step) in SIZE_STEP_KEYS {
+        spans.push(Span::styled(
+            format!("{}/{}", down, up),
+            Style::default().fg(Color::Cyan).bold(),
+        ));
+        spans.push(Span::styled(
+            format!(" {}{:.0}  ", prefix, step),
+            Style::default().fg(theme.muted),
+        ));
+    }
+    Line::from(spans)
+}
+
+/// Render your resting orders on the displayed outcome, with the cancel confirmation
+fn render_open_orders_popup(f: &mut Frame, app: &TrendingAppState, theme: &Theme) {
+    let area = centered_rect_fixed_width(52, 50, f.area());
+    f.render_widget(Clear, area);
+
+    let orderbook_state = &app.orderbook_state;
+    let mut content: Vec<Line> = if orderbook_state.open_orders.is_empty() {
+        vec![
+            Line::from(""),
+            Line::from(vec![Span::styled(
+                "No open orders on this outcome",
+                Style::default().fg(theme.muted),
+            )]),
+        ]
+    } else {
+        orderbook_state
+            .open_orders
+            .iter()
+            .enumerate()
+            .map(|(idx, order)| {
+                let original = order.original_size.parse::<f64>().unwrap_or(0.0);
+                let matched = order.size_matched.parse::<f64>().unwrap_or(0.0);
+                let price = order.price.parse::<f64>().unwrap_or(0.0);
+                let side_color = if order.side.eq_ignore_ascii_case("BUY") {
+                    Color::Green
+                } else {
+                    Color::Red
+                };
+                let style = if idx == orderbook_state.selected_open_order {
+                    Style::default().bg(theme.muted)
+                } else {
+                    Style::default()
+                };
+                let mut spans = vec![
+                    Span::styled(format!(" {:<4}", order.side), style.fg(side_color).bold()),
+                    Span::styled(
+                        format!(
+                            " {:>8.2} @ {:.1}¢ ",
+                            (original - matched).max(0.0),
+                            price * 100.0
+                        ),
+                        style.fg(Color::White),
+                    ),
+                ];
+                if matched > 0.0 {
+                    spans.push(Span::styled(
+                        format!(" {:.2} filled", matched),
+                        Style::default().fg(theme.muted),
+                    ));
+                }
+                Line::from(spans)
+            })
+            .collect()
+    };
+
+    if orderbook_state.confirm_cancel.is_some() {
+        content.push(Line::from(""));
+        content.push(Line::from(vec![Span::styled(
+            "Cancel the highlighted order? (y/n)",
+            Style::default().fg(Color::Yellow).bold(),
+        )]));
+    } else if let Some(ref message) = orderbook_state.open_orders_message {
+        content.push(Line::from(""));
+        content.push(Line::from(vec![Span::styled(
+            message.clone(),
+            Style::default().fg(Color::Cyan),
+        )]));
+    }
+
+    let block = Block::default()
+        .title(format!(
+            "Open Orders ({})",
+            orderbook_state.open_orders.len()
+        ))
+        .title_bottom(Line::from(vec![
+            Span::styled(" ↑↓", Style::default().fg(Color::Cyan).bold()),
+            Span::styled(" select  ", Style::default().fg(theme.muted)),
+            Span::styled("x", Style::default().fg(Color::Red).bold()),
+            Span::styled(" cancel order  ", Style::default().fg(theme.muted)),
+            Span::styled("Esc", Style::default().fg(Color::Cyan).bold()),
+            Span::styled(" close ", Style::default().fg(theme.muted)),
+        ]))
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(Color::Cyan))
+        .style(Style::default().bg(Color::Black));
+    let paragraph = Paragraph::new(content).block(block);
+    f.render_widget(paragraph, area);
+}
+
+/// Render the settings popup with the auto-refresh interval of each data source
+fn render_settings_popup(f: &mut Frame, app: &TrendingAppState, theme: &Theme) {
+    let area = centered_rect_fixed_width(44, 40, f.area());
+    f.render_widget(Clear, area);
+
+    let settings = &app.refresh;
+    let mut content = vec![
+        Line::from(vec![Span::styled(
+            "Auto-refresh intervals",
+            Style::default().fg(Color::White).bold(),
+        )]),
+        Line::from(""),
+    ];
+    for (i, source) in RefreshSource::ALL.into_iter().enumerate() {
+        let is_selected = i == settings.selected;
+        let value = match app.ui_prefs.refresh_intervals.interval(source) {
+            Some(interval) => format!("every {}s", interval.as_secs()),
+            None => "off".to_string(),
+        };
+        let style = if is_selected {
+            Style::default().fg(Color::Black).bg(Color::Cyan)
+        } else {
+            Style::default().fg(Color::White)
+        };
+        content.push(Line::from(vec![
+            Span::styled(format!(" {:<16}", source.label()), style),
+            Span::styled(
+                format!("◀ {:>10} ▶", value),
+                style.add_modifier(Modifier::BOLD),
+            ),
+        ]));
+    }
+    content.push(Line::from(""));
+    content.push(Line::from(vec![Span::styled(
+        "Saved for the next start. Near an event's close, its orderbook refreshes faster.",
+        Style::default().fg(theme.muted),
+    )]));
+
+    let block = Block::default()
+        .title("Settings")
+        .title_bottom(Line::from(vec![
+            Span::styled(" ↑↓", Style::default().fg(Color::Cyan).bold()),
+            Span::styled(" select  ", Style::default().fg(theme.muted)),
+            Span::styled("←→", Style::default().fg(Color::Cyan).bold()),
+            Span::styled(" change  ", Style::default().fg(theme.muted)),
+            Span::styled("Esc", Style::default().fg(Color::Cyan).bold()),
+            Span::styled(" close ", Style::default().fg(theme.muted)),
+        ]))
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(Color::Cyan))
+        .style(Style::default().bg(Color::Black));
+    let paragraph = Paragraph::new(content)
+        .block(block)
+        .wrap(Wrap { trim: true });
+    f.render_widget(paragraph, area);
+}
+
+/// Render the input popup for the yield tab's minimum 24h volume
+fn render_yield_min_volume_popup(f: &mut Frame, app: &TrendingAppState, theme: &Theme) {
+    use ratatui::layout::Position;
+
+    let area = centered_rect_fixed_width(48, 30, f.area());
+    f.render_widget(Clear, area);
+
+    let yield_state = &app.yield_state;
+    let current = if yield_state.min_volume > 0.0 {
+        format_volume(yield_state.min_volume)
+    } else {
+        "none".to_string()
+    };
+    let content = vec![
+        Line::from(""),
+        Line::from(vec![Span::styled(
+            "Hide opportunities with less 24h volume than:",
+            Style::default().fg(Color::White),
+        )]),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("$ ", Style::default().fg(Color::Yellow).bold()),
+            Span::styled(
+                yield_state.min_volume_input.clone(),
+                Style::default().fg(Color::White),
+            ),
+        ]),
+        Line::from(""),
+        Line::from(vec![Span::styled(
+            format!("Current minimum: {}", current),
+            Style::default().fg(theme.muted),
+        )]),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("Enter", Style::default().fg(Color::Green).bold()),
+            Span::styled(" apply (empty clears)  ", Style::default().fg(theme.muted)),
+            Span::styled("Esc", Style::default().fg(Color::Red).bold()),
+            Span::styled(" cancel", Style::default().fg(theme.muted)),
+        ]),
+    ];
+
+    let block = Block::default()
+        .title("Yield Min Volume")
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(Color::Cyan))
+        .style(Style::default().bg(Color::Black));
+    f.render_widget(Paragraph::new(content).block(block), area);
+
+    // Cursor after the typed value (border + "$ " prefix)
+    f.set_cursor_position(Position::new(
+        area.x + 3 + yield_state.min_volume_input.len() as u16,
+        area.y + 4,
+    ));
+}
+
+/// Render the command palette: the typed query over the matching commands
+fn render_command_palette_popup(f: &mut Frame, app: &TrendingAppState, theme: &Theme) {
+    use ratatui::layout::Position;
+
+    let area = centered_rect_fixed_width(56, 60, f.area());
+    f.render_widget(Clear, area);
+
+    let palette = &app.command_palette;
+    let matches = palette.matches();
+    // Borders (2) + query line and the blank line under it (2)
+    let visible = (area.height as usize).saturating_sub(4).max(1);
+    let start = palette.selected.saturating_sub(visible - 1);
+    let name_width = (area.width as usize).saturating_sub(8);
+
+    let mut content = vec![
+        Line::from(vec![
+            Span::styled(": ", Style::default().fg(Color::Yellow).bold()),
+            Span::styled(palette.query.clone(), Style::default().fg(Color::White)),
+        ]),
+        Line::from(""),
+    ];
+    if matches.is_empty() {
+        content.push(Line::from(vec![Span::styled(
+            " No matching commands",
+            Style::default().fg(theme.muted),
+        )]));
+    }
+    for (i, (name, action)) in matches.iter().enumerate().skip(start).take(visible) {
+        let style = if i == palette.selected {
+            Style::default().fg(Color::Black).bg(Color::Cyan)
+        } else {
+            Style::default().fg(Color::White)
+        };
+        let key = match action {
+            PaletteAction::Key(c) => c.to_string(),
+            PaletteAction::Login => String::new(),
+        };
+        content.push(Line::from(vec![
+            Span::styled(
+                format!(
+                    " {:<width$}",
+                    truncate(name, name_width),
+                    width = name_width
+                ),
+                style,
+            ),
+            Span::styled(format!("{:>4} ", key), style.add_modifier(Modifier::BOLD)),
+        ]));
+    }
+
+    let block = Block::default()
+        .title("Command Palette")
+        .title_bottom(Line::from(vec![
+            Span::styled(" ↑↓", Style::default().fg(Color::Cyan).bold()),
+            Span::styled(" select  ", Style::default().fg(theme.muted)),
+            Span::styled("Enter", Style::default().fg(Color::Cyan).bold()),
+            Span::styled(" run  ", Style::default().fg(theme.muted)),
+            Span::styled("Esc", Style::default().fg(Color::Cyan).bold()),
+            Span::styled(" close ", Style::default().fg(theme.muted)),
+        ]))
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(Color::Cyan))
+        .style(Style::default().bg(Color::Black));
+    f.render_widget(Paragraph::new(content).block(block), area);
+
+    // Cursor after the query (border + ": " prefix)
+    f.set_cursor_position(Position::new(
+        area.x + 3 + palette.query.width() as u16,
+        area.y + 1,
+    ));
+}
+
+/// Render the input popup for a personal note on an event
+fn render_edit_note_popup(f: &mut Frame, app: &TrendingAppState, theme: &Theme, slug: &str) {
+    use ratatui::layout::Position;
+
+    let area = centered_rect_fixed_width(64, 30, f.area());
+    f.render_widget(Clear, area);
+
+    let title = app
+        .current_tab_selected_event()
+        .filter(|event| event.slug == slug)
+        .map_or_else(|| slug.to_string(), |event| event.title.clone());
+    // Borders (2) + "> " prefix (2) + room for the cursor (1)
+    let input_width = (area.width as usize).saturating_sub(5);
+    let input = &app.note_input;
+    let visible_start = input.chars().count().saturating_sub(input_width);
+    let visible_input: String = input.chars().skip(visible_start).collect();
+    let content = vec![
+        Line::from(""),
+        Line::from(vec![Span::styled(
+            truncate(&title, input_width),
+            Style::default().fg(Color::White).bold(),
+        )]),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("> ", Style::default().fg(Color::Yellow).bold()),
+            Span::styled(visible_input.clone(), Style::default().fg(Color::White)),
+        ]),
+        Line::from(""),
+        Line::from(vec![Span::styled(
+            format!("{}/{} characters", input.chars().count(), MAX_NOTE_CHARS),
+            Style::default().fg(theme.muted),
+        )]),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("Enter", Style::default().fg(Color::Green).bold()),
+            Span::styled(" save (empty removes)  ", Style::default().fg(theme.muted)),
+            Span::styled("Esc", Style::default().fg(Color::Red).bold()),
+            Span::styled(" cancel", Style::default().fg(theme.muted)),
+        ]),
+    ];
+
+    let block = Block::default()
+        .title("Event Note")
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(Color::Cyan))
+        .style(Style::default().bg(Color::Black));
+    f.render_widget(Paragraph::new(content).block(block), area);
+
+    // Cursor after the typed note (border + "> " prefix)
+    f.set_cursor_position(Position::new(
+        area.x + 3 + visible_input.width() as u16,
+        area.y + 4,
+    ));
+}
+
 /// Helper to render an input field in the login form
 fn render_login_input_field(
     f: &mut Frame,
+    theme: &Theme,
     inner_x: u16,
     field_y: u16,
     label_width: u16,
@@ -294,9 +1100,9 @@ fn render_login_input_field(
 
     // Style: different background for input field, highlighted when active
     let (fg_color, bg_color) = if is_active {
-        (Color::White, Color::DarkGray)
+        (Color::White, theme.muted)
     } else {
-        (Color::Gray, Color::Rgb(30, 30, 30))
+        (theme.dim, theme.stripe_bg)
     };
 
     // Pad the display value to fill the field width (creates visible input area)
@@ -319,7 +1125,7 @@ fn render_login_input_field(
 }
 
 /// Render the login popup with input fields
-fn render_login_popup(f: &mut Frame, app: &TrendingAppState) {
+fn render_login_popup(f: &mut Frame, app: &TrendingAppState, theme: &Theme) {
     use ratatui::layout::Position;
 
     let area = centered_rect(80, 85, f.area());
@@ -368,6 +1174,7 @@ fn render_login_popup(f: &mut Frame, app: &TrendingAppState) {
     // Render required fields (each field takes 2 rows)
     if let Some(pos) = render_login_input_field(
         f,
+        theme,
         inner_area.x,
         base_y,
         label_width,
@@ -382,6 +1189,7 @@ fn render_login_popup(f: &mut Frame, app: &TrendingAppState) {
 
     if let Some(pos) = render_login_input_field(
         f,
+        theme,
         inner_area.x,
         base_y + 2,
         label_width,
@@ -396,6 +1204,7 @@ fn render_login_popup(f: &mut Frame, app: &TrendingAppState) {
 
     if let Some(pos) = render_login_input_field(
         f,
+        theme,
         inner_area.x,
         base_y + 4,
         label_width,
@@ -410,6 +1219,7 @@ fn render_login_popup(f: &mut Frame, app: &TrendingAppState) {
 
     if let Some(pos) = render_login_input_field(
         f,
+        theme,
         inner_area.x,
         base_y + 6,
         label_width,
@@ -431,10 +1241,7 @@ fn render_login_popup(f: &mut Frame, app: &TrendingAppState) {
                 .fg(Color::Magenta)
                 .add_modifier(Modifier::BOLD),
         ),
-        Span::styled(
-            "(for Favorites feature)",
-            Style::default().fg(Color::DarkGray),
-        ),
+        Span::styled("(for Favorites feature)", Style::default().fg(theme.muted)),
     ]));
     let cookie_header_area = Rect {
         x: inner_area.x,
@@ -446,7 +1253,7 @@ fn render_login_popup(f: &mut Frame, app: &TrendingAppState) {
 
     // Help text for cookies
     let cookie_help = Paragraph::new("Get from browser DevTools > Application > Cookies")
-        .style(Style::default().fg(Color::DarkGray).italic());
+        .style(Style::default().fg(theme.muted).italic());
     let cookie_help_area = Rect {
         x: inner_area.x,
         y: cookie_section_y + 1,
@@ -460,6 +1267,7 @@ fn render_login_popup(f: &mut Frame, app: &TrendingAppState) {
 
     if let Some(pos) = render_login_input_field(
         f,
+        theme,
         inner_area.x,
         cookie_fields_y,
         label_width,
@@ -474,6 +1282,7 @@ fn render_login_popup(f: &mut Frame, app: &TrendingAppState) {
 
     if let Some(pos) = render_login_input_field(
         f,
+        theme,
         inner_area.x,
         cookie_fields_y + 2,
         label_width,
@@ -488,6 +1297,7 @@ fn render_login_popup(f: &mut Frame, app: &TrendingAppState) {
 
     if let Some(pos) = render_login_input_field(
         f,
+        theme,
         inner_area.x,
         cookie_fields_y + 4,
         label_width,
@@ -531,13 +1341,13 @@ fn render_login_popup(f: &mut Frame, app: &TrendingAppState) {
     // Instructions at bottom
     let instructions = Line::from(vec![
         Span::styled("Tab", Style::default().fg(Color::Cyan).bold()),
-        Span::styled(" Next  ", Style::default().fg(Color::DarkGray)),
+        Span::styled(" Next  ", Style::default().fg(theme.muted)),
         Span::styled("Shift+Tab", Style::default().fg(Color::Cyan).bold()),
-        Span::styled(" Prev  ", Style::default().fg(Color::DarkGray)),
+        Span::styled(" Prev  ", Style::default().fg(theme.muted)),
         Span::styled("Enter", Style::default().fg(Color::Green).bold()),
-        Span::styled(" Submit  ", Style::default().fg(Color::DarkGray)),
+        Span::styled(" Submit  ", Style::default().fg(theme.muted)),
         Span::styled("Esc", Style::default().fg(Color::Red).bold()),
-        Span::styled(" Cancel", Style::default().fg(Color::DarkGray)),
+        Span::styled(" Cancel", Style::default().fg(theme.muted)),
     ]);
     let instructions_para = Paragraph::new(instructions);
     let instructions_area = Rect {
@@ -555,7 +1365,7 @@ fn render_login_popup(f: &mut Frame, app: &TrendingAppState) {
 }
 
 /// Render user profile popup
-fn render_user_profile_popup(f: &mut Frame, app: &TrendingAppState) {
+fn render_user_profile_popup(f: &mut Frame, app: &TrendingAppState, theme: &Theme) {
     // Fixed width: 55 (content) + 2 (borders) = 57
     let area = centered_rect_fixed_width(57, 60, f.area());
     f.render_widget(Clear, area);
@@ -578,7 +1388,7 @@ fn render_user_profile_popup(f: &mut Frame, app: &TrendingAppState) {
         // Name
         if let Some(ref name) = profile.name {
             content.push(Line::from(vec![
-                Span::styled("Name:      ", Style::default().fg(Color::DarkGray)),
+                Span::styled("Name:      ", Style::default().fg(theme.muted)),
                 Span::styled(name.clone(), Style::default().fg(Color::White).bold()),
             ]));
         }
@@ -592,7 +1402,7 @@ fn render_user_profile_popup(f: &mut Frame, app: &TrendingAppState) {
                 .unwrap_or(true);
             if show_pseudonym {
                 content.push(Line::from(vec![
-                    Span::styled("Pseudonym: ", Style::default().fg(Color::DarkGray)),
+                    Span::styled("Pseudonym: ", Style::default().fg(theme.muted)),
                     Span::styled(pseudonym.clone(), Style::default().fg(Color::Cyan)),
                 ]));
             }
@@ -604,7 +1414,7 @@ fn render_user_profile_popup(f: &mut Frame, app: &TrendingAppState) {
         {
             content.push(Line::from(""));
             content.push(Line::from(vec![
-                Span::styled("Bio:       ", Style::default().fg(Color::DarkGray)),
+                Span::styled("Bio:       ", Style::default().fg(theme.muted)),
                 Span::styled(truncate(bio, 50), Style::default().fg(Color::White)),
             ]));
         }
@@ -614,14 +1424,14 @@ fn render_user_profile_popup(f: &mut Frame, app: &TrendingAppState) {
             && !img.is_empty()
         {
             content.push(Line::from(vec![
-                Span::styled("Avatar:    ", Style::default().fg(Color::DarkGray)),
+                Span::styled("Avatar:    ", Style::default().fg(theme.muted)),
                 Span::styled(truncate(img, 45), Style::default().fg(Color::Blue)),
             ]));
         }
     } else if auth.username.is_some() {
         // Fallback: just show username if we have it but no full profile
         content.push(Line::from(vec![
-            Span::styled("Username:  ", Style::default().fg(Color::DarkGray)),
+            Span::styled("Username:  ", Style::default().fg(theme.muted)),
             Span::styled(
                 auth.username.clone().unwrap_or_default(),
                 Style::default().fg(Color::White).bold(),
@@ -630,14 +1440,14 @@ fn render_user_profile_popup(f: &mut Frame, app: &TrendingAppState) {
     } else {
         content.push(Line::from(vec![Span::styled(
             "(No profile information available)",
-            Style::default().fg(Color::DarkGray),
+            Style::default().fg(theme.muted),
         )]));
     }
 
     content.push(Line::from(""));
     content.push(Line::from(vec![Span::styled(
         "─".repeat((TRADE_POPUP_WIDTH - 4) as usize),
-        Style::default().fg(Color::DarkGray),
+        Style::default().fg(theme.muted),
     )]));
 
     // Account section header
@@ -652,7 +1462,7 @@ fn render_user_profile_popup(f: &mut Frame, app: &TrendingAppState) {
 
     // Status
     content.push(Line::from(vec![
-        Span::styled("Status:    ", Style::default().fg(Color::DarkGray)),
+        Span::styled("Status:    ", Style::default().fg(theme.muted)),
         Span::styled(
             if auth.is_authenticated {
                 "Authenticated"
@@ -670,7 +1480,7 @@ fn render_user_profile_popup(f: &mut Frame, app: &TrendingAppState) {
     // Address
     if let Some(ref addr) = auth.address {
         content.push(Line::from(vec![
-            Span::styled("Address:   ", Style::default().fg(Color::DarkGray)),
+            Span::styled("Address:   ", Style::default().fg(theme.muted)),
             Span::styled(addr.clone(), Style::default().fg(Color::Cyan)),
         ]));
     }
@@ -678,7 +1488,7 @@ fn render_user_profile_popup(f: &mut Frame, app: &TrendingAppState) {
     // Balance (cash)
     if let Some(balance) = auth.balance {
         content.push(Line::from(vec![
-            Span::styled("Cash:      ", Style::default().fg(Color::DarkGray)),
+            Span::styled("Cash:      ", Style::default().fg(theme.muted)),
             Span::styled(
                 format!("${:.2} USDC", balance),
                 Style::default().fg(Color::Green),
@@ -695,7 +1505,7 @@ fn render_user_profile_popup(f: &mut Frame, app: &TrendingAppState) {
             portfolio_value
         };
         content.push(Line::from(vec![
-            Span::styled("Portfolio: ", Style::default().fg(Color::DarkGray)),
+            Span::styled("Portfolio: ", Style::default().fg(theme.muted)),
             Span::styled(
                 format!("${:.2}", display_value),
                 Style::default().fg(Color::Green),
@@ -706,7 +1516,7 @@ fn render_user_profile_popup(f: &mut Frame, app: &TrendingAppState) {
     // Positions count
     if let Some(positions_count) = auth.positions_count {
         content.push(Line::from(vec![
-            Span::styled("Positions: ", Style::default().fg(Color::DarkGray)),
+            Span::styled("Positions: ", Style::default().fg(theme.muted)),
             Span::styled(
                 format!("{}", positions_count),
                 Style::default().fg(Color::Cyan),
@@ -719,7 +1529,7 @@ fn render_user_profile_popup(f: &mut Frame, app: &TrendingAppState) {
         let total = auth.balance.unwrap_or(0.0) + auth.portfolio_value.unwrap_or(0.0);
         content.push(Line::from(""));
         content.push(Line::from(vec![
-            Span::styled("Total:     ", Style::default().fg(Color::DarkGray)),
+            Span::styled("Total:     ", Style::default().fg(theme.muted)),
             Span::styled(
                 format!("${:.2}", total),
                 Style::default()
@@ -734,7 +1544,7 @@ fn render_user_profile_popup(f: &mut Frame, app: &TrendingAppState) {
         content.push(Line::from(""));
         content.push(Line::from(vec![Span::styled(
             "─".repeat((TRADE_POPUP_WIDTH - 4) as usize),
-            Style::default().fg(Color::DarkGray),
+            Style::default().fg(theme.muted),
         )]));
 
         content.push(Line::from(""));
@@ -748,28 +1558,28 @@ fn render_user_profile_popup(f: &mut Frame, app: &TrendingAppState) {
 
         // Unrealized P&L
         if let Some(unrealized) = auth.unrealized_pnl {
-            let (pnl_str, pnl_color) = format_pnl(unrealized);
+            let (pnl_str, pnl_color) = format_pnl(unrealized, theme);
             content.push(Line::from(vec![
-                Span::styled("Unrealized:", Style::default().fg(Color::DarkGray)),
+                Span::styled("Unrealized:", Style::default().fg(theme.muted)),
                 Span::styled(format!(" {}", pnl_str), Style::default().fg(pnl_color)),
             ]));
         }
 
         // Realized P&L
         if let Some(realized) = auth.realized_pnl {
-            let (pnl_str, pnl_color) = format_pnl(realized);
+            let (pnl_str, pnl_color) = format_pnl(realized, theme);
             content.push(Line::from(vec![
-                Span::styled("Realized:  ", Style::default().fg(Color::DarkGray)),
+                Span::styled("Realized:  ", Style::default().fg(theme.muted)),
                 Span::styled(format!(" {}", pnl_str), Style::default().fg(pnl_color)),
             ]));
         }
 
         // Total P&L
         let total_pnl = auth.unrealized_pnl.unwrap_or(0.0) + auth.realized_pnl.unwrap_or(0.0);
-        let (total_pnl_str, total_pnl_color) = format_pnl(total_pnl);
+        let (total_pnl_str, total_pnl_color) = format_pnl(total_pnl, theme);
         content.push(Line::from(""));
         content.push(Line::from(vec![
-            Span::styled("Total P&L: ", Style::default().fg(Color::DarkGray)),
+            Span::styled("Total P&L: ", Style::default().fg(theme.muted)),
             Span::styled(
                 format!(" {}", total_pnl_str),
                 Style::default()
@@ -777,21 +1587,39 @@ fn render_user_profile_popup(f: &mut Frame, app: &TrendingAppState) {
                     .add_modifier(Modifier::BOLD),
             ),
         ]));
+
+        // How total P&L moved since the app started, once there are two samples
+        if let [(started, _), .., _] = auth.pnl_history.as_slice() {
+            let totals: Vec<f64> = auth.pnl_history.iter().map(|(_, pnl)| *pnl).collect();
+            let mut spans = vec![Span::styled(
+                "Session:   ",
+                Style::default().fg(theme.muted),
+            )];
+            spans.push(Span::raw(" "));
+            spans.extend(price_sparkline_spans(&totals));
+            spans.push(Span::styled(
+                format!(" {}m", started.elapsed().as_secs() / 60),
+                Style::default().fg(theme.muted),
+            ));
+            content.push(Line::from(spans));
+        }
     }
 
     content.push(Line::from(""));
     content.push(Line::from(vec![Span::styled(
         "─".repeat((TRADE_POPUP_WIDTH - 4) as usize),
-        Style::default().fg(Color::DarkGray),
+        Style::default().fg(theme.muted),
     )]));
     content.push(Line::from(""));
 
     // Instructions
     content.push(Line::from(vec![
         Span::styled("Esc", Style::default().fg(Color::Cyan).bold()),
-        Span::styled(" close    ", Style::default().fg(Color::DarkGray)),
+        Span::styled(" close    ", Style::default().fg(theme.muted)),
+        Span::styled("l", Style::default().fg(Color::Red).bold()),
+        Span::styled(" logout    ", Style::default().fg(theme.muted)),
         Span::styled("L", Style::default().fg(Color::Red).bold()),
-        Span::styled(" logout", Style::default().fg(Color::DarkGray)),
+        Span::styled(" logout + forget login", Style::default().fg(theme.muted)),
     ]));
 
     // Build title with username if available
@@ -819,8 +1647,10 @@ fn render_user_profile_popup(f: &mut Frame, app: &TrendingAppState) {
 /// Render trade popup with buy/sell form
 /// Fixed width for trade popup (content + borders)
 pub const TRADE_POPUP_WIDTH: u16 = 57;
+/// Width of the limit price input (cents, e.g. "99.9", plus the cursor)
+const LIMIT_PRICE_FIELD_WIDTH: u16 = 6;
 
-fn render_trade_popup(f: &mut Frame, app: &TrendingAppState) {
+fn render_trade_popup(f: &mut Frame, app: &TrendingAppState, theme: &Theme) {
     use ratatui::layout::Position;
 
     let area = centered_rect_fixed_width(TRADE_POPUP_WIDTH, 60, f.area());
@@ -849,12 +1679,21 @@ fn render_trade_popup(f: &mut Frame, app: &TrendingAppState) {
     let mut current_line: u16 = 0;
     #[allow(unused_assignments)]
     let mut input_field_line: Option<u16> = None;
+    let mut limit_price_line: Option<u16> = None;
 
     // Build content lines
     let mut content = vec![];
 
-    // Empty line
-    content.push(Line::from(""));
+    // Empty line, or a banner if the market closed after the popup opened
+    let market_closed = app.trade_market_closed();
+    if market_closed {
+        content.push(Line::from(vec![Span::styled(
+            "⚠ Market closed - orders are disabled",
+            Style::default().fg(Color::Red).bold(),
+        )]));
+    } else {
+        content.push(Line::from(""));
+    }
     current_line += 1;
 
     // Market question (truncated to fit popup width)
@@ -867,7 +1706,7 @@ fn render_trade_popup(f: &mut Frame, app: &TrendingAppState) {
     content.push(Line::from(""));
     current_line += 1;
 
-    // Outcome tabs with square background (clickable, like orderbook Yes/No)
+    // Outcome chips with square background (clickable, like orderbook Yes/No)
     let mut outcome_spans = vec![];
     for (i, outcome) in form.outcomes.iter().enumerate() {
         if i > 0 {
@@ -877,9 +1716,12 @@ fn render_trade_popup(f: &mut Frame, app: &TrendingAppState) {
         let tab_style = if is_selected {
             Style::default().fg(Color::Black).bg(Color::Cyan).bold()
         } else {
-            Style::default().fg(Color::Gray)
+            Style::default().fg(theme.dim)
         };
-        outcome_spans.push(Span::styled(format!(" {} ", outcome.name), tab_style));
+        outcome_spans.push(Span::styled(outcome.chip_label(), tab_style));
+    }
+    if form.outcomes.len() > 1 && !form.is_numeric_field_active() {
+        outcome_spans.push(Span::styled("  ←/→", Style::default().fg(theme.muted)));
     }
     content.push(Line::from(outcome_spans));
     current_line += 1;
@@ -887,7 +1729,7 @@ fn render_trade_popup(f: &mut Frame, app: &TrendingAppState) {
     // Underline below outcome tabs
     content.push(Line::from(vec![Span::styled(
         "─".repeat((TRADE_POPUP_WIDTH - 4) as usize),
-        Style::default().fg(Color::DarkGray),
+        Style::default().fg(theme.muted),
     )]));
     current_line += 1;
 
@@ -896,7 +1738,7 @@ fn render_trade_popup(f: &mut Frame, app: &TrendingAppState) {
 
     // Best ask price for selected outcome
     content.push(Line::from(vec![
-        Span::styled("Best Ask:   ", Style::default().fg(Color::DarkGray)),
+        Span::styled("Best Ask:   ", Style::default().fg(theme.muted)),
         Span::styled(
             format!("{:.1}¢", form.best_ask() * 100.0),
             Style::default().fg(Color::Yellow),
@@ -912,7 +1754,7 @@ fn render_trade_popup(f: &mut Frame, app: &TrendingAppState) {
         && let Some(balance) = app.auth_state.balance
     {
         content.push(Line::from(vec![
-            Span::styled("Balance:    ", Style::default().fg(Color::DarkGray)),
+            Span::styled("Balance:    ", Style::default().fg(theme.muted)),
             Span::styled(
                 format!("${:.2}", balance),
                 Style::default().fg(Color::Green),
@@ -926,7 +1768,7 @@ fn render_trade_popup(f: &mut Frame, app: &TrendingAppState) {
 
     content.push(Line::from(vec![Span::styled(
         "─".repeat((TRADE_POPUP_WIDTH - 4) as usize),
-        Style::default().fg(Color::DarkGray),
+        Style::default().fg(theme.muted),
     )]));
     current_line += 1;
 
@@ -935,28 +1777,32 @@ fn render_trade_popup(f: &mut Frame, app: &TrendingAppState) {
 
     // Order type selection (LIMIT / MARKET)
     let order_type_active = form.active_field == TradeField::OrderType;
-    let limit_style = if form.order_type == OrderType::Limit {
+    let limit_style = if !form.clob_tradeable {
+        Style::default().fg(theme.muted).crossed_out()
+    } else if form.order_type == OrderType::Limit {
         Style::default().fg(Color::Black).bg(Color::Cyan).bold()
     } else if order_type_active {
         Style::default().fg(Color::Cyan)
     } else {
-        Style::default().fg(Color::DarkGray)
+        Style::default().fg(theme.muted)
     };
     let market_style = if form.order_type == OrderType::Market {
         Style::default().fg(Color::Black).bg(Color::Magenta).bold()
     } else if order_type_active {
         Style::default().fg(Color::Magenta)
     } else {
-        Style::default().fg(Color::DarkGray)
+        Style::default().fg(theme.muted)
     };
 
     content.push(Line::from(vec![
-        Span::styled("Order:      ", Style::default().fg(Color::DarkGray)),
+        Span::styled("Order:      ", Style::default().fg(theme.muted)),
         Span::styled(" LIMIT ", limit_style),
         Span::raw("  "),
         Span::styled(" MARKET ", market_style),
-        if order_type_active {
-            Span::styled("  ← Space to toggle", Style::default().fg(Color::DarkGray))
+        if !form.clob_tradeable {
+            Span::styled("  AMM only", Style::default().fg(Color::Yellow))
+        } else if order_type_active {
+            Span::styled("  ← Space to toggle", Style::default().fg(theme.muted))
         } else {
             Span::raw("")
         },
@@ -969,35 +1815,39 @@ fn render_trade_popup(f: &mut Frame, app: &TrendingAppState) {
     // Order-type specific fields
     match form.order_type {
         OrderType::Limit => {
-            // Limit Price with +/- controls
+            // Limit Price input (in cents or percent) with +/- controls
             let limit_price_active = form.active_field == TradeField::LimitPrice;
-            let price_style = if limit_price_active {
-                Style::default().fg(Color::White).bold()
-            } else {
-                Style::default().fg(Color::White)
-            };
+            limit_price_line = Some(current_line);
 
             content.push(Line::from(vec![
-                Span::styled("Limit:      ", Style::default().fg(Color::DarkGray)),
+                Span::styled("Limit:      ", Style::default().fg(theme.muted)),
                 Span::styled(
                     " - ",
                     if limit_price_active {
                         Style::default().fg(Color::Black).bg(Color::Yellow)
                     } else {
-                        Style::default().fg(Color::DarkGray)
+                        Style::default().fg(theme.muted)
                     },
                 ),
-                Span::styled(format!(" {:.1}¢ ", form.limit_price * 100.0), price_style),
+                // Placeholder for input field
+                Span::raw(" ".repeat(LIMIT_PRICE_FIELD_WIDTH as usize)),
+                Span::styled(
+                    form.limit_price_unit.symbol(),
+                    Style::default().fg(Color::White),
+                ),
                 Span::styled(
                     " + ",
                     if limit_price_active {
                         Style::default().fg(Color::Black).bg(Color::Yellow)
                     } else {
-                        Style::default().fg(Color::DarkGray)
+                        Style::default().fg(theme.muted)
                     },
                 ),
                 if limit_price_active {
-                    Span::styled("  ← -/+ to adjust", Style::default().fg(Color::DarkGray))
+                    Span::styled(
+                        "  ← type, -/+ to adjust, % for ¢/%",
+                        Style::default().fg(theme.muted),
+                    )
                 } else {
                     Span::raw("")
                 },
@@ -1012,23 +1862,48 @@ fn render_trade_popup(f: &mut Frame, app: &TrendingAppState) {
             input_field_line = Some(current_line);
 
             content.push(Line::from(vec![
-                Span::styled("Shares:     ", Style::default().fg(Color::DarkGray)),
+                Span::styled("Shares:     ", Style::default().fg(theme.muted)),
                 // Placeholder for input field
                 Span::raw("                    "),
             ]));
+            if shares_active {
+                content.push(size_step_hint("", theme));
+            }
 
             content.push(Line::from(""));
 
-            // Total (calculated)
+            // Total (exact shares * limit price, not rounded to cents)
             let total = form.total_cost();
             content.push(Line::from(vec![
-                Span::styled("Total:      ", Style::default().fg(Color::DarkGray)),
                 Span::styled(
-                    format!("${:.2}", total),
+                    if form.side == TradeSide::Buy {
+                        "Cost:       "
+                    } else {
+                        "Total:      "
+                    },
+                    Style::default().fg(theme.muted),
+                ),
+                Span::styled(
+                    format_usd_exact(total),
                     Style::default().fg(Color::Yellow).bold(),
                 ),
             ]));
 
+            // Max payout (each winning share pays $1)
+            if form.side == TradeSide::Buy {
+                content.push(Line::from(vec![
+                    Span::styled("Max payout: ", Style::default().fg(theme.muted)),
+                    Span::styled(
+                        format_usd_exact(form.max_payout()),
+                        Style::default().fg(Color::White),
+                    ),
+                    Span::styled(
+                        format!(" ({} × $1)", form.shares_f64()),
+                        Style::default().fg(theme.muted),
+                    ),
+                ]));
+            }
+
             // Store active state for later use
             let _ = shares_active;
         },
@@ -1038,22 +1913,78 @@ fn render_trade_popup(f: &mut Frame, app: &TrendingAppState) {
             input_field_line = Some(current_line);
 
             content.push(Line::from(vec![
-                Span::styled("Amount:     $ ", Style::default().fg(Color::DarkGray)),
+                Span::styled("Amount:     $ ", Style::default().fg(theme.muted)),
                 // Placeholder for input field
                 Span::raw("                    "),
             ]));
 
+            // Quick-amount keys
+            if amount_active {
+                let mut hint_spans = vec![Span::styled(
+                    "Quick:      ",
+                    Style::default().fg(theme.muted),
+                )];
+                for (key, amount) in QUICK_AMOUNTS {
+                    hint_spans.push(Span::styled(
+                        key.to_string(),
+                        Style::default().fg(Color::Cyan).bold(),
+                    ));
+                    hint_spans.push(Span::styled(
+                        format!(" ${:.0}  ", amount),
+                        Style::default().fg(theme.muted),
+                    ));
+                }
+                hint_spans.push(Span::styled(
+                    MAX_AMOUNT_KEY.to_string(),
+                    Style::default().fg(Color::Cyan).bold(),
+                ));
+                hint_spans.push(Span::styled(" max", Style::default().fg(theme.muted)));
+                content.push(Line::from(hint_spans));
+                content.push(size_step_hint("$", theme));
+            }
+
             content.push(Line::from(""));
 
             // Estimated shares
             let shares = form.estimated_shares();
             content.push(Line::from(vec![
-                Span::styled("Est. Shares: ", Style::default().fg(Color::DarkGray)),
+                Span::styled("Est. Shares: ", Style::default().fg(theme.muted)),
                 Span::styled(format!("{:.2}", shares), Style::default().fg(Color::White)),
             ]));
+        },
+    }
 
-            // Store active state for later use
-            let _ = amount_active;
+    // Estimated fee and the net amount; gross figures above stay visible for the breakdown
+    match (form.estimated_fee(), form.net_cost(), form.fee_rate) {
+        (Some(fee), Some(net), Some(fee_rate)) => {
+            content.push(Line::from(vec![
+                Span::styled("Fees:       ", Style::default().fg(theme.muted)),
+                Span::styled(format_usd_exact(fee), Style::default().fg(Color::White)),
+                Span::styled(
+                    format!(" ({:.0} bps)", fee_rate * 10_000.0),
+                    Style::default().fg(theme.muted),
+                ),
+            ]));
+            content.push(Line::from(vec![
+                Span::styled(
+                    if form.side == TradeSide::Buy {
+                        "Net cost:   "
+                    } else {
+                        "Net total:  "
+                    },
+                    Style::default().fg(theme.muted),
+                ),
+                Span::styled(
+                    format_usd_exact(net),
+                    Style::default().fg(Color::Yellow).bold(),
+                ),
+            ]));
+        },
+        _ => {
+            content.push(Line::from(vec![
+                Span::styled("Fees:       ", Style::default().fg(theme.muted)),
+                Span::styled("N/A", Style::default().fg(theme.muted)),
+            ]));
         },
     }
 
@@ -1073,22 +2004,22 @@ fn render_trade_popup(f: &mut Frame, app: &TrendingAppState) {
             } else {
                 "Proceeds:   "
             },
-            Style::default().fg(Color::DarkGray),
+            Style::default().fg(theme.muted),
         ),
         Span::styled(
             format!(
-                "{}${:.2}",
+                "{}{}",
                 if profit >= 0.0 {
                     "+"
                 } else {
-                    ""
+                    "-"
                 },
-                profit.abs()
+                format_usd_exact(profit.abs())
             ),
             Style::default().fg(profit_color).bold(),
         ),
         if form.side == TradeSide::Buy {
-            Span::styled(" (if outcome wins)", Style::default().fg(Color::DarkGray))
+            Span::styled(" (if outcome wins)", Style::default().fg(theme.muted))
         } else {
             Span::raw("")
         },
@@ -1098,16 +2029,37 @@ fn render_trade_popup(f: &mut Frame, app: &TrendingAppState) {
 
     content.push(Line::from(vec![Span::styled(
         "─".repeat((TRADE_POPUP_WIDTH - 4) as usize),
-        Style::default().fg(Color::DarkGray),
+        Style::default().fg(theme.muted),
     )]));
 
-    // Error message if any
-    if let Some(ref error) = form.error_message {
+    // Pending confirmation replaces the error line, colored by side
+    if form.awaiting_confirm {
+        let side_color = match form.side {
+            TradeSide::Buy => Color::Green,
+            TradeSide::Sell => Color::Red,
+        };
+        content.push(Line::from(""));
+        content.push(Line::from(vec![Span::styled(
+            form.confirmation_summary(),
+            Style::default().fg(side_color).bold(),
+        )]));
+    } else if let Some(error) = form
+        .error_message
+        .clone()
+        .or_else(|| form.balance_error(app.auth_state.balance))
+    {
         content.push(Line::from(""));
         content.push(Line::from(vec![Span::styled(
             error,
             Style::default().fg(Color::Red),
         )]));
+    } else if let Some(result) = &form.last_result {
+        // Previous order's outcome while the popup stays open for the next one
+        content.push(Line::from(""));
+        content.push(Line::from(vec![Span::styled(
+            result.clone(),
+            Style::default().fg(Color::Green),
+        )]));
     }
 
     // Not authenticated warning
@@ -1122,18 +2074,32 @@ fn render_trade_popup(f: &mut Frame, app: &TrendingAppState) {
     content.push(Line::from(""));
 
     // Instructions
-    content.push(Line::from(vec![
-        Span::styled("Tab", Style::default().fg(Color::Cyan).bold()),
-        Span::styled(" field  ", Style::default().fg(Color::DarkGray)),
-        Span::styled("Space", Style::default().fg(Color::Cyan).bold()),
-        Span::styled(" toggle  ", Style::default().fg(Color::DarkGray)),
-        Span::styled("-/+", Style::default().fg(Color::Yellow).bold()),
-        Span::styled(" price  ", Style::default().fg(Color::DarkGray)),
-        Span::styled("Enter", Style::default().fg(Color::Green).bold()),
-        Span::styled(" submit  ", Style::default().fg(Color::DarkGray)),
-        Span::styled("Esc", Style::default().fg(Color::Red).bold()),
-        Span::styled(" cancel", Style::default().fg(Color::DarkGray)),
-    ]));
+    if market_closed {
+        content.push(Line::from(vec![
+            Span::styled("Esc", Style::default().fg(Color::Red).bold()),
+            Span::styled(" close", Style::default().fg(theme.muted)),
+        ]));
+    } else if form.awaiting_confirm {
+        content.push(Line::from(vec![
+            Span::styled("Enter", Style::default().fg(Color::Green).bold()),
+            Span::styled(" confirm  ", Style::default().fg(theme.muted)),
+            Span::styled("Esc", Style::default().fg(Color::Red).bold()),
+            Span::styled(" back to editing", Style::default().fg(theme.muted)),
+        ]));
+    } else {
+        content.push(Line::from(vec![
+            Span::styled("Tab", Style::default().fg(Color::Cyan).bold()),
+            Span::styled(" field  ", Style::default().fg(theme.muted)),
+            Span::styled("Space", Style::default().fg(Color::Cyan).bold()),
+            Span::styled(" toggle  ", Style::default().fg(theme.muted)),
+            Span::styled("-/+", Style::default().fg(Color::Yellow).bold()),
+            Span::styled(" price  ", Style::default().fg(theme.muted)),
+            Span::styled("Enter", Style::default().fg(Color::Green).bold()),
+            Span::styled(" review  ", Style::default().fg(theme.muted)),
+            Span::styled("Esc", Style::default().fg(Color::Red).bold()),
+            Span::styled(" cancel", Style::default().fg(theme.muted)),
+        ]));
+    }
 
     // Build title with clickable BUY/SELL tabs (like orderbook's Yes/No tabs)
     let buy_style = if form.side == TradeSide::Buy {
@@ -1141,19 +2107,19 @@ fn render_trade_popup(f: &mut Frame, app: &TrendingAppState) {
             .fg(Color::White)
             .add_modifier(Modifier::BOLD)
     } else {
-        Style::default().fg(Color::DarkGray)
+        Style::default().fg(theme.muted)
     };
     let sell_style = if form.side == TradeSide::Sell {
         Style::default()
             .fg(Color::White)
             .add_modifier(Modifier::BOLD)
     } else {
-        Style::default().fg(Color::DarkGray)
+        Style::default().fg(theme.muted)
     };
 
     let title_line = Line::from(vec![
         Span::styled("BUY", buy_style),
-        Span::styled(" - ", Style::default().fg(Color::DarkGray)),
+        Span::styled(" - ", Style::default().fg(theme.muted)),
         Span::styled("SELL", sell_style),
     ]);
 
@@ -1177,44 +2143,24 @@ fn render_trade_popup(f: &mut Frame, app: &TrendingAppState) {
 
     f.render_widget(paragraph, area);
 
-    // Render input field with background color and cursor
-    if let Some(field_line) = input_field_line {
-        let (input_value, is_active, label_len) = match form.order_type {
-            OrderType::Limit => {
-                let display = if form.shares.is_empty() {
-                    "0".to_string()
-                } else {
-                    form.shares.clone()
-                };
-                (display, form.active_field == TradeField::Shares, 12) // "Shares:     " = 12
-            },
-            OrderType::Market => {
-                let display = if form.amount.is_empty() {
-                    "0.00".to_string()
-                } else {
-                    form.amount.clone()
-                };
-                (display, form.active_field == TradeField::Amount, 14) // "Amount:     $ " = 14
-            },
-        };
-
-        let field_width: u16 = 15;
+    // Render an input field with background color, and the cursor if it is active
+    let mut render_input = |field_line: u16, label_len: u16, width: u16, value: &str, is_active| {
         let input_area = Rect {
             x: area.x + 2 + label_len,  // border + padding + label length
             y: area.y + 1 + field_line, // border + content offset
-            width: field_width,
+            width,
             height: 1,
         };
 
         // Style: different background for input field, highlighted when active
         let (fg_color, bg_color) = if is_active {
-            (Color::White, Color::DarkGray)
+            (Color::White, theme.muted)
         } else {
-            (Color::Gray, Color::Rgb(30, 30, 30))
+            (theme.dim, theme.stripe_bg)
         };
 
         // Pad the display value to fill the field width
-        let padded_value = format!("{:<width$}", input_value, width = field_width as usize);
+        let padded_value = format!("{:<width$}", value, width = width as usize);
 
         let input_para =
             Paragraph::new(padded_value).style(Style::default().fg(fg_color).bg(bg_color));
@@ -1222,8 +2168,40 @@ fn render_trade_popup(f: &mut Frame, app: &TrendingAppState) {
 
         // Set cursor position if this field is active
         if is_active {
-            let cursor_x = input_area.x + input_value.len() as u16;
+            let cursor_x = input_area.x + value.len() as u16;
             f.set_cursor_position(Position::new(cursor_x, input_area.y));
         }
+    };
+
+    if let Some(field_line) = limit_price_line {
+        render_input(
+            field_line,
+            15, // "Limit:      " + " - " = 15
+            LIMIT_PRICE_FIELD_WIDTH,
+            &form.limit_price_input,
+            form.active_field == TradeField::LimitPrice,
+        );
+    }
+
+    if let Some(field_line) = input_field_line {
+        let (input_value, is_active, label_len) = match form.order_type {
+            OrderType::Limit => {
+                let display = if form.shares.is_empty() {
+                    "0".to_string()
+                } else {
+                    form.shares.clone()
+                };
+                (display, form.active_field == TradeField::Shares, 12) // "Shares:     " = 12
+            },
+            OrderType::Market => {
+                let display = if form.amount.is_empty() {
+                    "0.00".to_string()
+                } else {
+                    form.amount.clone()
+                };
+                (display, form.active_field == TradeField::Amount, 14) // "Amount:     $ " = 14
+            },
+        };
+        render_input(field_line, label_len, 15, &input_value, is_active);
     }
 }
@@ -0,0 +1,169 @@
+//! Positions tab rendering functions
+
+use {
+    super::{
+        theme::Theme,
+        utils::{format_pnl, format_price_cents, spinner_frame, truncate},
+    },
+    crate::trending_tui::state::TrendingAppState,
+    ratatui::{
+        Frame,
+        layout::{Alignment, Constraint, Rect},
+        style::{Color, Modifier, Style},
+        widgets::{
+            Block, BorderType, Borders, Cell, Paragraph, Row, Scrollbar, ScrollbarOrientation,
+            ScrollbarState, Table, TableState,
+        },
+    },
+};
+
+/// Render the positions tab: a table of the user's open positions
+pub fn render_positions_tab(f: &mut Frame, app: &TrendingAppState, theme: &Theme, area: Rect) {
+    let positions_state = &app.positions_state;
+
+    // Check authentication first
+    if !app.auth_state.is_authenticated {
+        let message = Paragraph::new("Please login to view your positions.\n\nPress Tab to go to Login button, then Enter to open login dialog.")
+            .block(
+                Block::default()
+                    .title(" Positions ")
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded),
+            )
+            .alignment(Alignment::Center)
+            .style(Style::default().fg(Color::Yellow));
+        f.render_widget(message, area);
+        return;
+    }
+
+    let status_text = if positions_state.is_loading && positions_state.positions.is_empty() {
+        Some(("Loading positions...".to_string(), Color::Yellow))
+    } else if let Some(ref error) = positions_state.error_message {
+        Some((format!("{}\n\nPress r to retry.", error), Color::Red))
+    } else if positions_state.positions.is_empty() {
+        Some(("No open positions.".to_string(), theme.dim))
+    } else {
+        None
+    };
+    if let Some((text, color)) = status_text {
+        let paragraph = Paragraph::new(text)
+            .block(
+                Block::default()
+                    .title(" Positions ")
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded),
+            )
+            .alignment(Alignment::Center)
+            .style(Style::default().fg(color));
+        f.render_widget(paragraph, area);
+        return;
+    }
+
+    // Calculate visible rows and apply scroll
+    let visible_height = (area.height as usize).saturating_sub(3); // -3 for borders and header
+    let total_rows = positions_state.positions.len();
+    let scroll = positions_state
+        .scroll
+        .min(total_rows.saturating_sub(visible_height.max(1)));
+
+    let rows: Vec<Row> = positions_state
+        .positions
+        .iter()
+        .enumerate()
+        .skip(scroll)
+        .take(visible_height)
+        .map(|(idx, position)| {
+            let outcome_style = if position.outcome == "Yes" {
+                Style::default().fg(Color::Green)
+            } else {
+                Style::default().fg(Color::Red)
+            };
+            let (pnl_str, pnl_color) = format_pnl(position.unrealized_pnl, theme);
+
+            // Alternating row colors (zebra striping) for better readability
+            let bg_color = if idx % 2 == 0 {
+                Color::Reset
+            } else {
+                theme.stripe_bg
+            };
+
+            Row::new(vec![
+                Cell::from(truncate(&position.market_name, 60)),
+                Cell::from(position.outcome.clone()).style(outcome_style),
+                Cell::from(format!("{:.2}", position.shares)),
+                Cell::from(format_price_cents(position.avg_price)),
+                Cell::from(format_price_cents(position.current_price)),
+                Cell::from(format!("${:.2}", position.current_value)),
+                Cell::from(pnl_str).style(Style::default().fg(pnl_color)),
+            ])
+            .style(Style::default().bg(bg_color))
+        })
+        .collect();
+
+    let total_pnl: f64 = positions_state
+        .positions
+        .iter()
+        .map(|p| p.unrealized_pnl)
+        .sum();
+    let (total_pnl_str, _) = format_pnl(total_pnl, theme);
+    let title = format!(
+        " Positions ({}) | P&L: {} | Sort: {}{} ",
+        total_rows,
+        total_pnl_str,
+        positions_state.sort_by.label(),
+        if positions_state.is_loading {
+            format!(" | {} Refreshing...", spinner_frame())
+        } else {
+            String::new()
+        }
+    );
+
+    let table = Table::new(rows, [
+        Constraint::Fill(1),    // Market (takes remaining space)
+        Constraint::Length(8),  // Outcome
+        Constraint::Length(10), // Shares
+        Constraint::Length(8),  // Avg price
+        Constraint::Length(8),  // Current price
+        Constraint::Length(10), // Value
+        Constraint::Length(10), // Unrealized P&L
+    ])
+    .header(
+        Row::new(vec![
+            "Market", "Outcome", "Shares", "Avg", "Current", "Value", "P&L",
+        ])
+        .style(
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        ),
+    )
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .title(title)
+            .border_style(Style::default().fg(Color::Yellow)),
+    )
+    .column_spacing(1)
+    .row_highlight_style(
+        Style::default()
+            .bg(theme.highlight_bg)
+            .add_modifier(Modifier::BOLD),
+    );
+
+    let mut table_state = TableState::default();
+    table_state.select(Some(positions_state.selected_index.saturating_sub(scroll)));
+    f.render_stateful_widget(table, area, &mut table_state);
+
+    // Render scrollbar if needed
+    if total_rows > visible_height {
+        let mut scrollbar_state = ScrollbarState::new(total_rows)
+            .position(scroll)
+            .viewport_content_length(visible_height);
+        f.render_stateful_widget(
+            Scrollbar::default().orientation(ScrollbarOrientation::VerticalRight),
+            area,
+            &mut scrollbar_state,
+        );
+    }
+}
@@ -0,0 +1,157 @@
+//! Color theme for the trending TUI
+//!
+//! The Dark preset can be customized with `theme.json` in the config directory, mapping color
+//! names to ratatui color strings, e.g. `{"muted": "gray", "stripe_bg": "#1e1e28"}`. Missing
+//! names keep their defaults, so a theme file only needs the colors it changes.
+
+use {
+    crate::trending_tui::state::ThemePreset,
+    ratatui::style::Color,
+    std::{collections::HashMap, path::PathBuf, str::FromStr},
+};
+
+/// Named colors used by the render functions
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Theme {
+    pub accent: Color,       // Focused borders and the selected row
+    pub positive: Color,     // Gains, volume, Yes/buy
+    pub negative: Color,     // Losses, closed events, No/sell
+    pub muted: Color,        // Inactive rows and secondary text
+    pub dim: Color,          // Placeholder text
+    pub text: Color,         // Regular text
+    pub info: Color,         // Market counts, liquidity
+    pub secondary: Color,    // Open interest and other alternate metrics
+    pub emphasis: Color,     // Favorites and competitiveness
+    pub highlight_bg: Color, // Background of the selected market
+    pub stripe_bg: Color,    // Background of odd rows (zebra striping)
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self {
+            accent: Color::Yellow,
+            positive: Color::Green,
+            negative: Color::Red,
+            muted: Color::DarkGray,
+            dim: Color::Gray,
+            text: Color::White,
+            info: Color::Cyan,
+            secondary: Color::LightBlue,
+            emphasis: Color::Magenta,
+            highlight_bg: Color::Rgb(60, 60, 80),
+            stripe_bg: Color::Rgb(30, 30, 40),
+        }
+    }
+}
+
+impl Theme {
+    /// Palette for light terminal backgrounds, where the dark preset's gray text and
+    /// near-black row stripes disappear
+    pub fn light() -> Self {
+        Self {
+            accent: Color::Blue,
+            positive: Color::Rgb(0, 130, 0),
+            negative: Color::Rgb(190, 0, 0),
+            muted: Color::Rgb(100, 100, 100),
+            dim: Color::Rgb(80, 80, 80),
+            text: Color::Black,
+            info: Color::Rgb(0, 110, 130),
+            secondary: Color::Rgb(0, 80, 170),
+            emphasis: Color::Rgb(150, 0, 150),
+            highlight_bg: Color::Rgb(200, 210, 235),
+            stripe_bg: Color::Rgb(235, 235, 240),
+        }
+    }
+
+    /// Bright colors without row striping, for readability over style
+    pub fn high_contrast() -> Self {
+        Self {
+            accent: Color::LightYellow,
+            positive: Color::LightGreen,
+            negative: Color::LightRed,
+            muted: Color::Gray,
+            dim: Color::White,
+            text: Color::White,
+            info: Color::LightCyan,
+            secondary: Color::LightBlue,
+            emphasis: Color::LightMagenta,
+            highlight_bg: Color::Blue,
+            stripe_bg: Color::Reset,
+        }
+    }
+
+    /// Colors for `preset`; `dark` is the (possibly customized) Dark theme
+    pub fn for_preset(preset: ThemePreset, dark: &Theme) -> Self {
+        match preset {
+            ThemePreset::Dark => *dark,
+            ThemePreset::Light => Self::light(),
+            ThemePreset::HighContrast => Self::high_contrast(),
+        }
+    }
+
+    /// Path of the theme file
+    fn path() -> PathBuf {
+        crate::auth::AuthConfig::config_dir().join("theme.json")
+    }
+
+    /// Load the theme file, falling back to the default theme when it is missing or invalid
+    pub fn load() -> Self {
+        let Ok(content) = std::fs::read_to_string(Self::path()) else {
+            return Self::default();
+        };
+        match Self::from_json(&content) {
+            Ok(theme) => theme,
+            Err(_e) => {
+                log_warn!("Ignoring theme file: {}", _e);
+                Self::default()
+            },
+        }
+    }
+
+    /// Parse a theme file's JSON, starting from the default theme
+    fn from_json(content: &str) -> Result<Self, String> {
+        let colors: HashMap<String, String> =
+            serde_json::from_str(content).map_err(|e| format!("invalid JSON: {}", e))?;
+        let mut theme = Self::default();
+        for (name, value) in colors {
+            let color = Color::from_str(&value)
+                .map_err(|_| format!("invalid color for {}: {:?}", name, value))?;
+            let slot = match name.as_str() {
+                "accent" => &mut theme.accent,
+                "positive" => &mut theme.positive,
+                "negative" => &mut theme.negative,
+                "muted" => &mut theme.muted,
+                "dim" => &mut theme.dim,
+                "text" => &mut theme.text,
+                "info" => &mut theme.info,
+                "secondary" => &mut theme.secondary,
+                "emphasis" => &mut theme.emphasis,
+                "highlight_bg" => &mut theme.highlight_bg,
+                "stripe_bg" => &mut theme.stripe_bg,
+                _ => return Err(format!("unknown color name: {}", name)),
+            };
+            *slot = color;
+        }
+        Ok(theme)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn overrides_only_the_listed_colors() {
+        let theme = Theme::from_json(r##"{"muted": "gray", "stripe_bg": "#1e1e28"}"##).unwrap();
+        assert_eq!(theme.muted, Color::Gray);
+        assert_eq!(theme.stripe_bg, Color::Rgb(30, 30, 40));
+        assert_eq!(theme.accent, Theme::default().accent);
+    }
+
+    #[test]
+    fn rejects_unknown_names_and_colors() {
+        assert!(Theme::from_json(r#"{"backgroud": "red"}"#).is_err());
+        assert!(Theme::from_json(r#"{"accent": "not-a-color"}"#).is_err());
+        assert!(Theme::from_json("[]").is_err());
+    }
+}
@@ -1,14 +1,20 @@
 //! Trades panel rendering functions
 
 use {
-    super::utils::truncate,
-    crate::trending_tui::state::{FocusedPanel, Trade, TrendingAppState},
+    super::{
+        theme::Theme,
+        utils::{format_volume, panel_key_hint, truncate},
+    },
+    crate::trending_tui::state::{
+        ConnectionState, EventTrades, FocusedPanel, Trade, TrendingAppState, whale_threshold,
+    },
     chrono::DateTime,
     polymarket_api::gamma::Event,
     ratatui::{
         Frame,
-        layout::{Alignment, Constraint, Rect},
+        layout::{Alignment, Constraint, Direction, Layout, Rect},
         style::{Color, Modifier, Style},
+        text::{Line, Span},
         widgets::{
             Block, BorderType, Borders, Cell, Paragraph, Row, Scrollbar, ScrollbarOrientation,
             ScrollbarState, Table,
@@ -16,24 +22,299 @@ use {
     },
 };
 
+/// Height of the buy/sell summary line shown above the trades table
+pub const TRADES_SUMMARY_HEIGHT: u16 = 1;
+
+/// Marker, row style and value cell style for a trade row, bolding whale trades
+fn whale_styles(theme: &Theme, is_whale: bool, bg_color: Color) -> (&'static str, Style, Style) {
+    let row_style = Style::default().bg(bg_color);
+    if is_whale {
+        (
+            "🐋",
+            row_style.add_modifier(Modifier::BOLD),
+            Style::default().fg(theme.emphasis),
+        )
+    } else {
+        ("", row_style, Style::default())
+    }
+}
+
+/// One trades table row: whale marker, time, side, outcome, price, shares, value, market, user.
+/// `idx` picks the zebra stripe.
+fn trade_row(
+    theme: &Theme,
+    trade: &Trade,
+    idx: usize,
+    whale_above: Option<f64>,
+    market_name: &str,
+) -> Row<'static> {
+    let time = DateTime::from_timestamp(trade.timestamp, 0)
+        .map(|dt| dt.format("%H:%M:%S").to_string())
+        .unwrap_or_else(|| "now".to_string());
+
+    let side_style = if trade.side == "BUY" {
+        Style::default().fg(theme.positive)
+    } else {
+        Style::default().fg(theme.negative)
+    };
+
+    let outcome_style = if trade.outcome == "Yes" {
+        Style::default().fg(theme.positive)
+    } else {
+        Style::default().fg(theme.negative)
+    };
+
+    let title_truncated = truncate(market_name, 30);
+    let user_display = if !trade.user.is_empty() {
+        &trade.user
+    } else if !trade.pseudonym.is_empty() {
+        &trade.pseudonym
+    } else {
+        "-"
+    };
+    let user_truncated = truncate(user_display, 15);
+
+    let bg_color = if idx.is_multiple_of(2) {
+        Color::Reset
+    } else {
+        theme.stripe_bg
+    };
+
+    // Whales stand out with a marker and a bold row
+    let is_whale = whale_above.is_some_and(|t| trade.total_value > t);
+    let (whale_marker, row_style, value_style) = whale_styles(theme, is_whale, bg_color);
+
+    Row::new(vec![
+        Cell::from(whale_marker),
+        Cell::from(time).style(Style::default().fg(theme.dim)),
+        Cell::from(trade.side.clone()).style(side_style),
+        Cell::from(trade.outcome.clone()).style(outcome_style),
+        Cell::from(format!("${:.4}", trade.price)),
+        Cell::from(format!("{:.2}", trade.shares)),
+        Cell::from(format!("${:.2}", trade.total_value)).style(value_style),
+        Cell::from(title_truncated),
+        Cell::from(user_truncated),
+    ])
+    .style(row_style)
+}
+
+/// Trades table with the column layout and header shared by the trades panels
+fn trades_table<'a>(theme: &Theme, rows: Vec<Row<'a>>) -> Table<'a> {
+    Table::new(rows, [
+        Constraint::Length(2),
+        Constraint::Length(9),
+        Constraint::Length(5),
+        Constraint::Length(4),
+        Constraint::Length(8),
+        Constraint::Length(9),
+        Constraint::Length(9),
+        Constraint::Fill(1),
+        Constraint::Length(12),
+    ])
+    .header(
+        Row::new(vec![
+            "", "Time", "Side", "Out", "Price", "Shares", "Value", "Market", "User",
+        ])
+        .style(
+            Style::default()
+                .fg(theme.accent)
+                .add_modifier(Modifier::BOLD),
+        ),
+    )
+}
+
+/// Halves of the trades area for the main panel and the split view's second panel, with the
+/// second panel's event. `None` when split view is off or follows the event already shown.
+pub fn split_trades_areas<'a>(
+    app: &'a TrendingAppState,
+    primary_slug: &str,
+    area: Rect,
+) -> Option<(Rect, Rect, &'a str)> {
+    let secondary = app
+        .secondary_watch_slug
+        .as_deref()
+        .filter(|slug| *slug != primary_slug)?;
+    let halves = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(area);
+    Some((halves[0], halves[1], secondary))
+}
+
+/// Render the split view's second trades panel for `slug` (bound with 'd'). It shows the
+/// newest trades first without scrolling, since focus and scroll belong to the main trades panel.
+pub fn render_secondary_trades(
+    f: &mut Frame,
+    app: &TrendingAppState,
+    theme: &Theme,
+    slug: &str,
+    area: Rect,
+) {
+    let trades = app.get_trades(slug);
+    let event_title = app
+        .event_cache
+        .get(slug)
+        .map_or(slug, |event| event.title.as_str());
+    let mut title = format!(
+        "Split: {} ({})",
+        truncate(
+            event_title,
+            (area.width as usize).saturating_sub(30).max(10)
+        ),
+        trades.len()
+    );
+    if let Some(event_trades) = app.trades.event_trades.get(slug)
+        && (event_trades.is_watching || event_trades.connection_state == ConnectionState::Error)
+    {
+        title.push_str(&format!(" ({})", event_trades.connection_state.label()));
+    }
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .title(title)
+        .title_bottom(Line::from(" d: close split ").left_aligned())
+        .border_style(Style::default().fg(theme.muted));
+
+    if trades.is_empty() {
+        let paragraph = Paragraph::new("Watching for trades...")
+            .block(block)
+            .alignment(Alignment::Center)
+            .style(Style::default().fg(theme.dim));
+        f.render_widget(paragraph, area);
+        return;
+    }
+
+    // Borders (2) + header (1)
+    let visible_height = (area.height as usize).saturating_sub(3);
+    let whale_above = whale_threshold(trades, app.trades.whale_threshold);
+    // Trades are stored newest-first
+    let rows: Vec<Row> = trades
+        .iter()
+        .enumerate()
+        .take(visible_height)
+        .map(|(idx, trade)| trade_row(theme, trade, idx, whale_above, &trade.title))
+        .collect();
+    f.render_widget(
+        trades_table(theme, rows).block(block).column_spacing(1),
+        area,
+    );
+}
+
+/// Render a one-line buy/sell volume summary for the loaded trades
+fn render_volume_summary(f: &mut Frame, theme: &Theme, event_trades: &EventTrades, area: Rect) {
+    let (buy_volume, sell_volume, buy_count, sell_count) = event_trades.volume_summary();
+
+    let ratio_span = if sell_volume > 0.0 {
+        let ratio = buy_volume / sell_volume;
+        let color = if ratio >= 1.0 {
+            theme.positive
+        } else {
+            theme.negative
+        };
+        Span::styled(
+            format!("{:.2}x", ratio),
+            Style::default().fg(color).add_modifier(Modifier::BOLD),
+        )
+    } else if buy_volume > 0.0 {
+        Span::styled(
+            "all buys",
+            Style::default()
+                .fg(theme.positive)
+                .add_modifier(Modifier::BOLD),
+        )
+    } else {
+        Span::styled("-", Style::default().fg(theme.muted))
+    };
+
+    // format_volume returns an empty string for zero
+    let volume_or_zero = |v: f64| {
+        let formatted = format_volume(v);
+        if formatted.is_empty() {
+            "$0".to_string()
+        } else {
+            formatted
+        }
+    };
+
+    let line = Line::from(vec![
+        Span::styled(" Buys ", Style::default().fg(theme.dim)),
+        Span::styled(
+            volume_or_zero(buy_volume),
+            Style::default().fg(theme.positive),
+        ),
+        Span::styled(
+            format!(" ({})", buy_count),
+            Style::default().fg(theme.muted),
+        ),
+        Span::styled("  Sells ", Style::default().fg(theme.dim)),
+        Span::styled(
+            volume_or_zero(sell_volume),
+            Style::default().fg(theme.negative),
+        ),
+        Span::styled(
+            format!(" ({})", sell_count),
+            Style::default().fg(theme.muted),
+        ),
+        Span::styled("  Buy/Sell ", Style::default().fg(theme.dim)),
+        ratio_span,
+    ]);
+    f.render_widget(Paragraph::new(line), area);
+}
+
+/// Connection state to show for an event's trades panel: shown while watching,
+/// and kept after a failure so the error stays visible
+pub fn connection_state(
+    app: &TrendingAppState,
+    event: &Event,
+    is_watching: bool,
+) -> Option<ConnectionState> {
+    let state = app.trades.event_trades.get(&event.slug)?.connection_state;
+    (is_watching || state == ConnectionState::Error).then_some(state)
+}
+
+/// Panel title with trade count and, while watching, the WebSocket connection state
+/// e.g. "Trades (12) (Connected)"
+fn trades_title(
+    app: &TrendingAppState,
+    count: usize,
+    connection: Option<ConnectionState>,
+    is_focused: bool,
+) -> String {
+    let mut title = format!("Trades ({})", count);
+    if let Some(state) = connection {
+        title.push_str(&format!(" ({})", state.label()));
+    }
+    if app.trades.oldest_first {
+        title.push_str(" (Oldest first)");
+    }
+    if is_focused {
+        title.push_str(" (Focused)");
+    }
+    title
+}
+
 /// Render the trades table with event context (for finding market names)
 pub fn render_trades_table(
     f: &mut Frame,
     app: &TrendingAppState,
+    theme: &Theme,
     trades: &[Trade],
     event: Option<&Event>,
     is_watching: bool,
+    connection: Option<ConnectionState>,
     area: Rect,
 ) {
     let is_focused = app.navigation.focused_panel == FocusedPanel::Trades;
     let block_style = if is_focused {
-        Style::default().fg(Color::Yellow)
+        Style::default().fg(theme.accent)
     } else {
         Style::default()
     };
 
     if trades.is_empty() {
-        let status_text = if is_watching {
+        let status_text = if connection == Some(ConnectionState::Error) {
+            "Connection failed. Press Enter to retry."
+        } else if is_watching {
             "Watching for trades... (Press Enter to stop)"
         } else {
             "Not watching. Press Enter to start watching this event."
@@ -43,19 +324,38 @@ pub fn render_trades_table(
                 Block::default()
                     .borders(Borders::ALL)
                     .border_type(BorderType::Rounded)
-                    .title(if is_focused {
-                        format!("Trades ({}) (Focused)", trades.len())
-                    } else {
-                        format!("Trades ({})", trades.len())
-                    })
+                    .title(trades_title(app, trades.len(), connection, is_focused))
+                    .title_bottom(panel_key_hint(
+                        FocusedPanel::Trades,
+                        is_focused,
+                        area.width,
+                        0,
+                        theme,
+                    ))
                     .border_style(block_style),
             )
             .alignment(Alignment::Center)
-            .style(Style::default().fg(Color::Gray));
+            .style(Style::default().fg(theme.dim));
         f.render_widget(paragraph, area);
         return;
     }
 
+    // Buy/sell summary line above the table
+    let area = match event.and_then(|e| app.trades.event_trades.get(&e.slug)) {
+        Some(event_trades) => {
+            let chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([
+                    Constraint::Length(TRADES_SUMMARY_HEIGHT), // Summary line
+                    Constraint::Min(0),                        // Trades table
+                ])
+                .split(area);
+            render_volume_summary(f, theme, event_trades, chunks[0]);
+            chunks[1]
+        },
+        None => area,
+    };
+
     // Calculate visible rows and apply scroll
     let visible_height = (area.height as usize).saturating_sub(3); // -3 for header
     let total_rows = trades.len();
@@ -64,8 +364,11 @@ pub fn render_trades_table(
         .trades
         .min(total_rows.saturating_sub(visible_height.max(1)));
 
-    let rows: Vec<Row> = trades
-        .iter()
+    let whale_above = whale_threshold(trades, app.trades.whale_threshold);
+    let rows: Vec<Row> = app
+        .trades
+        .display_order(trades)
+        .into_iter()
         .enumerate()
         .skip(scroll)
         .take(visible_height)
@@ -75,15 +378,15 @@ pub fn render_trades_table(
                 .unwrap_or_else(|| "now".to_string());
 
             let side_style = if trade.side == "BUY" {
-                Style::default().fg(Color::Green)
+                Style::default().fg(theme.positive)
             } else {
-                Style::default().fg(Color::Red)
+                Style::default().fg(theme.negative)
             };
 
             let outcome_style = if trade.outcome == "Yes" {
-                Style::default().fg(Color::Green)
+                Style::default().fg(theme.positive)
             } else {
-                Style::default().fg(Color::Red)
+                Style::default().fg(theme.negative)
             };
 
             // Find the market by asset_id and use short name if available
@@ -120,24 +423,30 @@ pub fn render_trades_table(
             let bg_color = if idx % 2 == 0 {
                 Color::Reset
             } else {
-                Color::Rgb(30, 30, 40)
+                theme.stripe_bg
             };
 
+            // Whales stand out with a marker and a bold row
+            let is_whale = whale_above.is_some_and(|t| trade.total_value > t);
+            let (whale_marker, row_style, value_style) = whale_styles(theme, is_whale, bg_color);
+
             Row::new(vec![
-                Cell::from(time).style(Style::default().fg(Color::Gray)),
+                Cell::from(whale_marker),
+                Cell::from(time).style(Style::default().fg(theme.dim)),
                 Cell::from(trade.side.clone()).style(side_style),
                 Cell::from(trade.outcome.clone()).style(outcome_style),
                 Cell::from(format!("${:.4}", trade.price)),
                 Cell::from(format!("{:.2}", trade.shares)),
-                Cell::from(format!("${:.2}", trade.total_value)),
+                Cell::from(format!("${:.2}", trade.total_value)).style(value_style),
                 Cell::from(title_truncated),
                 Cell::from(user_truncated),
             ])
-            .style(Style::default().bg(bg_color))
+            .style(row_style)
         })
         .collect();
 
     let table = Table::new(rows, [
+        Constraint::Length(2),  // Whale marker
         Constraint::Length(9),  // Time
         Constraint::Length(5),  // Side
         Constraint::Length(4),  // Outcome
@@ -149,11 +458,11 @@ pub fn render_trades_table(
     ])
     .header(
         Row::new(vec![
-            "Time", "Side", "Out", "Price", "Shares", "Value", "Market", "User",
+            "", "Time", "Side", "Out", "Price", "Shares", "Value", "Market", "User",
         ])
         .style(
             Style::default()
-                .fg(Color::Yellow)
+                .fg(theme.accent)
                 .add_modifier(Modifier::BOLD),
         ),
     )
@@ -161,17 +470,20 @@ pub fn render_trades_table(
         Block::default()
             .borders(Borders::ALL)
             .border_type(BorderType::Rounded)
-            .title(if is_focused {
-                format!("Trades ({}) (Focused)", trades.len())
-            } else {
-                format!("Trades ({})", trades.len())
-            })
+            .title(trades_title(app, trades.len(), connection, is_focused))
+            .title_bottom(panel_key_hint(
+                FocusedPanel::Trades,
+                is_focused,
+                area.width,
+                0,
+                theme,
+            ))
             .border_style(block_style),
     )
     .column_spacing(1)
     .row_highlight_style(
         Style::default()
-            .bg(Color::Rgb(60, 60, 80))
+            .bg(theme.highlight_bg)
             .add_modifier(Modifier::BOLD),
     );
 
@@ -205,19 +517,23 @@ pub fn render_trades_table(
 pub fn render_trades_panel(
     f: &mut Frame,
     app: &TrendingAppState,
+    theme: &Theme,
     trades: &[Trade],
     is_watching: bool,
+    connection: Option<ConnectionState>,
     area: Rect,
 ) {
     let is_focused = app.navigation.focused_panel == FocusedPanel::Trades;
     let block_style = if is_focused {
-        Style::default().fg(Color::Yellow)
+        Style::default().fg(theme.accent)
     } else {
         Style::default()
     };
 
     if trades.is_empty() {
-        let status_text = if is_watching {
+        let status_text = if connection == Some(ConnectionState::Error) {
+            "Connection failed. Press Enter to retry."
+        } else if is_watching {
             "Watching for trades... (Press Enter to stop)"
         } else {
             "Not watching. Press Enter to start watching this event."
@@ -227,15 +543,18 @@ pub fn render_trades_panel(
                 Block::default()
                     .borders(Borders::ALL)
                     .border_type(BorderType::Rounded)
-                    .title(if is_focused {
-                        format!("Trades ({}) (Focused)", trades.len())
-                    } else {
-                        format!("Trades ({})", trades.len())
-                    })
+                    .title(trades_title(app, trades.len(), connection, is_focused))
+                    .title_bottom(panel_key_hint(
+                        FocusedPanel::Trades,
+                        is_focused,
+                        area.width,
+                        0,
+                        theme,
+                    ))
                     .border_style(block_style),
             )
             .alignment(Alignment::Center)
-            .style(Style::default().fg(Color::Gray));
+            .style(Style::default().fg(theme.dim));
         f.render_widget(paragraph, area);
     } else {
         // Calculate visible rows and apply scroll
@@ -246,90 +565,33 @@ pub fn render_trades_panel(
             .trades
             .min(total_rows.saturating_sub(visible_height.max(1)));
 
-        let rows: Vec<Row> = trades
-            .iter()
+        let whale_above = whale_threshold(trades, app.trades.whale_threshold);
+        let rows: Vec<Row> = app
+            .trades
+            .display_order(trades)
+            .into_iter()
             .enumerate()
             .skip(scroll)
             .take(visible_height)
-            .map(|(idx, trade)| {
-                let time = DateTime::from_timestamp(trade.timestamp, 0)
-                    .map(|dt| dt.format("%H:%M:%S").to_string())
-                    .unwrap_or_else(|| "now".to_string());
-
-                let side_style = if trade.side == "BUY" {
-                    Style::default().fg(Color::Green)
-                } else {
-                    Style::default().fg(Color::Red)
-                };
-
-                let outcome_style = if trade.outcome == "Yes" {
-                    Style::default().fg(Color::Green)
-                } else {
-                    Style::default().fg(Color::Red)
-                };
-
-                let title_truncated = truncate(&trade.title, 30);
-                let user_display = if !trade.user.is_empty() {
-                    &trade.user
-                } else if !trade.pseudonym.is_empty() {
-                    &trade.pseudonym
-                } else {
-                    "-"
-                };
-                let user_truncated = truncate(user_display, 15);
-
-                let bg_color = if idx % 2 == 0 {
-                    Color::Reset
-                } else {
-                    Color::Rgb(30, 30, 40)
-                };
-
-                Row::new(vec![
-                    Cell::from(time).style(Style::default().fg(Color::Gray)),
-                    Cell::from(trade.side.clone()).style(side_style),
-                    Cell::from(trade.outcome.clone()).style(outcome_style),
-                    Cell::from(format!("${:.4}", trade.price)),
-                    Cell::from(format!("{:.2}", trade.shares)),
-                    Cell::from(format!("${:.2}", trade.total_value)),
-                    Cell::from(title_truncated),
-                    Cell::from(user_truncated),
-                ])
-                .style(Style::default().bg(bg_color))
-            })
+            .map(|(idx, trade)| trade_row(theme, trade, idx, whale_above, &trade.title))
             .collect();
 
-        let table = Table::new(rows, [
-            Constraint::Length(9),
-            Constraint::Length(5),
-            Constraint::Length(4),
-            Constraint::Length(8),
-            Constraint::Length(9),
-            Constraint::Length(9),
-            Constraint::Fill(1),
-            Constraint::Length(12),
-        ])
-        .header(
-            Row::new(vec![
-                "Time", "Side", "Out", "Price", "Shares", "Value", "Market", "User",
-            ])
-            .style(
-                Style::default()
-                    .fg(Color::Yellow)
-                    .add_modifier(Modifier::BOLD),
-            ),
-        )
-        .block(
-            Block::default()
-                .borders(Borders::ALL)
-                .border_type(BorderType::Rounded)
-                .title(if is_focused {
-                    format!("Trades ({}) (Focused)", trades.len())
-                } else {
-                    format!("Trades ({})", trades.len())
-                })
-                .border_style(block_style),
-        )
-        .column_spacing(1);
+        let table = trades_table(theme, rows)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+                    .title(trades_title(app, trades.len(), connection, is_focused))
+                    .title_bottom(panel_key_hint(
+                        FocusedPanel::Trades,
+                        is_focused,
+                        area.width,
+                        0,
+                        theme,
+                    ))
+                    .border_style(block_style),
+            )
+            .column_spacing(1);
 
         f.render_widget(table, area);
 
@@ -1,6 +1,8 @@
 //! Utility functions for rendering
 
 use {
+    super::theme::Theme,
+    crate::trending_tui::state::{FocusedPanel, parse_utc_timestamp},
     chrono::{DateTime, Utc},
     polymarket_api::gamma::Event,
     ratatui::{
@@ -57,6 +59,33 @@ pub fn format_price_cents(price: f64) -> String {
     }
 }
 
+/// Format a dollar amount without hiding sub-cent precision
+/// Always shows at least 2 decimals; extra digits are kept only when non-zero
+/// Examples: 62.5 -> "$62.50", 12.3456 -> "$12.3456", 1.0 / 3.0 -> "$0.333333"
+pub fn format_usd_exact(value: f64) -> String {
+    let formatted = format!("{:.6}", value.abs());
+    let (int_part, frac_part) = formatted.split_once('.').unwrap_or((&formatted, ""));
+    let mut frac = frac_part.trim_end_matches('0').to_string();
+    while frac.len() < 2 {
+        frac.push('0');
+    }
+    let sign = if value < 0.0 && formatted.chars().any(|c| c != '0' && c != '.') {
+        "-"
+    } else {
+        ""
+    };
+    format!("{}${}.{}", sign, int_part, frac)
+}
+
+/// Braille spinner frames, advanced every 100ms while requests are in flight
+const SPINNER_FRAMES: [&str; 10] = ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
+
+/// Current spinner frame, derived from the clock so every spinner on screen stays in step
+pub fn spinner_frame() -> &'static str {
+    let tick = chrono::Utc::now().timestamp_millis() / 100;
+    SPINNER_FRAMES[tick.rem_euclid(SPINNER_FRAMES.len() as i64) as usize]
+}
+
 /// Format a volume/liquidity value with appropriate units (K, M)
 pub fn format_volume(value: f64) -> String {
     if value >= 1_000_000.0 {
@@ -70,6 +99,69 @@ pub fn format_volume(value: f64) -> String {
     }
 }
 
+/// Format a share count compactly (e.g. 950 -> "950", 12_345 -> "12.3K", 2_500_000 -> "2.5M")
+pub fn format_size_short(size: f64) -> String {
+    if size >= 1_000_000.0 {
+        format!("{:.1}M", size / 1_000_000.0)
+    } else if size >= 1_000.0 {
+        format!("{:.1}K", size / 1_000.0)
+    } else {
+        format!("{:.0}", size)
+    }
+}
+
+/// Render values as a sparkline of block characters, one span per value, colored like a
+/// heatmap (gray for no activity, then green, yellow, and red as values near the peak)
+pub fn sparkline_spans(values: &[f64], theme: &Theme) -> Vec<Span<'static>> {
+    const BLOCKS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+    let max = values.iter().copied().fold(0.0_f64, f64::max);
+    values
+        .iter()
+        .map(|&value| {
+            if value <= 0.0 || max <= 0.0 {
+                return Span::styled("·", Style::default().fg(theme.muted));
+            }
+            let ratio = value / max;
+            let idx = ((ratio * (BLOCKS.len() - 1) as f64).round() as usize).min(BLOCKS.len() - 1);
+            let color = if ratio >= 0.75 {
+                Color::Red
+            } else if ratio >= 0.4 {
+                Color::Yellow
+            } else {
+                Color::Green
+            };
+            Span::styled(BLOCKS[idx].to_string(), Style::default().fg(color))
+        })
+        .collect()
+}
+
+/// Render prices as a sparkline scaled between their low and high, green when the last price
+/// is at or above the first and red otherwise
+pub fn price_sparkline_spans(prices: &[f64]) -> Vec<Span<'static>> {
+    const BLOCKS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+    let low = prices.iter().copied().fold(f64::INFINITY, f64::min);
+    let high = prices.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    let rising = prices.last() >= prices.first();
+    let color = if rising {
+        Color::Green
+    } else {
+        Color::Red
+    };
+    let line: String = prices
+        .iter()
+        .map(|&price| {
+            // A flat series sits in the middle row
+            let ratio = if high > low {
+                (price - low) / (high - low)
+            } else {
+                0.5
+            };
+            BLOCKS[((ratio * (BLOCKS.len() - 1) as f64).round() as usize).min(BLOCKS.len() - 1)]
+        })
+        .collect();
+    vec![Span::styled(line, Style::default().fg(color))]
+}
+
 /// Truncate a string to a maximum number of characters
 pub fn truncate(s: &str, max_chars: usize) -> String {
     let char_count = s.chars().count();
@@ -83,10 +175,10 @@ pub fn truncate(s: &str, max_chars: usize) -> String {
 
 /// Format a profit/loss value with appropriate sign and color
 /// Returns (formatted_string, color)
-pub fn format_pnl(value: f64) -> (String, Color) {
+pub fn format_pnl(value: f64, theme: &Theme) -> (String, Color) {
     // Treat near-zero values as zero to avoid -$0.00
     if value.abs() < 0.005 {
-        ("$0.00".to_string(), Color::DarkGray)
+        ("$0.00".to_string(), theme.muted)
     } else if value > 0.0 {
         (format!("+${:.2}", value), Color::Green)
     } else {
@@ -94,6 +186,27 @@ pub fn format_pnl(value: f64) -> (String, Color) {
     }
 }
 
+/// Key hint for the bottom border of `panel` while it has focus, cut to the width left
+/// beside `reserved` columns (e.g. a right-aligned "3 of 50─"). Empty when it doesn't fit.
+pub fn panel_key_hint(
+    panel: FocusedPanel,
+    is_focused: bool,
+    panel_width: u16,
+    reserved: usize,
+    theme: &Theme,
+) -> Line<'static> {
+    // Borders (2), padding around the hint (2), and a gap before the reserved text (1)
+    let available = (panel_width as usize).saturating_sub(5 + reserved);
+    if !is_focused || available < 8 {
+        return Line::default();
+    }
+    Line::from(Span::styled(
+        format!(" {} ", truncate_to_width(panel.border_hint(), available)),
+        Style::default().fg(theme.muted),
+    ))
+    .left_aligned()
+}
+
 /// Truncate a string to fit within a maximum display width (not byte length).
 /// This properly handles Unicode characters that may have different display widths.
 pub fn truncate_to_width(s: &str, max_width: usize) -> String {
@@ -197,6 +310,7 @@ pub fn centered_rect_fixed_width(width: u16, percent_y: u16, r: Rect) -> Rect {
 /// Returns the cursor position if the field should show a cursor
 pub fn render_search_input(
     f: &mut Frame,
+    theme: &Theme,
     area: Rect,
     query: &str,
     title: &str,
@@ -228,14 +342,11 @@ pub fn render_search_input(
     // Determine display text
     let (display_text, text_style) = if query.is_empty() {
         // Show placeholder with dark background
-        (
-            placeholder.to_string(),
-            Style::default().fg(Color::DarkGray),
-        )
+        (placeholder.to_string(), Style::default().fg(theme.muted))
     } else if is_loading {
         // Show query with loading indicator
         (
-            format!("{} (searching...)", query),
+            format!("{} {} searching...", query, spinner_frame()),
             Style::default().fg(Color::Cyan).bold(),
         )
     } else {
@@ -247,7 +358,7 @@ pub fn render_search_input(
     let padded_text = format!("{:<width$}", display_text, width = inner_width as usize);
 
     // Use background color to make input field visible
-    let input_para = Paragraph::new(padded_text).style(text_style.bg(Color::Rgb(40, 40, 40)));
+    let input_para = Paragraph::new(padded_text).style(text_style.bg(theme.stripe_bg));
     f.render_widget(input_para, input_area);
 
     // Set cursor position at end of query text
@@ -263,6 +374,67 @@ pub fn render_search_input(
     }
 }
 
+/// Format how long ago `time` was, relative to `now` (e.g. "5m ago", "2h ago", "3d ago")
+pub fn format_age(time: DateTime<Utc>, now: DateTime<Utc>) -> String {
+    let elapsed = now.signed_duration_since(time);
+    if elapsed.num_minutes() < 1 {
+        "just now".to_string()
+    } else if elapsed.num_hours() < 1 {
+        format!("{}m ago", elapsed.num_minutes())
+    } else if elapsed.num_days() < 1 {
+        format!("{}h ago", elapsed.num_hours())
+    } else if elapsed.num_days() < 60 {
+        format!("{}d ago", elapsed.num_days())
+    } else {
+        format!("{}mo ago", elapsed.num_days() / 30)
+    }
+}
+
+/// What an event's competitive score means, as explained in the help popup
+pub const COMPETITIVE_DESCRIPTION: &str = "Score 0-100% (closer to 50/50 = more competitive)";
+
+/// Color for a competitive score (0-1): the closer the odds are to 50/50, the brighter
+pub fn competitive_color(score: f64, theme: &Theme) -> Color {
+    if score >= 0.9 {
+        theme.emphasis
+    } else if score >= 0.6 {
+        theme.accent
+    } else {
+        theme.muted
+    }
+}
+
+/// How soon an event is due to resolve
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResolutionWindow {
+    Within24Hours,
+    Within7Days,
+    Later,
+}
+
+impl ResolutionWindow {
+    pub fn color(self, theme: &Theme) -> Color {
+        match self {
+            ResolutionWindow::Within24Hours => Color::Red,
+            ResolutionWindow::Within7Days => Color::Yellow,
+            ResolutionWindow::Later => theme.muted,
+        }
+    }
+}
+
+/// Bucket an end date by time remaining from `now`. Past end dates count as within 24 hours,
+/// since those events are awaiting resolution. Returns `None` without a parseable end date.
+pub fn resolution_window(end_date: Option<&str>, now: DateTime<Utc>) -> Option<ResolutionWindow> {
+    let remaining = parse_utc_timestamp(end_date?)?.signed_duration_since(now);
+    Some(if remaining < chrono::Duration::hours(24) {
+        ResolutionWindow::Within24Hours
+    } else if remaining < chrono::Duration::days(7) {
+        ResolutionWindow::Within7Days
+    } else {
+        ResolutionWindow::Later
+    })
+}
+
 /// Shared function to build event info lines for display
 /// Used by both Events tab and Yield tab to show consistent event details
 pub fn build_event_info_lines(
@@ -271,6 +443,7 @@ pub fn build_event_info_lines(
     trade_count_display: &str,
     trade_label: &str,
     area_width: u16,
+    theme: &Theme,
 ) -> Vec<Line<'static>> {
     // Calculate total volume from all markets
     let total_volume: f64 = event
@@ -282,12 +455,8 @@ pub fn build_event_info_lines(
     // Format end date with relative time
     let end_date_str = event
         .end_date
-        .as_ref()
-        .and_then(|date_str| {
-            DateTime::parse_from_rfc3339(date_str)
-                .ok()
-                .map(|dt| dt.with_timezone(&Utc))
-        })
+        .as_deref()
+        .and_then(parse_utc_timestamp)
         .map(|dt| {
             let now = Utc::now();
             let duration = dt.signed_duration_since(now);
@@ -343,7 +512,7 @@ pub fn build_event_info_lines(
                     Color::Red
                 }),
             ),
-            Span::styled(" | ", Style::default().fg(Color::Gray)),
+            Span::styled(" | ", Style::default().fg(theme.dim)),
             Span::styled(
                 if event.closed {
                     "Closed"
@@ -356,7 +525,7 @@ pub fn build_event_info_lines(
                     Color::Green
                 }),
             ),
-            Span::styled(" | ", Style::default().fg(Color::Gray)),
+            Span::styled(" | ", Style::default().fg(theme.dim)),
             Span::styled(
                 if is_watching {
                     "Watching"
@@ -366,7 +535,7 @@ pub fn build_event_info_lines(
                 Style::default().fg(if is_watching {
                     Color::Red
                 } else {
-                    Color::Gray
+                    theme.dim
                 }),
             ),
         ]),
@@ -384,7 +553,7 @@ pub fn build_event_info_lines(
                     .fg(Color::Green)
                     .add_modifier(Modifier::BOLD),
             ),
-            Span::styled(" | ", Style::default().fg(Color::Gray)),
+            Span::styled(" | ", Style::default().fg(theme.dim)),
             Span::styled(
                 format!("{}: ", trade_label),
                 Style::default().fg(Color::Yellow).bold(),
@@ -398,7 +567,7 @@ pub fn build_event_info_lines(
                 } else if is_watching {
                     Color::Cyan
                 } else {
-                    Color::Gray
+                    theme.dim
                 }),
             ),
         ]),
@@ -436,3 +605,109 @@ pub fn build_event_info_lines(
 
     lines
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn now() -> DateTime<Utc> {
+        parse_utc_timestamp("2025-06-01T12:00:00Z").unwrap()
+    }
+
+    #[test]
+    fn parse_utc_timestamp_handles_offsets_and_garbage() {
+        assert_eq!(
+            parse_utc_timestamp("2025-06-01T14:00:00+02:00"),
+            Some(now())
+        );
+        assert_eq!(parse_utc_timestamp("not a date"), None);
+        assert_eq!(parse_utc_timestamp(""), None);
+    }
+
+    #[test]
+    fn format_age_picks_the_largest_sensible_unit() {
+        let ago = |secs: i64| format_age(now() - chrono::Duration::seconds(secs), now());
+        assert_eq!(ago(30), "just now");
+        assert_eq!(ago(5 * 60), "5m ago");
+        assert_eq!(ago(2 * 3600 + 59 * 60), "2h ago");
+        assert_eq!(ago(3 * 86400), "3d ago");
+        assert_eq!(ago(90 * 86400), "3mo ago");
+        // Clock skew can put a creation date slightly in the future
+        assert_eq!(ago(-120), "just now");
+    }
+
+    #[test]
+    fn resolution_window_buckets_by_time_remaining() {
+        assert_eq!(
+            resolution_window(Some("2025-06-01T18:00:00Z"), now()),
+            Some(ResolutionWindow::Within24Hours)
+        );
+        assert_eq!(
+            resolution_window(Some("2025-06-02T12:00:00Z"), now()),
+            Some(ResolutionWindow::Within7Days)
+        );
+        assert_eq!(
+            resolution_window(Some("2025-06-08T11:59:59Z"), now()),
+            Some(ResolutionWindow::Within7Days)
+        );
+        assert_eq!(
+            resolution_window(Some("2025-06-08T12:00:00Z"), now()),
+            Some(ResolutionWindow::Later)
+        );
+    }
+
+    #[test]
+    fn resolution_window_treats_past_end_dates_as_imminent() {
+        assert_eq!(
+            resolution_window(Some("2025-05-30T00:00:00Z"), now()),
+            Some(ResolutionWindow::Within24Hours)
+        );
+    }
+
+    #[test]
+    fn resolution_window_without_end_date_is_none() {
+        assert_eq!(resolution_window(None, now()), None);
+        assert_eq!(resolution_window(Some("soon"), now()), None);
+    }
+
+    #[test]
+    fn price_sparkline_scales_between_low_and_high() {
+        let spans = price_sparkline_spans(&[0.40, 0.45, 0.50]);
+        assert_eq!(spans[0].content, "▁▅█");
+        assert_eq!(spans[0].style.fg, Some(Color::Green));
+
+        let flat = price_sparkline_spans(&[0.5, 0.5]);
+        assert_eq!(flat[0].content, "▅▅");
+    }
+
+    #[test]
+    fn format_size_short_abbreviates_thousands_and_millions() {
+        assert_eq!(format_size_short(950.4), "950");
+        assert_eq!(format_size_short(12_345.0), "12.3K");
+        assert_eq!(format_size_short(2_500_000.0), "2.5M");
+    }
+
+    #[test]
+    fn panel_key_hint_fits_beside_reserved_text() {
+        let hint = panel_key_hint(FocusedPanel::Markets, true, 30, 8, &Theme::default());
+        assert_eq!(hint.width(), 30 - 2 - 8 - 1);
+        assert!(hint.to_string().ends_with("… "));
+
+        assert_eq!(
+            panel_key_hint(FocusedPanel::Markets, false, 80, 0, &Theme::default()).width(),
+            0
+        );
+        assert_eq!(
+            panel_key_hint(FocusedPanel::Markets, true, 12, 0, &Theme::default()).width(),
+            0
+        );
+    }
+
+    #[test]
+    fn competitive_color_brightens_toward_even_odds() {
+        let theme = Theme::default();
+        assert_eq!(competitive_color(0.97, &theme), theme.emphasis);
+        assert_eq!(competitive_color(0.75, &theme), theme.accent);
+        assert_eq!(competitive_color(0.2, &theme), theme.muted);
+    }
+}
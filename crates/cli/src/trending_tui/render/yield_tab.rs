@@ -1,8 +1,11 @@
 //! Yield tab rendering functions
 
 use {
-    super::utils::{format_price_cents, truncate},
-    crate::trending_tui::state::{FocusedPanel, TrendingAppState},
+    super::{
+        theme::Theme,
+        utils::{format_price_cents, format_volume, spinner_frame, truncate},
+    },
+    crate::trending_tui::state::{FocusedPanel, TrendingAppState, YieldViewMode},
     chrono::{DateTime, Utc},
     ratatui::{
         Frame,
@@ -19,7 +22,7 @@ use {
 // Re-use functions from sibling modules
 use super::utils::{build_event_info_lines, render_search_input};
 
-pub fn render_yield_tab(f: &mut Frame, app: &TrendingAppState, area: Rect) {
+pub fn render_yield_tab(f: &mut Frame, app: &TrendingAppState, theme: &Theme, area: Rect) {
     let yield_state = &app.yield_state;
 
     // If searching, add a search input area at the top
@@ -35,6 +38,7 @@ pub fn render_yield_tab(f: &mut Frame, app: &TrendingAppState, area: Rect) {
         // Render search input with proper styling
         render_search_input(
             f,
+            theme,
             chunks[0],
             &yield_state.search_query,
             "Search (Esc to close)",
@@ -52,13 +56,12 @@ pub fn render_yield_tab(f: &mut Frame, app: &TrendingAppState, area: Rect) {
             ])
             .split(chunks[1]);
 
-        // Show search results if available, otherwise show normal yield list
-        if !yield_state.search_results.is_empty() || !yield_state.last_searched_query.is_empty() {
-            render_yield_search_results(f, app, main_chunks[0]);
-            render_yield_search_details(f, app, main_chunks[1]);
+        if yield_state.view_mode == YieldViewMode::SearchResults {
+            render_yield_search_results(f, app, theme, main_chunks[0]);
+            render_yield_search_details(f, app, theme, main_chunks[1]);
         } else {
-            render_yield_list(f, app, main_chunks[0]);
-            render_yield_details(f, app, main_chunks[1]);
+            render_yield_list(f, app, theme, main_chunks[0]);
+            render_yield_details(f, app, theme, main_chunks[1]);
         }
     // If filtering, add a filter input area at the top
     } else if yield_state.is_filtering {
@@ -73,6 +76,7 @@ pub fn render_yield_tab(f: &mut Frame, app: &TrendingAppState, area: Rect) {
         // Render filter input with proper styling
         render_search_input(
             f,
+            theme,
             chunks[0],
             &yield_state.filter_query,
             "Filter (Esc to close)",
@@ -90,8 +94,8 @@ pub fn render_yield_tab(f: &mut Frame, app: &TrendingAppState, area: Rect) {
             ])
             .split(chunks[1]);
 
-        render_yield_list(f, app, main_chunks[0]);
-        render_yield_details(f, app, main_chunks[1]);
+        render_yield_list(f, app, theme, main_chunks[0]);
+        render_yield_details(f, app, theme, main_chunks[1]);
     } else {
         // Normal mode: Split into list on left and details on right
         let chunks = Layout::default()
@@ -102,18 +106,18 @@ pub fn render_yield_tab(f: &mut Frame, app: &TrendingAppState, area: Rect) {
             ])
             .split(area);
 
-        // Show search results if available (even when search input is hidden), otherwise show normal yield list
-        if !yield_state.search_results.is_empty() || !yield_state.last_searched_query.is_empty() {
-            render_yield_search_results(f, app, chunks[0]);
-            render_yield_search_details(f, app, chunks[1]);
+        // Search results stay visible after the search input is hidden, until toggled away
+        if yield_state.view_mode == YieldViewMode::SearchResults {
+            render_yield_search_results(f, app, theme, chunks[0]);
+            render_yield_search_details(f, app, theme, chunks[1]);
         } else {
-            render_yield_list(f, app, chunks[0]);
-            render_yield_details(f, app, chunks[1]);
+            render_yield_list(f, app, theme, chunks[0]);
+            render_yield_details(f, app, theme, chunks[1]);
         }
     }
 }
 
-fn render_yield_list(f: &mut Frame, app: &TrendingAppState, area: Rect) {
+fn render_yield_list(f: &mut Frame, app: &TrendingAppState, theme: &Theme, area: Rect) {
     let yield_state = &app.yield_state;
 
     if yield_state.is_loading {
@@ -122,7 +126,7 @@ fn render_yield_list(f: &mut Frame, app: &TrendingAppState, area: Rect) {
                 Block::default()
                     .borders(Borders::ALL)
                     .border_type(BorderType::Rounded)
-                    .title("Yield Opportunities"),
+                    .title(format!("Yield Opportunities {} ", spinner_frame())),
             )
             .alignment(Alignment::Center)
             .style(Style::default().fg(Color::Yellow));
@@ -139,7 +143,7 @@ fn render_yield_list(f: &mut Frame, app: &TrendingAppState, area: Rect) {
                     .title("Yield Opportunities"),
             )
             .alignment(Alignment::Center)
-            .style(Style::default().fg(Color::Gray));
+            .style(Style::default().fg(theme.dim));
         f.render_widget(empty, area);
         return;
     }
@@ -148,18 +152,26 @@ fn render_yield_list(f: &mut Frame, app: &TrendingAppState, area: Rect) {
     let filtered = yield_state.filtered_opportunities();
 
     if filtered.is_empty() {
-        let empty = Paragraph::new(format!(
-            "No matches for '{}'\nPress Esc to clear filter.",
-            yield_state.filter_query
-        ))
-        .block(
-            Block::default()
-                .borders(Borders::ALL)
-                .border_type(BorderType::Rounded)
-                .title("Yield Opportunities (filtered)"),
-        )
-        .alignment(Alignment::Center)
-        .style(Style::default().fg(Color::Gray));
+        let message = if yield_state.filter_query.is_empty() {
+            format!(
+                "No opportunities with 24h volume >= {}\nPress m to change the minimum.",
+                format_volume(yield_state.min_volume)
+            )
+        } else {
+            format!(
+                "No matches for '{}'\nPress Esc to clear filter.",
+                yield_state.filter_query
+            )
+        };
+        let empty = Paragraph::new(message)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+                    .title("Yield Opportunities (filtered)"),
+            )
+            .alignment(Alignment::Center)
+            .style(Style::default().fg(theme.dim));
         f.render_widget(empty, area);
         return;
     }
@@ -239,9 +251,9 @@ fn render_yield_list(f: &mut Frame, app: &TrendingAppState, area: Rect) {
             }
             name_spans.push(Span::styled(
                 event_title.to_string(),
-                Style::default().fg(Color::DarkGray),
+                Style::default().fg(theme.muted),
             ));
-            name_spans.push(Span::styled(" > ", Style::default().fg(Color::DarkGray)));
+            name_spans.push(Span::styled(" > ", Style::default().fg(theme.muted)));
             name_spans.push(Span::styled(
                 opp.market_name.clone(),
                 Style::default().fg(Color::White),
@@ -252,7 +264,7 @@ fn render_yield_list(f: &mut Frame, app: &TrendingAppState, area: Rect) {
             let bg_color = if idx % 2 == 0 {
                 Color::Reset
             } else {
-                Color::Rgb(30, 30, 40)
+                theme.stripe_bg
             };
 
             Row::new(vec![
@@ -273,8 +285,8 @@ fn render_yield_list(f: &mut Frame, app: &TrendingAppState, area: Rect) {
         Style::default()
     };
 
-    // Build title with filter info if active (count moved to bottom)
-    let title = if !yield_state.filter_query.is_empty() {
+    // Build title with filter and min volume info if active (count moved to bottom)
+    let mut title = if !yield_state.filter_query.is_empty() {
         format!(
             "Yield - Filter: '{}' - Sort: {}",
             truncate(&yield_state.filter_query, 15),
@@ -286,13 +298,15 @@ fn render_yield_list(f: &mut Frame, app: &TrendingAppState, area: Rect) {
             yield_state.sort_by.label()
         )
     };
+    if yield_state.min_volume > 0.0 {
+        title.push_str(&format!(
+            " - Min Vol: {}",
+            format_volume(yield_state.min_volume)
+        ));
+    }
 
     // Build position indicator for bottom right (lazygit style)
-    let total_count = if !yield_state.filter_query.is_empty() {
-        filtered.len()
-    } else {
-        yield_state.opportunities.len()
-    };
+    let total_count = filtered.len();
     let position_indicator = if total_count > 0 {
         format!("{} of {}", yield_state.selected_index + 1, total_count)
     } else {
@@ -308,7 +322,7 @@ fn render_yield_list(f: &mut Frame, app: &TrendingAppState, area: Rect) {
 
     if yield_state.is_search_loading {
         block = block.title_bottom(Line::from(vec![
-            Span::raw(" Searching... "),
+            Span::raw(format!(" {} Searching... ", spinner_frame())),
             Span::raw(" ".repeat(10)), // spacer
             Span::raw(format!("{}─", position_indicator)),
         ]));
@@ -336,7 +350,7 @@ fn render_yield_list(f: &mut Frame, app: &TrendingAppState, area: Rect) {
     .column_spacing(1)
     .row_highlight_style(
         Style::default()
-            .bg(Color::Rgb(60, 60, 80))
+            .bg(theme.highlight_bg)
             .add_modifier(Modifier::BOLD),
     );
 
@@ -346,7 +360,7 @@ fn render_yield_list(f: &mut Frame, app: &TrendingAppState, area: Rect) {
     f.render_stateful_widget(table, area, &mut table_state);
 
     // Render scrollbar if needed
-    let total_items = yield_state.opportunities.len();
+    let total_items = filtered.len();
     if total_items > visible_height {
         let mut scrollbar_state = ScrollbarState::new(total_items)
             .position(yield_state.scroll)
@@ -359,7 +373,7 @@ fn render_yield_list(f: &mut Frame, app: &TrendingAppState, area: Rect) {
     }
 }
 
-fn render_yield_details(f: &mut Frame, app: &TrendingAppState, area: Rect) {
+fn render_yield_details(f: &mut Frame, app: &TrendingAppState, theme: &Theme, area: Rect) {
     let yield_state = &app.yield_state;
 
     if let Some(opp) = yield_state.selected_opportunity() {
@@ -391,7 +405,8 @@ fn render_yield_details(f: &mut Frame, app: &TrendingAppState, area: Rect) {
 
             // Use shared function to build event info lines
             // Yield tab doesn't track watching status or trades, so use defaults
-            let event_lines = build_event_info_lines(event, false, "-", "Trades", chunks[0].width);
+            let event_lines =
+                build_event_info_lines(event, false, "-", "Trades", chunks[0].width, theme);
 
             let is_details_focused = app.navigation.focused_panel == FocusedPanel::EventDetails;
             let event_block_style = if is_details_focused {
@@ -495,7 +510,7 @@ fn render_yield_details(f: &mut Frame, app: &TrendingAppState, area: Rect) {
                         format_price_cents(opp.price),
                         opp.outcome
                     ),
-                    Style::default().fg(Color::DarkGray),
+                    Style::default().fg(theme.muted),
                 )]),
                 Line::from(vec![Span::styled(
                     format!(
@@ -507,11 +522,11 @@ fn render_yield_details(f: &mut Frame, app: &TrendingAppState, area: Rect) {
                 Line::from(""),
                 Line::from(vec![Span::styled(
                     "Note: Actual profit depends on available",
-                    Style::default().fg(Color::DarkGray),
+                    Style::default().fg(theme.muted),
                 )]),
                 Line::from(vec![Span::styled(
                     "liquidity. Large orders cause price slippage.",
-                    Style::default().fg(Color::DarkGray),
+                    Style::default().fg(theme.muted),
                 )]),
             ];
 
@@ -646,7 +661,7 @@ fn render_yield_details(f: &mut Frame, app: &TrendingAppState, area: Rect) {
                         format_price_cents(opp.price),
                         opp.outcome
                     ),
-                    Style::default().fg(Color::DarkGray),
+                    Style::default().fg(theme.muted),
                 )]),
                 Line::from(vec![Span::styled(
                     format!(
@@ -658,11 +673,11 @@ fn render_yield_details(f: &mut Frame, app: &TrendingAppState, area: Rect) {
                 Line::from(""),
                 Line::from(vec![Span::styled(
                     "Note: Actual profit depends on available",
-                    Style::default().fg(Color::DarkGray),
+                    Style::default().fg(theme.muted),
                 )]),
                 Line::from(vec![Span::styled(
                     "liquidity. Large orders cause price slippage.",
-                    Style::default().fg(Color::DarkGray),
+                    Style::default().fg(theme.muted),
                 )]),
             ];
 
@@ -695,7 +710,7 @@ fn render_yield_details(f: &mut Frame, app: &TrendingAppState, area: Rect) {
                     .title("Event"),
             )
             .alignment(Alignment::Center)
-            .style(Style::default().fg(Color::Gray));
+            .style(Style::default().fg(theme.dim));
         f.render_widget(event_empty, chunks[0]);
 
         let market_empty = Paragraph::new("").block(
@@ -709,7 +724,7 @@ fn render_yield_details(f: &mut Frame, app: &TrendingAppState, area: Rect) {
 }
 
 /// Render yield search results (events with yield info)
-fn render_yield_search_results(f: &mut Frame, app: &TrendingAppState, area: Rect) {
+fn render_yield_search_results(f: &mut Frame, app: &TrendingAppState, theme: &Theme, area: Rect) {
     let yield_state = &app.yield_state;
 
     if yield_state.is_search_loading && yield_state.search_results.is_empty() {
@@ -740,7 +755,7 @@ fn render_yield_search_results(f: &mut Frame, app: &TrendingAppState, area: Rect
                     .title("Search Results"),
             )
             .alignment(Alignment::Center)
-            .style(Style::default().fg(Color::Gray));
+            .style(Style::default().fg(theme.dim));
         f.render_widget(empty, area);
         return;
     }
@@ -779,7 +794,7 @@ fn render_yield_search_results(f: &mut Frame, app: &TrendingAppState, area: Rect
                     },
                 )
             } else {
-                ("No yield".to_string(), Color::DarkGray)
+                ("No yield".to_string(), theme.muted)
             };
 
             // Calculate total volume from cached event
@@ -842,7 +857,7 @@ fn render_yield_search_results(f: &mut Frame, app: &TrendingAppState, area: Rect
             let bg_color = if idx % 2 == 0 {
                 Color::Reset
             } else {
-                Color::Rgb(30, 30, 40)
+                theme.stripe_bg
             };
 
             Row::new(vec![
@@ -900,7 +915,7 @@ fn render_yield_search_results(f: &mut Frame, app: &TrendingAppState, area: Rect
     .column_spacing(1)
     .row_highlight_style(
         Style::default()
-            .bg(Color::Rgb(60, 60, 80))
+            .bg(theme.highlight_bg)
             .add_modifier(Modifier::BOLD),
     );
 
@@ -922,7 +937,7 @@ fn render_yield_search_results(f: &mut Frame, app: &TrendingAppState, area: Rect
 }
 
 /// Render details for a selected yield search result
-fn render_yield_search_details(f: &mut Frame, app: &TrendingAppState, area: Rect) {
+fn render_yield_search_details(f: &mut Frame, app: &TrendingAppState, theme: &Theme, area: Rect) {
     let yield_state = &app.yield_state;
 
     if let Some(result) = yield_state.selected_search_result() {
@@ -951,7 +966,8 @@ fn render_yield_search_details(f: &mut Frame, app: &TrendingAppState, area: Rect
                 .split(area);
 
             // Use shared function to build event info lines
-            let event_lines = build_event_info_lines(event, false, "-", "Trades", chunks[0].width);
+            let event_lines =
+                build_event_info_lines(event, false, "-", "Trades", chunks[0].width, theme);
 
             let is_details_focused = app.navigation.focused_panel == FocusedPanel::EventDetails;
             let event_block_style = if is_details_focused {
@@ -1047,7 +1063,7 @@ fn render_yield_search_details(f: &mut Frame, app: &TrendingAppState, area: Rect
                             format_price_cents(y.price),
                             y.outcome
                         ),
-                        Style::default().fg(Color::DarkGray),
+                        Style::default().fg(theme.muted),
                     )]),
                     Line::from(vec![Span::styled(
                         format!("  Profit per share: {}", format_price_cents(1.0 - y.price)),
@@ -1056,11 +1072,11 @@ fn render_yield_search_details(f: &mut Frame, app: &TrendingAppState, area: Rect
                     Line::from(""),
                     Line::from(vec![Span::styled(
                         "Note: Actual profit depends on available",
-                        Style::default().fg(Color::DarkGray),
+                        Style::default().fg(theme.muted),
                     )]),
                     Line::from(vec![Span::styled(
                         "liquidity. Large orders cause price slippage.",
-                        Style::default().fg(Color::DarkGray),
+                        Style::default().fg(theme.muted),
                     )]),
                 ];
 
@@ -1079,7 +1095,7 @@ fn render_yield_search_details(f: &mut Frame, app: &TrendingAppState, area: Rect
                     Line::from(""),
                     Line::from(vec![Span::styled(
                         "No high-probability outcomes found",
-                        Style::default().fg(Color::DarkGray),
+                        Style::default().fg(theme.muted),
                     )]),
                     Line::from(""),
                     Line::from(vec![Span::styled(
@@ -1087,7 +1103,7 @@ fn render_yield_search_details(f: &mut Frame, app: &TrendingAppState, area: Rect
                             "(Looking for outcomes >= {:.0}%)",
                             app.yield_state.min_prob * 100.0
                         ),
-                        Style::default().fg(Color::DarkGray),
+                        Style::default().fg(theme.muted),
                     )]),
                 ];
 
@@ -1212,7 +1228,7 @@ fn render_yield_search_details(f: &mut Frame, app: &TrendingAppState, area: Rect
                             .border_style(yield_block_style),
                     )
                     .alignment(Alignment::Center)
-                    .style(Style::default().fg(Color::DarkGray));
+                    .style(Style::default().fg(theme.muted));
                 f.render_widget(no_yield, chunks[1]);
             }
         }
@@ -1234,7 +1250,7 @@ fn render_yield_search_details(f: &mut Frame, app: &TrendingAppState, area: Rect
                     .title("Event"),
             )
             .alignment(Alignment::Center)
-            .style(Style::default().fg(Color::Gray));
+            .style(Style::default().fg(theme.dim));
         f.render_widget(event_empty, chunks[0]);
 
         let yield_empty = Paragraph::new("").block(
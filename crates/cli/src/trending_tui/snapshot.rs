@@ -0,0 +1,39 @@
+//! Plain-text snapshots of the rendered screen
+
+use {ratatui::buffer::Buffer, std::io::Write, unicode_width::UnicodeWidthStr};
+
+/// Write the contents of a rendered frame buffer to a timestamped text file.
+/// Returns the path to the saved file.
+pub fn save_screen_snapshot(buffer: &Buffer) -> std::io::Result<String> {
+    let timestamp = chrono::Local::now().format("%Y%m%d_%H%M%S");
+    let filename = format!("polymarket_screen_{}.txt", timestamp);
+
+    let mut file = std::fs::File::create(&filename)?;
+    for line in buffer_lines(buffer) {
+        writeln!(file, "{}", line)?;
+    }
+
+    Ok(filename)
+}
+
+/// Extract each row of the buffer as text, without trailing whitespace
+fn buffer_lines(buffer: &Buffer) -> Vec<String> {
+    let area = buffer.area;
+    (area.top()..area.bottom())
+        .map(|y| {
+            let mut line = String::new();
+            // Cells covered by the previous wide character hold filler that isn't drawn
+            let mut skip = 0;
+            for x in area.left()..area.right() {
+                if skip > 0 {
+                    skip -= 1;
+                    continue;
+                }
+                let symbol = buffer[(x, y)].symbol();
+                line.push_str(symbol);
+                skip = symbol.width().saturating_sub(1);
+            }
+            line.trim_end().to_string()
+        })
+        .collect()
+}
@@ -0,0 +1,265 @@
+//! Captured API failures for the errors popup
+//!
+//! Unlike the logs panel, entries here keep the request URL, status code and response body of
+//! each failure. The log is bounded, and credentials are redacted before anything is stored.
+
+use {
+    polymarket_api::PolymarketError,
+    std::{
+        collections::VecDeque,
+        time::{Duration, Instant},
+    },
+};
+
+/// Maximum number of failures kept (oldest are dropped first)
+const MAX_API_ERRORS: usize = 50;
+/// Response bodies are truncated to this many characters
+const MAX_BODY_CHARS: usize = 500;
+/// Failures newer than this count towards the header badge
+const RECENT_WINDOW: Duration = Duration::from_secs(5 * 60);
+
+/// Query parameter, header and JSON field names whose values are never stored
+const SECRET_KEYS: [&str; 12] = [
+    "poly_api_key",
+    "poly_passphrase",
+    "poly_signature",
+    "apikey",
+    "api_key",
+    "secret",
+    "passphrase",
+    "authorization",
+    "cookie",
+    "session_nonce",
+    "polymarketsession",
+    "polymarketnonce",
+];
+
+/// A single captured API failure
+#[derive(Debug, Clone)]
+pub struct ApiErrorEntry {
+    pub timestamp: chrono::DateTime<chrono::Local>,
+    pub context: String, // What the TUI was fetching, e.g. "Orderbook"
+    pub url: Option<String>,
+    pub status: Option<u16>,
+    pub body: Option<String>,
+    pub message: String,
+    recorded_at: Instant,
+}
+
+/// Bounded log of API failures, newest last
+#[derive(Debug)]
+pub struct ApiErrorLog {
+    entries: VecDeque<ApiErrorEntry>,
+    pub scroll: usize,
+}
+
+impl ApiErrorLog {
+    pub fn new() -> Self {
+        Self {
+            entries: VecDeque::new(),
+            scroll: 0,
+        }
+    }
+
+    /// Record a failed API call, redacting credentials and truncating the body
+    pub fn record(&mut self, context: &str, error: &PolymarketError) {
+        let body = error.body().map(|body| {
+            let body = redact_secrets(body.trim());
+            if body.chars().count() > MAX_BODY_CHARS {
+                let truncated: String = body.chars().take(MAX_BODY_CHARS).collect();
+                format!("{}… (truncated)", truncated)
+            } else {
+                body
+            }
+        });
+        // The body is shown on its own line, so keep it out of the summary message
+        let message = match error {
+            PolymarketError::Api { status, .. } => format!("HTTP {}", status),
+            other => redact_secrets(&other.to_string()),
+        };
+        if self.entries.len() == MAX_API_ERRORS {
+            self.entries.pop_front();
+        }
+        self.entries.push_back(ApiErrorEntry {
+            timestamp: chrono::Local::now(),
+            context: context.to_string(),
+            url: error.url().map(redact_secrets),
+            status: error.status(),
+            body,
+            message,
+            recorded_at: Instant::now(),
+        });
+    }
+
+    /// Captured failures, newest first
+    pub fn entries(&self) -> impl Iterator<Item = &ApiErrorEntry> {
+        self.entries.iter().rev()
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Number of failures within the last few minutes
+    pub fn recent_count(&self) -> usize {
+        self.entries
+            .iter()
+            .filter(|entry| entry.recorded_at.elapsed() < RECENT_WINDOW)
+            .count()
+    }
+
+    pub fn scroll_up(&mut self) {
+        self.scroll = self.scroll.saturating_sub(1);
+    }
+
+    /// Scroll down, stopping around the last entry (each takes up to four lines)
+    pub fn scroll_down(&mut self) {
+        if self.scroll + 1 < self.entries.len() * 4 {
+            self.scroll += 1;
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.scroll = 0;
+    }
+}
+
+/// Replace the values of credential-like fields (`key=value`, `key: value`, `"key":"value"`)
+/// and any known credential (see `AuthConfig::register_secrets`) with `***`
+pub fn redact_secrets(text: &str) -> String {
+    let text = &crate::auth::redact_credentials(text);
+    let lower = text.to_ascii_lowercase();
+    let mut result = String::with_capacity(text.len());
+    let mut pos = 0;
+    while pos < text.len() {
+        let next = SECRET_KEYS
+            .iter()
+            .filter_map(|key| lower[pos..].find(key).map(|i| (pos + i, key.len())))
+            .min_by_key(|(start, _)| *start);
+        let Some((start, key_len)) = next else {
+            break;
+        };
+        let key_end = start + key_len;
+        // Skip a closing quote and the separator between the key and its value
+        let value_start = key_end
+            + text[key_end..]
+                .find(|c: char| !matches!(c, '"' | '\'' | '=' | ':' | ' '))
+                .unwrap_or(text.len() - key_end);
+        let has_separator = text[key_end..value_start].contains(['=', ':']);
+        if !has_separator {
+            result.push_str(&text[pos..key_end]);
+            pos = key_end;
+            continue;
+        }
+        let value_end = value_start
+            + text[value_start..]
+                .find(|c: char| {
+                    matches!(c, '&' | '"' | '\'' | ',' | ';' | '}') || c.is_whitespace()
+                })
+                .unwrap_or(text.len() - value_start);
+        result.push_str(&text[pos..value_start]);
+        result.push_str(crate::auth::REDACTED);
+        pos = value_end;
+    }
+    result.push_str(&text[pos..]);
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn redacts_query_parameters() {
+        assert_eq!(
+            redact_secrets("https://clob.example/trades?market=1&api_key=abc123&limit=5"),
+            "https://clob.example/trades?market=1&api_key=***&limit=5"
+        );
+    }
+
+    #[test]
+    fn redacts_headers_and_json_fields() {
+        assert_eq!(
+            redact_secrets("POLY_API_KEY: abc\nPOLY_SIGNATURE: xyz"),
+            "POLY_API_KEY: ***\nPOLY_SIGNATURE: ***"
+        );
+        assert_eq!(
+            redact_secrets(r#"{"error":"bad","passphrase":"hunter2"}"#),
+            r#"{"error":"bad","passphrase":"***"}"#
+        );
+    }
+
+    #[test]
+    fn redacts_known_credentials_in_log_lines() {
+        // A local list rather than `AuthConfig::register_secrets`, which is process-wide
+        let secrets = [
+            "PLACEHOLDER_SECRET_0123456789abcdefghijklmno=".to_string(),
+            "placeholder-session-cookie".to_string(),
+        ];
+        assert_eq!(
+            crate::auth::redact_values(
+                "[DEBUG] signing with PLACEHOLDER_SECRET_0123456789abcdefghijklmno= for \
+                 placeholder-session-cookie",
+                &secrets
+            ),
+            "[DEBUG] signing with *** for ***"
+        );
+        assert_eq!(
+            redact_secrets("Cookie header polymarketnonce=abc; polymarketsession=def"),
+            "Cookie header polymarketnonce=***; polymarketsession=***"
+        );
+    }
+
+    #[test]
+    fn redacts_a_registered_config() {
+        // Placeholder values unique to this test, so registering them process-wide can't
+        // change what other tests see
+        let config = crate::auth::AuthConfig {
+            api_key: "placeholder-api-key".to_string(),
+            secret: "PLACEHOLDER_REGISTERED_SECRET_abcdefghijklmnop=".to_string(),
+            passphrase: "placeholder-registered-passphrase".to_string(),
+            address: "0x0000000000000000000000000000000000000000".to_string(),
+            username: None,
+            session_cookie: Some("placeholder-registered-cookie".to_string()),
+            session_nonce: None,
+            session_auth_type: None,
+        };
+        config.register_secrets();
+        assert_eq!(
+            redact_secrets(
+                "sig PLACEHOLDER_REGISTERED_SECRET_abcdefghijklmnop= pass \
+                 placeholder-registered-passphrase cookie placeholder-registered-cookie \
+                 api_key=abc"
+            ),
+            "sig *** pass *** cookie *** api_key=***"
+        );
+    }
+
+    #[test]
+    fn leaves_unrelated_text_alone() {
+        let text = "market not found: secretariat-wins?token_id=42";
+        assert_eq!(redact_secrets(text), text);
+    }
+
+    #[test]
+    fn keeps_the_newest_entries() {
+        let mut log = ApiErrorLog::new();
+        for i in 0..MAX_API_ERRORS + 3 {
+            log.record(
+                &format!("fetch {}", i),
+                &PolymarketError::InvalidData("boom".to_string()),
+            );
+        }
+        assert_eq!(log.len(), MAX_API_ERRORS);
+        assert_eq!(
+            log.entries().next().map(|entry| entry.context.as_str()),
+            Some("fetch 52")
+        );
+        assert_eq!(log.recent_count(), MAX_API_ERRORS);
+    }
+}
@@ -2,16 +2,44 @@
 
 use {
     super::{
-        AuthState, EventFilter, EventSortBy, EventTrades, FavoritesState, LogsState, MainTab,
-        NavigationState, OrderbookState, OutcomeInfo, PaginationState, PopupType, ScrollState,
-        SearchMode, SearchState, Trade, TradeFormState, TradesState, YieldState,
+        ApiErrorLog, AuthState, CommandPaletteState, ConnectionState, EventFilter, EventSortBy,
+        EventTrades, EventViewState, FavoritesState, FetchLimiter, LogsState, MAX_BULK_WATCH,
+        MAX_NOTE_CHARS, MainTab, NavigationState, OrderbookState, OutcomeInfo, PaginationState,
+        PopupType, PositionsState, RefreshSettings, ScrollState, SearchMode, SearchState,
+        TagFilterState, Toast, Trade, TradeFormState, TradesState, UiPrefs, YieldState,
+        filter_cache::{
+            EventRowKey, EventRowsCache, EventRowsKey, EventSource, FilterKey, FilteredEventsCache,
+            event_matches, event_streams_trades, event_volume,
+        },
+        keep_trade_popup_open_from_env,
+        notes::{load_notes, save_notes},
+        tag_counts,
+    },
+    polymarket_api::{
+        Endpoints, PolymarketError,
+        gamma::{Event, Market},
+    },
+    ratatui::widgets::{ListItem, TableState},
+    std::{
+        borrow::Cow,
+        cell::{RefCell, RefMut},
+        collections::{HashMap, HashSet},
+        time::Instant,
     },
-    polymarket_api::gamma::Event,
-    ratatui::widgets::TableState,
-    std::collections::HashMap,
     tokio::task::JoinHandle,
 };
 
+/// Price samples kept per asset for the event details sparkline
+pub const MAX_PRICE_HISTORY: usize = 60;
+/// Height of the event details panel, and of its one-line compact form (borders included)
+pub const EVENT_DETAILS_HEIGHT: u16 = 8;
+pub const COMPACT_EVENT_DETAILS_HEIGHT: u16 = 3;
+/// Height of the banner above the main content while the initial events fetch has failed
+pub const LOAD_ERROR_BANNER_HEIGHT: u16 = 3;
+
+/// Amount `+`/`-` change the events list's minimum volume filter by
+pub const MIN_VOLUME_FILTER_STEP: f64 = 10_000.0;
+
 /// Main application state
 pub struct TrendingAppState {
     pub events: Vec<Event>,
@@ -20,10 +48,21 @@ pub struct TrendingAppState {
     pub scroll: ScrollState,
     pub pagination: PaginationState,
     pub logs: LogsState,
+    pub api_errors: ApiErrorLog, // API failures with request context, shown with 'E'
+    pub tag_filter: TagFilterState, // Narrows the events list to one tag, picked with '#'
+    pub min_volume_filter: f64,  // Hide events whose markets' summed volume is below this
+    pub streamable_only: bool,   // Hide events the live trade feed can't stream ('y')
     pub navigation: NavigationState,
     pub trades: TradesState,
-    pub event_filter: EventFilter, // Current filter (Trending, Breaking)
+    pub secondary_watch_slug: Option<String>, /* Event in the split view's second trades panel ('d') */
+    pub split_watch_started_stream: bool,     // The split view opened its event's stream itself
+    pub event_filter: EventFilter, // Current filter (Trending, Breaking, Closing Soon, New)
     pub market_prices: HashMap<String, f64>, // asset_id -> current price from API
+    pub prev_prices: HashMap<String, f64>, // asset_id -> price before the last update (for ▲/▼)
+    pub price_history: HashMap<String, Vec<f64>>, // asset_id -> recent prices, oldest first
+    pub prices_updated_at: Option<Instant>, // Last time fetched prices were merged in
+    pub auto_refresh_paused: bool, // Timed refreshes are off, e.g. on a metered connection ('Z')
+    pub pinned_markets: HashSet<String>, // First clob_token_id of markets pinned to the top
     pub event_trade_counts: HashMap<String, usize>, // event_slug -> total trade count from API
     pub has_clob_auth: bool,       // Whether CLOB API authentication is available
     pub popup: Option<PopupType>,  // Currently active popup/modal
@@ -31,17 +70,38 @@ pub struct TrendingAppState {
     pub events_cache: HashMap<EventFilter, Vec<Event>>, // Cache for each filter tab
     /// Global event cache keyed by slug - single source of truth for event data
     pub event_cache: HashMap<String, Event>,
-    pub show_logs: bool,   // Whether to show the logs panel (toggle with 'l')
-    pub main_tab: MainTab, // Current main tab (Trending vs Yield)
+    /// Saved scroll/market selection per event slug, pruned alongside the event cache
+    pub event_view_states: HashMap<String, EventViewState>,
+    pub show_logs: bool,      // Whether to show the logs panel (toggle with 'l')
+    pub show_event_age: bool, // Whether to show event creation age in the events list ('a')
+    pub compact_event_details: bool, // Collapse the event details panel to one line ('D')
+    pub keep_trade_popup_open: bool, // Keep the trade popup open after submitting (env config)
+    pub ui_prefs: UiPrefs,    // Optional display toggles (saved across restarts)
+    pub refresh: RefreshSettings, // Settings popup selection (',')
+    pub notes: HashMap<String, String>, // Personal notes by event slug (saved, edited with 'n')
+    pub note_input: String,   // Note being typed in the note popup
+    pub command_palette: CommandPaletteState, // Command palette query and selection (':')
+    pub main_tab: MainTab,    // Current main tab (Trending vs Yield)
     pub yield_state: YieldState, // State for the Yield tab
     pub favorites_state: FavoritesState, // State for the Favorites tab
+    pub positions_state: PositionsState, // State for the Positions tab
     pub auth_state: AuthState, // Authentication state
     pub login_form: super::LoginFormState, // Login form state
     pub trade_form: Option<TradeFormState>, // Trade form state (when trade popup is open)
-    pub event_sort_by: EventSortBy, // Current sort option for events list
+    // Sort chosen with 's' per tab (and per filter on the Trending tab); tabs without an entry
+    // keep the API's order
+    pub event_sorts: HashMap<(MainTab, Option<EventFilter>), EventSortBy>,
     pub gamma_api_status: Option<bool>, /* Gamma API health: Some(true) = healthy, Some(false) = unhealthy, None = unknown */
     pub data_api_status: Option<bool>, /* Data API health: Some(true) = healthy, Some(false) = unhealthy, None = unknown */
+    pub load_error: Option<String>, /* Why the initial events fetch failed, until a retry ('R') succeeds */
     pub orderbook_state: OrderbookState, // Orderbook panel state
+    pub endpoints: Endpoints,       // Active API endpoints (defaults or env overrides)
+    pub fetch_limiter: FetchLimiter, // Caps concurrent orderbook/price requests
+    pub toast: Option<Toast>,       // Transient message shown in the footer
+    pub snapshot_requested: bool,   // Save the next rendered frame to a text file
+    filtered_cache: RefCell<Option<FilteredEventsCache>>, // Memoized filtered_events()
+    event_rows_cache: RefCell<EventRowsCache>, // Rendered events list rows
+    events_generation: u64,         // Bumped whenever `events` is replaced, changed or reordered
 }
 
 impl TrendingAppState {
@@ -53,6 +113,10 @@ impl TrendingAppState {
             || order_by == "oneDayPriceChange"
         {
             EventFilter::Breaking
+        } else if order_by == "endDate" && ascending {
+            EventFilter::ClosingSoon
+        } else if order_by == "createdAt" && !ascending {
+            EventFilter::NewlyListed
         } else {
             EventFilter::Trending
         };
@@ -64,6 +128,11 @@ impl TrendingAppState {
         for event in &events {
             event_cache.insert(event.slug.clone(), event.clone());
         }
+        // The startup list is the first load; when it failed, the first retry seeds instead
+        let mut trades = TradesState::new();
+        if !events.is_empty() {
+            trades.auto_watch.seed(&events);
+        }
         Self {
             events,
             should_quit: false,
@@ -71,27 +140,56 @@ impl TrendingAppState {
             scroll: ScrollState::new(),
             pagination: PaginationState::new(order_by, ascending, current_limit),
             logs: LogsState::new(),
+            api_errors: ApiErrorLog::new(),
+            tag_filter: TagFilterState::new(),
+            min_volume_filter: 0.0,
+            streamable_only: false,
             navigation: NavigationState::new(),
-            trades: TradesState::new(),
+            trades,
+            secondary_watch_slug: None,
+            split_watch_started_stream: false,
             event_filter,
             market_prices: HashMap::new(),
+            prev_prices: HashMap::new(),
+            price_history: HashMap::new(),
+            prices_updated_at: None,
+            auto_refresh_paused: false,
+            pinned_markets: HashSet::new(),
             event_trade_counts: HashMap::new(),
             has_clob_auth,
             popup: None,
             trades_table_state: TableState::default(),
             events_cache,
             event_cache,
+            event_view_states: HashMap::new(),
             show_logs: false, // Hidden by default
+            show_event_age: false,
+            compact_event_details: false,
+            keep_trade_popup_open: keep_trade_popup_open_from_env(),
+            ui_prefs: UiPrefs::load(),
+            refresh: RefreshSettings::default(),
+            notes: load_notes(),
+            note_input: String::new(),
+            command_palette: CommandPaletteState::default(),
             main_tab: MainTab::Trending,
             yield_state: YieldState::new(),
             favorites_state: FavoritesState::new(),
+            positions_state: PositionsState::new(),
             auth_state: AuthState::new(),
             login_form: super::LoginFormState::new(),
             trade_form: None,
-            event_sort_by: EventSortBy::default(),
+            event_sorts: HashMap::new(),
             gamma_api_status: None,
             data_api_status: None,
+            load_error: None,
             orderbook_state: OrderbookState::new(),
+            endpoints: Endpoints::from_env(),
+            fetch_limiter: FetchLimiter::from_env(),
+            toast: None,
+            snapshot_requested: false,
+            filtered_cache: RefCell::new(None),
+            event_rows_cache: RefCell::new(EventRowsCache::default()),
+            events_generation: 0,
         }
     }
 
@@ -100,6 +198,244 @@ impl TrendingAppState {
         for event in events {
             self.event_cache.insert(event.slug.clone(), event.clone());
         }
+        // Queue newly-appearing events that match an auto-watch keyword
+        let matches = self.trades.auto_watch.new_matches(events);
+        self.trades.pending_auto_watch.extend(matches);
+        self.prune_event_views(events);
+    }
+
+    /// Take queued auto-watch events, split into (slug, title) pairs that fit under the
+    /// connection cap and slugs skipped because the cap was reached
+    pub fn take_auto_watch_events(&mut self) -> (Vec<(String, String)>, Vec<String>) {
+        let pending = std::mem::take(&mut self.trades.pending_auto_watch);
+        let mut open = self.trades.ws_handles.len();
+        let mut ready = Vec::new();
+        let mut skipped = Vec::new();
+        for slug in pending {
+            if self.is_watching(&slug) {
+                continue;
+            }
+            if open >= self.trades.auto_watch.max_connections {
+                skipped.push(slug);
+            } else if let Some(event) = self.event_cache.get(&slug) {
+                ready.push((slug, event.title.clone()));
+                open += 1;
+            }
+        }
+        (ready, skipped)
+    }
+
+    /// Slugs of open, unwatched events in the filtered list to bulk-watch, capped at
+    /// `MAX_BULK_WATCH`, and whether the cap left some out
+    pub fn bulk_watch_events(&self) -> (Vec<String>, bool) {
+        let mut candidates = self
+            .filtered_events()
+            .into_iter()
+            .filter(|event| !event.closed && !self.is_watching(&event.slug));
+        let ready: Vec<String> = candidates
+            .by_ref()
+            .take(MAX_BULK_WATCH)
+            .map(|event| event.slug.clone())
+            .collect();
+        let capped = candidates.next().is_some();
+        (ready, capped)
+    }
+
+    /// Drop saved event views for events that are no longer in any loaded list, the
+    /// incoming batch or the watched set. The global cache only grows, so it can't be
+    /// used to decide what is stale.
+    fn prune_event_views(&mut self, incoming: &[Event]) {
+        let loaded: HashSet<&str> = incoming
+            .iter()
+            .chain(&self.events)
+            .chain(self.events_cache.values().flatten())
+            .chain(&self.favorites_state.events)
+            .map(|event| event.slug.as_str())
+            .chain(self.trades.ws_handles.keys().map(String::as_str))
+            .collect();
+        self.event_view_states
+            .retain(|slug, _| loaded.contains(slug.as_str()));
+    }
+
+    /// Save the current markets/trades view for an event
+    pub fn save_event_view(&mut self, slug: &str) {
+        let view = EventViewState {
+            markets_scroll: self.scroll.markets,
+            selected_market_index: self.orderbook_state.selected_market_index,
+            trades_scroll: self.scroll.trades,
+            event_details_scroll: self.scroll.event_details,
+        };
+        self.event_view_states.insert(slug.to_string(), view);
+    }
+
+    /// Restore the saved view for an event (or reset to the top if none is saved).
+    /// Returns true if a non-default market was restored and its orderbook needs fetching.
+    pub fn restore_event_view(&mut self, slug: &str) -> bool {
+        let view = self
+            .event_view_states
+            .get(slug)
+            .copied()
+            .unwrap_or_default();
+        let market_count = self
+            .get_cached_event(slug)
+            .or_else(|| self.current_tab_selected_event())
+            .map(|e| e.markets.len())
+            .unwrap_or(0);
+        let selected_market_index = view
+            .selected_market_index
+            .min(market_count.saturating_sub(1));
+        self.scroll.markets = view.markets_scroll.min(selected_market_index);
+        self.scroll.trades = view.trades_scroll;
+        self.scroll.event_details = view.event_details_scroll;
+        if selected_market_index == self.orderbook_state.selected_market_index {
+            return false;
+        }
+        self.orderbook_state.reset();
+        self.orderbook_state.selected_market_index = selected_market_index;
+        true
+    }
+
+    /// Get the event shown in the detail panels for the current tab
+    pub fn current_tab_selected_event(&self) -> Option<&Event> {
+        match self.main_tab {
+            MainTab::Favorites => self.favorites_state.selected_event(),
+            MainTab::Trending => self.selected_event(),
+            MainTab::Yield | MainTab::Positions => None,
+        }
+    }
+
+    /// Sort an event's markets for display: pinned first, then active before closed, keeping
+    /// only those matching the markets filter. `orderbook_state.selected_market_index` indexes
+    /// into this order.
+    pub fn sorted_markets<'a>(&self, event: &'a Event) -> Vec<&'a Market> {
+        let query = self.orderbook_state.market_filter.to_lowercase();
+        let mut sorted: Vec<_> = event
+            .markets
+            .iter()
+            .filter(|m| query.is_empty() || market_matches(m, &query))
+            .collect();
+        sorted.sort_by_key(|m| (!self.is_market_pinned(m), m.closed));
+        sorted
+    }
+
+    /// Edit the markets filter, keeping the selected market selected while it still matches
+    /// (the first match otherwise). Returns true if a different market is now selected.
+    pub fn edit_market_filter(&mut self, edit: impl FnOnce(&mut String)) -> bool {
+        let Some(event) = self.current_tab_selected_event().cloned() else {
+            edit(&mut self.orderbook_state.market_filter);
+            return false;
+        };
+        let selected_id = self
+            .sorted_markets(&event)
+            .get(self.orderbook_state.selected_market_index)
+            .map(|m| m.id.clone());
+        edit(&mut self.orderbook_state.market_filter);
+        let sorted = self.sorted_markets(&event);
+        let new_idx = selected_id
+            .as_ref()
+            .and_then(|id| sorted.iter().position(|m| &m.id == id))
+            .unwrap_or(0);
+        let changed = sorted.get(new_idx).map(|m| &m.id) != selected_id.as_ref();
+        self.orderbook_state.selected_market_index = new_idx;
+        self.scroll.markets = self.scroll.markets.min(new_idx);
+        if changed {
+            self.orderbook_state.orderbook = None;
+        }
+        changed
+    }
+
+    /// The markets of `event` on screen in a Markets panel showing `visible_height` rows
+    pub fn visible_markets<'a>(&self, event: &'a Event, visible_height: usize) -> Vec<&'a Market> {
+        let sorted = self.sorted_markets(event);
+        let scroll = self
+            .scroll
+            .markets
+            .min(sorted.len().saturating_sub(visible_height.max(1)));
+        sorted
+            .into_iter()
+            .skip(scroll)
+            .take(visible_height)
+            .collect()
+    }
+
+    pub fn is_market_pinned(&self, market: &Market) -> bool {
+        market
+            .clob_token_ids
+            .as_ref()
+            .and_then(|ids| ids.first())
+            .is_some_and(|id| self.pinned_markets.contains(id))
+    }
+
+    /// Pin or unpin the market selected in the Markets panel, keeping it selected as it
+    /// moves. Returns the new pinned state, or None if there's no pinnable market.
+    pub fn toggle_selected_market_pin(&mut self) -> Option<bool> {
+        let event = self.current_tab_selected_event()?.clone();
+        let sorted = self.sorted_markets(&event);
+        let market = sorted.get(self.orderbook_state.selected_market_index)?;
+        let token_id = market.clob_token_ids.as_ref()?.first()?.clone();
+
+        let pinned = if self.pinned_markets.remove(&token_id) {
+            false
+        } else {
+            self.pinned_markets.insert(token_id.clone());
+            true
+        };
+
+        // Follow the market to its new position
+        let sorted = self.sorted_markets(&event);
+        if let Some(idx) = sorted.iter().position(|m| {
+            m.clob_token_ids
+                .as_ref()
+                .and_then(|ids| ids.first())
+                .is_some_and(|id| *id == token_id)
+        }) {
+            self.orderbook_state.selected_market_index = idx;
+            self.scroll.markets = self.scroll.markets.min(idx);
+        }
+        Some(pinned)
+    }
+
+    /// Token ID of the other outcome in the selected event's market that trades `token_id`
+    pub fn sibling_token(&self, token_id: &str) -> Option<String> {
+        let event = self.current_tab_selected_event()?;
+        event.markets.iter().find_map(|market| {
+            let ids = market.clob_token_ids.as_ref()?;
+            if !ids.iter().any(|id| id == token_id) {
+                return None;
+            }
+            ids.iter().find(|id| *id != token_id).cloned()
+        })
+    }
+
+    /// Whether `token_id` belongs to a selected-event market that doesn't trade on the CLOB
+    pub fn is_off_clob_token(&self, token_id: &str) -> bool {
+        self.current_tab_selected_event()
+            .and_then(|event| {
+                event.markets.iter().find(|market| {
+                    market
+                        .clob_token_ids
+                        .as_ref()
+                        .is_some_and(|ids| ids.iter().any(|id| id == token_id))
+                })
+            })
+            .is_some_and(|market| !market.is_clob_tradeable())
+    }
+
+    /// Get the token for the market/outcome currently selected in the orderbook, along with
+    /// whether the market is active (sorted markets: pinned, then non-closed first)
+    pub fn selected_orderbook_token(&self) -> Option<(String, bool)> {
+        let event = self.current_tab_selected_event()?;
+        let sorted = self.sorted_markets(event);
+        let market = sorted.get(self.orderbook_state.selected_market_index)?;
+        let outcome_idx = match self.orderbook_state.selected_outcome {
+            super::OrderbookOutcome::Yes => 0,
+            super::OrderbookOutcome::No => 1,
+        };
+        market
+            .clob_token_ids
+            .as_ref()
+            .and_then(|ids| ids.get(outcome_idx).cloned())
+            .map(|id| (id, !market.closed))
     }
 
     /// Get an event from the global cache by slug
@@ -107,58 +443,88 @@ impl TrendingAppState {
         self.event_cache.get(slug)
     }
 
-    /// Sort events by the current sort option
+    /// Key of the current tab's entry in `event_sorts`
+    fn sort_scope(&self) -> (MainTab, Option<EventFilter>) {
+        match self.main_tab {
+            MainTab::Trending => (MainTab::Trending, Some(self.event_filter)),
+            tab => (tab, None),
+        }
+    }
+
+    /// Order the API returns the current tab's events in, when a sort option describes it.
+    /// Breaking is ordered by price movement and Favorites as saved, which none do.
+    fn default_event_sort(&self) -> Option<EventSortBy> {
+        match (self.main_tab, self.event_filter) {
+            (MainTab::Trending, EventFilter::Trending) => Some(EventSortBy::Volume24hr),
+            (MainTab::Trending, EventFilter::ClosingSoon) => Some(EventSortBy::EndingSoon),
+            (MainTab::Trending, EventFilter::NewlyListed) => Some(EventSortBy::Newest),
+            _ => None,
+        }
+    }
+
+    /// Sort the current tab's events list is in: the one picked with 's', else the API's
+    fn current_event_sort(&self) -> Option<EventSortBy> {
+        self.event_sorts
+            .get(&self.sort_scope())
+            .copied()
+            .or_else(|| self.default_event_sort())
+    }
+
+    /// Sort option for the current tab's events list (the metric column follows it)
+    pub fn event_sort_by(&self) -> EventSortBy {
+        self.current_event_sort().unwrap_or_default()
+    }
+
+    /// Label for the current tab's order, "Default" while it is still the API's own order
+    pub fn event_sort_label(&self) -> &'static str {
+        self.current_event_sort()
+            .map_or("Default", |sort_by| sort_by.label())
+    }
+
+    /// Set the current tab's sort option and re-sort
+    pub fn set_event_sort(&mut self, sort_by: EventSortBy) {
+        self.event_sorts.insert(self.sort_scope(), sort_by);
+        self.sort_events();
+    }
+
+    /// Advance the current tab's sort option and re-sort, leaving other tabs' choices alone
+    pub fn cycle_event_sort(&mut self) -> EventSortBy {
+        let sort_by = self
+            .current_event_sort()
+            .map_or(EventSortBy::default(), |sort_by| sort_by.next());
+        self.set_event_sort(sort_by);
+        sort_by
+    }
+
+    /// Re-apply the current tab's sort after its events were replaced (tab switch or fetch)
+    pub fn restore_event_sort(&mut self) {
+        if self.event_sorts.contains_key(&self.sort_scope()) {
+            self.sort_events();
+        }
+    }
+
+    /// Re-apply the Favorites tab's sort after the favorites were reloaded
+    pub fn restore_favorites_sort(&mut self) {
+        if let Some(&sort_by) = self.event_sorts.get(&(MainTab::Favorites, None)) {
+            sort_events_by(&mut self.favorites_state.events, sort_by);
+            self.favorites_state.mark_events_changed();
+        }
+    }
+
+    /// Call after replacing or changing `events`, so the filtered list is rebuilt
+    pub fn mark_events_changed(&mut self) {
+        self.events_generation += 1;
+    }
+
+    /// Sort the current tab's events list by its sort option
     pub fn sort_events(&mut self) {
-        match self.event_sort_by {
-            EventSortBy::Volume24hr => {
-                self.events.sort_by(|a, b| {
-                    b.volume_24hr
-                        .partial_cmp(&a.volume_24hr)
-                        .unwrap_or(std::cmp::Ordering::Equal)
-                });
-            },
-            EventSortBy::VolumeTotal => {
-                self.events.sort_by(|a, b| {
-                    b.volume
-                        .partial_cmp(&a.volume)
-                        .unwrap_or(std::cmp::Ordering::Equal)
-                });
-            },
-            EventSortBy::Liquidity => {
-                self.events.sort_by(|a, b| {
-                    b.liquidity
-                        .partial_cmp(&a.liquidity)
-                        .unwrap_or(std::cmp::Ordering::Equal)
-                });
-            },
-            EventSortBy::Newest => {
-                // Sort by created_at descending (newest first)
-                self.events
-                    .sort_by(|a, b| match (&b.created_at, &a.created_at) {
-                        (Some(b_date), Some(a_date)) => b_date.cmp(a_date),
-                        (Some(_), None) => std::cmp::Ordering::Less,
-                        (None, Some(_)) => std::cmp::Ordering::Greater,
-                        (None, None) => std::cmp::Ordering::Equal,
-                    });
-            },
-            EventSortBy::EndingSoon => {
-                // Sort by end_date ascending (soonest first), None at end
-                self.events
-                    .sort_by(|a, b| match (&a.end_date, &b.end_date) {
-                        (Some(a_date), Some(b_date)) => a_date.cmp(b_date),
-                        (Some(_), None) => std::cmp::Ordering::Less,
-                        (None, Some(_)) => std::cmp::Ordering::Greater,
-                        (None, None) => std::cmp::Ordering::Equal,
-                    });
-            },
-            EventSortBy::Competitive => {
-                // Sort by competitive score descending (most competitive first)
-                self.events.sort_by(|a, b| {
-                    b.competitive
-                        .partial_cmp(&a.competitive)
-                        .unwrap_or(std::cmp::Ordering::Equal)
-                });
-            },
+        let sort_by = self.event_sort_by();
+        if self.main_tab == MainTab::Favorites {
+            sort_events_by(&mut self.favorites_state.events, sort_by);
+            self.favorites_state.mark_events_changed();
+        } else {
+            sort_events_by(&mut self.events, sort_by);
+            self.mark_events_changed();
         }
     }
 
@@ -183,10 +549,92 @@ impl TrendingAppState {
         outcomes: Vec<OutcomeInfo>,
         selected_idx: usize,
     ) {
-        self.trade_form = Some(TradeFormState::new(market_question, outcomes, selected_idx));
+        let off_clob = outcomes
+            .first()
+            .is_some_and(|outcome| self.is_off_clob_token(&outcome.token_id));
+        let mut form = TradeFormState::new(market_question, outcomes, selected_idx);
+        if off_clob {
+            form.disable_limit_orders();
+        }
+        self.trade_form = Some(form);
         self.popup = Some(PopupType::Trade);
     }
 
+    /// Outcomes of a market for the trade popup, priced from live prices when available
+    pub fn market_outcomes(&self, market: &Market) -> Vec<OutcomeInfo> {
+        let Some(ref token_ids) = market.clob_token_ids else {
+            return Vec::new();
+        };
+        token_ids
+            .iter()
+            .enumerate()
+            .map(|(i, token_id)| {
+                let name = market.outcomes.get(i).cloned().unwrap_or_else(|| {
+                    if i == 0 {
+                        "Yes".to_string()
+                    } else {
+                        "No".to_string()
+                    }
+                });
+                let price = self
+                    .market_prices
+                    .get(token_id)
+                    .copied()
+                    .or_else(|| {
+                        market
+                            .outcome_prices
+                            .get(i)
+                            .and_then(|p| p.parse::<f64>().ok())
+                    })
+                    .unwrap_or(0.5);
+                OutcomeInfo {
+                    name,
+                    token_id: token_id.clone(),
+                    price,
+                }
+            })
+            .collect()
+    }
+
+    /// Trade popup contents (question, outcomes, selected index) for the open market of `event`
+    /// that `asset_id` belongs to, with that outcome selected
+    pub fn trade_popup_for_asset(
+        &self,
+        event: &Event,
+        asset_id: &str,
+    ) -> Option<(String, Vec<OutcomeInfo>, usize)> {
+        let market = event.markets.iter().find(|market| {
+            !market.closed
+                && market
+                    .clob_token_ids
+                    .as_ref()
+                    .is_some_and(|ids| ids.iter().any(|id| id == asset_id))
+        })?;
+        let outcomes = self.market_outcomes(market);
+        let selected_idx = outcomes.iter().position(|o| o.token_id == asset_id)?;
+        Some((market.question.clone(), outcomes, selected_idx))
+    }
+
+    /// Whether the trade form's market has closed according to any loaded copy of its event
+    /// (refreshes can update the cache, the events list and favorites separately)
+    pub fn trade_market_closed(&self) -> bool {
+        let Some(token_id) = self.trade_form.as_ref().and_then(|form| form.token_id()) else {
+            return false;
+        };
+        self.event_cache
+            .values()
+            .chain(&self.events)
+            .chain(&self.favorites_state.events)
+            .flat_map(|event| &event.markets)
+            .filter(|market| {
+                market
+                    .clob_token_ids
+                    .as_ref()
+                    .is_some_and(|ids| ids.iter().any(|id| id == token_id))
+            })
+            .any(|market| market.closed)
+    }
+
     /// Check if a popup is active
     pub fn has_popup(&self) -> bool {
         self.popup.is_some()
@@ -202,7 +650,7 @@ impl TrendingAppState {
             return false;
         }
 
-        let filtered_len = self.filtered_events().len();
+        let filtered_len = self.filtered_events_len();
         // Fetch more when user is within 5 items of the end
         self.navigation.selected_index >= filtered_len.saturating_sub(5)
             && filtered_len >= self.pagination.current_limit
@@ -213,6 +661,10 @@ impl TrendingAppState {
         // Format: [LEVEL] message
         let formatted = format!("[{}] {}", level, message);
         self.logs.messages.push(formatted);
+        // Surface the first failure of the session instead of leaving it in a hidden panel
+        if self.logs.note_level(level) {
+            self.show_logs = true;
+        }
         // Keep only last 1000 logs
         if self.logs.messages.len() > 1000 {
             self.logs.messages.remove(0);
@@ -233,103 +685,218 @@ impl TrendingAppState {
     /// If in API search mode and results are available, use those
     /// Otherwise filter locally
     /// For Favorites tab, returns favorites events (search not supported yet)
+    ///
+    /// The result is cached and only recomputed when the lists, query, mode or tab change.
     pub fn filtered_events(&self) -> Vec<&Event> {
+        self.with_filtered_events(|list, indices| {
+            indices.iter().filter_map(|&i| list.get(i)).collect()
+        })
+    }
+
+    /// Number of events in `filtered_events`, without collecting them
+    pub fn filtered_events_len(&self) -> usize {
+        self.with_filtered_events(|_, indices| indices.len())
+    }
+
+    /// The event at `index` in `filtered_events`, without collecting the rest
+    pub fn filtered_event(&self, index: usize) -> Option<&Event> {
+        self.with_filtered_events(|list, indices| indices.get(index).and_then(|&i| list.get(i)))
+    }
+
+    /// Up to `count` events of `filtered_events` starting at `start`, with their positions
+    pub fn filtered_events_window(&self, start: usize, count: usize) -> Vec<(usize, &Event)> {
+        self.with_filtered_events(|list, indices| {
+            indices
+                .iter()
+                .enumerate()
+                .skip(start)
+                .take(count)
+                .filter_map(|(position, &i)| list.get(i).map(|event| (position, event)))
+                .collect()
+        })
+    }
+
+    /// Generation of the filtered events list, bumped whenever it is rebuilt
+    pub fn filtered_events_generation(&self) -> u64 {
+        self.filtered_cache().generation
+    }
+
+    /// The events list row at `position` in `filtered_events`, reused from the last frame
+    /// when none of its inputs changed and built with `build` otherwise
+    pub fn event_row(
+        &self,
+        key: EventRowsKey,
+        position: usize,
+        row_key: EventRowKey,
+        build: impl FnOnce() -> ListItem<'static>,
+    ) -> ListItem<'static> {
+        self.event_rows_cache
+            .borrow_mut()
+            .row(key, position, row_key, build)
+    }
+
+    /// Run `f` on the filtered events' source list and their indices into it
+    fn with_filtered_events<'s, R>(&'s self, f: impl FnOnce(&'s [Event], &[usize]) -> R) -> R {
+        let cache = self.filtered_cache();
+        f(self.event_source_list(cache.source), &cache.indices)
+    }
+
+    /// The filtered events cache, rebuilt first if any input changed since it was built
+    fn filtered_cache(&self) -> RefMut<'_, FilteredEventsCache> {
+        let key = FilterKey {
+            main_tab: self.main_tab,
+            mode: self.search.mode,
+            query: Cow::Borrowed(&self.search.query),
+            last_searched_query: Cow::Borrowed(&self.search.last_searched_query),
+            events_generation: self.events_generation,
+            results_generation: self.search.results_generation,
+            favorites_generation: self.favorites_state.events_generation,
+            tag: self.tag_filter.active.as_deref().map(Cow::Borrowed),
+            min_volume_bits: self.min_volume_filter.to_bits(),
+            streamable_only: self.streamable_only,
+        };
+        let mut cache = self.filtered_cache.borrow_mut();
+        let cached = match cache.take() {
+            Some(cached) if cached.key == key => cached,
+            previous => {
+                let (source, indices) = self.compute_filtered_events();
+                FilteredEventsCache {
+                    key: key.into_owned(),
+                    source,
+                    indices,
+                    generation: previous.map_or(0, |previous| previous.generation + 1),
+                }
+            },
+        };
+        RefMut::map(cache, |cache| cache.insert(cached))
+    }
+
+    /// Compute the filtered events as indices into their source list (see `filtered_events`)
+    fn compute_filtered_events(&self) -> (EventSource, Vec<usize>) {
+        let (source, mut indices) = self.compute_untagged_filtered_events();
+        if self.tag_filter.active.is_some() {
+            let list = self.event_source_list(source);
+            indices.retain(|&i| {
+                list.get(i)
+                    .is_some_and(|event| self.tag_filter.matches(event))
+            });
+        }
+        (source, indices)
+    }
+
+    /// Events passing every filter except the tag filter (the tag picker's choices)
+    fn untagged_filtered_events(&self) -> Vec<&Event> {
+        let (source, indices) = self.compute_untagged_filtered_events();
+        let list = self.event_source_list(source);
+        indices.iter().filter_map(|&i| list.get(i)).collect()
+    }
+
+    /// Filter by search, minimum volume and live trade support (see `compute_filtered_events`)
+    fn compute_untagged_filtered_events(&self) -> (EventSource, Vec<usize>) {
+        let (source, mut indices) = self.compute_search_filtered_events();
+        if self.min_volume_filter > 0.0 {
+            let list = self.event_source_list(source);
+            indices.retain(|&i| {
+                list.get(i)
+                    .is_some_and(|event| event_volume(event) >= self.min_volume_filter)
+            });
+        }
+        if self.streamable_only {
+            let list = self.event_source_list(source);
+            indices.retain(|&i| list.get(i).is_some_and(event_streams_trades));
+        }
+        (source, indices)
+    }
+
+    fn event_source_list(&self, source: EventSource) -> &[Event] {
+        match source {
+            EventSource::Events => &self.events,
+            EventSource::SearchResults => &self.search.results,
+            EventSource::Favorites => &self.favorites_state.events,
+        }
+    }
+
+    /// Filter by tab, search mode and query (see `compute_filtered_events`)
+    fn compute_search_filtered_events(&self) -> (EventSource, Vec<usize>) {
+        let all = |source: EventSource, events: &[Event]| (source, (0..events.len()).collect());
+        let matching = |source: EventSource, events: &[Event], query: &str| {
+            let query_lower = query.to_lowercase();
+            let indices = events
+                .iter()
+                .enumerate()
+                .filter(|(_, event)| event_matches(event, &query_lower))
+                .map(|(i, _)| i)
+                .collect();
+            (source, indices)
+        };
+
         // For Favorites tab, just return favorites events (no search support yet)
         if self.main_tab == MainTab::Favorites {
-            return self.favorites_state.events.iter().collect();
+            return all(EventSource::Favorites, &self.favorites_state.events);
         }
 
         if self.search.query.is_empty() {
             // No query, return all events from the current source
-            // If we have search results and not in local filter mode, return those; otherwise return all events
-            if !self.search.results.is_empty()
-                && self.search.mode != SearchMode::LocalFilter
-                && self.search.mode == SearchMode::ApiSearch
-            {
-                return self.search.results.iter().collect();
+            // If we have API search results, return those; otherwise return all events
+            if !self.search.results.is_empty() && self.search.mode == SearchMode::ApiSearch {
+                return all(EventSource::SearchResults, &self.search.results);
             }
-            return self.events.iter().collect();
+            return all(EventSource::Events, &self.events);
         }
 
         // If in local filter mode, always filter from the source list
         if self.search.mode == SearchMode::LocalFilter {
             // Determine source list: if we have search results, filter from those; otherwise filter from events
-            let query_lower = self.search.query.to_lowercase();
             if !self.search.results.is_empty() {
-                // Filter from search results (current displayed list)
-                return self
-                    .search
-                    .results
-                    .iter()
-                    .filter(|event| {
-                        event.title.to_lowercase().contains(&query_lower)
-                            || event.slug.to_lowercase().contains(&query_lower)
-                            || event
-                                .tags
-                                .iter()
-                                .any(|tag| tag.label.to_lowercase().contains(&query_lower))
-                            || event
-                                .markets
-                                .iter()
-                                .any(|market| market.question.to_lowercase().contains(&query_lower))
-                    })
-                    .collect();
-            } else {
-                // Filter from events list
-                return self
-                    .events
-                    .iter()
-                    .filter(|event| {
-                        event.title.to_lowercase().contains(&query_lower)
-                            || event.slug.to_lowercase().contains(&query_lower)
-                            || event
-                                .tags
-                                .iter()
-                                .any(|tag| tag.label.to_lowercase().contains(&query_lower))
-                            || event
-                                .markets
-                                .iter()
-                                .any(|market| market.question.to_lowercase().contains(&query_lower))
-                    })
-                    .collect();
+                return matching(
+                    EventSource::SearchResults,
+                    &self.search.results,
+                    &self.search.query,
+                );
             }
+            return matching(EventSource::Events, &self.events, &self.search.query);
         }
 
         // API search mode: use API results if available
         if !self.search.results.is_empty() && self.search.query == self.search.last_searched_query {
-            return self.search.results.iter().collect();
+            return all(EventSource::SearchResults, &self.search.results);
         }
 
         // Fall back to local filtering
-        let query_lower = self.search.query.to_lowercase();
-        self.events
-            .iter()
-            .filter(|event| {
-                event.title.to_lowercase().contains(&query_lower)
-                    || event.slug.to_lowercase().contains(&query_lower)
-                    || event
-                        .tags
-                        .iter()
-                        .any(|tag| tag.label.to_lowercase().contains(&query_lower))
-                    || event
-                        .markets
-                        .iter()
-                        .any(|market| market.question.to_lowercase().contains(&query_lower))
-            })
-            .collect()
+        matching(EventSource::Events, &self.events, &self.search.query)
     }
 
     /// Get the currently selected event from filtered list
     /// Uses current_selected_index() to be tab-aware
     pub fn selected_event_filtered(&self) -> Option<&Event> {
-        let filtered = self.filtered_events();
-        let selected_idx = self.current_selected_index();
-        filtered.get(selected_idx).copied()
+        self.filtered_event(self.current_selected_index())
+    }
+
+    /// Keep the selection inside the filtered list after its contents change
+    ///
+    /// Filter and search results can shrink underneath the selection (e.g. async results
+    /// arriving mid-typing), which would otherwise leave the details panel blank.
+    pub fn clamp_selection_to_filtered(&mut self) {
+        let last_index = self.filtered_events_len().saturating_sub(1);
+        let (selected_index, scroll) = match self.main_tab {
+            MainTab::Favorites => (
+                &mut self.favorites_state.selected_index,
+                &mut self.favorites_state.scroll,
+            ),
+            _ => (
+                &mut self.navigation.selected_index,
+                &mut self.scroll.events_list,
+            ),
+        };
+        *selected_index = (*selected_index).min(last_index);
+        *scroll = (*scroll).min(*selected_index);
     }
 
     pub fn enter_search_mode(&mut self) {
         self.search.mode = SearchMode::ApiSearch;
         self.search.query.clear();
+        self.search.history_index = None;
+        self.search.load_history();
     }
 
     pub fn enter_local_filter_mode(&mut self) {
@@ -340,38 +907,91 @@ impl TrendingAppState {
     pub fn exit_search_mode(&mut self) {
         self.search.mode = SearchMode::None;
         self.search.query.clear();
-        self.search.results.clear();
+        self.search.clear_results();
         self.search.last_searched_query.clear();
+        self.search.last_keystroke = None;
         self.navigation.selected_index = 0;
         self.scroll.events_list = 0;
     }
 
+    /// Whether the API search for the current query finished without any results (as opposed
+    /// to still running or not having been sent yet)
+    pub fn api_search_found_nothing(&self) -> bool {
+        self.search.mode == SearchMode::ApiSearch
+            && !self.search.is_searching
+            && !self.search.query.is_empty()
+            && self.search.query == self.search.last_searched_query
+            && self.search.results.is_empty()
+    }
+
+    /// Whether any API request with a loading flag (events, search, orderbook, favorites,
+    /// positions, yield) is in flight
+    pub fn is_network_busy(&self) -> bool {
+        self.pagination.is_fetching_more
+            || self.search.is_searching
+            || self.orderbook_state.is_loading
+            || self.favorites_state.is_loading
+            || self.positions_state.is_loading
+            || self.yield_state.is_loading
+            || self.yield_state.is_search_loading
+    }
+
     pub fn is_in_filter_mode(&self) -> bool {
         self.search.is_active()
     }
 
     pub fn add_search_char(&mut self, c: char) {
         self.search.query.push(c);
+        self.search.history_index = None;
+        self.search.record_keystroke();
         self.navigation.selected_index = 0;
         self.scroll.events_list = 0;
     }
 
     pub fn delete_search_char(&mut self) {
         self.search.query.pop();
+        self.search.history_index = None;
+        self.search.record_keystroke();
         self.navigation.selected_index = 0;
         self.scroll.events_list = 0;
         // Clear search results when query changes
         if self.search.query != self.search.last_searched_query {
-            self.search.results.clear();
+            self.search.clear_results();
         }
+        self.clamp_selection_to_filtered();
     }
 
-    pub fn set_search_results(&mut self, results: Vec<Event>, query: String) {
-        self.search.results = results;
+    /// Show the results of an API search. Returns the updated search history, which the
+    /// caller saves once the lock is released.
+    pub fn set_search_results(
+        &mut self,
+        results: Vec<Event>,
+        query: String,
+    ) -> Option<Vec<String>> {
+        let history = self.search.record_search(&query);
+        self.search.set_results(results);
         self.search.last_searched_query = query;
         self.search.is_searching = false;
         self.navigation.selected_index = 0;
         self.scroll.events_list = 0;
+        history
+    }
+
+    /// Recall an older (`older = true`) or newer API search query from the history
+    pub fn recall_search_history(&mut self, older: bool) {
+        let changed = if older {
+            self.search.recall_older()
+        } else {
+            self.search.recall_newer()
+        };
+        if changed {
+            self.search.record_keystroke();
+            self.navigation.selected_index = 0;
+            self.scroll.events_list = 0;
+            if self.search.query != self.search.last_searched_query {
+                self.search.clear_results();
+            }
+        }
     }
 
     pub fn set_searching(&mut self, searching: bool) {
@@ -392,6 +1012,16 @@ impl TrendingAppState {
         self.selected_event().map(|e| e.slug.clone())
     }
 
+    /// Events up to `radius` rows above and below the selection in the current tab's list
+    pub fn neighbor_events(&self, radius: usize) -> Vec<&Event> {
+        let selected = self.current_selected_index();
+        (1..=radius)
+            .flat_map(|distance| [selected.checked_sub(distance), Some(selected + distance)])
+            .flatten()
+            .filter_map(|idx| self.filtered_event(idx))
+            .collect()
+    }
+
     /// Get the current tab's selected index
     pub fn current_selected_index(&self) -> usize {
         match self.main_tab {
@@ -428,11 +1058,11 @@ impl TrendingAppState {
         }
     }
 
-    pub fn move_down(&mut self) {
-        let filtered_len = self.filtered_events().len();
+    pub fn move_down(&mut self, visible_height: usize) {
+        let filtered_len = self.filtered_events_len();
         if self.navigation.selected_index < filtered_len.saturating_sub(1) {
             self.navigation.selected_index += 1;
-            let visible_height = 20;
+            let visible_height = visible_height.max(1);
             if self.navigation.selected_index >= self.scroll.events_list + visible_height {
                 self.scroll.events_list = self.navigation.selected_index - visible_height + 1;
             }
@@ -441,6 +1071,200 @@ impl TrendingAppState {
         }
     }
 
+    /// Move the selection up by one page, keeping it visible
+    pub fn page_up(&mut self, visible_height: usize) {
+        let page = visible_height.max(1);
+        let new_index = self.navigation.selected_index.saturating_sub(page);
+        if new_index != self.navigation.selected_index {
+            self.navigation.selected_index = new_index;
+            self.scroll.events_list = self.scroll.events_list.saturating_sub(page).min(new_index);
+            self.scroll.markets = 0;
+        }
+    }
+
+    /// Move the selection down by one page, keeping it visible
+    pub fn page_down(&mut self, visible_height: usize) {
+        let page = visible_height.max(1);
+        let filtered_len = self.filtered_events_len();
+        let new_index = (self.navigation.selected_index + page).min(filtered_len.saturating_sub(1));
+        if new_index != self.navigation.selected_index {
+            self.navigation.selected_index = new_index;
+            let max_scroll = filtered_len.saturating_sub(page);
+            self.scroll.events_list = (self.scroll.events_list + page)
+                .min(max_scroll)
+                .max((new_index + 1).saturating_sub(page));
+            self.scroll.markets = 0;
+        }
+    }
+
+    /// Jump to the first event in the (filtered) list
+    pub fn select_first_event(&mut self) {
+        self.navigation.selected_index = 0;
+        self.scroll.events_list = 0;
+        self.scroll.markets = 0;
+    }
+
+    /// Raise or lower the events list's minimum volume by one step, keeping the selection
+    /// inside the shrunk or grown list
+    pub fn step_min_volume_filter(&mut self, increase: bool) {
+        self.min_volume_filter = if increase {
+            self.min_volume_filter + MIN_VOLUME_FILTER_STEP
+        } else {
+            (self.min_volume_filter - MIN_VOLUME_FILTER_STEP).max(0.0)
+        };
+        self.clamp_selection_to_filtered();
+    }
+
+    /// Show only events the live trade feed can stream, or all events again, keeping the
+    /// selection inside the list. Returns whether the filter is now on.
+    pub fn toggle_streamable_only(&mut self) -> bool {
+        self.streamable_only = !self.streamable_only;
+        self.clamp_selection_to_filtered();
+        self.streamable_only
+    }
+
+    /// Open the note popup for an event, starting from its current note
+    pub fn open_note_editor(&mut self, slug: String) {
+        self.note_input = self.notes.get(&slug).cloned().unwrap_or_default();
+        self.show_popup(PopupType::EditNote(slug));
+    }
+
+    pub fn add_note_char(&mut self, c: char) {
+        if self.note_input.chars().count() < MAX_NOTE_CHARS {
+            self.note_input.push(c);
+        }
+    }
+
+    pub fn delete_note_char(&mut self) {
+        self.note_input.pop();
+    }
+
+    /// Save the typed note on `slug` (an empty note removes it) and write all notes.
+    /// Returns whether the event now has a note.
+    pub fn save_note(&mut self, slug: &str) -> bool {
+        let note = self.note_input.trim();
+        let has_note = !note.is_empty();
+        if has_note {
+            self.notes.insert(slug.to_string(), note.to_string());
+        } else {
+            self.notes.remove(slug);
+        }
+        self.note_input.clear();
+        if let Err(_e) = save_notes(&self.notes) {
+            log_warn!("Failed to save notes: {}", _e);
+        }
+        has_note
+    }
+
+    /// Open the tag picker over the listed events, ignoring any active tag filter
+    pub fn open_tag_filter(&mut self) {
+        let options = tag_counts(self.untagged_filtered_events());
+        self.tag_filter.open(options);
+        self.show_popup(PopupType::TagFilter);
+    }
+
+    /// Filter the events list to the highlighted tag, returning its label
+    pub fn apply_tag_filter(&mut self) -> Option<String> {
+        let label = self.tag_filter.apply_selected()?.to_string();
+        self.select_first_listed_event();
+        Some(label)
+    }
+
+    /// Remove the tag filter. Returns false if none was active.
+    pub fn clear_tag_filter(&mut self) -> bool {
+        if self.tag_filter.active.take().is_none() {
+            return false;
+        }
+        self.select_first_listed_event();
+        true
+    }
+
+    /// Select the first event of the current tab's list
+    fn select_first_listed_event(&mut self) {
+        if self.main_tab == MainTab::Favorites {
+            self.favorites_state.select_first();
+            self.scroll.markets = 0;
+        } else {
+            self.select_first_event();
+        }
+    }
+
+    /// Jump to the last event in the (filtered) list, placing it on the last visible row
+    pub fn select_last_event(&mut self, visible_height: usize) {
+        let filtered_len = self.filtered_events_len();
+        self.navigation.selected_index = filtered_len.saturating_sub(1);
+        self.scroll.events_list = filtered_len.saturating_sub(visible_height.max(1));
+        self.scroll.markets = 0;
+    }
+
+    /// Select the first (`to_last` = false) or last market of the displayed event for the
+    /// orderbook, keeping it visible. Returns true if the selection changed.
+    pub fn select_edge_market(&mut self, to_last: bool, visible_height: usize) -> bool {
+        let market_count = self
+            .current_tab_selected_event()
+            .map(|e| self.sorted_markets(e).len())
+            .unwrap_or(0);
+        if market_count == 0 {
+            return false;
+        }
+        let new_idx = if to_last {
+            market_count - 1
+        } else {
+            0
+        };
+        self.scroll.markets = if to_last {
+            market_count.saturating_sub(visible_height.max(1))
+        } else {
+            0
+        };
+        if new_idx == self.orderbook_state.selected_market_index {
+            return false;
+        }
+        self.orderbook_state.selected_market_index = new_idx;
+        self.orderbook_state.orderbook = None;
+        true
+    }
+
+    /// Move the orderbook's market selection one step through the displayed event's sorted
+    /// markets, clamped to the ends and kept visible. Returns the newly selected market's
+    /// question, or None if the selection didn't move.
+    pub fn step_selected_market(&mut self, forward: bool, visible_height: usize) -> Option<String> {
+        let event = self.current_tab_selected_event()?;
+        let sorted = self.sorted_markets(event);
+        let current = self.orderbook_state.selected_market_index;
+        let new_idx = if forward {
+            (current + 1).min(sorted.len().saturating_sub(1))
+        } else {
+            current.saturating_sub(1)
+        };
+        if new_idx == current || new_idx >= sorted.len() {
+            return None;
+        }
+        let question = sorted[new_idx].question.clone();
+        self.orderbook_state.selected_market_index = new_idx;
+        self.orderbook_state.orderbook = None;
+        let visible_height = visible_height.max(1);
+        if new_idx < self.scroll.markets {
+            self.scroll.markets = new_idx;
+        } else if new_idx >= self.scroll.markets + visible_height {
+            self.scroll.markets = new_idx + 1 - visible_height;
+        }
+        Some(question)
+    }
+
+    /// Scroll the trades table of the displayed event to its top or bottom
+    pub fn scroll_trades_to_edge(&mut self, to_bottom: bool, visible_height: usize) {
+        let trades_len = self
+            .current_tab_selected_event()
+            .map(|e| self.get_trades(&e.slug).len())
+            .unwrap_or(0);
+        self.scroll.trades = if to_bottom {
+            trades_len.saturating_sub(visible_height.max(1))
+        } else {
+            0
+        };
+    }
+
     pub fn is_watching(&self, event_slug: &str) -> bool {
         self.trades
             .event_trades
@@ -449,6 +1273,135 @@ impl TrendingAppState {
             .unwrap_or(false)
     }
 
+    /// Current height of the event details panel on the Events and Favorites tabs
+    pub fn event_details_height(&self) -> u16 {
+        if self.compact_event_details {
+            COMPACT_EVENT_DETAILS_HEIGHT
+        } else {
+            EVENT_DETAILS_HEIGHT
+        }
+    }
+
+    /// Height of the header (tabs and separator, plus the search input while searching)
+    pub fn header_height(&self) -> u16 {
+        if self.is_in_filter_mode() {
+            5
+        } else {
+            2
+        }
+    }
+
+    /// Rows above the main content: the header plus the load error banner when shown
+    pub fn top_height(&self) -> u16 {
+        match self.load_error {
+            Some(_) => self.header_height() + LOAD_ERROR_BANNER_HEIGHT,
+            None => self.header_height(),
+        }
+    }
+
+    /// Gamma API health for the status dots; a failed initial load counts as unhealthy
+    /// even if the status endpoint still answers
+    pub fn gamma_health(&self) -> Option<bool> {
+        if self.load_error.is_some() {
+            Some(false)
+        } else {
+            self.gamma_api_status
+        }
+    }
+
+    /// Whether there are favorites to load: the account's (when logged in) or local bookmarks
+    pub fn can_load_favorites(&self) -> bool {
+        self.auth_state.is_authenticated || !self.favorites_state.local_slugs.is_empty()
+    }
+
+    /// Log out: reset the account state and drop its favorites and positions (local
+    /// bookmarks stay). With `forget_credentials` the saved login is deleted too, otherwise
+    /// the next start logs back in. A portfolio fetch still in flight is discarded when it
+    /// lands, and the portfolio auto-refresh only runs while logged in.
+    pub fn logout(&mut self, forget_credentials: bool) {
+        if forget_credentials && let Err(_e) = crate::auth::AuthConfig::delete() {
+            log_warn!("{}", _e);
+        }
+        self.auth_state = AuthState::new();
+        self.favorites_state.clear_account();
+        self.positions_state.clear();
+        self.has_clob_auth = false;
+        log_info!("Logged out");
+    }
+
+    /// Record a failed API call for the errors popup. A timeout is also logged as a warning
+    /// and marks the API it hit as unhealthy in the header.
+    pub fn record_api_error(&mut self, context: &str, error: &PolymarketError) {
+        if error.is_timeout() {
+            log_warn!(
+                "{} request timed out after {}s",
+                context,
+                polymarket_api::request_timeout().as_secs()
+            );
+            let url = error.url().unwrap_or_default();
+            if url.starts_with(&self.endpoints.gamma_api.url) {
+                self.gamma_api_status = Some(false);
+            } else if url.starts_with(&self.endpoints.data_api.url) {
+                self.data_api_status = Some(false);
+            }
+        }
+        self.api_errors.record(context, error);
+    }
+
+    /// Show a transient message in the footer
+    pub fn show_toast(&mut self, message: impl Into<String>) {
+        self.toast = Some(Toast::new(message));
+    }
+
+    /// The current toast message, if one is showing
+    pub fn active_toast(&self) -> Option<&str> {
+        self.toast
+            .as_ref()
+            .filter(|t| !t.is_expired())
+            .map(|t| t.message.as_str())
+    }
+
+    /// Merge freshly fetched prices, remembering each asset's previous price and
+    /// appending to its rolling history
+    pub fn update_market_prices(&mut self, prices: HashMap<String, f64>) {
+        if !prices.is_empty() {
+            self.prices_updated_at = Some(Instant::now());
+        }
+        for (asset_id, price) in prices {
+            let history = self.price_history.entry(asset_id.clone()).or_default();
+            if history.len() == MAX_PRICE_HISTORY {
+                history.remove(0);
+            }
+            history.push(price);
+            if let Some(old) = self.market_prices.insert(asset_id.clone(), price) {
+                self.prev_prices.insert(asset_id, old);
+            }
+        }
+    }
+
+    /// Write every loaded event to a timestamped JSON file in the working directory,
+    /// with each market's `outcome_prices` replaced by the live price where one was fetched.
+    /// Returns the file name.
+    pub fn save_events_to_file(&self) -> std::io::Result<String> {
+        let mut events = self.events.clone();
+        for market in events.iter_mut().flat_map(|event| event.markets.iter_mut()) {
+            let Some(token_ids) = &market.clob_token_ids else {
+                continue;
+            };
+            for (price, token_id) in market.outcome_prices.iter_mut().zip(token_ids) {
+                if let Some(live) = self.market_prices.get(token_id) {
+                    *price = live.to_string();
+                }
+            }
+        }
+
+        let timestamp = chrono::Local::now().format("%Y%m%d_%H%M%S");
+        let filename = format!("polymarket_events_{}.json", timestamp);
+        let file = std::fs::File::create(&filename)?;
+        serde_json::to_writer_pretty(std::io::BufWriter::new(file), &events)?;
+        Ok(filename)
+    }
+
     pub fn get_trades(&self, event_slug: &str) -> &[Trade] {
         self.trades
             .event_trades
@@ -457,12 +1410,20 @@ impl TrendingAppState {
             .unwrap_or(&[])
     }
 
-    pub fn start_watching(&mut self, event_slug: String, ws_handle: JoinHandle<()>) {
-        self.trades
+    /// Mark an event as connecting, before its stream task is spawned so that the task's
+    /// first state update can't be overwritten
+    pub fn start_watching(&mut self, event_slug: &str) {
+        let event_trades = self
+            .trades
             .event_trades
-            .entry(event_slug.clone())
-            .or_insert_with(EventTrades::new)
-            .is_watching = true;
+            .entry(event_slug.to_string())
+            .or_insert_with(EventTrades::new);
+        event_trades.is_watching = true;
+        event_trades.connection_state = ConnectionState::Connecting;
+    }
+
+    /// Keep the handle of a stream task started after `start_watching`, so it can be stopped
+    pub fn set_watch_handle(&mut self, event_slug: String, ws_handle: JoinHandle<()>) {
         self.trades.ws_handles.insert(event_slug, ws_handle);
     }
 
@@ -472,6 +1433,47 @@ impl TrendingAppState {
         }
         if let Some(event_trades) = self.trades.event_trades.get_mut(event_slug) {
             event_trades.is_watching = false;
+            event_trades.connection_state = ConnectionState::Disconnected;
+        }
+    }
+
+    /// Stop every open trade stream, returning how many were stopped
+    pub fn stop_watching_all(&mut self) -> usize {
+        self.secondary_watch_slug = None;
+        self.split_watch_started_stream = false;
+        let slugs: Vec<String> = self.trades.ws_handles.keys().cloned().collect();
+        for slug in &slugs {
+            self.stop_watching(slug);
+        }
+        slugs.len()
+    }
+
+    /// Leave the split view, stopping the second panel's stream if the split view started it
+    /// and its event isn't the one selected in the main panel. A stream that was already open
+    /// keeps running. Returns the event the split view showed.
+    pub fn exit_split_watch(&mut self) -> Option<String> {
+        let slug = self.secondary_watch_slug.take()?;
+        let started_stream = std::mem::take(&mut self.split_watch_started_stream);
+        let is_primary = self
+            .current_tab_selected_event()
+            .is_some_and(|event| event.slug == slug);
+        if started_stream && !is_primary {
+            self.stop_watching(&slug);
+        }
+        Some(slug)
+    }
+
+    /// Record a connection state change reported by an event's WebSocket task.
+    /// A failed connection stops watching so that Enter starts a fresh one.
+    pub fn set_connection_state(&mut self, event_slug: &str, state: ConnectionState) {
+        if state == ConnectionState::Error {
+            self.trades.ws_handles.remove(event_slug);
+        }
+        if let Some(event_trades) = self.trades.event_trades.get_mut(event_slug) {
+            event_trades.connection_state = state;
+            if state == ConnectionState::Error {
+                event_trades.is_watching = false;
+            }
         }
     }
 
@@ -482,3 +1484,73 @@ impl TrendingAppState {
         self.trades.ws_handles.clear();
     }
 }
+
+/// Whether a market's question or group title contains `query_lower`
+fn market_matches(market: &Market, query_lower: &str) -> bool {
+    market.question.to_lowercase().contains(query_lower)
+        || market
+            .group_item_title
+            .as_ref()
+            .is_some_and(|title| title.to_lowercase().contains(query_lower))
+}
+
+/// Sort events in place by `sort_by` (highest values first, except Ending Soon)
+fn sort_events_by(events: &mut [Event], sort_by: EventSortBy) {
+    match sort_by {
+        EventSortBy::Volume24hr => {
+            events.sort_by(|a, b| {
+                b.volume_24hr
+                    .partial_cmp(&a.volume_24hr)
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            });
+        },
+        EventSortBy::VolumeTotal => {
+            events.sort_by(|a, b| {
+                b.volume
+                    .partial_cmp(&a.volume)
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            });
+        },
+        EventSortBy::Liquidity => {
+            events.sort_by(|a, b| {
+                b.liquidity
+                    .partial_cmp(&a.liquidity)
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            });
+        },
+        EventSortBy::OpenInterest => {
+            // Events without open interest data sort last
+            events.sort_by(|a, b| {
+                b.total_open_interest()
+                    .partial_cmp(&a.total_open_interest())
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            });
+        },
+        EventSortBy::Newest => {
+            // Sort by created_at descending (newest first)
+            events.sort_by(|a, b| match (&b.created_at, &a.created_at) {
+                (Some(b_date), Some(a_date)) => b_date.cmp(a_date),
+                (Some(_), None) => std::cmp::Ordering::Less,
+                (None, Some(_)) => std::cmp::Ordering::Greater,
+                (None, None) => std::cmp::Ordering::Equal,
+            });
+        },
+        EventSortBy::EndingSoon => {
+            // Sort by end_date ascending (soonest first), None at end
+            events.sort_by(|a, b| match (&a.end_date, &b.end_date) {
+                (Some(a_date), Some(b_date)) => a_date.cmp(b_date),
+                (Some(_), None) => std::cmp::Ordering::Less,
+                (None, Some(_)) => std::cmp::Ordering::Greater,
+                (None, None) => std::cmp::Ordering::Equal,
+            });
+        },
+        EventSortBy::Competitive => {
+            // Sort by competitive score descending (most competitive first)
+            events.sort_by(|a, b| {
+                b.competitive
+                    .partial_cmp(&a.competitive)
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            });
+        },
+    }
+}
@@ -1,5 +1,10 @@
 //! Authentication state types
 
+use std::time::Instant;
+
+/// Most session P&L samples kept for the profile sparkline (one per portfolio refresh)
+pub const MAX_PNL_SAMPLES: usize = 36;
+
 /// Login form field being edited
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum LoginField {
@@ -154,12 +159,14 @@ pub struct AuthState {
     pub is_authenticated: bool,
     pub username: Option<String>,
     pub address: Option<String>,
-    pub balance: Option<f64>,           // USDC cash balance
-    pub portfolio_value: Option<f64>,   // Total portfolio value (positions)
+    pub has_session_cookie: bool, // Saved login includes session cookies (account favorites)
+    pub balance: Option<f64>,     // USDC cash balance
+    pub portfolio_value: Option<f64>, // Total portfolio value (positions)
     pub positions_count: Option<usize>, // Number of open positions
-    pub unrealized_pnl: Option<f64>,    // Unrealized profit/loss
-    pub realized_pnl: Option<f64>,      // Realized profit/loss
+    pub unrealized_pnl: Option<f64>, // Unrealized profit/loss
+    pub realized_pnl: Option<f64>, // Realized profit/loss
     pub profile: Option<UserProfile>,
+    pub pnl_history: Vec<(Instant, f64)>, // Total P&L this session, oldest first
 }
 
 impl AuthState {
@@ -168,13 +175,27 @@ impl AuthState {
             is_authenticated: false,
             username: None,
             address: None,
+            has_session_cookie: false,
             balance: None,
             portfolio_value: None,
             positions_count: None,
             unrealized_pnl: None,
             realized_pnl: None,
             profile: None,
+            pnl_history: Vec::new(),
+        }
+    }
+
+    /// Record the current total P&L, dropping the oldest sample once the history is full
+    pub fn record_pnl_sample(&mut self) {
+        if self.unrealized_pnl.is_none() && self.realized_pnl.is_none() {
+            return;
+        }
+        if self.pnl_history.len() == MAX_PNL_SAMPLES {
+            self.pnl_history.remove(0);
         }
+        let total = self.unrealized_pnl.unwrap_or(0.0) + self.realized_pnl.unwrap_or(0.0);
+        self.pnl_history.push((Instant::now(), total));
     }
 
     pub fn display_name(&self) -> String {
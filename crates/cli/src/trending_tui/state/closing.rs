@@ -0,0 +1,46 @@
+//! Faster refresh cadence for events about to close
+
+use {
+    chrono::{DateTime, Utc},
+    polymarket_api::gamma::Event,
+    std::time::Duration,
+};
+
+/// Start refreshing faster this long before an event's end date
+const CLOSING_WINDOW_SECS: i64 = 15 * 60;
+/// Refresh every second during the final stretch before the end date
+const FINAL_WINDOW_SECS: i64 = 2 * 60;
+/// Keep the faster cadence after the end date to catch the resolution
+const RESOLUTION_WINDOW_SECS: i64 = 10 * 60;
+
+const CLOSING_REFRESH_INTERVAL: Duration = Duration::from_secs(2);
+const FINAL_REFRESH_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Parse an RFC 3339 timestamp (e.g. an event end or creation date) into UTC
+pub fn parse_utc_timestamp(date_str: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(date_str)
+        .ok()
+        .map(|dt| dt.with_timezone(&Utc))
+}
+
+/// Parse an event's end date
+pub fn event_end_date(event: &Event) -> Option<DateTime<Utc>> {
+    parse_utc_timestamp(event.end_date.as_deref()?)
+}
+
+/// Refresh interval for an event near its end date, or `None` to use the normal cadence
+pub fn closing_refresh_interval(event: &Event, now: DateTime<Utc>) -> Option<Duration> {
+    if event.closed {
+        return None;
+    }
+    let remaining = event_end_date(event)?
+        .signed_duration_since(now)
+        .num_seconds();
+    if remaining > CLOSING_WINDOW_SECS || remaining <= -RESOLUTION_WINDOW_SECS {
+        None
+    } else if remaining > FINAL_WINDOW_SECS || remaining <= 0 {
+        Some(CLOSING_REFRESH_INTERVAL)
+    } else {
+        Some(FINAL_REFRESH_INTERVAL)
+    }
+}
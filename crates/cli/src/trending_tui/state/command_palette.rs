@@ -0,0 +1,177 @@
+//! Command palette (':'): every main-screen action, searchable by name
+
+/// What running a palette command does
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PaletteAction {
+    /// Replay this key through the main key handlers
+    Key(char),
+    /// Open the login form, or the profile when already logged in (like the header button)
+    Login,
+}
+
+/// Command names and actions, in the order shown for an empty query
+fn command_registry() -> Vec<(&'static str, PaletteAction)> {
+    use PaletteAction::{Key, Login};
+    vec![
+        ("Refresh events", Key('r')),
+        ("Retry failed load or orderbook fetch", Key('R')),
+        ("Search Polymarket", Key('/')),
+        ("Filter current list", Key('f')),
+        ("Filter events by tag", Key('#')),
+        ("Cycle sort", Key('s')),
+        ("Raise events min volume", Key('+')),
+        ("Lower events min volume", Key('-')),
+        ("Show only events with live trades", Key('y')),
+        ("Go to Events tab", Key('1')),
+        ("Go to Favorites tab", Key('2')),
+        ("Go to Breaking tab", Key('3')),
+        ("Go to Yield tab", Key('4')),
+        ("Go to Positions tab", Key('5')),
+        ("Go to Closing Soon tab", Key('6')),
+        ("Go to New tab", Key('7')),
+        ("Open event in browser", Key('o')),
+        ("Show event info", Key('i')),
+        ("Show resolution rules", Key('u')),
+        ("Bookmark/unbookmark event", Key('b')),
+        ("Edit event note", Key('n')),
+        ("Resync favorites", Key('F')),
+        ("Watch all visible events", Key('w')),
+        ("Stop watching all events", Key('W')),
+        ("Split view: follow event trades", Key('d')),
+        ("Pin/unpin market", Key('x')),
+        ("Previous market in orderbook", Key('[')),
+        ("Next market in orderbook", Key(']')),
+        ("Toggle orderbook outcome", Key('t')),
+        ("Show both orderbooks side by side", Key('T')),
+        ("Group orderbook price levels", Key('B')),
+        ("Show/cancel open orders", Key('X')),
+        ("Reverse trades order", Key('O')),
+        ("Export session trades summary to clipboard", Key('C')),
+        ("Cycle large-trade alert threshold", Key('A')),
+        ("Toggle logs panel", Key('l')),
+        ("Save logs to file", Key('S')),
+        ("Save screen snapshot", Key('P')),
+        ("Export events as JSON", Key('J')),
+        ("Show API errors", Key('E')),
+        ("Show/hide event age", Key('a')),
+        ("Show/hide liquidity", Key('V')),
+        ("Toggle compact lists", Key('z')),
+        ("Collapse event details", Key('D')),
+        ("Cycle color theme", Key('c')),
+        ("Settings: refresh intervals", Key(',')),
+        ("Pause/resume auto-refresh", Key('Z')),
+        ("Login / profile", Login),
+        ("Help", Key('?')),
+        ("Quit", Key('q')),
+    ]
+}
+
+/// Rank of `name` for `query` (lower is better): a substring match ranks by its position,
+/// and letters found in order with gaps rank after every substring match. `None` means no match.
+fn match_rank(name: &str, query: &str) -> Option<usize> {
+    let name = name.to_lowercase();
+    let query = query.to_lowercase();
+    if let Some(position) = name.find(&query) {
+        return Some(position);
+    }
+    let mut name_chars = name.chars().enumerate();
+    let mut last = 0;
+    for q in query.chars().filter(|c| !c.is_whitespace()) {
+        last = name_chars.find(|(_, c)| *c == q)?.0;
+    }
+    Some(name.len() + last)
+}
+
+/// Command palette state: the registry, the typed query and the highlighted match
+#[derive(Debug)]
+pub struct CommandPaletteState {
+    pub commands: Vec<(&'static str, PaletteAction)>,
+    pub query: String,
+    pub selected: usize, // Index into `matches()`
+}
+
+impl Default for CommandPaletteState {
+    fn default() -> Self {
+        Self {
+            commands: command_registry(),
+            query: String::new(),
+            selected: 0,
+        }
+    }
+}
+
+impl CommandPaletteState {
+    /// Start from an empty query with the first command highlighted
+    pub fn reset(&mut self) {
+        self.query.clear();
+        self.selected = 0;
+    }
+
+    /// Commands matching the query, best first
+    pub fn matches(&self) -> Vec<(&'static str, PaletteAction)> {
+        let mut ranked: Vec<_> = self
+            .commands
+            .iter()
+            .filter_map(|&(name, action)| {
+                match_rank(name, &self.query).map(|rank| (rank, name, action))
+            })
+            .collect();
+        ranked.sort_by_key(|(rank, ..)| *rank);
+        ranked
+            .into_iter()
+            .map(|(_, name, action)| (name, action))
+            .collect()
+    }
+
+    pub fn selected_action(&self) -> Option<PaletteAction> {
+        self.matches().get(self.selected).map(|(_, action)| *action)
+    }
+
+    pub fn add_char(&mut self, c: char) {
+        self.query.push(c);
+        self.selected = 0;
+    }
+
+    pub fn delete_char(&mut self) {
+        self.query.pop();
+        self.selected = 0;
+    }
+
+    pub fn select_next(&mut self) {
+        let count = self.matches().len();
+        self.selected = (self.selected + 1).min(count.saturating_sub(1));
+    }
+
+    pub fn select_previous(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn substring_matches_rank_before_scattered_letters() {
+        let mut palette = CommandPaletteState::default();
+        for c in "logs".chars() {
+            palette.add_char(c);
+        }
+        let names: Vec<_> = palette
+            .matches()
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect();
+        assert_eq!(names[..2], ["Save logs to file", "Toggle logs panel"]);
+
+        palette.reset();
+        for c in "TGLOGS".chars() {
+            palette.add_char(c);
+        }
+        assert_eq!(palette.selected_action(), Some(PaletteAction::Key('l')));
+
+        palette.add_char('#');
+        assert!(palette.matches().is_empty());
+        assert_eq!(palette.selected_action(), None);
+    }
+}
@@ -0,0 +1,29 @@
+//! JSON files in the config directory that keep state across restarts
+
+use {
+    serde::{Serialize, de::DeserializeOwned},
+    std::path::PathBuf,
+};
+
+fn path(file_name: &str) -> PathBuf {
+    crate::auth::AuthConfig::config_dir().join(file_name)
+}
+
+/// Read `file_name` from the config directory, or `None` if it is missing or unreadable
+pub fn load_json<T: DeserializeOwned>(file_name: &str) -> Option<T> {
+    let content = std::fs::read_to_string(path(file_name)).ok()?;
+    serde_json::from_str(&content).ok()
+}
+
+/// Write `value` to `file_name` in the config directory, creating the directory if needed.
+/// `what` names the data in error messages, e.g. "notes".
+pub fn save_json<T: Serialize>(file_name: &str, value: &T, what: &str) -> Result<(), String> {
+    let path = path(file_name);
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)
+            .map_err(|e| format!("Failed to create config directory: {}", e))?;
+    }
+    let content = serde_json::to_string_pretty(value)
+        .map_err(|e| format!("Failed to serialize {}: {}", what, e))?;
+    std::fs::write(&path, content).map_err(|e| format!("Failed to write {}: {}", what, e))
+}
@@ -1,13 +1,43 @@
 //! Favorites tab state
+//!
+//! The tab lists the account's favorites (synced through the Gamma API with session cookies)
+//! followed by events bookmarked locally, which need no login and are saved in the config
+//! directory.
 
-use {polymarket_api::gamma::Event, std::collections::HashSet};
+use {
+    super::config_file::{load_json, save_json},
+    polymarket_api::gamma::Event,
+    serde::{Deserialize, Serialize},
+    std::collections::{BTreeSet, HashSet},
+};
+
+/// Locally bookmarked event slugs, saved to `local_favorites.json` in the config directory
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+struct LocalFavorites {
+    slugs: BTreeSet<String>,
+}
+
+impl LocalFavorites {
+    const FILE: &'static str = "local_favorites.json";
+
+    fn load() -> Self {
+        load_json(Self::FILE).unwrap_or_default()
+    }
+
+    fn save(&self) -> Result<(), String> {
+        save_json(Self::FILE, self, "local favorites")
+    }
+}
 
 /// Favorites tab state
 #[derive(Debug)]
 pub struct FavoritesState {
     pub events: Vec<Event>,
+    pub events_generation: u64, // Bumped whenever `events` is replaced or changed
     pub favorite_ids: Vec<polymarket_api::FavoriteEvent>, // Favorite entries from API
-    pub favorite_event_slugs: HashSet<String>,            // Quick lookup for favorite slugs
+    pub favorite_event_slugs: HashSet<String>, // Quick lookup for favorite slugs
+    pub local_slugs: BTreeSet<String>, // Bookmarked on this machine only ('b' without cookies)
     pub selected_index: usize,
     pub scroll: usize,
     pub is_loading: bool,
@@ -19,8 +49,10 @@ impl FavoritesState {
     pub fn new() -> Self {
         Self {
             events: Vec::new(),
+            events_generation: 0,
             favorite_ids: Vec::new(),
             favorite_event_slugs: HashSet::new(),
+            local_slugs: LocalFavorites::load().slugs,
             selected_index: 0,
             scroll: 0,
             is_loading: false,
@@ -32,9 +64,56 @@ impl FavoritesState {
         self.events.get(self.selected_index)
     }
 
-    /// Check if an event slug is in favorites
+    /// Check if an event slug is in favorites (account or local)
     pub fn is_favorite(&self, slug: &str) -> bool {
-        self.favorite_event_slugs.contains(slug)
+        self.favorite_event_slugs.contains(slug) || self.local_slugs.contains(slug)
+    }
+
+    /// Call after changing `events` outside these methods, so the filtered list is rebuilt
+    pub fn mark_events_changed(&mut self) {
+        self.events_generation += 1;
+    }
+
+    pub fn is_local_favorite(&self, slug: &str) -> bool {
+        self.local_slugs.contains(slug)
+    }
+
+    /// Add or remove a local bookmark and save the set. `event` is listed right away when
+    /// given; otherwise it shows up on the next favorites load. Returns whether it was added.
+    pub fn toggle_local(&mut self, slug: &str, event: Option<Event>) -> bool {
+        let added = if self.local_slugs.remove(slug) {
+            // Account favorites stay listed
+            if !self.favorite_event_slugs.contains(slug) {
+                self.events.retain(|e| e.slug != slug);
+                self.mark_events_changed();
+                self.selected_index = self.selected_index.min(self.events.len().saturating_sub(1));
+                self.scroll = self.scroll.min(self.selected_index);
+            }
+            false
+        } else {
+            self.local_slugs.insert(slug.to_string());
+            if let Some(event) = event
+                && !self.events.iter().any(|e| e.slug == slug)
+            {
+                self.events.push(event);
+                self.mark_events_changed();
+            }
+            true
+        };
+        let saved = LocalFavorites {
+            slugs: self.local_slugs.clone(),
+        };
+        if let Err(_e) = saved.save() {
+            log_warn!("Failed to save local favorites: {}", _e);
+        }
+        added
+    }
+
+    /// Count favorites added and removed in `slugs` compared to the current list
+    pub fn diff_slugs(&self, slugs: &HashSet<String>) -> (usize, usize) {
+        let added = slugs.difference(&self.favorite_event_slugs).count();
+        let removed = self.favorite_event_slugs.difference(slugs).count();
+        (added, removed)
     }
 
     pub fn move_up(&mut self) {
@@ -55,9 +134,51 @@ impl FavoritesState {
         }
     }
 
+    /// Move the selection up by one page, keeping it visible
+    pub fn page_up(&mut self, visible_height: usize) {
+        let page = visible_height.max(1);
+        self.selected_index = self.selected_index.saturating_sub(page);
+        self.scroll = self.scroll.saturating_sub(page).min(self.selected_index);
+    }
+
+    /// Move the selection down by one page, keeping it visible
+    pub fn page_down(&mut self, visible_height: usize) {
+        let page = visible_height.max(1);
+        let len = self.events.len();
+        self.selected_index = (self.selected_index + page).min(len.saturating_sub(1));
+        self.scroll = (self.scroll + page)
+            .min(len.saturating_sub(page))
+            .max((self.selected_index + 1).saturating_sub(page));
+    }
+
+    /// Jump to the first favorite
+    pub fn select_first(&mut self) {
+        self.selected_index = 0;
+        self.scroll = 0;
+    }
+
+    /// Jump to the last favorite, placing it on the last visible row
+    pub fn select_last(&mut self, visible_height: usize) {
+        self.selected_index = self.events.len().saturating_sub(1);
+        self.scroll = self.events.len().saturating_sub(visible_height.max(1));
+    }
+
     pub fn clear(&mut self) {
         self.events.clear();
+        self.mark_events_changed();
+        self.favorite_ids.clear();
+        self.selected_index = 0;
+        self.scroll = 0;
+        self.error_message = None;
+    }
+
+    /// Drop the account's favorites (on logout), keeping the local bookmarks listed
+    pub fn clear_account(&mut self) {
+        let local_slugs = &self.local_slugs;
+        self.events.retain(|e| local_slugs.contains(&e.slug));
+        self.mark_events_changed();
         self.favorite_ids.clear();
+        self.favorite_event_slugs.clear();
         self.selected_index = 0;
         self.scroll = 0;
         self.error_message = None;
@@ -0,0 +1,102 @@
+//! Concurrency limit for orderbook and price requests
+
+use {
+    std::sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    },
+    tokio::sync::{OwnedSemaphorePermit, Semaphore},
+};
+
+/// Environment variable overriding the maximum number of in-flight orderbook/price requests
+pub const MAX_CONCURRENT_FETCHES_ENV: &str = "POLYMARKET_MAX_CONCURRENT_FETCHES";
+
+/// Limits how many orderbook/price requests are in flight at once.
+/// Requests beyond the limit queue for a permit instead of flooding the API.
+#[derive(Debug, Clone)]
+pub struct FetchLimiter {
+    semaphore: Arc<Semaphore>,
+    max_concurrent: usize,
+    queued: Arc<AtomicUsize>,  // Requests currently waiting for a permit
+    dropped: Arc<AtomicUsize>, // Queued requests discarded as stale once a permit was free
+}
+
+impl FetchLimiter {
+    pub const DEFAULT_MAX_CONCURRENT: usize = 4;
+
+    pub fn new(max_concurrent: usize) -> Self {
+        let max_concurrent = max_concurrent.max(1);
+        Self {
+            semaphore: Arc::new(Semaphore::new(max_concurrent)),
+            max_concurrent,
+            queued: Arc::new(AtomicUsize::new(0)),
+            dropped: Arc::new(AtomicUsize::new(0)),
+        }
+    }
+
+    /// Create a limiter sized from `POLYMARKET_MAX_CONCURRENT_FETCHES` (default 4)
+    pub fn from_env() -> Self {
+        let max_concurrent = std::env::var(MAX_CONCURRENT_FETCHES_ENV)
+            .ok()
+            .and_then(|v| v.trim().parse::<usize>().ok())
+            .filter(|&n| n > 0)
+            .unwrap_or(Self::DEFAULT_MAX_CONCURRENT);
+        Self::new(max_concurrent)
+    }
+
+    /// Wait for a request slot; the slot is released when the permit is dropped
+    pub async fn acquire(&self) -> OwnedSemaphorePermit {
+        if let Ok(permit) = Arc::clone(&self.semaphore).try_acquire_owned() {
+            return permit;
+        }
+        let _queued = QueuedGuard::new(&self.queued);
+        Arc::clone(&self.semaphore)
+            .acquire_owned()
+            .await
+            .expect("fetch semaphore is never closed")
+    }
+
+    /// Record a request that was skipped because it became stale while queued
+    pub fn record_dropped(&self) {
+        self.dropped.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn max_concurrent(&self) -> usize {
+        self.max_concurrent
+    }
+
+    pub fn in_flight(&self) -> usize {
+        self.max_concurrent - self.semaphore.available_permits()
+    }
+
+    pub fn queued(&self) -> usize {
+        self.queued.load(Ordering::Relaxed)
+    }
+
+    pub fn dropped(&self) -> usize {
+        self.dropped.load(Ordering::Relaxed)
+    }
+}
+
+/// Counts a request as queued for as long as it lives, so a waiter that is cancelled
+/// (its future dropped) stops counting too
+struct QueuedGuard<'a>(&'a AtomicUsize);
+
+impl<'a> QueuedGuard<'a> {
+    fn new(queued: &'a AtomicUsize) -> Self {
+        queued.fetch_add(1, Ordering::Relaxed);
+        Self(queued)
+    }
+}
+
+impl Drop for QueuedGuard<'_> {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+impl Default for FetchLimiter {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_MAX_CONCURRENT)
+    }
+}
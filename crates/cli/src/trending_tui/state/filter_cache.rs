@@ -0,0 +1,200 @@
+//! Memoized filtered events list and rendered rows
+//!
+//! Filtering lowercases and scans every loaded event, and the filtered list is needed several
+//! times per frame. The result is cached as indices into its source list and reused until one
+//! of its inputs changes. Each rebuild bumps the cache's generation, which keys the rendered
+//! events list rows so that scrolling a large list only formats rows it hasn't drawn yet.
+
+use {
+    super::{EventFilter, EventSortBy, MainTab, SearchMode, ThemePreset},
+    polymarket_api::gamma::Event,
+    ratatui::widgets::ListItem,
+    std::{borrow::Cow, collections::HashMap},
+};
+
+/// List the filtered events are drawn from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EventSource {
+    Events,
+    SearchResults,
+    Favorites,
+}
+
+/// Everything the filtered events list depends on. Lists are tracked by generation counters
+/// bumped whenever they are replaced or changed, and strings are borrowed from the app state
+/// when checking the cache, so a hit allocates nothing.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FilterKey<'a> {
+    pub main_tab: MainTab,
+    pub mode: SearchMode,
+    pub query: Cow<'a, str>,
+    pub last_searched_query: Cow<'a, str>,
+    pub events_generation: u64,
+    pub results_generation: u64,
+    pub favorites_generation: u64,
+    pub tag: Option<Cow<'a, str>>,
+    pub min_volume_bits: u64, // `to_bits` of the minimum volume filter
+    pub streamable_only: bool,
+}
+
+impl FilterKey<'_> {
+    pub fn into_owned(self) -> FilterKey<'static> {
+        FilterKey {
+            query: Cow::Owned(self.query.into_owned()),
+            last_searched_query: Cow::Owned(self.last_searched_query.into_owned()),
+            tag: self.tag.map(|tag| Cow::Owned(tag.into_owned())),
+            ..self
+        }
+    }
+}
+
+/// Cached filtered events, as indices into `source`
+#[derive(Debug)]
+pub struct FilteredEventsCache {
+    pub key: FilterKey<'static>,
+    pub source: EventSource,
+    pub indices: Vec<usize>,
+    pub generation: u64, // Bumped on every rebuild
+}
+
+/// Inputs shared by every rendered events list row; a change drops all cached rows
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EventRowsKey {
+    pub filter_generation: u64,
+    pub width: u16,
+    pub markets_width: usize, // Widest market count among the visible rows
+    pub theme_preset: ThemePreset,
+    pub event_filter: EventFilter,
+    pub sort_by: EventSortBy,
+    pub compact: bool,
+    pub show_liquidity: bool,
+    pub show_age: bool,
+    pub minute: i64, // Ages and resolution markers only change at minute resolution
+}
+
+/// Live inputs of a single row, compared on every frame
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EventRowKey {
+    pub selected: bool,
+    pub trade_count: usize,
+    pub watching: bool,
+    pub favorite: bool,
+    pub note: bool,
+}
+
+/// Rendered events list rows by position in the filtered list
+#[derive(Debug, Default)]
+pub struct EventRowsCache {
+    key: Option<EventRowsKey>,
+    rows: HashMap<usize, (EventRowKey, ListItem<'static>)>,
+}
+
+impl EventRowsCache {
+    /// The row at `position`, reused if it was built with the same inputs and built with
+    /// `build` otherwise
+    pub fn row(
+        &mut self,
+        key: EventRowsKey,
+        position: usize,
+        row_key: EventRowKey,
+        build: impl FnOnce() -> ListItem<'static>,
+    ) -> ListItem<'static> {
+        if self.key != Some(key) {
+            self.key = Some(key);
+            self.rows.clear();
+        }
+        match self.rows.get(&position) {
+            Some((cached_key, item)) if *cached_key == row_key => item.clone(),
+            _ => {
+                let item = build();
+                self.rows.insert(position, (row_key, item.clone()));
+                item
+            },
+        }
+    }
+}
+
+/// Summed volume of an event's markets (24h volume, falling back to total volume)
+pub fn event_volume(event: &Event) -> f64 {
+    event
+        .markets
+        .iter()
+        .map(|market| market.volume_24hr.or(market.volume_total).unwrap_or(0.0))
+        .sum()
+}
+
+/// Whether the live trade feed can stream an event: at least one open market has CLOB token ids
+pub fn event_streams_trades(event: &Event) -> bool {
+    event.markets.iter().any(|market| {
+        !market.closed
+            && market
+                .clob_token_ids
+                .as_ref()
+                .is_some_and(|ids| !ids.is_empty())
+    })
+}
+
+/// Whether an event's title, slug, tags or market questions contain the lowercased query
+pub fn event_matches(event: &Event, query_lower: &str) -> bool {
+    event.title.to_lowercase().contains(query_lower)
+        || event.slug.to_lowercase().contains(query_lower)
+        || event
+            .tags
+            .iter()
+            .any(|tag| tag.label.to_lowercase().contains(query_lower))
+        || event
+            .markets
+            .iter()
+            .any(|market| market.question.to_lowercase().contains(query_lower))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rows_key(filter_generation: u64) -> EventRowsKey {
+        EventRowsKey {
+            filter_generation,
+            width: 80,
+            markets_width: 1,
+            theme_preset: ThemePreset::default(),
+            event_filter: EventFilter::Trending,
+            sort_by: EventSortBy::default(),
+            compact: false,
+            show_liquidity: false,
+            show_age: false,
+            minute: 0,
+        }
+    }
+
+    const ROW: EventRowKey = EventRowKey {
+        selected: false,
+        trade_count: 0,
+        watching: false,
+        favorite: false,
+        note: false,
+    };
+
+    #[test]
+    fn rows_are_rebuilt_only_when_their_inputs_change() {
+        let mut cache = EventRowsCache::default();
+        let mut builds = 0;
+        let mut row = |cache: &mut EventRowsCache, key, row_key| {
+            cache.row(key, 3, row_key, || {
+                builds += 1;
+                ListItem::new("row")
+            });
+        };
+        row(&mut cache, rows_key(0), ROW);
+        row(&mut cache, rows_key(0), ROW);
+        row(&mut cache, rows_key(0), EventRowKey {
+            trade_count: 1,
+            ..ROW
+        });
+        row(&mut cache, rows_key(1), EventRowKey {
+            trade_count: 1,
+            ..ROW
+        });
+        assert_eq!(builds, 3);
+    }
+}
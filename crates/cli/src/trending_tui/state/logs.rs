@@ -1,10 +1,16 @@
 //! Logs state
 
+/// Environment variable that stops the logs panel from opening on the session's first error
+/// ("0"/"false"/"no"); any other value, or leaving it unset, keeps the default of opening it
+pub const SHOW_LOGS_ON_ERROR_ENV: &str = "POLYMARKET_SHOW_LOGS_ON_ERROR";
+
 /// Logs state
 #[derive(Debug)]
 pub struct LogsState {
     pub messages: Vec<String>,
     pub scroll: usize,
+    pub show_on_first_error: bool, // Open the logs panel on the first ERROR log (env config)
+    error_seen: bool,              // An ERROR log has been recorded this session
 }
 
 impl LogsState {
@@ -12,7 +18,21 @@ impl LogsState {
         Self {
             messages: Vec::new(),
             scroll: 0,
+            show_on_first_error: std::env::var(SHOW_LOGS_ON_ERROR_ENV).map_or(true, |v| {
+                !matches!(v.trim().to_ascii_lowercase().as_str(), "0" | "false" | "no")
+            }),
+            error_seen: false,
+        }
+    }
+
+    /// Record a log's level; true if it is the session's first error and the logs panel
+    /// should open for it
+    pub fn note_level(&mut self, level: &str) -> bool {
+        if level != "ERROR" || self.error_seen {
+            return false;
         }
+        self.error_seen = true;
+        self.show_on_first_error
     }
 
     /// Save all logs to a file. Returns the path to the saved file.
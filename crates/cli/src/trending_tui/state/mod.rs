@@ -1,44 +1,95 @@
 //! State types for the trending TUI
 //!
 //! This module contains all state types used by the TUI, organized into submodules:
+//! - `api_errors`: Captured API failures for the errors popup
 //! - `app_state`: Main application state (TrendingAppState)
 //! - `auth`: Authentication state (AuthState, LoginFormState, etc.)
+//! - `command_palette`: Searchable list of every action (':')
+//! - `closing`: Faster refresh cadence for events about to close
+//! - `config_file`: JSON files saved in the config directory
 //! - `favorites`: Favorites tab state
+//! - `fetch_limiter`: Concurrency limit for orderbook/price requests
+//! - `filter_cache`: Memoized filtered events list and rendered rows
 //! - `logs`: Logs panel state
 //! - `navigation`: Navigation, focus, and scroll state
+//! - `notes`: Per-event notes saved across restarts
 //! - `orderbook`: Orderbook panel state
 //! - `pagination`: Pagination state for infinite scrolling
 //! - `popup`: Popup/modal types
+//! - `positions`: Positions tab state
+//! - `refresh`: Per-source auto-refresh intervals
 //! - `search`: Search state
+//! - `startup`: Startup presets from environment variables
+//! - `tag_filter`: Tag filter for the events list
+//! - `theme_preset`: Color theme preset chosen at runtime
+//! - `toast`: Transient footer messages
 //! - `trade`: Trade form and trade data types
 //! - `trades_ws`: WebSocket trade management state
+//! - `ui_prefs`: Saved display toggles
 //! - `yield_state`: Yield tab state
 
+mod api_errors;
 mod app_state;
 mod auth;
+mod closing;
+mod command_palette;
+mod config_file;
 mod favorites;
+mod fetch_limiter;
+mod filter_cache;
 mod logs;
 mod navigation;
+mod notes;
 mod orderbook;
 mod pagination;
 mod popup;
+mod positions;
+mod refresh;
 mod search;
+mod startup;
+mod tag_filter;
+mod theme_preset;
+mod toast;
 mod trade;
 mod trades_ws;
+mod ui_prefs;
 mod yield_state;
 
 // Re-export all public types
 pub use {
-    app_state::TrendingAppState,
+    api_errors::{ApiErrorLog, redact_secrets},
+    app_state::{MAX_PRICE_HISTORY, TrendingAppState},
     auth::{AuthState, LoginField, LoginFormState, UserProfile},
+    closing::{closing_refresh_interval, event_end_date, parse_utc_timestamp},
+    command_palette::{CommandPaletteState, PaletteAction},
     favorites::FavoritesState,
+    fetch_limiter::FetchLimiter,
+    filter_cache::{EventRowKey, EventRowsKey},
     logs::LogsState,
-    navigation::{EventFilter, EventSortBy, FocusedPanel, MainTab, NavigationState, ScrollState},
-    orderbook::{OrderbookData, OrderbookLevel, OrderbookOutcome, OrderbookState},
+    navigation::{
+        EventFilter, EventSortBy, EventViewState, FocusedPanel, MainTab, NavigationState,
+        ScrollState, tab_digit,
+    },
+    notes::MAX_NOTE_CHARS,
+    orderbook::{
+        DEFAULT_REFRESH_INTERVAL as DEFAULT_ORDERBOOK_REFRESH_INTERVAL, OrderbookData,
+        OrderbookLevel, OrderbookOutcome, OrderbookState, format_group_increment,
+    },
     pagination::PaginationState,
     popup::PopupType,
+    positions::{PositionRow, PositionsState},
+    refresh::{RefreshSettings, RefreshSource, RefreshTimers},
     search::{SearchMode, SearchState},
-    trade::{EventTrades, OrderType, OutcomeInfo, Trade, TradeField, TradeFormState, TradeSide},
-    trades_ws::TradesState,
-    yield_state::{YieldOpportunity, YieldSearchResult, YieldState},
+    startup::StartupPrefs,
+    tag_filter::{TagFilterState, tag_counts},
+    theme_preset::ThemePreset,
+    toast::Toast,
+    trade::{
+        ConnectionState, EventTrades, LIMIT_PRICE_UNIT_KEY, MAX_AMOUNT_KEY, OrderType, OutcomeInfo,
+        QUICK_AMOUNTS, SIZE_STEP_KEYS, Trade, TradeField, TradeFormState, TradeSide,
+        keep_trade_popup_open_from_env, whale_threshold,
+    },
+    trades_ws::{MAX_BULK_WATCH, MAX_RECONNECT_ATTEMPTS, TradesState, reconnect_delay},
+    ui_prefs::UiPrefs,
+    yield_state::{YieldOpportunity, YieldSearchResult, YieldState, YieldViewMode},
 };
@@ -10,12 +10,39 @@ pub enum FocusedPanel {
     Logs,         // Bottom panel - logs
 }
 
+impl FocusedPanel {
+    /// Next panel in the Tab rotation; Logs is only part of it while the logs panel is shown
+    pub fn next(self, logs_shown: bool) -> Self {
+        match self {
+            FocusedPanel::Header => FocusedPanel::EventsList,
+            FocusedPanel::EventsList => FocusedPanel::EventDetails,
+            FocusedPanel::EventDetails => FocusedPanel::Markets,
+            FocusedPanel::Markets => FocusedPanel::Trades,
+            FocusedPanel::Trades if logs_shown => FocusedPanel::Logs,
+            FocusedPanel::Trades | FocusedPanel::Logs => FocusedPanel::Header,
+        }
+    }
+
+    /// Previous panel in the Tab rotation (Shift-Tab)
+    pub fn prev(self, logs_shown: bool) -> Self {
+        match self {
+            FocusedPanel::Header if logs_shown => FocusedPanel::Logs,
+            FocusedPanel::Header | FocusedPanel::Logs => FocusedPanel::Trades,
+            FocusedPanel::EventsList => FocusedPanel::Header,
+            FocusedPanel::EventDetails => FocusedPanel::EventsList,
+            FocusedPanel::Markets => FocusedPanel::EventDetails,
+            FocusedPanel::Trades => FocusedPanel::Markets,
+        }
+    }
+}
+
 /// Main tab at the top level
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum MainTab {
     Trending,
     Favorites,
     Yield,
+    Positions,
 }
 
 impl MainTab {
@@ -25,6 +52,7 @@ impl MainTab {
             MainTab::Trending => "Trending",
             MainTab::Favorites => "Favorites",
             MainTab::Yield => "Yield",
+            MainTab::Positions => "Positions",
         }
     }
 
@@ -33,16 +61,18 @@ impl MainTab {
         match self {
             MainTab::Trending => MainTab::Favorites,
             MainTab::Favorites => MainTab::Yield,
-            MainTab::Yield => MainTab::Trending,
+            MainTab::Yield => MainTab::Positions,
+            MainTab::Positions => MainTab::Trending,
         }
     }
 
     #[allow(dead_code)]
     pub fn prev(&self) -> Self {
         match self {
-            MainTab::Trending => MainTab::Yield,
+            MainTab::Trending => MainTab::Positions,
             MainTab::Favorites => MainTab::Trending,
             MainTab::Yield => MainTab::Favorites,
+            MainTab::Positions => MainTab::Yield,
         }
     }
 }
@@ -50,23 +80,42 @@ impl MainTab {
 /// Event filter type for different views
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum EventFilter {
-    Trending, // Order by volume24hr (default)
-    Breaking, // Order by oneDayPriceChange (biggest movers)
+    Trending,    // Order by volume24hr (default)
+    Breaking,    // Order by oneDayPriceChange (biggest movers)
+    ClosingSoon, // Order by endDate, soonest first
+    NewlyListed, // Order by createdAt, newest first
 }
 
 impl EventFilter {
+    /// Every filter, in tab order
+    pub const ALL: [EventFilter; 4] = [
+        EventFilter::Trending,
+        EventFilter::Breaking,
+        EventFilter::ClosingSoon,
+        EventFilter::NewlyListed,
+    ];
+
     pub fn order_by(&self) -> &'static str {
         match self {
             EventFilter::Trending => "volume24hr",
             EventFilter::Breaking => "oneDayPriceChange",
+            EventFilter::ClosingSoon => "endDate",
+            EventFilter::NewlyListed => "createdAt",
         }
     }
 
+    /// Whether the API should return this filter's events in ascending order
+    pub fn ascending(&self) -> bool {
+        matches!(self, EventFilter::ClosingSoon)
+    }
+
     #[allow(dead_code)]
     pub fn label(&self) -> &'static str {
         match self {
             EventFilter::Trending => "Events",
             EventFilter::Breaking => "Breaking",
+            EventFilter::ClosingSoon => "Closing Soon",
+            EventFilter::NewlyListed => "New",
         }
     }
 
@@ -74,15 +123,19 @@ impl EventFilter {
     pub fn next(&self) -> Self {
         match self {
             EventFilter::Trending => EventFilter::Breaking,
-            EventFilter::Breaking => EventFilter::Trending,
+            EventFilter::Breaking => EventFilter::ClosingSoon,
+            EventFilter::ClosingSoon => EventFilter::NewlyListed,
+            EventFilter::NewlyListed => EventFilter::Trending,
         }
     }
 
     #[allow(dead_code)]
     pub fn prev(&self) -> Self {
         match self {
-            EventFilter::Trending => EventFilter::Breaking,
+            EventFilter::Trending => EventFilter::NewlyListed,
             EventFilter::Breaking => EventFilter::Trending,
+            EventFilter::ClosingSoon => EventFilter::Breaking,
+            EventFilter::NewlyListed => EventFilter::ClosingSoon,
         }
     }
 }
@@ -92,11 +145,12 @@ impl EventFilter {
 pub enum EventSortBy {
     #[default]
     Volume24hr, // 24h Volume (default for Trending)
-    VolumeTotal, // Total Volume
-    Liquidity,   // Liquidity
-    Newest,      // Newest (by created date)
-    EndingSoon,  // Ending Soon
-    Competitive, // Competitive (closer odds)
+    VolumeTotal,  // Total Volume
+    Liquidity,    // Liquidity
+    OpenInterest, // Open interest (capital committed)
+    Newest,       // Newest (by created date)
+    EndingSoon,   // Ending Soon
+    Competitive,  // Competitive (closer odds)
 }
 
 impl EventSortBy {
@@ -105,6 +159,7 @@ impl EventSortBy {
             EventSortBy::Volume24hr => "24h Vol",
             EventSortBy::VolumeTotal => "Total Vol",
             EventSortBy::Liquidity => "Liquidity",
+            EventSortBy::OpenInterest => "Open Int",
             EventSortBy::Newest => "Newest",
             EventSortBy::EndingSoon => "Ending Soon",
             EventSortBy::Competitive => "Competitive",
@@ -115,7 +170,8 @@ impl EventSortBy {
         match self {
             EventSortBy::Volume24hr => EventSortBy::VolumeTotal,
             EventSortBy::VolumeTotal => EventSortBy::Liquidity,
-            EventSortBy::Liquidity => EventSortBy::Newest,
+            EventSortBy::Liquidity => EventSortBy::OpenInterest,
+            EventSortBy::OpenInterest => EventSortBy::Newest,
             EventSortBy::Newest => EventSortBy::EndingSoon,
             EventSortBy::EndingSoon => EventSortBy::Competitive,
             EventSortBy::Competitive => EventSortBy::Volume24hr,
@@ -129,6 +185,7 @@ impl EventSortBy {
             EventSortBy::Volume24hr => "volume24hr",
             EventSortBy::VolumeTotal => "volume",
             EventSortBy::Liquidity => "liquidity",
+            EventSortBy::OpenInterest => "openInterest",
             EventSortBy::Newest => "createdAt",
             EventSortBy::EndingSoon => "endDate",
             EventSortBy::Competitive => "competitive",
@@ -146,6 +203,11 @@ impl EventSortBy {
     }
 }
 
+/// Environment variable setting how many lines one mouse wheel notch scrolls
+pub const SCROLL_STEP_ENV: &str = "POLYMARKET_SCROLL_STEP";
+/// Lines scrolled per mouse wheel notch by default
+pub const DEFAULT_SCROLL_STEP: usize = 3;
+
 /// Scroll positions for all panels
 #[derive(Debug)]
 pub struct ScrollState {
@@ -155,6 +217,8 @@ pub struct ScrollState {
     pub event_details: usize, // Scroll position for event details
     #[allow(dead_code)]
     pub logs: usize, // Scroll position for logs panel
+    pub resolution: usize,    // Scroll position for the resolution rules popup
+    pub wheel_step: usize,    // Lines (or list items) moved per mouse wheel notch
 }
 
 impl ScrollState {
@@ -165,15 +229,44 @@ impl ScrollState {
             trades: 0,
             event_details: 0,
             logs: 0,
+            resolution: 0,
+            wheel_step: std::env::var(SCROLL_STEP_ENV)
+                .ok()
+                .and_then(|v| v.trim().parse().ok())
+                .filter(|&step| step > 0)
+                .unwrap_or(DEFAULT_SCROLL_STEP),
         }
     }
 }
 
+/// Saved per-event view (scroll positions and selected market), restored when the
+/// event is reselected
+#[derive(Debug, Clone, Copy, Default)]
+pub struct EventViewState {
+    pub markets_scroll: usize,        // Scroll position for markets panel
+    pub selected_market_index: usize, // Market selected for the orderbook
+    pub trades_scroll: usize,         // Scroll position for trades table
+    pub event_details_scroll: usize,  // Scroll position for event details
+}
+
+/// Largest vim-style count a digit prefix builds up to
+pub const MAX_PENDING_COUNT: usize = 999;
+
+/// The tab key a lone digit count stands for ('1'-'7' switch tabs when no move follows)
+pub fn tab_digit(count: usize) -> Option<char> {
+    if (1..=7).contains(&count) {
+        char::from_digit(count as u32, 10)
+    } else {
+        None
+    }
+}
+
 /// Navigation state (selection and focus)
 #[derive(Debug)]
 pub struct NavigationState {
     pub selected_index: usize,
     pub focused_panel: FocusedPanel,
+    pub pending_count: Option<usize>, // Digits typed before a move ("5j"), shown in the footer
 }
 
 impl NavigationState {
@@ -181,6 +274,19 @@ impl NavigationState {
         Self {
             selected_index: 0,
             focused_panel: FocusedPanel::EventsList, // Start with events list focused
+            pending_count: None,
         }
     }
+
+    /// Append a typed digit to the pending count
+    pub fn push_count_digit(&mut self, digit: char) {
+        let digit = digit.to_digit(10).unwrap_or(0) as usize;
+        let count = self.pending_count.unwrap_or(0) * 10 + digit;
+        self.pending_count = Some(count.min(MAX_PENDING_COUNT));
+    }
+
+    /// Clear the pending count, returning it
+    pub fn take_pending_count(&mut self) -> Option<usize> {
+        self.pending_count.take()
+    }
 }
@@ -0,0 +1,36 @@
+//! Personal notes on events, edited in the note popup ('n')
+//!
+//! Notes are keyed by event slug and never pruned, so they outlive the event closing.
+
+use {
+    super::config_file::{load_json, save_json},
+    serde::{Deserialize, Serialize},
+    std::collections::HashMap,
+};
+
+/// Longest note the popup accepts, in characters
+pub const MAX_NOTE_CHARS: usize = 200;
+
+/// Notes by event slug, saved to `notes.json` in the config directory
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+struct SavedNotes {
+    notes: HashMap<String, String>,
+}
+
+const NOTES_FILE: &str = "notes.json";
+
+/// The saved notes, or none if the file is missing or unreadable
+pub fn load_notes() -> HashMap<String, String> {
+    load_json::<SavedNotes>(NOTES_FILE)
+        .map(|saved| saved.notes)
+        .unwrap_or_default()
+}
+
+/// Write all notes for the next start
+pub fn save_notes(notes: &HashMap<String, String>) -> Result<(), String> {
+    let saved = SavedNotes {
+        notes: notes.clone(),
+    };
+    save_json(NOTES_FILE, &saved, "notes")
+}
@@ -1,5 +1,65 @@
 //! Orderbook state types
 
+use {
+    polymarket_api::clob::OpenOrder,
+    std::{
+        borrow::Cow,
+        time::{Duration, Instant},
+    },
+};
+
+/// Delay before the first automatic retry of a failed orderbook fetch; doubles per failure
+const RETRY_BASE_DELAY: Duration = Duration::from_secs(2);
+/// Upper bound for the automatic retry delay
+const RETRY_MAX_DELAY: Duration = Duration::from_secs(30);
+/// Normal interval between orderbook refreshes
+pub const DEFAULT_REFRESH_INTERVAL: Duration = Duration::from_secs(5);
+/// Price buckets 'B' cycles the orderbook through, in dollars; `None` shows every level
+const GROUP_INCREMENTS: [Option<f64>; 4] = [None, Some(0.005), Some(0.01), Some(0.05)];
+
+/// Label for a grouping increment, e.g. "0.5¢" or "1¢"
+pub fn format_group_increment(increment: f64) -> String {
+    let cents = increment * 100.0;
+    if (cents - cents.round()).abs() < 1e-9 {
+        format!("{:.0}¢", cents)
+    } else {
+        format!("{:.1}¢", cents)
+    }
+}
+
+/// Merge best-first `levels` into `increment`-wide buckets (bids round down, asks round up),
+/// keeping each bucket's best price and summing its sizes, with cumulative totals recomputed
+fn group_levels(levels: &[OrderbookLevel], increment: f64, is_bid: bool) -> Vec<OrderbookLevel> {
+    let mut grouped: Vec<(i64, OrderbookLevel)> = Vec::new();
+    let mut cumulative_total = 0.0;
+    let mut cumulative_size = 0.0;
+    for level in levels {
+        // Nudge before rounding so prices on a bucket edge aren't pushed past it
+        let steps = level.price / increment;
+        let bucket = if is_bid {
+            (steps + 1e-9).floor()
+        } else {
+            (steps - 1e-9).ceil()
+        } as i64;
+        cumulative_total += level.price * level.size;
+        cumulative_size += level.size;
+        match grouped.last_mut() {
+            Some((last_bucket, last)) if *last_bucket == bucket => {
+                last.size += level.size;
+                last.total = cumulative_total;
+                last.cumulative_size = cumulative_size;
+            },
+            _ => grouped.push((bucket, OrderbookLevel {
+                price: level.price,
+                size: level.size,
+                total: cumulative_total,
+                cumulative_size,
+            })),
+        }
+    }
+    grouped.into_iter().map(|(_, level)| level).collect()
+}
+
 /// Orderbook outcome tab (Yes or No)
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum OrderbookOutcome {
@@ -31,6 +91,7 @@ pub struct OrderbookLevel {
     pub price: f64,
     pub size: f64,
     pub total: f64, // cumulative total (running sum of price * size from best price)
+    pub cumulative_size: f64, // running sum of size from best price
 }
 
 /// Orderbook data for a market
@@ -43,6 +104,26 @@ pub struct OrderbookData {
     pub last_price: Option<f64>,
 }
 
+impl OrderbookData {
+    /// The book with its levels grouped into `increment`-wide price buckets
+    pub fn grouped(&self, increment: f64) -> Self {
+        Self {
+            asks: group_levels(&self.asks, increment, false),
+            bids: group_levels(&self.bids, increment, true),
+            ..self.clone()
+        }
+    }
+
+    /// Share of the total resting size (bids + asks) that sits on the bid side,
+    /// or `None` when the book is empty
+    pub fn bid_pressure(&self) -> Option<f64> {
+        let bid_depth: f64 = self.bids.iter().map(|level| level.size).sum();
+        let ask_depth: f64 = self.asks.iter().map(|level| level.size).sum();
+        let total = bid_depth + ask_depth;
+        (total > 0.0).then(|| bid_depth / total)
+    }
+}
+
 /// State for the orderbook panel
 #[derive(Debug)]
 pub struct OrderbookState {
@@ -51,8 +132,22 @@ pub struct OrderbookState {
     pub orderbook: Option<OrderbookData>, // Current orderbook data
     pub is_loading: bool,
     pub last_fetch: Option<std::time::Instant>,
-    pub token_id: Option<String>, // Current token ID being displayed
-    pub last_height: u16,         // Last rendered height to prevent jumps during loading
+    pub queued_token: Option<String>, // Token whose fetch is waiting for a request slot
+    pub token_id: Option<String>,     // Current token ID being displayed
+    pub last_height: u16,             // Last rendered height to prevent jumps during loading
+    pub error: Option<String>,        // Error from the last failed fetch (cleared on success)
+    pub failed_attempts: u32,         // Consecutive failed fetches for the current token
+    pub next_retry: Option<Instant>,  // When the next automatic retry is due after a failure
+    pub refresh_interval: Duration,   // Shortened while the event is about to close
+    pub both_sides: bool,             // Show both outcomes' books side by side (toggle with 'T')
+    pub other_orderbook: Option<OrderbookData>, /* Opposite outcome's book, fetched in both-sides mode */
+    pub open_orders: Vec<OpenOrder>, /* Your resting orders on the displayed token (authenticated only) */
+    pub selected_open_order: usize,  // Highlighted order in the open orders popup ('X')
+    pub confirm_cancel: Option<String>, // Order id awaiting y/n before it is cancelled
+    pub open_orders_message: Option<String>, // Result of the last cancel, shown in the popup
+    pub group_increment: Option<f64>, // Price bucket the displayed levels are grouped into ('B')
+    pub market_filter: String, // Narrows the markets panel by question or group title ('/' there)
+    pub is_filtering_markets: bool, // Typing into `market_filter`
 }
 
 impl OrderbookState {
@@ -63,31 +158,199 @@ impl OrderbookState {
             orderbook: None,
             is_loading: false,
             last_fetch: None,
+            queued_token: None,
             token_id: None,
             last_height: 5, // Start with min height
+            error: None,
+            failed_attempts: 0,
+            next_retry: None,
+            refresh_interval: DEFAULT_REFRESH_INTERVAL,
+            both_sides: false,
+            other_orderbook: None,
+            open_orders: Vec::new(),
+            selected_open_order: 0,
+            confirm_cancel: None,
+            open_orders_message: None,
+            group_increment: None,
+            market_filter: String::new(),
+            is_filtering_markets: false,
         }
     }
 
     pub fn reset(&mut self) {
         self.selected_market_index = 0;
         self.orderbook = None;
+        self.other_orderbook = None;
+        self.open_orders.clear();
         self.is_loading = false;
         self.token_id = None;
+        self.clear_error();
     }
 
     pub fn toggle_outcome(&mut self) {
         self.selected_outcome = self.selected_outcome.toggle();
         // Clear orderbook data when switching outcomes
         self.orderbook = None;
+        self.other_orderbook = None;
+        self.open_orders.clear();
         self.token_id = None;
+        self.clear_error();
+    }
+
+    /// Switch between showing the selected outcome's book and both books side by side
+    pub fn toggle_both_sides(&mut self) {
+        self.both_sides = !self.both_sides;
+        self.other_orderbook = None;
+    }
+
+    /// Move to the next grouping increment, wrapping back to ungrouped. Returns the new one.
+    pub fn cycle_group_increment(&mut self) -> Option<f64> {
+        let current = GROUP_INCREMENTS
+            .iter()
+            .position(|increment| *increment == self.group_increment)
+            .unwrap_or(0);
+        self.group_increment = GROUP_INCREMENTS[(current + 1) % GROUP_INCREMENTS.len()];
+        self.group_increment
+    }
+
+    /// `book` as displayed: grouped when an increment is set
+    fn displayed<'a>(&self, book: &'a OrderbookData) -> Cow<'a, OrderbookData> {
+        match self.group_increment {
+            Some(increment) => Cow::Owned(book.grouped(increment)),
+            None => Cow::Borrowed(book),
+        }
+    }
+
+    /// The selected outcome's book as displayed
+    pub fn displayed_orderbook(&self) -> Option<Cow<'_, OrderbookData>> {
+        self.orderbook.as_ref().map(|book| self.displayed(book))
+    }
+
+    /// Books for the first and second outcome, as displayed, when both sides are loaded
+    pub fn both_books(&self) -> Option<(Cow<'_, OrderbookData>, Cow<'_, OrderbookData>)> {
+        if !self.both_sides {
+            return None;
+        }
+        let selected = self.displayed(self.orderbook.as_ref()?);
+        let other = self.displayed(self.other_orderbook.as_ref()?);
+        Some(match self.selected_outcome {
+            OrderbookOutcome::Yes => (selected, other),
+            OrderbookOutcome::No => (other, selected),
+        })
+    }
+
+    /// The order highlighted in the open orders popup
+    pub fn selected_open_order(&self) -> Option<&OpenOrder> {
+        self.open_orders.get(self.selected_open_order)
+    }
+
+    pub fn select_previous_open_order(&mut self) {
+        self.selected_open_order = self.selected_open_order.saturating_sub(1);
+        self.confirm_cancel = None;
+    }
+
+    pub fn select_next_open_order(&mut self) {
+        if self.selected_open_order + 1 < self.open_orders.len() {
+            self.selected_open_order += 1;
+        }
+        self.confirm_cancel = None;
+    }
+
+    /// Replace the open orders with a fresh fetch, keeping the highlight in range
+    pub fn set_open_orders(&mut self, orders: Vec<OpenOrder>) {
+        self.open_orders = orders;
+        // A refresh can drop the order being confirmed; never let the prompt move to another
+        if let Some(id) = &self.confirm_cancel
+            && !self.open_orders.iter().any(|order| &order.id == id)
+        {
+            self.confirm_cancel = None;
+        }
+        self.selected_open_order = self
+            .selected_open_order
+            .min(self.open_orders.len().saturating_sub(1));
+    }
+
+    /// Drop an order that is no longer resting
+    pub fn remove_open_order(&mut self, order_id: &str) {
+        let orders = self
+            .open_orders
+            .drain(..)
+            .filter(|order| order.id != order_id)
+            .collect();
+        self.set_open_orders(orders);
+    }
+
+    /// Unfilled size of your open orders resting at `price` on the bid (BUY) or ask (SELL) side
+    pub fn open_order_size_at(&self, price: f64, is_bid: bool) -> f64 {
+        let side = if is_bid {
+            "BUY"
+        } else {
+            "SELL"
+        };
+        self.open_orders
+            .iter()
+            .filter(|order| order.side.eq_ignore_ascii_case(side))
+            .filter(|order| {
+                order
+                    .price
+                    .parse::<f64>()
+                    .is_ok_and(|p| (p - price).abs() < 1e-9)
+            })
+            .map(|order| {
+                let original = order.original_size.parse::<f64>().unwrap_or(0.0);
+                let matched = order.size_matched.parse::<f64>().unwrap_or(0.0);
+                (original - matched).max(0.0)
+            })
+            .sum()
     }
 
     pub fn needs_refresh(&self) -> bool {
+        // After a failure, wait for the backoff to elapse instead of the normal interval
+        if let Some(next_retry) = self.next_retry {
+            return Instant::now() >= next_retry;
+        }
         match self.last_fetch {
-            Some(last) => last.elapsed() >= std::time::Duration::from_secs(5),
+            Some(last) => last.elapsed() >= self.refresh_interval,
             None => true,
         }
     }
+
+    /// Record a failed fetch for `token_id` and schedule the next retry with exponential backoff.
+    /// Any previously fetched data is kept so the panel can show it as stale.
+    pub fn record_error(&mut self, token_id: String, error: String) {
+        if self.token_id.as_ref() != Some(&token_id) {
+            self.orderbook = None;
+            self.failed_attempts = 0;
+        }
+        self.failed_attempts += 1;
+        let delay = RETRY_BASE_DELAY
+            .saturating_mul(1 << (self.failed_attempts - 1).min(8))
+            .min(RETRY_MAX_DELAY);
+        self.next_retry = Some(Instant::now() + delay);
+        self.error = Some(error);
+        self.is_loading = false;
+        // Remember the token so the refresh loop retries it
+        self.token_id = Some(token_id);
+    }
+
+    /// Skip the remaining backoff so the next refresh tick retries immediately
+    pub fn retry_now(&mut self) {
+        if self.error.is_some() {
+            self.next_retry = Some(Instant::now());
+        }
+    }
+
+    pub fn clear_error(&mut self) {
+        self.error = None;
+        self.failed_attempts = 0;
+        self.next_retry = None;
+    }
+
+    /// Seconds until the next automatic retry, if a failed fetch is waiting to be retried
+    pub fn retry_in_secs(&self) -> Option<u64> {
+        self.next_retry
+            .map(|at| at.saturating_duration_since(Instant::now()).as_secs())
+    }
 }
 
 impl Default for OrderbookState {
@@ -95,3 +358,43 @@ impl Default for OrderbookState {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn level(price: f64, size: f64) -> OrderbookLevel {
+        OrderbookLevel {
+            price,
+            size,
+            total: 0.0,
+            cumulative_size: 0.0,
+        }
+    }
+
+    #[test]
+    fn grouping_keeps_the_best_price_and_sums_each_bucket() {
+        let book = OrderbookData {
+            asks: vec![level(0.503, 10.0), level(0.51, 5.0), level(0.512, 20.0)],
+            bids: vec![level(0.499, 10.0), level(0.495, 4.0), level(0.48, 1.0)],
+            spread: Some(0.004),
+            last_price: None,
+        };
+        let grouped = book.grouped(0.01);
+
+        // Asks round up: 0.503 and 0.51 share the 0.51 bucket
+        let asks: Vec<_> = grouped.asks.iter().map(|l| (l.price, l.size)).collect();
+        assert_eq!(asks, [(0.503, 15.0), (0.512, 20.0)]);
+        // Bids round down: 0.499 and 0.495 share the 0.49 bucket
+        let bids: Vec<_> = grouped.bids.iter().map(|l| (l.price, l.size)).collect();
+        assert_eq!(bids, [(0.499, 14.0), (0.48, 1.0)]);
+
+        // Cumulative totals still end at the whole side's notional
+        let ask_notional = 0.503 * 10.0 + 0.51 * 5.0 + 0.512 * 20.0;
+        assert!((grouped.asks[1].total - ask_notional).abs() < 1e-9);
+        assert_eq!(grouped.asks[1].cumulative_size, 35.0);
+        assert_eq!(grouped.spread, Some(0.004));
+        assert_eq!(format_group_increment(0.005), "0.5¢");
+        assert_eq!(format_group_increment(0.01), "1¢");
+    }
+}
@@ -4,10 +4,18 @@
 #[derive(Debug, Clone, PartialEq)]
 #[allow(dead_code)]
 pub enum PopupType {
-    Help,              // Show help/keyboard shortcuts
-    ConfirmQuit,       // Confirm before quitting
-    EventInfo(String), // Show detailed event info (slug)
-    Login,             // Login modal with credential input
-    UserProfile,       // Show authenticated user profile
-    Trade,             // Trade modal (form state is in app.trade_form)
+    Help,               // Show help/keyboard shortcuts
+    ConfirmQuit,        // Confirm before quitting
+    EventInfo(String),  // Full (untruncated) title and slug of an event (slug)
+    Resolution(String), // Resolution rules of an event and its selected market (slug)
+    Login,              // Login modal with credential input
+    UserProfile,        // Show authenticated user profile
+    Trade,              // Trade modal (form state is in app.trade_form)
+    YieldMinVolume,     // Min 24h volume input for yield opportunities
+    ApiErrors,          // Captured API failures with request details
+    TagFilter,          // Pick a tag to narrow the events list to
+    OpenOrders,         // Your resting orders on the displayed outcome, with cancel
+    Settings,           // Per-source auto-refresh intervals
+    EditNote(String),   // Edit the personal note on an event (slug)
+    CommandPalette,     // Search and run any action by name
 }
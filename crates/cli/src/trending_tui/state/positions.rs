@@ -0,0 +1,179 @@
+//! Positions tab state types
+
+/// A single open position of the logged-in user
+#[derive(Debug, Clone)]
+pub struct PositionRow {
+    pub market_name: String,
+    pub outcome: String,
+    pub shares: f64,
+    pub avg_price: f64,
+    pub current_price: f64,
+    pub current_value: f64,
+    pub unrealized_pnl: f64,
+    pub event_slug: String,
+}
+
+impl From<&polymarket_api::data::Position> for PositionRow {
+    fn from(position: &polymarket_api::data::Position) -> Self {
+        let shares = position.size.unwrap_or(0.0);
+        let current_price = position.cur_price.unwrap_or(0.0);
+        Self {
+            market_name: position.title.clone(),
+            outcome: position.outcome.clone(),
+            shares,
+            avg_price: position.avg_price.unwrap_or(0.0),
+            current_price,
+            current_value: position.current_value.unwrap_or(shares * current_price),
+            unrealized_pnl: position.cash_pnl.unwrap_or(0.0),
+            event_slug: position.event_slug.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PositionSortBy {
+    Value,  // Sort by current value (default)
+    Pnl,    // Sort by unrealized P&L
+    Shares, // Sort by number of shares
+    Market, // Sort by market name (A-Z)
+}
+
+impl PositionSortBy {
+    pub fn label(&self) -> &'static str {
+        match self {
+            PositionSortBy::Value => "Value",
+            PositionSortBy::Pnl => "P&L",
+            PositionSortBy::Shares => "Shares",
+            PositionSortBy::Market => "Market",
+        }
+    }
+
+    pub fn next(&self) -> Self {
+        match self {
+            PositionSortBy::Value => PositionSortBy::Pnl,
+            PositionSortBy::Pnl => PositionSortBy::Shares,
+            PositionSortBy::Shares => PositionSortBy::Market,
+            PositionSortBy::Market => PositionSortBy::Value,
+        }
+    }
+}
+
+/// Positions tab state
+#[derive(Debug)]
+pub struct PositionsState {
+    pub positions: Vec<PositionRow>,
+    pub selected_index: usize,
+    pub scroll: usize,
+    pub is_loading: bool,
+    pub loaded: bool, // A fetch has succeeded, even if it returned no positions
+    pub error_message: Option<String>,
+    pub sort_by: PositionSortBy,
+}
+
+impl PositionsState {
+    pub fn new() -> Self {
+        Self {
+            positions: Vec::new(),
+            selected_index: 0,
+            scroll: 0,
+            is_loading: false,
+            loaded: false,
+            error_message: None,
+            sort_by: PositionSortBy::Value,
+        }
+    }
+
+    /// Replace the positions with a fresh fetch, keeping the sort order
+    pub fn set_positions(&mut self, positions: Vec<PositionRow>) {
+        self.positions = positions;
+        self.is_loading = false;
+        self.loaded = true;
+        self.error_message = None;
+        self.sort_positions();
+        self.selected_index = self
+            .selected_index
+            .min(self.positions.len().saturating_sub(1));
+        self.scroll = self.scroll.min(self.selected_index);
+    }
+
+    /// Forget all positions (e.g. on logout)
+    pub fn clear(&mut self) {
+        *self = Self::new();
+    }
+
+    pub fn sort_positions(&mut self) {
+        match self.sort_by {
+            PositionSortBy::Value => self
+                .positions
+                .sort_by(|a, b| b.current_value.total_cmp(&a.current_value)),
+            PositionSortBy::Pnl => self
+                .positions
+                .sort_by(|a, b| b.unrealized_pnl.total_cmp(&a.unrealized_pnl)),
+            PositionSortBy::Shares => self.positions.sort_by(|a, b| b.shares.total_cmp(&a.shares)),
+            PositionSortBy::Market => self.positions.sort_by(|a, b| {
+                a.market_name
+                    .to_lowercase()
+                    .cmp(&b.market_name.to_lowercase())
+            }),
+        }
+    }
+
+    /// Switch to the next sort order and jump back to the top
+    pub fn cycle_sort(&mut self) {
+        self.sort_by = self.sort_by.next();
+        self.sort_positions();
+        self.select_first();
+    }
+
+    pub fn selected_position(&self) -> Option<&PositionRow> {
+        self.positions.get(self.selected_index)
+    }
+
+    pub fn move_up(&mut self) {
+        if self.selected_index > 0 {
+            self.selected_index -= 1;
+            if self.selected_index < self.scroll {
+                self.scroll = self.selected_index;
+            }
+        }
+    }
+
+    pub fn move_down(&mut self, visible_height: usize) {
+        if self.selected_index + 1 < self.positions.len() {
+            self.selected_index += 1;
+            if self.selected_index >= self.scroll + visible_height {
+                self.scroll = self.selected_index - visible_height + 1;
+            }
+        }
+    }
+
+    /// Move the selection up by one page, keeping it visible
+    pub fn page_up(&mut self, visible_height: usize) {
+        let page = visible_height.max(1);
+        self.selected_index = self.selected_index.saturating_sub(page);
+        self.scroll = self.scroll.saturating_sub(page).min(self.selected_index);
+    }
+
+    /// Move the selection down by one page, keeping it visible
+    pub fn page_down(&mut self, visible_height: usize) {
+        let page = visible_height.max(1);
+        let len = self.positions.len();
+        self.selected_index = (self.selected_index + page).min(len.saturating_sub(1));
+        self.scroll = (self.scroll + page)
+            .min(len.saturating_sub(page))
+            .max((self.selected_index + 1).saturating_sub(page));
+    }
+
+    /// Jump to the first position
+    pub fn select_first(&mut self) {
+        self.selected_index = 0;
+        self.scroll = 0;
+    }
+
+    /// Jump to the last position, scrolling so it is visible
+    pub fn select_last(&mut self, visible_height: usize) {
+        let len = self.positions.len();
+        self.selected_index = len.saturating_sub(1);
+        self.scroll = len.saturating_sub(visible_height.max(1));
+    }
+}
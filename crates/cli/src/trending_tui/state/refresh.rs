@@ -0,0 +1,211 @@
+//! Per-source auto-refresh intervals, edited in the settings popup (',')
+
+use {
+    serde::{Deserialize, Serialize},
+    std::{
+        collections::HashMap,
+        time::{Duration, Instant},
+    },
+};
+
+/// Data that refreshes on its own timer
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RefreshSource {
+    Orderbook,
+    Prices,
+    Events,
+    Favorites,
+    Portfolio,
+}
+
+impl RefreshSource {
+    /// All sources, in the order the settings popup lists them
+    pub const ALL: [RefreshSource; 5] = [
+        RefreshSource::Orderbook,
+        RefreshSource::Prices,
+        RefreshSource::Events,
+        RefreshSource::Favorites,
+        RefreshSource::Portfolio,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            RefreshSource::Orderbook => "Orderbook",
+            RefreshSource::Prices => "Market prices",
+            RefreshSource::Events => "Events list",
+            RefreshSource::Favorites => "Favorites",
+            RefreshSource::Portfolio => "Portfolio",
+        }
+    }
+
+    /// Choices in seconds; 0 turns the refresh off. The orderbook always refreshes.
+    fn steps(self) -> &'static [u64] {
+        match self {
+            RefreshSource::Orderbook => &[1, 2, 5, 10, 30],
+            RefreshSource::Prices => &[0, 5, 10, 30, 60],
+            RefreshSource::Events => &[0, 30, 60, 120, 300],
+            RefreshSource::Favorites => &[0, 60, 120, 300],
+            RefreshSource::Portfolio => &[0, 15, 30, 60, 120],
+        }
+    }
+}
+
+/// Seconds between refreshes for each source, saved with the UI preferences
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RefreshIntervals {
+    pub orderbook_secs: u64,
+    pub prices_secs: u64,
+    pub events_secs: u64,
+    pub favorites_secs: u64,
+    pub portfolio_secs: u64,
+}
+
+impl Default for RefreshIntervals {
+    fn default() -> Self {
+        Self {
+            orderbook_secs: super::orderbook::DEFAULT_REFRESH_INTERVAL.as_secs(),
+            prices_secs: 10,
+            events_secs: 0,
+            favorites_secs: 0,
+            portfolio_secs: 30,
+        }
+    }
+}
+
+impl RefreshIntervals {
+    fn secs_mut(&mut self, source: RefreshSource) -> &mut u64 {
+        match source {
+            RefreshSource::Orderbook => &mut self.orderbook_secs,
+            RefreshSource::Prices => &mut self.prices_secs,
+            RefreshSource::Events => &mut self.events_secs,
+            RefreshSource::Favorites => &mut self.favorites_secs,
+            RefreshSource::Portfolio => &mut self.portfolio_secs,
+        }
+    }
+
+    pub fn secs(&self, source: RefreshSource) -> u64 {
+        match source {
+            RefreshSource::Orderbook => self.orderbook_secs,
+            RefreshSource::Prices => self.prices_secs,
+            RefreshSource::Events => self.events_secs,
+            RefreshSource::Favorites => self.favorites_secs,
+            RefreshSource::Portfolio => self.portfolio_secs,
+        }
+    }
+
+    /// Time between refreshes of `source`, or `None` when its auto-refresh is off
+    pub fn interval(&self, source: RefreshSource) -> Option<Duration> {
+        match self.secs(source) {
+            0 if source != RefreshSource::Orderbook => None,
+            secs => Some(Duration::from_secs(secs.max(1))),
+        }
+    }
+
+    /// Move `source` to the next (or previous) choice, stopping at either end.
+    /// A hand-edited value between choices snaps to the nearest one in that direction.
+    pub fn step(&mut self, source: RefreshSource, forward: bool) {
+        let steps = source.steps();
+        let current = self.secs(source);
+        let next = if forward {
+            steps.iter().copied().find(|s| *s > current)
+        } else {
+            steps.iter().rev().copied().find(|s| *s < current)
+        };
+        if let Some(next) = next {
+            *self.secs_mut(source) = next;
+        }
+    }
+}
+
+/// When each source last auto-refreshed. A timer restarts whenever it comes due, even if the
+/// refresh itself is skipped.
+#[derive(Debug)]
+pub struct RefreshTimers {
+    last_refresh: HashMap<RefreshSource, Instant>,
+}
+
+impl RefreshTimers {
+    /// All timers starting now, so nothing refreshes right after the startup load
+    pub fn new() -> Self {
+        let now = Instant::now();
+        Self {
+            last_refresh: RefreshSource::ALL.into_iter().map(|s| (s, now)).collect(),
+        }
+    }
+
+    /// Whether `source` is due under `intervals`; restarts its timer when it is
+    pub fn take_due(&mut self, source: RefreshSource, intervals: &RefreshIntervals) -> bool {
+        let Some(interval) = intervals.interval(source) else {
+            return false;
+        };
+        let last = self.last_refresh.entry(source).or_insert_with(Instant::now);
+        if last.elapsed() < interval {
+            return false;
+        }
+        *last = Instant::now();
+        true
+    }
+}
+
+/// Settings popup state: the highlighted row (the intervals live in the UI preferences)
+#[derive(Debug, Default)]
+pub struct RefreshSettings {
+    pub selected: usize, // Index into `RefreshSource::ALL`
+}
+
+impl RefreshSettings {
+    pub fn selected_source(&self) -> RefreshSource {
+        RefreshSource::ALL[self.selected.min(RefreshSource::ALL.len() - 1)]
+    }
+
+    pub fn select_next(&mut self) {
+        self.selected = (self.selected + 1).min(RefreshSource::ALL.len() - 1);
+    }
+
+    pub fn select_previous(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stepping_stops_at_the_ends_and_zero_turns_off() {
+        let mut intervals = RefreshIntervals::default();
+        intervals.step(RefreshSource::Prices, false);
+        intervals.step(RefreshSource::Prices, false);
+        assert_eq!(intervals.interval(RefreshSource::Prices), None);
+        intervals.step(RefreshSource::Prices, false);
+        assert_eq!(intervals.prices_secs, 0);
+
+        intervals.orderbook_secs = 1;
+        intervals.step(RefreshSource::Orderbook, false);
+        assert_eq!(
+            intervals.interval(RefreshSource::Orderbook),
+            Some(Duration::from_secs(1))
+        );
+
+        intervals.events_secs = 45; // Hand-edited, between choices
+        intervals.step(RefreshSource::Events, true);
+        assert_eq!(intervals.events_secs, 60);
+    }
+
+    #[test]
+    fn timers_start_at_creation_and_skip_disabled_sources() {
+        let mut timers = RefreshTimers::new();
+        let mut intervals = RefreshIntervals::default();
+        assert!(!timers.take_due(RefreshSource::Prices, &intervals));
+        assert!(!timers.take_due(RefreshSource::Events, &intervals));
+
+        intervals.prices_secs = 1;
+        timers.last_refresh.insert(
+            RefreshSource::Prices,
+            Instant::now() - Duration::from_secs(2),
+        );
+        assert!(timers.take_due(RefreshSource::Prices, &intervals));
+        assert!(!timers.take_due(RefreshSource::Prices, &intervals));
+    }
+}
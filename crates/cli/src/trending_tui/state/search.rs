@@ -1,6 +1,16 @@
 //! Search state types
 
-use polymarket_api::gamma::Event;
+use {
+    super::config_file::{load_json, save_json},
+    polymarket_api::gamma::Event,
+    std::time::{Duration, Instant},
+};
+
+/// Quiet period after the last keystroke before an API search is dispatched
+pub const SEARCH_DEBOUNCE: Duration = Duration::from_millis(300);
+
+/// Number of previous API search queries kept in the history
+const MAX_SEARCH_HISTORY: usize = 20;
 
 /// Search mode enum to replace boolean flags
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -15,23 +25,123 @@ pub enum SearchMode {
 pub struct SearchState {
     pub mode: SearchMode,
     pub query: String,
-    pub results: Vec<Event>,         // Results from API search
-    pub is_searching: bool,          // Whether a search API call is in progress
-    pub last_searched_query: String, // Last query that was searched
+    pub results: Vec<Event>,             // Results from API search
+    pub results_generation: u64,         // Bumped whenever `results` is replaced or cleared
+    pub is_searching: bool,              // Whether a search API call is in progress
+    pub last_searched_query: String,     // Last query that was searched
+    pub last_keystroke: Option<Instant>, // Last query edit not yet searched (API search debounce)
+    pub history: Vec<String>,            // Previous API search queries, most recent first
+    pub history_index: Option<usize>,    // Entry recalled into the query with Up/Down
+    history_loaded: bool,                // Whether the history file has been read yet
 }
 
 impl SearchState {
+    /// File in the config directory holding the search history
+    const HISTORY_FILE: &'static str = "search_history.json";
+
     pub fn new() -> Self {
         Self {
             mode: SearchMode::None,
             query: String::new(),
             results: Vec::new(),
+            results_generation: 0,
             is_searching: false,
             last_searched_query: String::new(),
+            last_keystroke: None,
+            history: Vec::new(),
+            history_index: None,
+            history_loaded: false,
         }
     }
 
     pub fn is_active(&self) -> bool {
         self.mode != SearchMode::None
     }
+
+    pub fn set_results(&mut self, results: Vec<Event>) {
+        self.results = results;
+        self.results_generation += 1;
+    }
+
+    pub fn clear_results(&mut self) {
+        self.results.clear();
+        self.results_generation += 1;
+    }
+
+    /// Restart the debounce window after the query was edited in API search mode
+    pub fn record_keystroke(&mut self) {
+        if self.mode == SearchMode::ApiSearch {
+            self.last_keystroke = Some(Instant::now());
+        }
+    }
+
+    /// Take the query of a pending API search once the debounce window has elapsed.
+    /// With `force` set (e.g. on Enter) the pending search is returned immediately.
+    pub fn take_pending_search(&mut self, force: bool) -> Option<String> {
+        let last_keystroke = self.last_keystroke?;
+        if !force && last_keystroke.elapsed() < SEARCH_DEBOUNCE {
+            return None;
+        }
+        self.last_keystroke = None;
+        Some(self.query.clone())
+    }
+
+    /// Read the history file the first time it is needed, so startup doesn't pay for it
+    pub fn load_history(&mut self) {
+        if self.history_loaded {
+            return;
+        }
+        self.history_loaded = true;
+        self.history = load_json(Self::HISTORY_FILE).unwrap_or_default();
+        self.history.truncate(MAX_SEARCH_HISTORY);
+    }
+
+    /// Write `history` to the config directory. This blocks on file I/O, so call it off the
+    /// async runtime and without holding the app state lock.
+    pub fn save_history(history: &[String]) -> Result<(), String> {
+        save_json(Self::HISTORY_FILE, &history, "search history")
+    }
+
+    /// Move a searched query to the front of the history. Returns the history to persist with
+    /// `save_history`, or `None` for an empty query.
+    pub fn record_search(&mut self, query: &str) -> Option<Vec<String>> {
+        let query = query.trim();
+        if query.is_empty() {
+            return None;
+        }
+        self.load_history();
+        self.history.retain(|q| q != query);
+        self.history.insert(0, query.to_string());
+        self.history.truncate(MAX_SEARCH_HISTORY);
+        Some(self.history.clone())
+    }
+
+    /// Replace the query with the next older history entry. Returns false at the oldest entry.
+    pub fn recall_older(&mut self) -> bool {
+        let index = self.history_index.map_or(0, |i| i + 1);
+        let Some(query) = self.history.get(index) else {
+            return false;
+        };
+        self.query = query.clone();
+        self.history_index = Some(index);
+        true
+    }
+
+    /// Replace the query with the next newer history entry, clearing it past the newest.
+    /// Returns false when no history entry is being shown.
+    pub fn recall_newer(&mut self) -> bool {
+        match self.history_index {
+            None => false,
+            Some(0) => {
+                self.history_index = None;
+                self.query.clear();
+                true
+            },
+            Some(i) => {
+                self.history_index = Some(i - 1);
+                self.query = self.history[i - 1].clone();
+                true
+            },
+        }
+    }
 }
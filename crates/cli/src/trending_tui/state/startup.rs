@@ -0,0 +1,91 @@
+//! Startup presets read from the environment
+//!
+//! These let scripts and shell aliases open the TUI on a given tab, sort and fetch size.
+//! Command-line flags take precedence over them; unset or invalid values fall back to the
+//! built-in defaults.
+
+use super::{EventFilter, EventSortBy, MainTab};
+
+/// Environment variable with the number of events fetched at startup
+pub const STARTUP_LIMIT_ENV: &str = "POLYMARKET_TUI_LIMIT";
+/// Environment variable with the events list's initial sort (e.g. "liquidity")
+pub const STARTUP_SORT_ENV: &str = "POLYMARKET_TUI_SORT";
+/// Environment variable with the tab to open on (e.g. "breaking", "yield")
+pub const STARTUP_TAB_ENV: &str = "POLYMARKET_TUI_TAB";
+
+/// Initial limit, sort and tab requested through the environment
+#[derive(Debug, Clone, Copy, Default)]
+pub struct StartupPrefs {
+    pub limit: Option<usize>,
+    pub sort: Option<EventSortBy>,
+    pub tab: Option<(MainTab, Option<EventFilter>)>, // Same keys as the per-tab sort scopes
+}
+
+impl StartupPrefs {
+    pub fn from_env() -> Self {
+        let limit = env_value(STARTUP_LIMIT_ENV, |v| v.parse().ok().filter(|n| *n > 0));
+        let sort = env_value(STARTUP_SORT_ENV, parse_sort);
+        let tab = env_value(STARTUP_TAB_ENV, parse_tab);
+        Self { limit, sort, tab }
+    }
+}
+
+/// Parse `name` from the environment, warning about values that don't parse
+fn env_value<T>(name: &str, parse: impl Fn(&str) -> Option<T>) -> Option<T> {
+    let value = std::env::var(name).ok()?;
+    let parsed = parse(value.trim());
+    if parsed.is_none() {
+        log_warn!("Ignoring {}={:?}: unrecognized value", name, value);
+    }
+    parsed
+}
+
+/// Sort option by name; accepts the API order names and the labels shown in the TUI
+fn parse_sort(name: &str) -> Option<EventSortBy> {
+    let name = name.to_ascii_lowercase().replace([' ', '_', '-'], "");
+    let sort = match name.as_str() {
+        "volume24hr" | "24hvol" | "24h" => EventSortBy::Volume24hr,
+        "volume" | "totalvol" | "total" => EventSortBy::VolumeTotal,
+        "liquidity" => EventSortBy::Liquidity,
+        "openinterest" | "openint" => EventSortBy::OpenInterest,
+        "newest" | "createdat" => EventSortBy::Newest,
+        "endingsoon" | "enddate" => EventSortBy::EndingSoon,
+        "competitive" => EventSortBy::Competitive,
+        _ => return None,
+    };
+    Some(sort)
+}
+
+/// Tab by name or by its number key (1-7)
+fn parse_tab(name: &str) -> Option<(MainTab, Option<EventFilter>)> {
+    let name = name.to_ascii_lowercase().replace([' ', '_', '-'], "");
+    let tab = match name.as_str() {
+        "1" | "events" | "trending" => (MainTab::Trending, Some(EventFilter::Trending)),
+        "2" | "favorites" => (MainTab::Favorites, None),
+        "3" | "breaking" => (MainTab::Trending, Some(EventFilter::Breaking)),
+        "4" | "yield" => (MainTab::Yield, None),
+        "5" | "positions" => (MainTab::Positions, None),
+        "6" | "closingsoon" => (MainTab::Trending, Some(EventFilter::ClosingSoon)),
+        "7" | "new" => (MainTab::Trending, Some(EventFilter::NewlyListed)),
+        _ => return None,
+    };
+    Some(tab)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_sort_and_tab_names_loosely() {
+        assert_eq!(parse_sort("Open-Interest"), Some(EventSortBy::OpenInterest));
+        assert_eq!(parse_sort("ending_soon"), Some(EventSortBy::EndingSoon));
+        assert_eq!(parse_sort("popular"), None);
+        assert_eq!(
+            parse_tab("Closing Soon"),
+            Some((MainTab::Trending, Some(EventFilter::ClosingSoon)))
+        );
+        assert_eq!(parse_tab("4"), Some((MainTab::Yield, None)));
+        assert_eq!(parse_tab("8"), None);
+    }
+}
@@ -0,0 +1,123 @@
+//! Tag filter state for the events list
+
+use {polymarket_api::gamma::Event, std::collections::HashMap};
+
+/// Active tag filter and the tag picker popup's options
+#[derive(Debug, Default)]
+pub struct TagFilterState {
+    pub active: Option<String>, // Tag label the events list is narrowed to
+    pub options: Vec<(String, usize)>, // (tag label, event count) shown in the picker
+    pub selected: usize,
+}
+
+impl TagFilterState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Fill the picker with `options` (see `tag_counts`), preselecting the active tag
+    pub fn open(&mut self, options: Vec<(String, usize)>) {
+        self.options = options;
+        self.selected = self
+            .active
+            .as_ref()
+            .and_then(|active| self.options.iter().position(|(label, _)| label == active))
+            .unwrap_or(0);
+    }
+
+    pub fn move_up(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    pub fn move_down(&mut self) {
+        if self.selected + 1 < self.options.len() {
+            self.selected += 1;
+        }
+    }
+
+    /// Make the highlighted tag the active filter, returning its label
+    pub fn apply_selected(&mut self) -> Option<&str> {
+        let (label, _) = self.options.get(self.selected)?;
+        self.active = Some(label.clone());
+        self.active.as_deref()
+    }
+
+    /// Whether `event` has the active tag (always true without a filter)
+    pub fn matches(&self, event: &Event) -> bool {
+        self.active
+            .as_ref()
+            .is_none_or(|active| event.tags.iter().any(|tag| &tag.label == active))
+    }
+}
+
+/// Distinct tag labels across `events` with how many events carry each, most common first
+pub fn tag_counts<'a>(events: impl IntoIterator<Item = &'a Event>) -> Vec<(String, usize)> {
+    let mut counts: HashMap<&str, usize> = HashMap::new();
+    for event in events {
+        let mut labels: Vec<&str> = event.tags.iter().map(|tag| tag.label.as_str()).collect();
+        // An event listing the same tag twice still counts once
+        labels.sort_unstable();
+        labels.dedup();
+        for label in labels.into_iter().filter(|label| !label.is_empty()) {
+            *counts.entry(label).or_insert(0) += 1;
+        }
+    }
+    let mut counts: Vec<(String, usize)> = counts
+        .into_iter()
+        .map(|(label, count)| (label.to_string(), count))
+        .collect();
+    counts.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    counts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(slug: &str, tags: &[&str]) -> Event {
+        let tags: Vec<_> = tags
+            .iter()
+            .map(|label| serde_json::json!({"id": label, "label": label, "slug": label}))
+            .collect();
+        serde_json::from_value(serde_json::json!({
+            "id": slug,
+            "slug": slug,
+            "title": slug,
+            "active": true,
+            "closed": false,
+            "tags": tags,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn counts_each_tag_once_per_event() {
+        let events = [
+            event("a", &["Politics", "Elections", "Politics"]),
+            event("b", &["Crypto"]),
+            event("c", &["Politics"]),
+        ];
+        assert_eq!(tag_counts(&events), vec![
+            ("Politics".to_string(), 2),
+            ("Crypto".to_string(), 1),
+            ("Elections".to_string(), 1),
+        ]);
+    }
+
+    #[test]
+    fn filters_to_the_applied_tag() {
+        let events = [event("a", &["Politics"]), event("b", &["Crypto"])];
+        let mut state = TagFilterState::new();
+        assert!(events.iter().all(|e| state.matches(e)));
+
+        state.open(tag_counts(&events));
+        state.move_down();
+        assert_eq!(state.apply_selected(), Some("Politics"));
+        assert!(state.matches(&events[0]));
+        assert!(!state.matches(&events[1]));
+
+        // Reopening preselects the active tag
+        state.open(tag_counts(&events));
+        assert_eq!(state.selected, 1);
+    }
+}
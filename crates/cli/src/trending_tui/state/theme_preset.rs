@@ -0,0 +1,30 @@
+//! Color theme preset chosen at runtime
+
+use serde::{Deserialize, Serialize};
+
+/// Built-in color palettes, cycled with a key and remembered in the UI preferences
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ThemePreset {
+    #[default]
+    Dark, // Customizable with theme.json
+    Light,
+    HighContrast,
+}
+
+impl ThemePreset {
+    pub fn label(&self) -> &'static str {
+        match self {
+            ThemePreset::Dark => "Dark",
+            ThemePreset::Light => "Light",
+            ThemePreset::HighContrast => "High contrast",
+        }
+    }
+
+    pub fn next(&self) -> Self {
+        match self {
+            ThemePreset::Dark => ThemePreset::Light,
+            ThemePreset::Light => ThemePreset::HighContrast,
+            ThemePreset::HighContrast => ThemePreset::Dark,
+        }
+    }
+}
@@ -0,0 +1,26 @@
+//! Transient status messages shown in the footer
+
+use std::time::{Duration, Instant};
+
+/// How long a toast stays visible
+const TOAST_DURATION: Duration = Duration::from_secs(4);
+
+/// A short-lived message shown in place of the footer help
+#[derive(Debug, Clone)]
+pub struct Toast {
+    pub message: String,
+    pub shown_at: Instant,
+}
+
+impl Toast {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            shown_at: Instant::now(),
+        }
+    }
+
+    pub fn is_expired(&self) -> bool {
+        self.shown_at.elapsed() >= TOAST_DURATION
+    }
+}
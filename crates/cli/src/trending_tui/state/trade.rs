@@ -1,6 +1,6 @@
 //! Trade-related state types
 
-use polymarket_api::rtds::RTDSMessage;
+use {polymarket_api::rtds::RTDSMessage, std::collections::HashMap};
 
 #[derive(Debug)]
 pub struct Trade {
@@ -15,12 +15,51 @@ pub struct Trade {
     pub user: String,
     #[allow(dead_code)]
     pub pseudonym: String,
+    pub wallet: String, // Trader's proxy wallet address
+}
+
+impl Trade {
+    /// Key identifying the trader: wallet address, falling back to name or pseudonym
+    fn trader_key(&self) -> Option<&str> {
+        [&self.wallet, &self.user, &self.pseudonym]
+            .into_iter()
+            .map(String::as_str)
+            .find(|s| !s.is_empty())
+    }
+}
+
+/// State of the live trades WebSocket for a watched event
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConnectionState {
+    Connecting,
+    Connected,
+    Reconnecting(u32), // Waiting to retry; holds the attempt number
+    Disconnected,
+    Error,
+}
+
+impl ConnectionState {
+    pub fn label(&self) -> String {
+        match self {
+            ConnectionState::Connecting => "Connecting".to_string(),
+            ConnectionState::Connected => "Connected".to_string(),
+            ConnectionState::Reconnecting(attempt) => format!("Reconnecting #{}", attempt),
+            ConnectionState::Disconnected => "Disconnected".to_string(),
+            ConnectionState::Error => "Error".to_string(),
+        }
+    }
 }
 
 #[derive(Debug)]
 pub struct EventTrades {
     pub trades: Vec<Trade>,
     pub is_watching: bool,
+    pub connection_state: ConnectionState, // Updated by the WebSocket task
+    traders: HashMap<String, usize>,       // trader key -> number of buffered trades by them
+    // Session totals, including trades since evicted from the buffer
+    pub session_trades: usize,
+    pub session_volume: f64,
+    pub largest_trade: Option<(f64, String)>, // (value, "BUY 120.00 Yes @ 62.5¢ - market")
 }
 
 impl EventTrades {
@@ -28,10 +67,21 @@ impl EventTrades {
         Self {
             trades: Vec::new(),
             is_watching: false,
+            connection_state: ConnectionState::Disconnected,
+            traders: HashMap::new(),
+            session_trades: 0,
+            session_volume: 0.0,
+            largest_trade: None,
         }
     }
 
-    pub fn add_trade(&mut self, msg: &RTDSMessage) {
+    /// Number of distinct traders among the buffered trades
+    pub fn unique_traders(&self) -> usize {
+        self.traders.len()
+    }
+
+    /// Insert a trade at the front, keeping at most `max_trades` (newest first)
+    pub fn add_trade(&mut self, msg: &RTDSMessage, max_trades: usize) {
         let rounded_shares = (msg.payload.size * 100.0).round() / 100.0;
         let total_value = msg.payload.price * msg.payload.size;
 
@@ -46,14 +96,114 @@ impl EventTrades {
             asset_id: msg.payload.asset.clone(),
             user: msg.payload.name.clone(),
             pseudonym: msg.payload.pseudonym.clone(),
+            wallet: msg.payload.proxy_wallet.clone(),
         };
 
+        if let Some(key) = trade.trader_key() {
+            *self.traders.entry(key.to_string()).or_insert(0) += 1;
+        }
+        self.session_trades += 1;
+        self.session_volume += total_value;
+        if self
+            .largest_trade
+            .as_ref()
+            .is_none_or(|(value, _)| total_value > *value)
+        {
+            self.largest_trade = Some((
+                total_value,
+                format!(
+                    "{} {:.2} {} @ {:.1}¢ - {}",
+                    trade.side,
+                    trade.shares,
+                    trade.outcome,
+                    trade.price * 100.0,
+                    trade.title
+                ),
+            ));
+        }
         self.trades.insert(0, trade);
-        // Keep only the last 500 trades per event
-        if self.trades.len() > 500 {
-            self.trades.truncate(500);
+
+        // Keep only the most recent trades; also trims history if the cap was lowered.
+        // Evicted trades no longer count towards their trader.
+        if self.trades.len() > max_trades {
+            for evicted in self.trades.drain(max_trades..) {
+                if let Some(key) = evicted.trader_key()
+                    && let Some(count) = self.traders.get_mut(key)
+                {
+                    *count -= 1;
+                    if *count == 0 {
+                        self.traders.remove(key);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Total traded value per minute over the last `minutes` minutes ending at `now`
+    /// (unix seconds), oldest bin first
+    pub fn volume_per_minute(&self, minutes: usize, now: i64) -> Vec<f64> {
+        let mut bins = vec![0.0; minutes];
+        for trade in &self.trades {
+            let minutes_ago = (now - trade.timestamp).max(0) as usize / 60;
+            if minutes_ago < minutes {
+                bins[minutes - 1 - minutes_ago] += trade.total_value;
+            }
         }
+        bins
     }
+
+    /// Volume-weighted average price of the loaded trades in one asset (outcome token), or
+    /// `None` without any
+    pub fn vwap_for(&self, asset_id: &str) -> Option<f64> {
+        let (value, shares) = self
+            .trades
+            .iter()
+            .filter(|trade| trade.asset_id == asset_id)
+            .fold((0.0, 0.0), |(value, shares), trade| {
+                (value + trade.price * trade.shares, shares + trade.shares)
+            });
+        (shares > 0.0).then(|| value / shares)
+    }
+
+    /// Buy/sell totals over the loaded trades:
+    /// (buy dollar volume, sell dollar volume, buy count, sell count)
+    pub fn volume_summary(&self) -> (f64, f64, usize, usize) {
+        self.trades.iter().fold(
+            (0.0, 0.0, 0, 0),
+            |(buy_vol, sell_vol, buys, sells), trade| {
+                if trade.side == "BUY" {
+                    (buy_vol + trade.total_value, sell_vol, buys + 1, sells)
+                } else {
+                    (buy_vol, sell_vol + trade.total_value, buys, sells + 1)
+                }
+            },
+        )
+    }
+}
+
+/// How many times the median trade value a trade must exceed to count as a whale
+pub const WHALE_MEDIAN_MULTIPLE: f64 = 10.0;
+/// Fewest loaded trades for which the median is trusted as a whale baseline
+const WHALE_MIN_TRADES: usize = 10;
+
+/// Dollar value above which a trade is highlighted as a whale: `configured` if set, otherwise
+/// a multiple of the median value of `trades` once enough of them are loaded
+pub fn whale_threshold(trades: &[Trade], configured: Option<f64>) -> Option<f64> {
+    if configured.is_some() {
+        return configured;
+    }
+    if trades.len() < WHALE_MIN_TRADES {
+        return None;
+    }
+    let mut values: Vec<f64> = trades.iter().map(|t| t.total_value).collect();
+    values.sort_by(f64::total_cmp);
+    let mid = values.len() / 2;
+    let median = if values.len().is_multiple_of(2) {
+        (values[mid - 1] + values[mid]) / 2.0
+    } else {
+        values[mid]
+    };
+    (median > 0.0).then_some(median * WHALE_MEDIAN_MULTIPLE)
 }
 
 /// Trade side (Buy or Sell)
@@ -106,6 +256,35 @@ impl OrderType {
     }
 }
 
+/// Unit the limit price is typed in. For a binary outcome 62¢ and a 62% implied probability
+/// are the same number, so only the label changes; the price is stored as a fraction either way.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LimitPriceUnit {
+    #[default]
+    Cents,
+    Percent,
+}
+
+impl LimitPriceUnit {
+    pub fn toggle(&self) -> Self {
+        match self {
+            LimitPriceUnit::Cents => LimitPriceUnit::Percent,
+            LimitPriceUnit::Percent => LimitPriceUnit::Cents,
+        }
+    }
+
+    /// Suffix shown after the typed value
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            LimitPriceUnit::Cents => "¢",
+            LimitPriceUnit::Percent => "%",
+        }
+    }
+}
+
+/// Key that switches the limit price between cents and probability input
+pub const LIMIT_PRICE_UNIT_KEY: char = '%';
+
 /// Trade form field being edited
 /// Note: Side is now controlled via clickable title tabs, not a field
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -163,6 +342,67 @@ pub struct OutcomeInfo {
     pub price: f64,
 }
 
+impl OutcomeInfo {
+    /// Text of this outcome's chip in the trade popup, e.g. " Yes 45.5¢ "
+    pub fn chip_label(&self) -> String {
+        format!(" {} {:.1}¢ ", self.name, self.price * 100.0)
+    }
+}
+
+/// Decimal places accepted for dollar amounts
+const AMOUNT_DECIMALS: usize = 2;
+/// Decimal places accepted for share counts (the CLOB's size precision)
+const SHARES_DECIMALS: usize = 2;
+/// Decimal places accepted for typed limit prices (0.1¢ / 0.1% ticks)
+const LIMIT_PRICE_CENTS_DECIMALS: usize = 1;
+/// Bounds for limit prices; outcome prices are strictly between 0 and 1
+const MIN_LIMIT_PRICE: f64 = 0.001;
+const MAX_LIMIT_PRICE: f64 = 0.999;
+
+/// Keys that fill in a preset dollar amount for market orders
+pub const QUICK_AMOUNTS: [(char, f64); 4] = [('a', 10.0), ('s', 25.0), ('d', 50.0), ('f', 100.0)];
+/// Key that fills in the whole cash balance for market orders
+pub const MAX_AMOUNT_KEY: char = 'm';
+/// (decrease, increase) key pairs that step the shares or amount field, with the step size
+/// (shares for limit orders, dollars for market orders)
+pub const SIZE_STEP_KEYS: [(char, char, f64); 3] =
+    [('-', '+', 10.0), ('[', ']', 100.0), ('{', '}', 1000.0)];
+
+/// Environment variable that keeps the trade popup open after a submitted order
+/// ("1"/"true"/"yes"), clearing the form for the next one
+pub const TRADE_KEEP_OPEN_ENV: &str = "POLYMARKET_TRADE_KEEP_OPEN";
+
+/// Whether `POLYMARKET_TRADE_KEEP_OPEN` asks to keep the trade popup open after submitting
+pub fn keep_trade_popup_open_from_env() -> bool {
+    std::env::var(TRADE_KEEP_OPEN_ENV)
+        .is_ok_and(|v| matches!(v.trim().to_ascii_lowercase().as_str(), "1" | "true" | "yes"))
+}
+
+/// Format a price as the text shown in the limit price field (e.g. 0.535 -> "53.5"), which
+/// reads the same in cents and percent
+fn cents_input(price: f64) -> String {
+    let cents = format!("{:.1}", price * 100.0);
+    cents.trim_end_matches(".0").to_string()
+}
+
+/// Parse a numeric form input, rejecting empty input, a lone "." and anything that isn't a
+/// plain non-negative decimal (e.g. "1.2.3", "-1", "1e3")
+pub fn parse_decimal_input(input: &str) -> Option<f64> {
+    let mut parts = input.split('.');
+    let int_part = parts.next().unwrap_or("");
+    let frac_part = parts.next().unwrap_or("");
+    if parts.next().is_some()
+        || (int_part.is_empty() && frac_part.is_empty())
+        || !int_part
+            .chars()
+            .chain(frac_part.chars())
+            .all(|c| c.is_ascii_digit())
+    {
+        return None;
+    }
+    input.parse().ok()
+}
+
 /// Trade form state
 #[derive(Debug, Clone)]
 #[allow(dead_code)]
@@ -172,12 +412,18 @@ pub struct TradeFormState {
     pub selected_outcome_idx: usize, // Index of currently selected outcome
     pub side: TradeSide,
     pub order_type: OrderType,
-    pub limit_price: f64, // Limit price (for limit orders)
-    pub shares: String,   // Number of shares (input as string for editing, for limit orders)
-    pub amount: String,   // Amount in dollars (input as string for editing, for market orders)
+    pub limit_price: f64,          // Limit price (for limit orders)
+    pub limit_price_input: String, // Limit price as typed in `limit_price_unit` (kept in sync)
+    pub limit_price_unit: LimitPriceUnit,
+    pub shares: String, // Number of shares (input as string for editing, for limit orders)
+    pub amount: String, // Amount in dollars (input as string for editing, for market orders)
     pub active_field: TradeField,
     pub error_message: Option<String>,
     pub is_submitting: bool,
+    pub awaiting_confirm: bool, // First Enter shows the order summary, the second submits
+    pub fee_rate: Option<f64>,  // Base fee rate as a fraction (None until fetched)
+    pub last_result: Option<String>, // Outcome of the previous order when the popup stays open
+    pub clob_tradeable: bool,   // False for AMM-only markets, which have no limit orders
 }
 
 impl TradeFormState {
@@ -190,21 +436,48 @@ impl TradeFormState {
             side: TradeSide::Buy,
             order_type: OrderType::Limit,
             limit_price: initial_price,
+            limit_price_input: cents_input(initial_price),
+            limit_price_unit: LimitPriceUnit::default(),
             shares: String::new(),
             amount: String::new(),
             active_field: TradeField::Shares, // Default to shares input for limit orders
             error_message: None,
             is_submitting: false,
+            awaiting_confirm: false,
+            fee_rate: None,
+            last_result: None,
+            clob_tradeable: true,
         }
     }
 
+    /// Lock the form to market orders for a market that doesn't trade on the CLOB
+    pub fn disable_limit_orders(&mut self) {
+        self.clob_tradeable = false;
+        self.order_type = OrderType::Market;
+        self.active_field = TradeField::Amount;
+    }
+
+    /// Clear the amounts after a submitted order so the next one can be entered right away,
+    /// keeping the outcome, side, order type and limit price
+    pub fn reset_for_next_order(&mut self, result: String) {
+        self.shares.clear();
+        self.amount.clear();
+        self.active_field = match self.order_type {
+            OrderType::Limit => TradeField::Shares,
+            OrderType::Market => TradeField::Amount,
+        };
+        self.error_message = None;
+        self.is_submitting = false;
+        self.awaiting_confirm = false;
+        self.last_result = Some(result);
+    }
+
     /// Get the currently selected outcome
     pub fn selected_outcome(&self) -> Option<&OutcomeInfo> {
         self.outcomes.get(self.selected_outcome_idx)
     }
 
     /// Get the current token ID
-    #[allow(dead_code)]
     pub fn token_id(&self) -> Option<&str> {
         self.selected_outcome().map(|o| o.token_id.as_str())
     }
@@ -225,31 +498,179 @@ impl TradeFormState {
     pub fn select_outcome(&mut self, idx: usize) {
         if idx < self.outcomes.len() && idx != self.selected_outcome_idx {
             self.selected_outcome_idx = idx;
+            self.awaiting_confirm = false;
             // Update limit price to the new outcome's price
             if let Some(outcome) = self.outcomes.get(idx) {
                 self.limit_price = outcome.price;
+                self.limit_price_input = cents_input(outcome.price);
             }
             self.error_message = None;
         }
     }
 
+    /// Select the next (or previous) outcome, wrapping around
+    pub fn cycle_outcome(&mut self, forward: bool) {
+        let count = self.outcomes.len();
+        if count > 1 {
+            let idx = if forward {
+                (self.selected_outcome_idx + 1) % count
+            } else {
+                (self.selected_outcome_idx + count - 1) % count
+            };
+            self.select_outcome(idx);
+        }
+    }
+
     pub fn add_char(&mut self, c: char) {
+        if self.active_field == TradeField::LimitPrice {
+            self.add_limit_price_char(c);
+            return;
+        }
+
         let target = match self.active_field {
             TradeField::Shares => Some(&mut self.shares),
             TradeField::Amount => Some(&mut self.amount),
             _ => None,
         };
 
+        let max_decimals = match self.active_field {
+            TradeField::Amount => AMOUNT_DECIMALS,
+            _ => SHARES_DECIMALS,
+        };
+
         if let Some(field) = target {
-            // Only allow numeric input and decimal point
-            if c.is_ascii_digit() || (c == '.' && !field.contains('.')) {
+            // Only allow numeric input and a single decimal point, up to the field's precision
+            let decimals = field.split_once('.').map(|(_, frac)| frac.len());
+            let accepted = match c {
+                '.' => decimals.is_none(),
+                c if c.is_ascii_digit() => decimals.is_none_or(|d| d < max_decimals),
+                _ => false,
+            };
+            if accepted {
                 field.push(c);
             }
         }
         self.error_message = None;
     }
 
+    /// Handle a quick-amount key (presets or max balance) while editing a market order's
+    /// amount. Returns false if `c` isn't a quick-amount key or doesn't apply here.
+    pub fn apply_quick_amount(&mut self, c: char, balance: Option<f64>) -> bool {
+        if self.order_type != OrderType::Market || self.active_field != TradeField::Amount {
+            return false;
+        }
+        let amount = if c == MAX_AMOUNT_KEY {
+            match balance {
+                // Round down to whole cents so the amount never exceeds the balance
+                Some(balance) => (balance * 100.0).floor() / 100.0,
+                None => {
+                    self.error_message = Some("Balance unknown - login to use max".to_string());
+                    return true;
+                },
+            }
+        } else {
+            match QUICK_AMOUNTS.iter().find(|(key, _)| *key == c) {
+                Some((_, amount)) => *amount,
+                None => return false,
+            }
+        };
+        self.amount = format!("{:.2}", amount).trim_end_matches(".00").to_string();
+        self.error_message = None;
+        true
+    }
+
+    /// Handle a size step key on the shares or amount field, clamping buys to what `balance`
+    /// can pay for. Returns false if `c` isn't a step key or no size field is active.
+    pub fn apply_size_step(&mut self, c: char, balance: Option<f64>) -> bool {
+        let Some(delta) = SIZE_STEP_KEYS.iter().find_map(|&(down, up, step)| {
+            if c == down {
+                Some(-step)
+            } else if c == up {
+                Some(step)
+            } else {
+                None
+            }
+        }) else {
+            return false;
+        };
+        let current = match self.active_field {
+            TradeField::Shares => self.shares_f64(),
+            TradeField::Amount => self.amount_f64(),
+            _ => return false,
+        };
+        let mut value = (current + delta).max(0.0);
+        if self.side == TradeSide::Buy
+            && let Some(balance) = balance
+        {
+            let affordable = match self.active_field {
+                TradeField::Shares if self.limit_price > 0.0 => {
+                    (balance / self.limit_price).floor()
+                },
+                TradeField::Shares => value,
+                _ => (balance * 100.0).floor() / 100.0,
+            };
+            value = value.min(affordable);
+        }
+        let text = format!("{:.2}", value).trim_end_matches(".00").to_string();
+        match self.active_field {
+            TradeField::Shares => self.shares = text,
+            _ => self.amount = text,
+        }
+        self.error_message = None;
+        true
+    }
+
+    /// Type into the limit price field (cents or percent), ignoring keys that would make it invalid
+    fn add_limit_price_char(&mut self, c: char) {
+        let decimals = self
+            .limit_price_input
+            .split_once('.')
+            .map(|(_, frac)| frac.len());
+        let accepted = match c {
+            '.' => decimals.is_none(),
+            c if c.is_ascii_digit() => decimals.is_none_or(|d| d < LIMIT_PRICE_CENTS_DECIMALS),
+            _ => false,
+        };
+        if !accepted {
+            return;
+        }
+        let mut input = self.limit_price_input.clone();
+        input.push(c);
+        // A price of 100¢ (100%) or more can never fill
+        if parse_decimal_input(&input).is_some_and(|value| value >= 100.0) {
+            return;
+        }
+        self.limit_price_input = input;
+        self.apply_limit_price_input();
+        self.error_message = None;
+    }
+
+    /// Update `limit_price` from the typed value, clamped to a valid outcome price and
+    /// snapped to the 0.1¢ tick
+    fn apply_limit_price_input(&mut self) {
+        if let Some(value) = parse_decimal_input(&self.limit_price_input) {
+            // Cents and percent share a scale: both are the price times 100
+            let price = (value / 100.0).clamp(MIN_LIMIT_PRICE, MAX_LIMIT_PRICE);
+            self.limit_price = (price * 1000.0).round() / 1000.0;
+        }
+    }
+
+    /// Switch the limit price between cents and probability input, rewriting the typed
+    /// text from the snapped price so a half-typed value doesn't carry over
+    pub fn toggle_limit_price_unit(&mut self) {
+        self.limit_price_unit = self.limit_price_unit.toggle();
+        self.limit_price_input = cents_input(self.limit_price);
+        self.error_message = None;
+    }
+
     pub fn delete_char(&mut self) {
+        if self.active_field == TradeField::LimitPrice {
+            self.limit_price_input.pop();
+            self.apply_limit_price_input();
+            self.error_message = None;
+            return;
+        }
+
         let target = match self.active_field {
             TradeField::Shares => Some(&mut self.shares),
             TradeField::Amount => Some(&mut self.amount),
@@ -269,6 +690,9 @@ impl TradeFormState {
     }
 
     pub fn toggle_order_type(&mut self) {
+        if !self.clob_tradeable {
+            return;
+        }
         self.order_type = self.order_type.toggle();
         // Reset active field to appropriate default for the new order type
         self.active_field = match self.order_type {
@@ -280,26 +704,64 @@ impl TradeFormState {
 
     /// Increment limit price by 0.1 cents (0.001)
     pub fn increment_limit_price(&mut self) {
-        self.limit_price = (self.limit_price + 0.001).min(1.0);
+        self.limit_price = (self.limit_price + 0.001).min(MAX_LIMIT_PRICE);
         // Round to avoid floating point issues
         self.limit_price = (self.limit_price * 1000.0).round() / 1000.0;
+        self.limit_price_input = cents_input(self.limit_price);
         self.error_message = None;
     }
 
     /// Decrement limit price by 0.1 cents (0.001)
     pub fn decrement_limit_price(&mut self) {
-        self.limit_price = (self.limit_price - 0.001).max(0.001);
+        self.limit_price = (self.limit_price - 0.001).max(MIN_LIMIT_PRICE);
         // Round to avoid floating point issues
         self.limit_price = (self.limit_price * 1000.0).round() / 1000.0;
+        self.limit_price_input = cents_input(self.limit_price);
         self.error_message = None;
     }
 
     pub fn shares_f64(&self) -> f64 {
-        self.shares.parse().unwrap_or(0.0)
+        parse_decimal_input(&self.shares).unwrap_or(0.0)
     }
 
     pub fn amount_f64(&self) -> f64 {
-        self.amount.parse().unwrap_or(0.0)
+        parse_decimal_input(&self.amount).unwrap_or(0.0)
+    }
+
+    /// Dollars a buy order would spend
+    pub fn order_cost(&self) -> f64 {
+        match self.order_type {
+            OrderType::Limit => self.total_cost(),
+            OrderType::Market => self.amount_f64(),
+        }
+    }
+
+    /// Error when a buy would spend more than the known cash balance.
+    /// An unknown balance (not logged in) is not checked.
+    pub fn balance_error(&self, balance: Option<f64>) -> Option<String> {
+        let balance = balance?;
+        (self.side == TradeSide::Buy && self.order_cost() > balance)
+            .then(|| format!("Exceeds your balance of ${:.2}", balance))
+    }
+
+    /// Validate the form before submitting
+    pub fn validate(&self, balance: Option<f64>) -> Result<(), String> {
+        if self.order_type == OrderType::Limit
+            && !parse_decimal_input(&self.limit_price_input).is_some_and(|cents| cents > 0.0)
+        {
+            return Err("Please enter a valid limit price".to_string());
+        }
+        let (input, message) = match self.order_type {
+            OrderType::Limit => (&self.shares, "Please enter a valid number of shares"),
+            OrderType::Market => (&self.amount, "Please enter a valid amount"),
+        };
+        if !parse_decimal_input(input).is_some_and(|value| value > 0.0) {
+            return Err(message.to_string());
+        }
+        match self.balance_error(balance) {
+            Some(error) => Err(error),
+            None => Ok(()),
+        }
     }
 
     /// Calculate total cost for limit orders (shares * limit_price)
@@ -318,27 +780,79 @@ impl TradeFormState {
         }
     }
 
-    /// Calculate potential profit (for buy: payout - cost, for sell: proceeds)
+    /// Maximum payout if the outcome wins (each share pays $1)
+    pub fn max_payout(&self) -> f64 {
+        match self.order_type {
+            OrderType::Limit => self.shares_f64(),
+            OrderType::Market => self.estimated_shares(),
+        }
+    }
+
+    /// Estimated fee, or None while the fee rate is unknown.
+    /// Polymarket charges `rate * min(price, 1 - price)` per share.
+    pub fn estimated_fee(&self) -> Option<f64> {
+        let fee_rate = self.fee_rate?;
+        let (price, shares) = match self.order_type {
+            OrderType::Limit => (self.limit_price, self.shares_f64()),
+            OrderType::Market => (self.best_ask(), self.estimated_shares()),
+        };
+        Some(fee_rate * price.min(1.0 - price) * shares)
+    }
+
+    /// Cost including fees for buys, proceeds after fees for sells
+    pub fn net_cost(&self) -> Option<f64> {
+        let fee = self.estimated_fee()?;
+        Some(match self.side {
+            TradeSide::Buy => self.order_cost() + fee,
+            TradeSide::Sell => self.order_cost() - fee,
+        })
+    }
+
+    /// Calculate potential profit (for buy: payout - cost, for sell: proceeds), net of the
+    /// estimated fee when the fee rate is known
     pub fn potential_profit(&self) -> f64 {
+        let cost = self.order_cost();
+        let fee = self.estimated_fee().unwrap_or(0.0);
+        match self.side {
+            TradeSide::Buy => self.max_payout() - cost - fee,
+            TradeSide::Sell => cost - fee, // Proceeds from selling
+        }
+    }
+
+    /// One-line summary of the order shown before it is submitted,
+    /// e.g. "Buy 100 Yes shares @ 53¢ = $53.00". The limit price is the clamped and snapped
+    /// price that gets submitted, not the typed text.
+    pub fn confirmation_summary(&self) -> String {
+        let verb = match self.side {
+            TradeSide::Buy => "Buy",
+            TradeSide::Sell => "Sell",
+        };
         match self.order_type {
-            OrderType::Limit => {
-                let shares = self.shares_f64();
-                let cost = self.total_cost();
-                match self.side {
-                    TradeSide::Buy => shares - cost, // Shares pay $1 each if won
-                    TradeSide::Sell => cost,         // Proceeds from selling
-                }
-            },
-            OrderType::Market => {
-                let shares = self.estimated_shares();
-                match self.side {
-                    TradeSide::Buy => shares - self.amount_f64(), // Shares pay $1 each if won
-                    TradeSide::Sell => self.amount_f64(),         // Proceeds from selling
-                }
-            },
+            OrderType::Limit => format!(
+                "{} {} {} shares @ {}{} = ${:.2}",
+                verb,
+                self.shares,
+                self.outcome_name(),
+                cents_input(self.limit_price),
+                self.limit_price_unit.symbol(),
+                self.total_cost()
+            ),
+            OrderType::Market => format!(
+                "{} ${} of {} @ ~{:.1}¢ (~{:.2} shares)",
+                verb,
+                self.amount,
+                self.outcome_name(),
+                self.best_ask() * 100.0,
+                self.estimated_shares()
+            ),
         }
     }
 
+    /// Whether the active field is a numeric input, where Left/Right don't switch outcomes
+    pub fn is_numeric_field_active(&self) -> bool {
+        self.active_field != TradeField::OrderType
+    }
+
     pub fn next_field(&mut self) {
         self.active_field = self.active_field.next(self.order_type);
     }
@@ -352,10 +866,292 @@ impl TradeFormState {
         self.shares.clear();
         self.amount.clear();
         self.limit_price = self.best_ask();
+        self.limit_price_input = cents_input(self.limit_price);
         self.side = TradeSide::Buy;
         self.order_type = OrderType::Limit;
         self.active_field = TradeField::Shares;
         self.error_message = None;
         self.is_submitting = false;
+        self.awaiting_confirm = false;
+        if !self.clob_tradeable {
+            self.disable_limit_orders();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn market_form() -> TradeFormState {
+        let outcomes = vec![OutcomeInfo {
+            name: "Yes".to_string(),
+            token_id: "1".to_string(),
+            price: 0.5,
+        }];
+        let mut form = TradeFormState::new("Question?".to_string(), outcomes, 0);
+        form.toggle_order_type();
+        form
+    }
+
+    fn type_str(form: &mut TradeFormState, input: &str) {
+        for c in input.chars() {
+            form.add_char(c);
+        }
+    }
+
+    #[test]
+    fn parse_decimal_input_accepts_plain_decimals() {
+        assert_eq!(parse_decimal_input("12"), Some(12.0));
+        assert_eq!(parse_decimal_input("1.25"), Some(1.25));
+        assert_eq!(parse_decimal_input("1."), Some(1.0));
+        assert_eq!(parse_decimal_input(".5"), Some(0.5));
+    }
+
+    #[test]
+    fn parse_decimal_input_rejects_malformed_input() {
+        assert_eq!(parse_decimal_input(""), None);
+        assert_eq!(parse_decimal_input("."), None);
+        assert_eq!(parse_decimal_input("1.2.3"), None);
+        assert_eq!(parse_decimal_input("-1"), None);
+        assert_eq!(parse_decimal_input("1e3"), None);
+        assert_eq!(parse_decimal_input("inf"), None);
+    }
+
+    #[test]
+    fn add_char_caps_decimal_places() {
+        let mut form = market_form();
+        type_str(&mut form, "1.2.345");
+        assert_eq!(form.amount, "1.23");
+    }
+
+    fn trade_worth(total_value: f64) -> Trade {
+        Trade {
+            timestamp: 0,
+            side: "BUY".to_string(),
+            outcome: "Yes".to_string(),
+            price: 0.5,
+            shares: total_value * 2.0,
+            total_value,
+            title: String::new(),
+            asset_id: String::new(),
+            user: String::new(),
+            pseudonym: String::new(),
+            wallet: String::new(),
+        }
+    }
+
+    #[test]
+    fn whale_threshold_prefers_configured_value_then_median() {
+        let mut trades: Vec<Trade> = (1..=9).map(|v| trade_worth(v as f64)).collect();
+        assert_eq!(whale_threshold(&trades, Some(500.0)), Some(500.0));
+        // Too few trades for a meaningful median
+        assert_eq!(whale_threshold(&trades, None), None);
+        trades.push(trade_worth(10_000.0));
+        // Median of 1..=9 plus one outlier is 5.5
+        assert_eq!(whale_threshold(&trades, None), Some(55.0));
+    }
+
+    #[test]
+    fn vwap_for_weights_by_shares_per_outcome() {
+        let mut event_trades = EventTrades::new();
+        for (asset_id, price, shares) in
+            [("yes", 0.6, 100.0), ("yes", 0.4, 300.0), ("no", 0.55, 10.0)]
+        {
+            event_trades.trades.push(Trade {
+                price,
+                shares,
+                asset_id: asset_id.to_string(),
+                ..trade_worth(price * shares)
+            });
+        }
+        assert!((event_trades.vwap_for("yes").unwrap() - 0.45).abs() < 1e-9);
+        assert!((event_trades.vwap_for("no").unwrap() - 0.55).abs() < 1e-9);
+        assert_eq!(event_trades.vwap_for("other"), None);
+    }
+
+    #[test]
+    fn off_clob_forms_stay_on_market_orders() {
+        let mut form = market_form();
+        form.toggle_order_type();
+        form.disable_limit_orders();
+        assert_eq!(form.order_type, OrderType::Market);
+        assert_eq!(form.active_field, TradeField::Amount);
+        form.toggle_order_type();
+        assert_eq!(form.order_type, OrderType::Market);
+        form.clear();
+        assert_eq!(form.order_type, OrderType::Market);
+    }
+
+    #[test]
+    fn apply_quick_amount_sets_presets_and_max() {
+        let mut form = market_form();
+        assert!(form.apply_quick_amount('s', None));
+        assert_eq!(form.amount, "25");
+        assert!(form.apply_quick_amount('m', Some(123.456)));
+        assert_eq!(form.amount, "123.45");
+        assert!(!form.apply_quick_amount('x', None));
+        // Limit orders have no amount field
+        form.toggle_order_type();
+        assert!(!form.apply_quick_amount('a', None));
+    }
+
+    #[test]
+    fn apply_size_step_adds_and_clamps_to_balance() {
+        let mut form = market_form();
+        assert!(form.apply_size_step('}', None));
+        assert!(form.apply_size_step(']', None));
+        assert_eq!(form.amount, "1100");
+        assert!(form.apply_size_step('-', None));
+        assert_eq!(form.amount, "1090");
+        // Buys can't step past the balance, and nothing steps below zero
+        assert!(form.apply_size_step('}', Some(1500.255)));
+        assert_eq!(form.amount, "1500.25");
+        form.amount = "5".to_string();
+        assert!(form.apply_size_step('-', None));
+        assert_eq!(form.amount, "0");
+        // Limit orders step shares, capped at what the limit price affords
+        form.toggle_order_type();
+        form.limit_price = 0.5;
+        assert!(form.apply_size_step('}', Some(120.0)));
+        assert_eq!(form.shares, "240");
+        assert!(!form.apply_size_step('x', None));
+    }
+
+    #[test]
+    fn typed_limit_price_is_in_cents_and_clamped() {
+        let mut form = market_form();
+        form.toggle_order_type();
+        form.active_field = TradeField::LimitPrice;
+        form.limit_price_input.clear();
+        type_str(&mut form, "53");
+        assert_eq!(form.limit_price, 0.53);
+        // A third digit would reach 100¢ or more and is ignored
+        type_str(&mut form, "0");
+        assert_eq!(form.limit_price_input, "53");
+        type_str(&mut form, ".55");
+        assert_eq!(form.limit_price_input, "53.5");
+        assert_eq!(form.limit_price, 0.535);
+        form.limit_price_input.clear();
+        type_str(&mut form, "0");
+        assert_eq!(form.limit_price, MIN_LIMIT_PRICE);
+        form.increment_limit_price();
+        assert_eq!(form.limit_price_input, "0.2");
+    }
+
+    #[test]
+    fn limit_price_in_percent_matches_cents() {
+        let mut form = market_form();
+        form.toggle_order_type();
+        form.active_field = TradeField::LimitPrice;
+        form.toggle_limit_price_unit();
+        assert_eq!(form.limit_price_unit, LimitPriceUnit::Percent);
+        form.limit_price_input.clear();
+        type_str(&mut form, "62.25");
+        assert_eq!(form.limit_price_input, "62.2");
+        assert_eq!(form.limit_price, 0.622);
+        form.shares = "10".to_string();
+        assert!(form.confirmation_summary().contains("@ 62.2% ="));
+
+        // A half-typed value is replaced by the snapped price when switching back
+        form.limit_price_input.push('.');
+        form.toggle_limit_price_unit();
+        assert_eq!(form.limit_price_unit, LimitPriceUnit::Cents);
+        assert_eq!(form.limit_price_input, "62.2");
+    }
+
+    #[test]
+    fn validate_rejects_lone_decimal_point() {
+        let mut form = market_form();
+        type_str(&mut form, ".");
+        assert!(form.validate(None).is_err());
+    }
+
+    #[test]
+    fn validate_checks_buys_against_known_balance() {
+        let mut form = market_form();
+        type_str(&mut form, "25");
+        assert!(form.validate(Some(20.0)).is_err());
+        assert!(form.validate(Some(25.0)).is_ok());
+        // Unknown balance: input is allowed
+        assert!(form.validate(None).is_ok());
+        // Sells don't spend cash
+        form.side = TradeSide::Sell;
+        assert!(form.validate(Some(20.0)).is_ok());
+    }
+
+    #[test]
+    fn cycle_outcome_wraps_and_resets_limit_price() {
+        let outcomes = ["Yes", "No"]
+            .iter()
+            .zip([0.3, 0.7])
+            .map(|(name, price)| OutcomeInfo {
+                name: name.to_string(),
+                token_id: name.to_string(),
+                price,
+            })
+            .collect();
+        let mut form = TradeFormState::new("Question?".to_string(), outcomes, 0);
+        form.cycle_outcome(false);
+        assert_eq!(form.selected_outcome_idx, 1);
+        assert_eq!(form.limit_price, 0.7);
+        form.cycle_outcome(true);
+        assert_eq!(form.selected_outcome_idx, 0);
+        assert_eq!(form.limit_price_input, "30");
+    }
+
+    #[test]
+    fn confirmation_summary_describes_limit_order() {
+        let mut form = market_form();
+        form.toggle_order_type();
+        form.active_field = TradeField::Shares;
+        type_str(&mut form, "100");
+        form.active_field = TradeField::LimitPrice;
+        form.limit_price_input.clear();
+        type_str(&mut form, "53");
+        assert_eq!(
+            form.confirmation_summary(),
+            "Buy 100 Yes shares @ 53¢ = $53.00"
+        );
+
+        // A price below the minimum tick is shown as the clamped price that gets submitted
+        form.limit_price_input.clear();
+        type_str(&mut form, "0.01");
+        assert_eq!(
+            form.confirmation_summary(),
+            "Buy 100 Yes shares @ 0.1¢ = $0.10"
+        );
+    }
+
+    #[test]
+    fn fees_adjust_net_cost_and_profit() {
+        let mut form = market_form();
+        form.toggle_order_type();
+        form.active_field = TradeField::Shares;
+        type_str(&mut form, "100");
+        assert_eq!(form.estimated_fee(), None);
+        assert_eq!(form.net_cost(), None);
+
+        // 2% of min(0.5, 0.5) per share on 100 shares
+        form.fee_rate = Some(0.02);
+        assert_eq!(form.estimated_fee(), Some(1.0));
+        assert_eq!(form.net_cost(), Some(51.0));
+        assert_eq!(form.potential_profit(), 49.0);
+    }
+
+    #[test]
+    fn reset_for_next_order_keeps_the_setup() {
+        let mut form = market_form();
+        form.toggle_order_type();
+        form.active_field = TradeField::Shares;
+        type_str(&mut form, "100");
+        form.awaiting_confirm = true;
+
+        form.reset_for_next_order("Order placed".to_string());
+        assert!(form.shares.is_empty());
+        assert!(!form.awaiting_confirm);
+        assert_eq!(form.order_type, OrderType::Limit);
+        assert_eq!(form.active_field, TradeField::Shares);
+        assert_eq!(form.last_result.as_deref(), Some("Order placed"));
     }
 }
@@ -1,6 +1,117 @@
 //! Trades and WebSocket management state
 
-use {super::trade::EventTrades, std::collections::HashMap, tokio::task::JoinHandle};
+use {
+    super::trade::{EventTrades, Trade},
+    polymarket_api::gamma::Event,
+    std::{
+        collections::{HashMap, HashSet},
+        time::Duration,
+    },
+    tokio::task::JoinHandle,
+};
+
+/// Consecutive failed reconnects before a watched event's WebSocket gives up
+pub const MAX_RECONNECT_ATTEMPTS: u32 = 10;
+/// Delay before the first reconnect; doubles per consecutive attempt
+pub const RECONNECT_BASE_DELAY: Duration = Duration::from_secs(1);
+/// Upper bound for the reconnect delay
+pub const RECONNECT_MAX_DELAY: Duration = Duration::from_secs(30);
+
+/// Backoff before reconnect `attempt` (1-based): 1s, 2s, 4s, ... capped at `RECONNECT_MAX_DELAY`
+pub fn reconnect_delay(attempt: u32) -> Duration {
+    RECONNECT_BASE_DELAY
+        .saturating_mul(1 << attempt.saturating_sub(1).min(16))
+        .min(RECONNECT_MAX_DELAY)
+}
+
+/// Default number of trades retained per event
+pub const DEFAULT_MAX_TRADES: usize = 500;
+/// Environment variable overriding how many trades are retained per event
+pub const MAX_TRADES_ENV: &str = "POLYMARKET_MAX_TRADES";
+
+/// Environment variable with comma-separated keywords to auto-watch (e.g. "Fed,election")
+pub const AUTO_WATCH_KEYWORDS_ENV: &str = "POLYMARKET_AUTO_WATCH_KEYWORDS";
+/// Environment variable capping how many WebSocket connections auto-watch may open
+pub const AUTO_WATCH_MAX_ENV: &str = "POLYMARKET_AUTO_WATCH_MAX";
+/// Default cap on open trade WebSocket connections for auto-watch
+pub const DEFAULT_AUTO_WATCH_MAX: usize = 5;
+
+/// Most events a single "watch all visible" action starts streams for
+pub const MAX_BULK_WATCH: usize = 10;
+
+/// Environment variable with the dollar value above which a live trade raises an alert
+pub const TRADE_ALERT_THRESHOLD_ENV: &str = "POLYMARKET_TRADE_ALERT_THRESHOLD";
+/// Environment variable that silences the terminal bell on trade alerts ("0", "false" or "no")
+pub const TRADE_ALERT_BELL_ENV: &str = "POLYMARKET_TRADE_ALERT_BELL";
+/// Thresholds the alert key cycles through before turning alerts off
+pub const TRADE_ALERT_STEPS: [f64; 4] = [1_000.0, 10_000.0, 50_000.0, 100_000.0];
+/// Environment variable with the dollar value above which trades are highlighted as whales
+pub const WHALE_THRESHOLD_ENV: &str = "POLYMARKET_WHALE_THRESHOLD";
+
+/// Automatically watch newly-seen events whose title contains one of the keywords
+#[derive(Debug, Clone, Default)]
+pub struct AutoWatchConfig {
+    pub keywords: Vec<String>, // Lowercased keywords; empty disables auto-watch
+    pub max_connections: usize, // Auto-watch only starts a stream while fewer are open
+    seen: HashSet<String>,     // Slugs of every event loaded so far
+    seeded: bool,              // Whether the first load has filled `seen`
+}
+
+impl AutoWatchConfig {
+    pub fn from_env() -> Self {
+        let keywords = std::env::var(AUTO_WATCH_KEYWORDS_ENV)
+            .map(|v| {
+                v.split(',')
+                    .map(|k| k.trim().to_lowercase())
+                    .filter(|k| !k.is_empty())
+                    .collect()
+            })
+            .unwrap_or_default();
+        let max_connections = std::env::var(AUTO_WATCH_MAX_ENV)
+            .ok()
+            .and_then(|v| v.trim().parse().ok())
+            .unwrap_or(DEFAULT_AUTO_WATCH_MAX);
+        Self {
+            keywords,
+            max_connections,
+            ..Self::default()
+        }
+    }
+
+    /// Mark events as already seen, so they never count as newly appearing
+    pub fn seed(&mut self, events: &[Event]) {
+        self.seen
+            .extend(events.iter().map(|event| event.slug.clone()));
+        self.seeded = true;
+    }
+
+    /// Slugs of open events not seen in any earlier load whose title matches a keyword.
+    /// The first load only seeds the seen set: its events were there before the app started.
+    pub fn new_matches(&mut self, events: &[Event]) -> Vec<String> {
+        if !self.seeded {
+            self.seed(events);
+            return Vec::new();
+        }
+        events
+            .iter()
+            .filter(|event| {
+                self.seen.insert(event.slug.clone())
+                    && !event.closed
+                    && self.matching_keyword(&event.title).is_some()
+            })
+            .map(|event| event.slug.clone())
+            .collect()
+    }
+
+    /// The first keyword contained in `title` (case-insensitive)
+    pub fn matching_keyword(&self, title: &str) -> Option<&str> {
+        let title = title.to_lowercase();
+        self.keywords
+            .iter()
+            .find(|k| title.contains(k.as_str()))
+            .map(String::as_str)
+    }
+}
 
 /// Trades and WebSocket management state
 #[derive(Debug)]
@@ -9,13 +120,144 @@ pub struct TradesState {
     pub event_trades: HashMap<String, EventTrades>,
     // Map from event slug to websocket task handle
     pub ws_handles: HashMap<String, JoinHandle<()>>,
+    /// Maximum trades kept per event (newest first); older trades are discarded on insert.
+    /// Each `Trade` owns several heap-allocated strings (side, outcome, title, asset id,
+    /// user, pseudonym), so memory grows roughly linearly with this cap times the number of
+    /// watched events. Raise it to keep more history on very active markets, lower it on
+    /// constrained machines. Lowering it at runtime trims existing events on their next trade.
+    pub max_trades: usize,
+    pub auto_watch: AutoWatchConfig,
+    // Slugs of newly-cached events matching an auto-watch keyword, waiting to be watched
+    pub pending_auto_watch: Vec<String>,
+    // Map from asset_id to the unix timestamp of its most recent live trade
+    pub last_trade_at: HashMap<String, i64>,
+    // Show trades oldest-first; storage stays newest-first
+    pub oldest_first: bool,
+    // Live trades worth more than this many dollars raise an alert; None disables alerts
+    pub trade_alert_threshold: Option<f64>,
+    // Ring the terminal bell on alerts
+    pub trade_alert_bell: bool,
+    // Fixed whale highlight threshold; None derives one from the loaded trades' median value
+    pub whale_threshold: Option<f64>,
 }
 
 impl TradesState {
     pub fn new() -> Self {
+        Self::with_max_trades(
+            std::env::var(MAX_TRADES_ENV)
+                .ok()
+                .and_then(|v| v.trim().parse().ok())
+                .filter(|v: &usize| *v > 0)
+                .unwrap_or(DEFAULT_MAX_TRADES),
+        )
+    }
+
+    pub fn with_max_trades(max_trades: usize) -> Self {
         Self {
             event_trades: HashMap::new(),
             ws_handles: HashMap::new(),
+            max_trades,
+            auto_watch: AutoWatchConfig::from_env(),
+            pending_auto_watch: Vec::new(),
+            last_trade_at: HashMap::new(),
+            oldest_first: false,
+            trade_alert_threshold: std::env::var(TRADE_ALERT_THRESHOLD_ENV)
+                .ok()
+                .and_then(|v| v.trim().parse().ok())
+                .filter(|v: &f64| *v > 0.0),
+            trade_alert_bell: std::env::var(TRADE_ALERT_BELL_ENV).map_or(true, |v| {
+                !matches!(v.trim().to_ascii_lowercase().as_str(), "0" | "false" | "no")
+            }),
+            whale_threshold: std::env::var(WHALE_THRESHOLD_ENV)
+                .ok()
+                .and_then(|v| v.trim().parse().ok())
+                .filter(|v: &f64| *v > 0.0),
+        }
+    }
+
+    /// Whether a trade worth `total_value` dollars should raise an alert
+    pub fn is_alert_trade(&self, total_value: f64) -> bool {
+        self.trade_alert_threshold
+            .is_some_and(|threshold| total_value > threshold)
+    }
+
+    /// Move the alert threshold to the next step above it, turning alerts off after the last
+    pub fn cycle_trade_alert_threshold(&mut self) -> Option<f64> {
+        self.trade_alert_threshold = match self.trade_alert_threshold {
+            None => Some(TRADE_ALERT_STEPS[0]),
+            Some(current) => TRADE_ALERT_STEPS.iter().copied().find(|s| *s > current),
+        };
+        self.trade_alert_threshold
+    }
+
+    /// `trades` (stored newest-first) in the order they should be displayed
+    pub fn display_order<'a>(&self, trades: &'a [Trade]) -> Vec<&'a Trade> {
+        if self.oldest_first {
+            trades.iter().rev().collect()
+        } else {
+            trades.iter().collect()
+        }
+    }
+
+    /// Remember when `asset_id` last traded, ignoring out-of-order older trades
+    pub fn record_trade_time(&mut self, asset_id: &str, timestamp: i64) {
+        let last = self
+            .last_trade_at
+            .entry(asset_id.to_string())
+            .or_insert(timestamp);
+        *last = (*last).max(timestamp);
+    }
+
+    /// Most recent live trade across `asset_ids` (e.g. both outcomes of a market)
+    pub fn last_trade_time<'a>(
+        &self,
+        asset_ids: impl IntoIterator<Item = &'a String>,
+    ) -> Option<i64> {
+        asset_ids
+            .into_iter()
+            .filter_map(|id| self.last_trade_at.get(id).copied())
+            .max()
+    }
+
+    /// Plain-text recap of the trades observed this session, busiest event first
+    pub fn session_summary(&self) -> String {
+        let mut events: Vec<(&String, &EventTrades)> = self
+            .event_trades
+            .iter()
+            .filter(|(_, t)| t.session_trades > 0 || t.is_watching)
+            .collect();
+        events.sort_by(|a, b| {
+            b.1.session_trades
+                .cmp(&a.1.session_trades)
+                .then_with(|| a.0.cmp(b.0))
+        });
+
+        let total_trades: usize = events.iter().map(|(_, t)| t.session_trades).sum();
+        let total_volume: f64 = events.iter().map(|(_, t)| t.session_volume).sum();
+        let largest = events
+            .iter()
+            .filter_map(|(_, t)| t.largest_trade.as_ref())
+            .max_by(|a, b| a.0.total_cmp(&b.0));
+
+        let mut lines = vec![
+            "Polymarket session summary".to_string(),
+            format!("Events watched: {}", events.len()),
+            format!("Total trades: {}", total_trades),
+            format!("Total volume: ${:.2}", total_volume),
+        ];
+        if let Some((value, description)) = largest {
+            lines.push(format!("Largest trade: ${:.2} ({})", value, description));
+        }
+        if !events.is_empty() {
+            lines.push(String::new());
+            lines.push("Per event:".to_string());
+            for (slug, trades) in &events {
+                lines.push(format!(
+                    "  {}: {} trades, ${:.2}",
+                    slug, trades.session_trades, trades.session_volume
+                ));
+            }
         }
+        lines.join("\n")
     }
 }
@@ -0,0 +1,39 @@
+//! Display preferences remembered across restarts
+
+use {
+    super::{
+        ThemePreset,
+        config_file::{load_json, save_json},
+        refresh::RefreshIntervals,
+    },
+    serde::{Deserialize, Serialize},
+};
+
+/// Toggles for optional UI elements and the auto-refresh intervals, saved to `ui_prefs.json`
+/// in the config directory
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct UiPrefs {
+    /// Show each event's liquidity next to the sort metric in the events list ('V')
+    pub show_liquidity: bool,
+    /// Compact events and favorites lists: icons, title and market count only ('z')
+    pub compact_mode: bool,
+    /// Active color palette ('c')
+    pub theme_preset: ThemePreset,
+    /// Seconds between auto-refreshes of each source (settings popup, ',')
+    pub refresh_intervals: RefreshIntervals,
+}
+
+impl UiPrefs {
+    const FILE: &'static str = "ui_prefs.json";
+
+    /// The saved preferences, or the defaults if none were saved
+    pub fn load() -> Self {
+        load_json(Self::FILE).unwrap_or_default()
+    }
+
+    /// Write the preferences for the next start
+    pub fn save(&self) -> Result<(), String> {
+        save_json(Self::FILE, self, "UI preferences")
+    }
+}
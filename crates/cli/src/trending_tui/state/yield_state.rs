@@ -47,6 +47,25 @@ pub struct YieldState {
     pub is_searching: bool,                     // Whether search input is active
     pub is_search_loading: bool,                // Whether API search is in progress
     pub last_searched_query: String,            // Last query that was searched
+    pub view_mode: YieldViewMode,               // Which list navigation/selection applies to
+    pub min_volume_input: String,               // Min volume being typed in the popup
+}
+
+/// Which list the Yield tab is showing
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum YieldViewMode {
+    #[default]
+    Opportunities, // Yield opportunities (optionally filtered)
+    SearchResults, // Results of the last API search
+}
+
+impl YieldViewMode {
+    pub fn label(&self) -> &'static str {
+        match self {
+            YieldViewMode::Opportunities => "Opportunities",
+            YieldViewMode::SearchResults => "Search",
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -92,6 +111,8 @@ impl YieldState {
             is_searching: false,
             is_search_loading: false,
             last_searched_query: String::new(),
+            view_mode: YieldViewMode::default(),
+            min_volume_input: String::new(),
         }
     }
 
@@ -118,8 +139,7 @@ impl YieldState {
     }
 
     pub fn move_up(&mut self) {
-        let filtered_len = self.filtered_opportunities().len();
-        if filtered_len == 0 {
+        if self.displayed_count() == 0 {
             return;
         }
         if self.selected_index > 0 {
@@ -131,11 +151,11 @@ impl YieldState {
     }
 
     pub fn move_down(&mut self, visible_height: usize) {
-        let filtered_len = self.filtered_opportunities().len();
-        if filtered_len == 0 {
+        let len = self.displayed_count();
+        if len == 0 {
             return;
         }
-        if self.selected_index < filtered_len.saturating_sub(1) {
+        if self.selected_index < len.saturating_sub(1) {
             self.selected_index += 1;
             if self.selected_index >= self.scroll + visible_height {
                 self.scroll = self.selected_index - visible_height + 1;
@@ -143,32 +163,135 @@ impl YieldState {
         }
     }
 
+    /// Move the selection up by one page, keeping it visible
+    pub fn page_up(&mut self, visible_height: usize) {
+        let page = visible_height.max(1);
+        self.selected_index = self.selected_index.saturating_sub(page);
+        self.scroll = self.scroll.saturating_sub(page).min(self.selected_index);
+    }
+
+    /// Move the selection down by one page, keeping it visible
+    pub fn page_down(&mut self, visible_height: usize) {
+        let page = visible_height.max(1);
+        let len = self.displayed_count();
+        self.selected_index = (self.selected_index + page).min(len.saturating_sub(1));
+        self.scroll = (self.scroll + page)
+            .min(len.saturating_sub(page))
+            .max((self.selected_index + 1).saturating_sub(page));
+    }
+
+    /// Jump to the first item of the active list
+    pub fn select_first(&mut self) {
+        self.selected_index = 0;
+        self.scroll = 0;
+    }
+
+    /// Jump to the last item of the active list, placing it on the last visible row
+    pub fn select_last(&mut self, visible_height: usize) {
+        let len = self.displayed_count();
+        self.selected_index = len.saturating_sub(1);
+        self.scroll = len.saturating_sub(visible_height.max(1));
+    }
+
+    /// Get the selected opportunity (only in the opportunities view)
     pub fn selected_opportunity(&self) -> Option<&YieldOpportunity> {
+        if self.view_mode != YieldViewMode::Opportunities {
+            return None;
+        }
         self.filtered_opportunities()
             .get(self.selected_index)
             .copied()
     }
 
-    /// Get filtered opportunities based on the current filter query
-    pub fn filtered_opportunities(&self) -> Vec<&YieldOpportunity> {
-        if self.filter_query.is_empty() {
-            return self.opportunities.iter().collect();
+    /// Slug of the event selected in the active list
+    pub fn selected_event_slug(&self) -> Option<String> {
+        match self.view_mode {
+            YieldViewMode::Opportunities => {
+                self.selected_opportunity().map(|o| o.event_slug.clone())
+            },
+            YieldViewMode::SearchResults => {
+                self.selected_search_result().map(|r| r.event_slug.clone())
+            },
         }
+    }
 
+    /// Switch between the opportunities list and the last search results.
+    /// Returns false (and stays put) when there are no search results to show.
+    pub fn toggle_view_mode(&mut self) -> bool {
+        let next = match self.view_mode {
+            YieldViewMode::Opportunities => {
+                if self.search_results.is_empty() && self.last_searched_query.is_empty() {
+                    return false;
+                }
+                YieldViewMode::SearchResults
+            },
+            YieldViewMode::SearchResults => YieldViewMode::Opportunities,
+        };
+        self.view_mode = next;
+        self.selected_index = 0;
+        self.scroll = 0;
+        true
+    }
+
+    /// Get opportunities passing the minimum volume and the current filter query
+    pub fn filtered_opportunities(&self) -> Vec<&YieldOpportunity> {
         let query_lower = self.filter_query.to_lowercase();
         self.opportunities
             .iter()
+            .filter(|opp| opp.volume >= self.min_volume)
             .filter(|opp| {
-                opp.event_title.to_lowercase().contains(&query_lower)
+                query_lower.is_empty()
+                    || opp.event_title.to_lowercase().contains(&query_lower)
                     || opp.event_slug.to_lowercase().contains(&query_lower)
                     || opp.market_name.to_lowercase().contains(&query_lower)
             })
             .collect()
     }
 
+    /// Prefill the min volume popup input with the active threshold
+    pub fn start_min_volume_edit(&mut self) {
+        self.min_volume_input = if self.min_volume > 0.0 {
+            format!("{}", self.min_volume)
+        } else {
+            String::new()
+        };
+    }
+
+    pub fn add_min_volume_char(&mut self, c: char) {
+        if c.is_ascii_digit() || (c == '.' && !self.min_volume_input.contains('.')) {
+            self.min_volume_input.push(c);
+        }
+    }
+
+    pub fn delete_min_volume_char(&mut self) {
+        self.min_volume_input.pop();
+    }
+
+    /// Apply the typed min volume (empty clears it). Returns false if the input isn't a number.
+    pub fn apply_min_volume_input(&mut self) -> bool {
+        let min_volume = if self.min_volume_input.is_empty() {
+            0.0
+        } else {
+            match self.min_volume_input.parse::<f64>() {
+                Ok(value) => value,
+                Err(_) => return false,
+            }
+        };
+        self.min_volume = min_volume;
+        self.selected_index = 0;
+        self.scroll = 0;
+        true
+    }
+
     pub fn enter_filter_mode(&mut self) {
         self.is_filtering = true;
         self.filter_query.clear();
+        // Filtering applies to opportunities, so make sure they're the active list
+        if self.view_mode != YieldViewMode::Opportunities {
+            self.view_mode = YieldViewMode::Opportunities;
+            self.selected_index = 0;
+            self.scroll = 0;
+        }
     }
 
     pub fn exit_filter_mode(&mut self) {
@@ -201,6 +324,9 @@ impl YieldState {
         self.search_query.clear();
         self.search_results.clear();
         self.last_searched_query.clear();
+        self.view_mode = YieldViewMode::SearchResults;
+        self.selected_index = 0;
+        self.scroll = 0;
     }
 
     pub fn exit_search_mode(&mut self) {
@@ -208,6 +334,7 @@ impl YieldState {
         self.search_query.clear();
         self.search_results.clear();
         self.last_searched_query.clear();
+        self.view_mode = YieldViewMode::Opportunities;
         self.selected_index = 0;
         self.scroll = 0;
     }
@@ -234,6 +361,7 @@ impl YieldState {
         self.search_results = results;
         self.last_searched_query = query;
         self.is_search_loading = false;
+        self.view_mode = YieldViewMode::SearchResults;
         self.selected_index = 0;
         self.scroll = 0;
     }
@@ -244,22 +372,19 @@ impl YieldState {
         self.is_filtering || self.is_searching
     }
 
-    /// Get the currently displayed items count
-    #[allow(dead_code)]
+    /// Number of items in the active list
     pub fn displayed_count(&self) -> usize {
-        if self.is_searching && !self.search_results.is_empty() {
-            self.search_results.len()
-        } else {
-            self.filtered_opportunities().len()
+        match self.view_mode {
+            YieldViewMode::Opportunities => self.filtered_opportunities().len(),
+            YieldViewMode::SearchResults => self.search_results.len(),
         }
     }
 
-    /// Get selected search result (when in search mode)
+    /// Get the selected search result (only in the search results view)
     pub fn selected_search_result(&self) -> Option<&YieldSearchResult> {
-        if self.is_searching || !self.search_results.is_empty() {
-            self.search_results.get(self.selected_index)
-        } else {
-            None
+        match self.view_mode {
+            YieldViewMode::SearchResults => self.search_results.get(self.selected_index),
+            YieldViewMode::Opportunities => None,
         }
     }
 }
@@ -147,6 +147,8 @@ where
         // Format the final log message with our level prefix
         // At this point, message_content should NOT contain any [LEVEL] prefix
         let log_message = format!("[{}] {}", level_str, message_content);
+        // Mask credentials before the message reaches the logs panel or a saved log file
+        let log_message = crate::trending_tui::state::redact_secrets(&log_message);
 
         // Store in shared state
         // We need to use blocking or ensure this completes
@@ -35,8 +35,6 @@ macro_rules! log_debug {
     ($($arg:tt)*) => {};
 }
 
-const CLOB_API_BASE: &str = "https://clob.polymarket.com";
-
 /// Order side (buy or sell)
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 #[serde(rename_all = "UPPERCASE")]
@@ -110,6 +108,13 @@ pub struct MidpointResponse {
     pub mid: String,
 }
 
+/// Fee rate response from GET /fee-rate endpoint
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FeeRateResponse {
+    /// Base fee rate in basis points
+    pub base_fee: u32,
+}
+
 /// Historical price point
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PriceHistoryPoint {
@@ -300,17 +305,20 @@ pub struct ClobClient {
     passphrase: Option<String>,
     /// Polygon wallet address (required for L2 authentication)
     address: Option<String>,
+    /// API base URL (defaults to the public CLOB API)
+    base_url: String,
 }
 
 impl ClobClient {
     /// Create a new CLOB client without authentication (for public endpoints)
     pub fn new() -> Self {
         Self {
-            client: reqwest::Client::new(),
+            client: crate::http::client(),
             api_key: None,
             api_secret: None,
             passphrase: None,
             address: None,
+            base_url: crate::endpoints::clob_api_url(),
         }
     }
 
@@ -322,14 +330,32 @@ impl ClobClient {
         address: String,
     ) -> Self {
         Self {
-            client: reqwest::Client::new(),
+            client: crate::http::client(),
             api_key: Some(api_key),
             api_secret: Some(api_secret),
             passphrase: Some(passphrase),
             address: Some(address),
+            base_url: crate::endpoints::clob_api_url(),
         }
     }
 
+    /// Use a custom API base URL (e.g. a mirror or staging deployment)
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Give up on requests that take longer than `timeout` (instead of the configured default)
+    pub fn with_timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.client = crate::http::client_with_timeout(timeout);
+        self
+    }
+
+    /// Get the API base URL used by this client
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
     /// Create a new CLOB client from environment variables
     /// Requires: api_key, secret, passphrase, address (or poly_address)
     pub fn from_env() -> Self {
@@ -393,7 +419,7 @@ impl ClobClient {
 
     /// Get orderbook for a specific market (condition ID)
     pub async fn get_orderbook(&self, condition_id: &str) -> Result<Orderbook> {
-        let url = format!("{}/book", CLOB_API_BASE);
+        let url = format!("{}/book", self.base_url);
         let params = [("market", condition_id)];
         let orderbook: Orderbook = self
             .client
@@ -408,7 +434,7 @@ impl ClobClient {
 
     /// Get recent trades for a specific market (condition ID)
     pub async fn get_trades(&self, condition_id: &str, limit: Option<usize>) -> Result<Vec<Trade>> {
-        let url = format!("{}/trades", CLOB_API_BASE);
+        let url = format!("{}/trades", self.base_url);
         let mut params = vec![("market", condition_id.to_string())];
         if let Some(limit) = limit {
             params.push(("limit", limit.to_string()));
@@ -426,13 +452,13 @@ impl ClobClient {
 
     /// Get orderbook for a specific token ID (clob_token_id from Gamma API)
     pub async fn get_orderbook_by_asset(&self, token_id: &str) -> Result<Orderbook> {
-        let _url = format!("{}/book?token_id={}", CLOB_API_BASE, token_id);
+        let _url = format!("{}/book?token_id={}", self.base_url, token_id);
         log_info!("GET {}", _url);
 
         let params = [("token_id", token_id)];
         let response = self
             .client
-            .get(format!("{}/book", CLOB_API_BASE))
+            .get(format!("{}/book", self.base_url))
             .query(&params)
             .send()
             .await?;
@@ -460,14 +486,8 @@ impl ClobClient {
         }
 
         if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-            return Err(crate::error::PolymarketError::InvalidData(format!(
-                "HTTP {}: {}",
-                status, error_text
-            )));
+            let error = crate::error::PolymarketError::from_response(response).await;
+            return Err(error);
         }
 
         let response_text = response.text().await?;
@@ -522,7 +542,7 @@ impl ClobClient {
         asset_id: &str,
         limit: Option<usize>,
     ) -> Result<Vec<Trade>> {
-        let url = format!("{}/trades", CLOB_API_BASE);
+        let url = format!("{}/trades", self.base_url);
         let mut params = vec![("asset_id", asset_id.to_string())];
         if let Some(limit) = limit {
             params.push(("limit", limit.to_string()));
@@ -553,7 +573,7 @@ impl ClobClient {
         let query_string = query_parts.join("&");
         let request_path = format!("/trades?{}", query_string);
 
-        log_info!("GET {}{} (authenticated)", CLOB_API_BASE, request_path);
+        log_info!("GET {}{} (authenticated)", self.base_url, request_path);
 
         // Create L2 auth headers
         let headers = self
@@ -564,20 +584,14 @@ impl ClobClient {
                 )
             })?;
 
-        let url = format!("{}{}", CLOB_API_BASE, request_path);
+        let url = format!("{}{}", self.base_url, request_path);
         let response = self.client.get(&url).headers(headers).send().await?;
 
         let status = response.status();
         if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-            log_info!("GET {} -> error: {} - {}", request_path, status, error_text);
-            return Err(crate::error::PolymarketError::InvalidData(format!(
-                "HTTP {}: {}",
-                status, error_text
-            )));
+            let error = crate::error::PolymarketError::from_response(response).await;
+            log_info!("GET {} -> error: {}", request_path, error);
+            return Err(error);
         }
 
         let trades: Vec<Trade> = response.json().await?;
@@ -606,7 +620,16 @@ impl ClobClient {
         } else {
             "/orders".to_string()
         };
+        self.fetch_open_orders(request_path).await
+    }
+
+    /// Get user's open orders on one outcome token (requires authentication)
+    pub async fn get_open_orders_for_asset(&self, asset_id: &str) -> Result<Vec<OpenOrder>> {
+        self.fetch_open_orders(format!("/orders?asset_id={}", asset_id))
+            .await
+    }
 
+    async fn fetch_open_orders(&self, request_path: String) -> Result<Vec<OpenOrder>> {
         let headers = self
             .create_l2_headers("GET", &request_path, None)
             .ok_or_else(|| {
@@ -615,19 +638,13 @@ impl ClobClient {
                 )
             })?;
 
-        let url = format!("{}{}", CLOB_API_BASE, request_path);
+        let url = format!("{}{}", self.base_url, request_path);
         let response = self.client.get(&url).headers(headers).send().await?;
 
         let status = response.status();
         if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-            return Err(crate::error::PolymarketError::InvalidData(format!(
-                "HTTP {}: {}",
-                status, error_text
-            )));
+            let error = crate::error::PolymarketError::from_response(response).await;
+            return Err(error);
         }
 
         let orders: Vec<OpenOrder> = response.json().await?;
@@ -646,19 +663,13 @@ impl ClobClient {
                 )
             })?;
 
-        let url = format!("{}{}", CLOB_API_BASE, request_path);
+        let url = format!("{}{}", self.base_url, request_path);
         let response = self.client.get(&url).headers(headers).send().await?;
 
         let status = response.status();
         if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-            return Err(crate::error::PolymarketError::InvalidData(format!(
-                "HTTP {}: {}",
-                status, error_text
-            )));
+            let error = crate::error::PolymarketError::from_response(response).await;
+            return Err(error);
         }
 
         let order: OpenOrder = response.json().await?;
@@ -681,19 +692,13 @@ impl ClobClient {
                 )
             })?;
 
-        let url = format!("{}{}", CLOB_API_BASE, request_path);
+        let url = format!("{}{}", self.base_url, request_path);
         let response = self.client.get(&url).headers(headers).send().await?;
 
         let status = response.status();
         if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-            return Err(crate::error::PolymarketError::InvalidData(format!(
-                "HTTP {}: {}",
-                status, error_text
-            )));
+            let error = crate::error::PolymarketError::from_response(response).await;
+            return Err(error);
         }
 
         let balance: BalanceAllowance = response.json().await?;
@@ -712,19 +717,13 @@ impl ClobClient {
                 )
             })?;
 
-        let url = format!("{}{}", CLOB_API_BASE, request_path);
+        let url = format!("{}{}", self.base_url, request_path);
         let response = self.client.delete(&url).headers(headers).send().await?;
 
         let status = response.status();
         if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-            return Err(crate::error::PolymarketError::InvalidData(format!(
-                "HTTP {}: {}",
-                status, error_text
-            )));
+            let error = crate::error::PolymarketError::from_response(response).await;
+            return Err(error);
         }
 
         let result: CancelOrdersResponse = response.json().await?;
@@ -743,19 +742,13 @@ impl ClobClient {
                 )
             })?;
 
-        let url = format!("{}{}", CLOB_API_BASE, request_path);
+        let url = format!("{}{}", self.base_url, request_path);
         let response = self.client.delete(&url).headers(headers).send().await?;
 
         let status = response.status();
         if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-            return Err(crate::error::PolymarketError::InvalidData(format!(
-                "HTTP {}: {}",
-                status, error_text
-            )));
+            let error = crate::error::PolymarketError::from_response(response).await;
+            return Err(error);
         }
 
         let result: CancelOrdersResponse = response.json().await?;
@@ -778,7 +771,7 @@ impl ClobClient {
     /// * `token_id` - The unique identifier for the token
     /// * `side` - The side of the market (BUY or SELL)
     pub async fn get_price(&self, token_id: &str, side: Side) -> Result<PriceResponse> {
-        let url = format!("{}/price", CLOB_API_BASE);
+        let url = format!("{}/price", self.base_url);
         let side_str = match side {
             Side::Buy => "BUY",
             Side::Sell => "SELL",
@@ -788,11 +781,8 @@ impl ClobClient {
         let response = self.client.get(&url).query(&params).send().await?;
 
         if !response.status().is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-            return Err(crate::error::PolymarketError::InvalidData(error_text));
+            let error = crate::error::PolymarketError::from_response(response).await;
+            return Err(error);
         }
 
         let price: PriceResponse = response.json().await?;
@@ -806,23 +796,38 @@ impl ClobClient {
     /// # Arguments
     /// * `token_id` - The unique identifier for the token
     pub async fn get_midpoint(&self, token_id: &str) -> Result<MidpointResponse> {
-        let url = format!("{}/midpoint", CLOB_API_BASE);
+        let url = format!("{}/midpoint", self.base_url);
         let params = [("token_id", token_id)];
 
         let response = self.client.get(&url).query(&params).send().await?;
 
         if !response.status().is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-            return Err(crate::error::PolymarketError::InvalidData(error_text));
+            let error = crate::error::PolymarketError::from_response(response).await;
+            return Err(error);
         }
 
         let midpoint: MidpointResponse = response.json().await?;
         Ok(midpoint)
     }
 
+    /// Get the base fee rate for a token, in basis points
+    ///
+    /// # Arguments
+    /// * `token_id` - The unique identifier for the token
+    pub async fn get_fee_rate_bps(&self, token_id: &str) -> Result<u32> {
+        let url = format!("{}/fee-rate", self.base_url);
+        let params = [("token_id", token_id)];
+
+        let response = self.client.get(&url).query(&params).send().await?;
+
+        if !response.status().is_success() {
+            return Err(crate::error::PolymarketError::from_response(response).await);
+        }
+
+        let fee_rate: FeeRateResponse = response.json().await?;
+        Ok(fee_rate.base_fee)
+    }
+
     /// Get historical price data for a token
     ///
     /// # Arguments
@@ -839,7 +844,7 @@ impl ClobClient {
         interval: Option<PriceInterval>,
         fidelity: Option<u32>,
     ) -> Result<PriceHistoryResponse> {
-        let url = format!("{}/prices-history", CLOB_API_BASE);
+        let url = format!("{}/prices-history", self.base_url);
         let mut params = vec![("market", token_id.to_string())];
 
         if let Some(start) = start_ts {
@@ -858,11 +863,8 @@ impl ClobClient {
         let response = self.client.get(&url).query(&params).send().await?;
 
         if !response.status().is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-            return Err(crate::error::PolymarketError::InvalidData(error_text));
+            let error = crate::error::PolymarketError::from_response(response).await;
+            return Err(error);
         }
 
         let history: PriceHistoryResponse = response.json().await?;
@@ -877,16 +879,13 @@ impl ClobClient {
         &self,
         requests: Vec<SpreadRequest>,
     ) -> Result<std::collections::HashMap<String, String>> {
-        let url = format!("{}/spreads", CLOB_API_BASE);
+        let url = format!("{}/spreads", self.base_url);
 
         let response = self.client.post(&url).json(&requests).send().await?;
 
         if !response.status().is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-            return Err(crate::error::PolymarketError::InvalidData(error_text));
+            let error = crate::error::PolymarketError::from_response(response).await;
+            return Err(error);
         }
 
         let spreads: std::collections::HashMap<String, String> = response.json().await?;
@@ -898,16 +897,13 @@ impl ClobClient {
     /// # Arguments
     /// * `requests` - Array of batch token requests (max 500)
     pub async fn get_orderbooks(&self, requests: Vec<BatchTokenRequest>) -> Result<Vec<Orderbook>> {
-        let url = format!("{}/books", CLOB_API_BASE);
+        let url = format!("{}/books", self.base_url);
 
         let response = self.client.post(&url).json(&requests).send().await?;
 
         if !response.status().is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-            return Err(crate::error::PolymarketError::InvalidData(error_text));
+            let error = crate::error::PolymarketError::from_response(response).await;
+            return Err(error);
         }
 
         let orderbooks: Vec<Orderbook> = response.json().await?;
@@ -925,16 +921,13 @@ impl ClobClient {
         &self,
         requests: Vec<BatchTokenRequest>,
     ) -> Result<std::collections::HashMap<String, TokenPrices>> {
-        let url = format!("{}/prices", CLOB_API_BASE);
+        let url = format!("{}/prices", self.base_url);
 
         let response = self.client.post(&url).json(&requests).send().await?;
 
         if !response.status().is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-            return Err(crate::error::PolymarketError::InvalidData(error_text));
+            let error = crate::error::PolymarketError::from_response(response).await;
+            return Err(error);
         }
 
         let prices: std::collections::HashMap<String, TokenPrices> = response.json().await?;
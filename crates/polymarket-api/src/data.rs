@@ -8,8 +8,6 @@ use {
     serde::{Deserialize, Serialize},
 };
 
-const DATA_API_BASE: &str = "https://data-api.polymarket.com";
-
 /// Trade information from Data API
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DataTrade {
@@ -270,6 +268,8 @@ impl TradeSide {
 /// Data API client
 pub struct DataClient {
     client: reqwest::Client,
+    /// API base URL (defaults to the public Data API)
+    base_url: String,
 }
 
 /// Data API status response
@@ -282,13 +282,31 @@ impl DataClient {
     /// Create a new Data API client
     pub fn new() -> Self {
         Self {
-            client: reqwest::Client::new(),
+            client: crate::http::client(),
+            base_url: crate::endpoints::data_api_url(),
         }
     }
 
+    /// Use a custom API base URL (e.g. a mirror or staging deployment)
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Give up on requests that take longer than `timeout` (instead of the configured default)
+    pub fn with_timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.client = crate::http::client_with_timeout(timeout);
+        self
+    }
+
+    /// Get the API base URL used by this client
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
     /// Check Data API health status
     pub async fn get_status(&self) -> Result<DataStatusResponse> {
-        let url = DATA_API_BASE;
+        let url = &self.base_url;
         let status: DataStatusResponse = self.client.get(url).send().await?.json().await?;
         Ok(status)
     }
@@ -302,7 +320,7 @@ impl DataClient {
         filter_type: Option<&str>,
         filter_amount: Option<f64>,
     ) -> Result<Vec<DataTrade>> {
-        let url = format!("{}/trades", DATA_API_BASE);
+        let url = format!("{}/trades", self.base_url);
         let mut params = vec![
             ("eventId", event_id.to_string()),
             ("limit", limit.unwrap_or(10).to_string()),
@@ -334,7 +352,7 @@ impl DataClient {
         limit: Option<usize>,
         offset: Option<usize>,
     ) -> Result<Vec<DataTrade>> {
-        let url = format!("{}/trades", DATA_API_BASE);
+        let url = format!("{}/trades", self.base_url);
         let params = vec![
             ("eventSlug", event_slug.to_string()),
             ("limit", limit.unwrap_or(10).to_string()),
@@ -359,7 +377,7 @@ impl DataClient {
         limit: Option<usize>,
         offset: Option<usize>,
     ) -> Result<Vec<DataTrade>> {
-        let url = format!("{}/trades", DATA_API_BASE);
+        let url = format!("{}/trades", self.base_url);
         let params = vec![
             ("conditionId", condition_id.to_string()),
             ("limit", limit.unwrap_or(10).to_string()),
@@ -391,7 +409,7 @@ impl DataClient {
     /// # Notes
     /// See also: `get_positions_filtered` for fine-grained control over pagination and filtering.
     pub async fn get_positions(&self, user_address: &str) -> Result<Vec<Position>> {
-        let url = format!("{}/positions", DATA_API_BASE);
+        let url = format!("{}/positions", self.base_url);
         let limit = 500; // Valid range: 0-500.
         let mut all_positions: Vec<Position> = Vec::new();
 
@@ -442,7 +460,7 @@ impl DataClient {
     /// A vector of `ClosedPosition` structs representing all of the user's closed positions, performing
     /// pagination in the request as required to fetch all results.
     pub async fn get_closed_positions(&self, user_address: &str) -> Result<Vec<ClosedPosition>> {
-        let url = format!("{}/closed-positions", DATA_API_BASE);
+        let url = format!("{}/closed-positions", self.base_url);
         let limit = 50; // Valid range: 0-50.
         let mut all_positions: Vec<ClosedPosition> = Vec::new();
 
@@ -505,7 +523,7 @@ impl DataClient {
         sort_direction: Option<SortDirection>,
         side: Option<TradeSide>,
     ) -> Result<Vec<Activity>> {
-        let url = format!("{}/activity", DATA_API_BASE);
+        let url = format!("{}/activity", self.base_url);
         let mut params = vec![
             ("user", user_address.to_string()),
             ("limit", limit.unwrap_or(100).to_string()),
@@ -578,7 +596,7 @@ impl DataClient {
         filter_amount: Option<f64>,
         side: Option<TradeSide>,
     ) -> Result<Vec<DataTrade>> {
-        let url = format!("{}/trades", DATA_API_BASE);
+        let url = format!("{}/trades", self.base_url);
         let mut params = vec![
             ("limit", limit.unwrap_or(100).to_string()),
             ("offset", offset.unwrap_or(0).to_string()),
@@ -640,7 +658,7 @@ impl DataClient {
         limit: Option<usize>,
         offset: Option<usize>,
     ) -> Result<Vec<Position>> {
-        let url = format!("{}/positions", DATA_API_BASE);
+        let url = format!("{}/positions", self.base_url);
         let mut params = vec![
             ("user", user_address.to_string()),
             ("limit", limit.unwrap_or(100).to_string()),
@@ -0,0 +1,170 @@
+//! API endpoint configuration
+//!
+//! Every client defaults to the public Polymarket endpoints. Each base URL can be
+//! overridden with an environment variable (for mirrors, staging, or self-hosted proxies),
+//! process-wide from a config file via [`configure`], or per client via `with_base_url` /
+//! `with_url`. Environment variables take precedence over the configured URLs.
+
+use {serde::Deserialize, std::sync::OnceLock};
+
+/// Default Gamma API base URL
+pub const DEFAULT_GAMMA_API_URL: &str = "https://gamma-api.polymarket.com";
+/// Default Data API base URL
+pub const DEFAULT_DATA_API_URL: &str = "https://data-api.polymarket.com";
+/// Default CLOB REST API base URL
+pub const DEFAULT_CLOB_API_URL: &str = "https://clob.polymarket.com";
+/// Default CLOB market WebSocket URL
+pub const DEFAULT_CLOB_WS_URL: &str = "wss://ws-subscriptions-clob.polymarket.com/ws/market";
+/// Default RTDS (Real-Time Data Stream) WebSocket URL
+pub const DEFAULT_RTDS_WS_URL: &str = "wss://ws-live-data.polymarket.com/";
+
+/// Environment variable overriding the Gamma API base URL
+pub const GAMMA_API_URL_ENV: &str = "POLYMARKET_GAMMA_API_URL";
+/// Environment variable overriding the Data API base URL
+pub const DATA_API_URL_ENV: &str = "POLYMARKET_DATA_API_URL";
+/// Environment variable overriding the CLOB REST API base URL
+pub const CLOB_API_URL_ENV: &str = "POLYMARKET_CLOB_API_URL";
+/// Environment variable overriding the CLOB market WebSocket URL
+pub const CLOB_WS_URL_ENV: &str = "POLYMARKET_CLOB_WS_URL";
+/// Environment variable overriding the RTDS WebSocket URL
+pub const RTDS_WS_URL_ENV: &str = "POLYMARKET_RTDS_WS_URL";
+
+/// Base URLs set in a config file, e.g. `{"gamma_base_url": "https://gamma.example.com"}`.
+/// Unset fields keep the default endpoint.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(default)]
+pub struct EndpointsConfig {
+    pub gamma_base_url: Option<String>,
+    pub data_base_url: Option<String>,
+    pub clob_base_url: Option<String>,
+    pub clob_ws_url: Option<String>,
+    pub rtds_ws_url: Option<String>,
+}
+
+static CONFIGURED: OnceLock<EndpointsConfig> = OnceLock::new();
+
+/// Use the base URLs of `config` for every client created afterwards. Call it once at startup,
+/// before creating clients; returns false (and changes nothing) if already configured.
+pub fn configure(config: EndpointsConfig) -> bool {
+    CONFIGURED.set(config).is_ok()
+}
+
+/// The configured URL selected by `field`, if any
+fn configured(field: fn(&EndpointsConfig) -> &Option<String>) -> Option<&'static str> {
+    CONFIGURED.get().and_then(|config| field(config).as_deref())
+}
+
+/// A resolved endpoint URL and whether it came from an override
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Endpoint {
+    pub url: String,
+    pub overridden: bool,
+}
+
+impl Endpoint {
+    /// Resolve an endpoint from `env_var`, then the `configured` URL, falling back to
+    /// `default` when both are unset or empty. An empty `env_var` doesn't hide the configured URL.
+    fn resolve(
+        env_var: &str,
+        configured: Option<&str>,
+        default: &str,
+        trim_trailing_slash: bool,
+    ) -> Self {
+        let clean = |v: &str| {
+            let v = v.trim();
+            let v = if trim_trailing_slash {
+                v.trim_end_matches('/')
+            } else {
+                v
+            };
+            (!v.is_empty()).then(|| v.to_string())
+        };
+        let url = std::env::var(env_var)
+            .ok()
+            .and_then(|v| clean(&v))
+            .or_else(|| configured.and_then(clean));
+        match url {
+            Some(url) => Self {
+                url,
+                overridden: true,
+            },
+            None => Self {
+                url: default.to_string(),
+                overridden: false,
+            },
+        }
+    }
+}
+
+/// Active endpoints for all Polymarket APIs
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Endpoints {
+    pub gamma_api: Endpoint,
+    pub data_api: Endpoint,
+    pub clob_api: Endpoint,
+    pub clob_ws: Endpoint,
+    pub rtds_ws: Endpoint,
+}
+
+impl Endpoints {
+    /// Resolve all endpoints from the environment and the configured URLs
+    pub fn from_env() -> Self {
+        Self {
+            gamma_api: gamma_api(),
+            data_api: data_api(),
+            clob_api: clob_api(),
+            clob_ws: clob_ws(),
+            rtds_ws: rtds_ws(),
+        }
+    }
+}
+
+fn gamma_api() -> Endpoint {
+    let configured = configured(|c| &c.gamma_base_url);
+    Endpoint::resolve(GAMMA_API_URL_ENV, configured, DEFAULT_GAMMA_API_URL, true)
+}
+
+fn data_api() -> Endpoint {
+    let configured = configured(|c| &c.data_base_url);
+    Endpoint::resolve(DATA_API_URL_ENV, configured, DEFAULT_DATA_API_URL, true)
+}
+
+fn clob_api() -> Endpoint {
+    let configured = configured(|c| &c.clob_base_url);
+    Endpoint::resolve(CLOB_API_URL_ENV, configured, DEFAULT_CLOB_API_URL, true)
+}
+
+fn clob_ws() -> Endpoint {
+    let configured = configured(|c| &c.clob_ws_url);
+    Endpoint::resolve(CLOB_WS_URL_ENV, configured, DEFAULT_CLOB_WS_URL, false)
+}
+
+fn rtds_ws() -> Endpoint {
+    let configured = configured(|c| &c.rtds_ws_url);
+    Endpoint::resolve(RTDS_WS_URL_ENV, configured, DEFAULT_RTDS_WS_URL, false)
+}
+
+/// Gamma API base URL (override via `POLYMARKET_GAMMA_API_URL` or `gamma_base_url`)
+pub fn gamma_api_url() -> String {
+    gamma_api().url
+}
+
+/// Data API base URL (override via `POLYMARKET_DATA_API_URL` or `data_base_url`)
+pub fn data_api_url() -> String {
+    data_api().url
+}
+
+/// CLOB REST API base URL (override via `POLYMARKET_CLOB_API_URL` or `clob_base_url`)
+pub fn clob_api_url() -> String {
+    clob_api().url
+}
+
+/// CLOB market WebSocket URL (override via `POLYMARKET_CLOB_WS_URL` or `clob_ws_url`)
+pub fn clob_ws_url() -> String {
+    clob_ws().url
+}
+
+/// RTDS WebSocket URL (override via `POLYMARKET_RTDS_WS_URL` or `rtds_ws_url`)
+pub fn rtds_ws_url() -> String {
+    rtds_ws().url
+}
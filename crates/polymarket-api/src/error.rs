@@ -16,6 +16,57 @@ pub enum PolymarketError {
 
     #[error("Invalid data: {0}")]
     InvalidData(String),
+
+    #[error("HTTP {status} from {url}: {body}")]
+    Api {
+        url: String,
+        status: u16,
+        body: String,
+    },
+}
+
+impl PolymarketError {
+    /// Build an [`PolymarketError::Api`] from a non-success response, consuming its body
+    pub(crate) async fn from_response(response: reqwest::Response) -> Self {
+        let url = response.url().to_string();
+        let status = response.status().as_u16();
+        let body = response
+            .text()
+            .await
+            .unwrap_or_else(|_| "Unknown error".to_string());
+        PolymarketError::Api { url, status, body }
+    }
+
+    /// URL of the failed request, when known
+    pub fn url(&self) -> Option<&str> {
+        match self {
+            PolymarketError::Api { url, .. } => Some(url),
+            PolymarketError::Http(e) => e.url().map(|url| url.as_str()),
+            _ => None,
+        }
+    }
+
+    /// HTTP status code of the failed request, when one was received
+    pub fn status(&self) -> Option<u16> {
+        match self {
+            PolymarketError::Api { status, .. } => Some(*status),
+            PolymarketError::Http(e) => e.status().map(|status| status.as_u16()),
+            _ => None,
+        }
+    }
+
+    /// Whether the request failed because it took longer than the request timeout
+    pub fn is_timeout(&self) -> bool {
+        matches!(self, PolymarketError::Http(e) if e.is_timeout())
+    }
+
+    /// Response body of the failed request
+    pub fn body(&self) -> Option<&str> {
+        match self {
+            PolymarketError::Api { body, .. } => Some(body),
+            _ => None,
+        }
+    }
 }
 
 pub type Result<T> = std::result::Result<T, PolymarketError>;
@@ -47,8 +47,6 @@ macro_rules! log_error {
     ($($arg:tt)*) => {};
 }
 
-const GAMMA_API_BASE: &str = "https://gamma-api.polymarket.com";
-
 // Helper function to deserialize clobTokenIds which can be either a JSON string or an array
 fn deserialize_clob_token_ids<'de, D>(
     deserializer: D,
@@ -148,18 +146,39 @@ pub struct Event {
     /// Total liquidity
     #[serde(default)]
     pub liquidity: Option<f64>,
+    /// Open interest: value of outstanding shares, i.e. capital currently committed
+    #[serde(rename = "openInterest", default)]
+    pub open_interest: Option<f64>,
     /// Competitive score (0-1, higher means more competitive/closer odds)
     #[serde(default)]
     pub competitive: Option<f64>,
     /// When the event was created
     #[serde(rename = "createdAt", default)]
     pub created_at: Option<String>,
+    /// Resolution rules: what the event's markets resolve on, and how
+    #[serde(default)]
+    pub description: Option<String>,
+    /// Where the outcome is checked (often a URL)
+    #[serde(rename = "resolutionSource", default)]
+    pub resolution_source: Option<String>,
     /// Max price change in the last 24 hours across all markets (for Breaking tab)
     /// This is populated when fetching breaking events, not from the API directly
     #[serde(skip)]
     pub max_price_change_24hr: Option<f64>,
 }
 
+impl Event {
+    /// Open interest for the event, summed from its markets when the event doesn't report it
+    pub fn total_open_interest(&self) -> Option<f64> {
+        self.open_interest.or_else(|| {
+            self.markets
+                .iter()
+                .filter_map(|market| market.open_interest)
+                .reduce(|total, oi| total + oi)
+        })
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Tag {
     pub id: String,
@@ -193,6 +212,8 @@ pub struct Market {
     pub volume_24hr: Option<f64>,
     #[serde(rename = "volumeTotal", default)]
     pub volume_total: Option<f64>,
+    #[serde(rename = "openInterest", default)]
+    pub open_interest: Option<f64>,
     /// Whether the market is active (accepting new trades)
     #[serde(default)]
     pub active: bool,
@@ -205,6 +226,9 @@ pub struct Market {
     /// Whether the market is accepting orders
     #[serde(rename = "acceptingOrders", default)]
     pub accepting_orders: bool,
+    /// Whether the market trades on the CLOB orderbook (absent on some older responses)
+    #[serde(rename = "enableOrderBook", default)]
+    pub enable_order_book: Option<bool>,
     /// UMA oracle resolution statuses (JSON string like "[\"proposed\", \"disputed\"]")
     #[serde(rename = "umaResolutionStatuses", default)]
     pub uma_resolution_statuses: Option<String>,
@@ -214,6 +238,12 @@ pub struct Market {
     /// Price change in the last 24 hours (used for Breaking tab sorting)
     #[serde(rename = "oneDayPriceChange", default)]
     pub one_day_price_change: Option<f64>,
+    /// Resolution rules for this market (grouped markets often repeat the event's)
+    #[serde(default)]
+    pub description: Option<String>,
+    /// Where this market's outcome is checked
+    #[serde(rename = "resolutionSource", default)]
+    pub resolution_source: Option<String>,
 }
 
 impl Market {
@@ -222,6 +252,16 @@ impl Market {
         self.events.first()
     }
 
+    /// Whether orders for this market go through the CLOB, so the orderbook and limit
+    /// orders apply. Falls back to having CLOB token IDs when the venue isn't reported.
+    pub fn is_clob_tradeable(&self) -> bool {
+        self.enable_order_book.unwrap_or_else(|| {
+            self.clob_token_ids
+                .as_ref()
+                .is_some_and(|ids| !ids.is_empty())
+        })
+    }
+
     /// Check if market is in resolution/review process
     pub fn is_in_review(&self) -> bool {
         if let Some(ref statuses) = self.uma_resolution_statuses {
@@ -277,6 +317,8 @@ pub struct GammaClient {
     cache: Option<FileCache>,
     /// Authentication credentials (for favorite events, etc.)
     auth: Option<GammaAuth>,
+    /// API base URL (defaults to the public Gamma API)
+    base_url: String,
 }
 
 /// Authentication credentials for Gamma API
@@ -301,21 +343,40 @@ pub struct GammaAuth {
 impl GammaClient {
     pub fn new() -> Self {
         Self {
-            client: reqwest::Client::new(),
+            client: crate::http::client(),
             cache: None,
             auth: None,
+            base_url: crate::endpoints::gamma_api_url(),
         }
     }
 
     /// Create a new GammaClient with authentication
     pub fn with_auth(auth: GammaAuth) -> Self {
         Self {
-            client: reqwest::Client::new(),
+            client: crate::http::client(),
             cache: None,
             auth: Some(auth),
+            base_url: crate::endpoints::gamma_api_url(),
         }
     }
 
+    /// Use a custom API base URL (e.g. a mirror or staging deployment)
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Give up on requests that take longer than `timeout` (instead of the configured default)
+    pub fn with_timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.client = crate::http::client_with_timeout(timeout);
+        self
+    }
+
+    /// Get the API base URL used by this client
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
     /// Set authentication credentials
     pub fn set_auth(&mut self, auth: GammaAuth) {
         self.auth = Some(auth);
@@ -338,9 +399,10 @@ impl GammaClient {
     pub fn with_cache<P: AsRef<std::path::Path>>(cache_dir: P) -> Result<Self> {
         let cache = FileCache::new(cache_dir)?;
         Ok(Self {
-            client: reqwest::Client::new(),
+            client: crate::http::client(),
             cache: Some(cache),
             auth: None,
+            base_url: crate::endpoints::gamma_api_url(),
         })
     }
 
@@ -361,7 +423,7 @@ impl GammaClient {
         let limit = limit.unwrap_or(100);
         let url = format!(
             "{}/events?active=true&closed=false&limit={}",
-            GAMMA_API_BASE, limit
+            self.base_url, limit
         );
         let events: Vec<Event> = self.client.get(&url).send().await?.json().await?;
         Ok(events)
@@ -385,15 +447,39 @@ impl GammaClient {
 
         let url = format!(
             "{}/events?active=true&closed=false&order={}&ascending={}&limit={}",
-            GAMMA_API_BASE, order_by, ascending, limit
+            self.base_url, order_by, ascending, limit
         );
+        self.get_events_list(&url).await
+    }
+
+    /// Get events that haven't reached their end date yet, soonest first. Ordering by end date
+    /// alone would lead with events whose end date has passed but that are still open.
+    ///
+    /// # Arguments
+    /// * `limit` - Maximum number of events to return (default 50)
+    pub async fn get_closing_soon_events(&self, limit: Option<usize>) -> Result<Vec<Event>> {
+        let limit = limit.unwrap_or(50);
+        let now = chrono::Utc::now().format("%Y-%m-%dT%H:%M:%SZ").to_string();
+        let url = format!(
+            "{}/events?active=true&closed=false&order=endDate&ascending=true&end_date_min={}&limit={}",
+            self.base_url,
+            urlencoding::encode(&now),
+            limit
+        );
+        self.get_events_list(&url).await
+    }
 
+    /// GET an events list endpoint, turning an error status into a `PolymarketError`
+    async fn get_events_list(&self, url: &str) -> Result<Vec<Event>> {
         log_info!("GET {}", url);
 
-        let response = self.client.get(&url).send().await?;
-        let _status = response.status();
+        let response = self.client.get(url).send().await?;
+        let status = response.status();
 
-        log_info!("GET {} -> status: {}", url, _status);
+        log_info!("GET {} -> status: {}", url, status);
+        if !status.is_success() {
+            return Err(crate::error::PolymarketError::from_response(response).await);
+        }
 
         let events: Vec<Event> = response.json().await?;
         Ok(events)
@@ -414,15 +500,18 @@ impl GammaClient {
 
         let url = format!(
             "{}/markets?active=true&closed=false&order=oneDayPriceChange&ascending=false&limit={}",
-            GAMMA_API_BASE, market_limit
+            self.base_url, market_limit
         );
 
         log_info!("GET {}", url);
 
         let response = self.client.get(&url).send().await?;
-        let _status = response.status();
+        let status = response.status();
 
-        log_info!("GET {} -> status: {}", url, _status);
+        log_info!("GET {} -> status: {}", url, status);
+        if !status.is_success() {
+            return Err(crate::error::PolymarketError::from_response(response).await);
+        }
 
         let markets: Vec<Market> = response.json().await?;
 
@@ -472,7 +561,7 @@ impl GammaClient {
     }
 
     pub async fn get_market_by_slug(&self, slug: &str) -> Result<Vec<Market>> {
-        let url = format!("{}/markets?slug={}", GAMMA_API_BASE, slug);
+        let url = format!("{}/markets?slug={}", self.base_url, slug);
         let response: serde_json::Value = self.client.get(&url).send().await?.json().await?;
 
         // The API might return a single market or an array
@@ -502,7 +591,7 @@ impl GammaClient {
 
     /// Get event by ID
     pub async fn get_event_by_id(&self, event_id: &str) -> Result<Option<Event>> {
-        let url = format!("{}/events/{}", GAMMA_API_BASE, event_id);
+        let url = format!("{}/events/{}", self.base_url, event_id);
         let response = self.client.get(&url).send().await?;
 
         if response.status() == 404 {
@@ -515,14 +604,14 @@ impl GammaClient {
 
     /// Get event by slug
     pub async fn get_event_by_slug(&self, slug: &str) -> Result<Option<Event>> {
-        let url = format!("{}/events?slug={}", GAMMA_API_BASE, slug);
+        let url = format!("{}/events?slug={}", self.base_url, slug);
         let events: Vec<Event> = self.client.get(&url).send().await?.json().await?;
         Ok(events.into_iter().next())
     }
 
     /// Get market by ID
     pub async fn get_market_by_id(&self, market_id: &str) -> Result<Option<Market>> {
-        let url = format!("{}/markets/{}", GAMMA_API_BASE, market_id);
+        let url = format!("{}/markets/{}", self.base_url, market_id);
         let response = self.client.get(&url).send().await?;
 
         if response.status() == 404 {
@@ -540,7 +629,7 @@ impl GammaClient {
         closed: Option<bool>,
         limit: Option<usize>,
     ) -> Result<Vec<Market>> {
-        let url = format!("{}/markets", GAMMA_API_BASE);
+        let url = format!("{}/markets", self.base_url);
         let mut params = Vec::new();
 
         if let Some(active) = active {
@@ -566,7 +655,7 @@ impl GammaClient {
 
     /// Get categories/tags
     pub async fn get_categories(&self) -> Result<Vec<Tag>> {
-        let url = format!("{}/categories", GAMMA_API_BASE);
+        let url = format!("{}/categories", self.base_url);
         let categories: Vec<Tag> = self.client.get(&url).send().await?.json().await?;
         Ok(categories)
     }
@@ -580,7 +669,7 @@ impl GammaClient {
         let limit = limit.unwrap_or(100);
         let url = format!(
             "{}/events?category={}&limit={}",
-            GAMMA_API_BASE, category_slug, limit
+            self.base_url, category_slug, limit
         );
         let events: Vec<Event> = self.client.get(&url).send().await?.json().await?;
         Ok(events)
@@ -591,7 +680,7 @@ impl GammaClient {
         let limit_per_type = limit.unwrap_or(50);
         let url = format!(
             "{}/public-search?q={}&optimized=true&limit_per_type={}&type=events&search_tags=true&search_profiles=true&cache=true",
-            GAMMA_API_BASE,
+            self.base_url,
             urlencoding::encode(query),
             limit_per_type
         );
@@ -628,10 +717,11 @@ impl GammaClient {
                 status,
                 response_text
             );
-            return Err(crate::error::PolymarketError::InvalidData(format!(
-                "Search API returned status {}: {}",
-                status, response_text
-            )));
+            return Err(crate::error::PolymarketError::Api {
+                url: url.clone(),
+                status: status.as_u16(),
+                body: response_text,
+            });
         }
 
         #[derive(Deserialize)]
@@ -733,14 +823,14 @@ impl GammaClient {
 
     /// Check API health status
     pub async fn get_status(&self) -> Result<String> {
-        let url = format!("{}/status", GAMMA_API_BASE);
+        let url = format!("{}/status", self.base_url);
         let status = self.client.get(&url).send().await?.text().await?;
         Ok(status)
     }
 
     /// Get tag by ID
     pub async fn get_tag_by_id(&self, tag_id: &str) -> Result<Option<Tag>> {
-        let url = format!("{}/tags/{}", GAMMA_API_BASE, tag_id);
+        let url = format!("{}/tags/{}", self.base_url, tag_id);
         let response = self.client.get(&url).send().await?;
 
         if response.status() == 404 {
@@ -753,7 +843,7 @@ impl GammaClient {
 
     /// Get tag by slug
     pub async fn get_tag_by_slug(&self, slug: &str) -> Result<Option<Tag>> {
-        let url = format!("{}/tags/slug/{}", GAMMA_API_BASE, slug);
+        let url = format!("{}/tags/slug/{}", self.base_url, slug);
         let response = self.client.get(&url).send().await?;
 
         if response.status() == 404 {
@@ -766,7 +856,7 @@ impl GammaClient {
 
     /// Get related tags for a tag ID
     pub async fn get_related_tags(&self, tag_id: &str) -> Result<Vec<Tag>> {
-        let url = format!("{}/tags/{}/related-tags", GAMMA_API_BASE, tag_id);
+        let url = format!("{}/tags/{}/related-tags", self.base_url, tag_id);
         let tags: Vec<Tag> = self.client.get(&url).send().await?.json().await?;
         Ok(tags)
     }
@@ -774,14 +864,14 @@ impl GammaClient {
     /// Get all series
     pub async fn get_series(&self, limit: Option<usize>) -> Result<Vec<Series>> {
         let limit = limit.unwrap_or(100);
-        let url = format!("{}/series?limit={}", GAMMA_API_BASE, limit);
+        let url = format!("{}/series?limit={}", self.base_url, limit);
         let series: Vec<Series> = self.client.get(&url).send().await?.json().await?;
         Ok(series)
     }
 
     /// Get series by ID
     pub async fn get_series_by_id(&self, series_id: &str) -> Result<Option<Series>> {
-        let url = format!("{}/series/{}", GAMMA_API_BASE, series_id);
+        let url = format!("{}/series/{}", self.base_url, series_id);
         let response = self.client.get(&url).send().await?;
 
         if response.status() == 404 {
@@ -794,7 +884,7 @@ impl GammaClient {
 
     /// Get public profile by wallet address
     pub async fn get_public_profile(&self, address: &str) -> Result<Option<PublicProfile>> {
-        let url = format!("{}/public-profile", GAMMA_API_BASE);
+        let url = format!("{}/public-profile", self.base_url);
         let params = [("address", address)];
         let response = self.client.get(&url).query(&params).send().await?;
 
@@ -808,14 +898,14 @@ impl GammaClient {
 
     /// Get tags for a specific event
     pub async fn get_event_tags(&self, event_id: &str) -> Result<Vec<Tag>> {
-        let url = format!("{}/events/{}/tags", GAMMA_API_BASE, event_id);
+        let url = format!("{}/events/{}/tags", self.base_url, event_id);
         let tags: Vec<Tag> = self.client.get(&url).send().await?.json().await?;
         Ok(tags)
     }
 
     /// Get tags for a specific market
     pub async fn get_market_tags(&self, market_id: &str) -> Result<Vec<Tag>> {
-        let url = format!("{}/markets/{}/tags", GAMMA_API_BASE, market_id);
+        let url = format!("{}/markets/{}/tags", self.base_url, market_id);
         let tags: Vec<Tag> = self.client.get(&url).send().await?.json().await?;
         Ok(tags)
     }
@@ -903,7 +993,7 @@ impl GammaClient {
             ));
         }
 
-        let url = format!("{}/favorite_events", GAMMA_API_BASE);
+        let url = format!("{}/favorite_events", self.base_url);
         let request_path = "/favorite_events";
 
         log_info!("GET {} (cookie auth)", url);
@@ -914,12 +1004,9 @@ impl GammaClient {
         let status = response.status();
 
         if !status.is_success() {
-            let body = response.text().await.unwrap_or_default();
-            log_warn!("GET {} -> error: {} - {}", request_path, status, body);
-            return Err(crate::error::PolymarketError::InvalidData(format!(
-                "Failed to get favorite events: {} - {}",
-                status, body
-            )));
+            let error = crate::error::PolymarketError::from_response(response).await;
+            log_warn!("GET {} -> error: {}", request_path, error);
+            return Err(error);
         }
 
         log_info!("GET {} -> {}", request_path, status);
@@ -937,7 +1024,7 @@ impl GammaClient {
             ));
         }
 
-        let url = format!("{}/favorite_events", GAMMA_API_BASE);
+        let url = format!("{}/favorite_events", self.base_url);
         let request_path = "/favorite_events";
 
         let body = serde_json::to_string(&AddFavoriteRequest {
@@ -958,13 +1045,9 @@ impl GammaClient {
             .await?;
 
         if !response.status().is_success() {
-            let status = response.status();
-            let body = response.text().await.unwrap_or_default();
-            log_warn!("Failed to add favorite event: {} - {}", status, body);
-            return Err(crate::error::PolymarketError::InvalidData(format!(
-                "Failed to add favorite event: {}",
-                status
-            )));
+            let error = crate::error::PolymarketError::from_response(response).await;
+            log_warn!("Failed to add favorite event: {}", error);
+            return Err(error);
         }
 
         let favorite: FavoriteEvent = response.json().await?;
@@ -981,7 +1064,7 @@ impl GammaClient {
             ));
         }
 
-        let url = format!("{}/favorite_events/{}", GAMMA_API_BASE, favorite_id);
+        let url = format!("{}/favorite_events/{}", self.base_url, favorite_id);
         let request_path = format!("/favorite_events/{}", favorite_id);
 
         log_info!("DELETE {} (cookie auth)", request_path);
@@ -991,13 +1074,9 @@ impl GammaClient {
         let response = self.client.delete(&url).headers(headers).send().await?;
 
         if !response.status().is_success() {
-            let status = response.status();
-            let body = response.text().await.unwrap_or_default();
-            log_warn!("Failed to remove favorite event: {} - {}", status, body);
-            return Err(crate::error::PolymarketError::InvalidData(format!(
-                "Failed to remove favorite event: {}",
-                status
-            )));
+            let error = crate::error::PolymarketError::from_response(response).await;
+            log_warn!("Failed to remove favorite event: {}", error);
+            return Err(error);
         }
 
         log_info!("Removed favorite event: {}", favorite_id);
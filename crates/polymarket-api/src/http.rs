@@ -0,0 +1,34 @@
+//! Shared HTTP client settings
+//!
+//! Every REST client gives up on a request after the request timeout, so a stalled API
+//! fails the call instead of leaving it pending. The default can be changed with an
+//! environment variable, or per client via `with_timeout`.
+
+use std::time::Duration;
+
+/// Default time allowed for a request, including reading the response
+pub const DEFAULT_REQUEST_TIMEOUT: Duration = Duration::from_secs(15);
+/// Environment variable overriding the request timeout, in seconds
+pub const REQUEST_TIMEOUT_ENV: &str = "POLYMARKET_REQUEST_TIMEOUT_SECS";
+
+/// Request timeout from `POLYMARKET_REQUEST_TIMEOUT_SECS`, or the default when unset or invalid
+pub fn request_timeout() -> Duration {
+    std::env::var(REQUEST_TIMEOUT_ENV)
+        .ok()
+        .and_then(|v| v.trim().parse::<u64>().ok())
+        .filter(|secs| *secs > 0)
+        .map_or(DEFAULT_REQUEST_TIMEOUT, Duration::from_secs)
+}
+
+/// HTTP client whose requests time out after `timeout`
+pub(crate) fn client_with_timeout(timeout: Duration) -> reqwest::Client {
+    reqwest::Client::builder()
+        .timeout(timeout)
+        .build()
+        .unwrap_or_default()
+}
+
+/// HTTP client using the configured request timeout
+pub(crate) fn client() -> reqwest::Client {
+    client_with_timeout(request_timeout())
+}
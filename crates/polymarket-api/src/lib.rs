@@ -2,8 +2,10 @@ pub mod cache;
 pub mod clob;
 pub mod data;
 pub mod display;
+pub mod endpoints;
 pub mod error;
 pub mod gamma;
+pub mod http;
 pub mod rtds;
 pub mod websocket;
 
@@ -12,8 +14,10 @@ pub use {
     clob::ClobClient,
     data::DataClient,
     display::{MarketUpdateFormatter, RTDSFormatter},
+    endpoints::Endpoints,
     error::{PolymarketError, Result, lock_mutex},
     gamma::{FavoriteEvent, GammaAuth, GammaClient},
+    http::request_timeout,
     rtds::{ActivityPayload, RTDSClient, RTDSMessage},
     websocket::PolymarketWebSocket,
 };
@@ -10,8 +10,6 @@ use {
 #[cfg(feature = "tracing")]
 use tracing::{debug, error, warn};
 
-const RTDS_WS_URL: &str = "wss://ws-live-data.polymarket.com/";
-
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RTDSSubscription {
     pub action: String, // "subscribe" or "unsubscribe"
@@ -85,6 +83,8 @@ pub struct RTDSClient {
     event_id: Option<u64>,
     clob_auth: Option<ClobAuth>,
     gamma_auth: Option<GammaAuth>,
+    /// WebSocket URL (defaults to the public RTDS endpoint)
+    url: String,
 }
 
 impl RTDSClient {
@@ -129,9 +129,16 @@ impl RTDSClient {
             event_id: None,
             clob_auth,
             gamma_auth,
+            url: crate::endpoints::rtds_ws_url(),
         }
     }
 
+    /// Use a custom WebSocket URL (e.g. a mirror or self-hosted proxy)
+    pub fn with_url(mut self, url: impl Into<String>) -> Self {
+        self.url = url.into();
+        self
+    }
+
     pub fn with_event_slug(mut self, event_slug: String) -> Self {
         self.event_slug = Some(event_slug);
         self
@@ -156,14 +163,28 @@ impl RTDSClient {
         self
     }
 
-    pub async fn connect_and_listen<F>(&self, mut on_update: F) -> Result<()>
+    pub async fn connect_and_listen<F>(&self, on_update: F) -> Result<()>
+    where
+        F: FnMut(RTDSMessage) + Send,
+    {
+        self.connect_and_listen_with_status(|| {}, on_update).await
+    }
+
+    /// Like [`connect_and_listen`](Self::connect_and_listen), but calls `on_connected` once the
+    /// socket is open and the subscription has been sent
+    pub async fn connect_and_listen_with_status<C, F>(
+        &self,
+        on_connected: C,
+        mut on_update: F,
+    ) -> Result<()>
     where
+        C: FnOnce() + Send,
         F: FnMut(RTDSMessage) + Send,
     {
         #[cfg(feature = "tracing")]
-        debug!("Connecting to RTDS WebSocket: {}", RTDS_WS_URL);
+        debug!("Connecting to RTDS WebSocket: {}", self.url);
 
-        let (ws_stream, _) = connect_async(RTDS_WS_URL).await.map_err(|e| {
+        let (ws_stream, _) = connect_async(self.url.as_str()).await.map_err(|e| {
             PolymarketError::WebSocket(format!("Failed to connect to RTDS WebSocket: {}", e))
         })?;
 
@@ -239,6 +260,8 @@ impl RTDSClient {
         #[cfg(feature = "tracing")]
         debug!("RTDS subscription sent successfully");
 
+        on_connected();
+
         // Start PING task (send PING every 5 seconds as per RTDS docs)
         let write_ping = Arc::clone(&write);
         let ping_handle = tokio::spawn(async move {
@@ -23,8 +23,6 @@ pub use {
     types::{ErrorMessage, OrderUpdate, OrderbookUpdate, PriceLevel, PriceUpdate, TradeUpdate},
 };
 
-const WS_URL: &str = "wss://ws-subscriptions-clob.polymarket.com/ws/market";
-
 /// Main WebSocket message enum that can represent any message type received from the API
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "type")]
@@ -49,6 +47,8 @@ pub enum WebSocketMessage {
 pub struct PolymarketWebSocket {
     pub(crate) asset_ids: Vec<String>,
     market_info_cache: HashMap<String, crate::gamma::MarketInfo>,
+    /// WebSocket URL (defaults to the public CLOB market channel)
+    url: String,
 }
 
 impl PolymarketWebSocket {
@@ -57,9 +57,16 @@ impl PolymarketWebSocket {
         Self {
             asset_ids,
             market_info_cache: HashMap::new(),
+            url: crate::endpoints::clob_ws_url(),
         }
     }
 
+    /// Use a custom WebSocket URL (e.g. a mirror or self-hosted proxy)
+    pub fn with_url(mut self, url: impl Into<String>) -> Self {
+        self.url = url.into();
+        self
+    }
+
     /// Connect to the WebSocket and listen for updates
     ///
     /// The callback function will be called for each message received.
@@ -67,7 +74,7 @@ impl PolymarketWebSocket {
     where
         F: FnMut(WebSocketMessage) + Send,
     {
-        let (ws_stream, _) = connect_async(WS_URL)
+        let (ws_stream, _) = connect_async(self.url.as_str())
             .await
             .context("Failed to connect to WebSocket")?;
 
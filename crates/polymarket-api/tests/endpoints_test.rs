@@ -0,0 +1,74 @@
+use polymarket_api::{
+    ClobClient, DataClient, GammaClient,
+    endpoints::{
+        self, DEFAULT_CLOB_API_URL, DEFAULT_DATA_API_URL, DEFAULT_GAMMA_API_URL, Endpoints,
+        EndpointsConfig, GAMMA_API_URL_ENV,
+    },
+};
+
+fn assert_with_base_url_trims_trailing_slash() {
+    let gamma = GammaClient::new().with_base_url("http://localhost:8080/");
+    assert_eq!(gamma.base_url(), "http://localhost:8080");
+
+    let data = DataClient::new().with_base_url("https://data.staging.example.com/");
+    assert_eq!(data.base_url(), "https://data.staging.example.com");
+
+    let clob = ClobClient::new().with_base_url("https://clob.staging.example.com");
+    assert_eq!(clob.base_url(), "https://clob.staging.example.com");
+}
+
+// Kept as a single test: mutating the environment while other test threads construct
+// clients (which read it) would race.
+#[test]
+fn test_endpoints_and_base_url_overrides() {
+    assert_with_base_url_trims_trailing_slash();
+
+    unsafe { std::env::remove_var(GAMMA_API_URL_ENV) };
+    let defaults = Endpoints::from_env();
+    assert_eq!(defaults.gamma_api.url, DEFAULT_GAMMA_API_URL);
+    assert!(!defaults.gamma_api.overridden);
+
+    unsafe { std::env::set_var(GAMMA_API_URL_ENV, " https://gamma.mirror.example.com/ ") };
+    let endpoints = Endpoints::from_env();
+    assert_eq!(endpoints.gamma_api.url, "https://gamma.mirror.example.com");
+    assert!(endpoints.gamma_api.overridden);
+    assert_eq!(
+        GammaClient::new().base_url(),
+        "https://gamma.mirror.example.com"
+    );
+    unsafe { std::env::remove_var(GAMMA_API_URL_ENV) };
+
+    // Unrelated endpoints keep their defaults
+    assert_eq!(endpoints.data_api.url, DEFAULT_DATA_API_URL);
+    assert_eq!(endpoints.clob_api.url, DEFAULT_CLOB_API_URL);
+
+    // Configured URLs apply when the environment doesn't override them
+    assert!(endpoints::configure(EndpointsConfig {
+        clob_base_url: Some("https://clob.config.example.com/".to_string()),
+        gamma_base_url: Some("https://gamma.config.example.com".to_string()),
+        ..Default::default()
+    }));
+    let configured = Endpoints::from_env();
+    assert_eq!(configured.clob_api.url, "https://clob.config.example.com");
+    assert!(configured.clob_api.overridden);
+    assert_eq!(configured.data_api.url, DEFAULT_DATA_API_URL);
+
+    unsafe { std::env::set_var(GAMMA_API_URL_ENV, "https://gamma.env.example.com") };
+    assert_eq!(
+        GammaClient::new().base_url(),
+        "https://gamma.env.example.com"
+    );
+    unsafe { std::env::remove_var(GAMMA_API_URL_ENV) };
+    assert_eq!(
+        GammaClient::new().base_url(),
+        "https://gamma.config.example.com"
+    );
+    // An empty variable doesn't hide the configured URL
+    unsafe { std::env::set_var(GAMMA_API_URL_ENV, "  ") };
+    assert_eq!(
+        GammaClient::new().base_url(),
+        "https://gamma.config.example.com"
+    );
+    unsafe { std::env::remove_var(GAMMA_API_URL_ENV) };
+    assert!(!endpoints::configure(EndpointsConfig::default()));
+}
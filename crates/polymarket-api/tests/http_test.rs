@@ -0,0 +1,18 @@
+use polymarket_api::http::{DEFAULT_REQUEST_TIMEOUT, REQUEST_TIMEOUT_ENV, request_timeout};
+
+// Kept as a single test: the cases mutate the same environment variable.
+#[test]
+fn test_request_timeout_from_env() {
+    unsafe { std::env::remove_var(REQUEST_TIMEOUT_ENV) };
+    assert_eq!(request_timeout(), DEFAULT_REQUEST_TIMEOUT);
+
+    unsafe { std::env::set_var(REQUEST_TIMEOUT_ENV, " 30 ") };
+    assert_eq!(request_timeout().as_secs(), 30);
+
+    // Zero or unparsable values keep the default
+    for invalid in ["0", "soon", "-5"] {
+        unsafe { std::env::set_var(REQUEST_TIMEOUT_ENV, invalid) };
+        assert_eq!(request_timeout(), DEFAULT_REQUEST_TIMEOUT);
+    }
+    unsafe { std::env::remove_var(REQUEST_TIMEOUT_ENV) };
+}